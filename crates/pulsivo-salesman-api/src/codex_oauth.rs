@@ -26,6 +26,7 @@ const DEFAULT_REDIRECT_URI: &str = "http://localhost:1455/auth/callback";
 const DEFAULT_SCOPES: &str = "openid profile email offline_access model.request";
 const REQUIRED_SCOPE_MODEL_REQUEST: &str = "model.request";
 const MAX_PENDING_AGE_SECS: i64 = 15 * 60;
+const DEFAULT_TOKEN_TIMEOUT_SECS: u64 = 15;
 
 #[derive(Debug, Clone)]
 struct PendingPkce {
@@ -180,6 +181,7 @@ fn apply_codex_auth_to_runtime(state: &AppState, auth: &StoredCodexAuth) {
         .write()
         .unwrap_or_else(|e| e.into_inner())
         .detect_auth();
+    refresh_llm_driver_pool(state);
 }
 
 fn clear_codex_auth_from_runtime(state: &AppState) {
@@ -191,6 +193,20 @@ fn clear_codex_auth_from_runtime(state: &AppState) {
         .write()
         .unwrap_or_else(|e| e.into_inner())
         .detect_auth();
+    refresh_llm_driver_pool(state);
+}
+
+/// Drop pooled drivers built with the old credentials and warm new ones in
+/// the background.
+fn refresh_llm_driver_pool(state: &AppState) {
+    let Some(pool) = &state.kernel.llm_driver_pool else {
+        return;
+    };
+    pool.invalidate();
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        let kernel = state.kernel.clone();
+        handle.spawn(async move { crate::sales::warm_sales_llm_drivers(&kernel).await });
+    }
 }
 
 fn parse_jwt_payload(jwt: &str) -> Option<serde_json::Value> {
@@ -331,7 +347,7 @@ fn hydrate_scope_from_tokens(auth: &mut StoredCodexAuth) {
     }
 }
 
-fn missing_org_context_error() -> String {
+pub(crate) fn missing_org_context_error() -> String {
     "OAuth token is missing organization context (chatgpt_account_id). Reconnect from Sales > Connect OAuth or import ~/.codex/auth.json.".to_string()
 }
 
@@ -427,13 +443,50 @@ fn oauth_client_id(req: &StartCodexOAuthRequest) -> String {
         .unwrap_or_else(|| DEFAULT_CLIENT_ID.to_string())
 }
 
-fn oauth_redirect_uri(_state: &AppState, req: &StartCodexOAuthRequest) -> String {
-    req.redirect_uri
-        .clone()
-        .filter(|s| !s.trim().is_empty())
-        .or_else(|| std::env::var("OPENAI_OAUTH_REDIRECT_URI").ok())
-        .filter(|s| !s.trim().is_empty())
-        .unwrap_or_else(|| DEFAULT_REDIRECT_URI.to_string())
+fn oauth_env_redirect_uri() -> Option<String> {
+    std::env::var("OPENAI_OAUTH_REDIRECT_URI")
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Resolve the redirect URI for an OAuth start request, rejecting values
+/// outside the configured allowlist.
+fn oauth_redirect_uri(state: &AppState, req: &StartCodexOAuthRequest) -> Result<String, String> {
+    let redirect_uri = req
+        .redirect_uri
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .or_else(oauth_env_redirect_uri)
+        .unwrap_or_else(|| DEFAULT_REDIRECT_URI.to_string());
+    let mut allowlist = state.kernel.config_snapshot().oauth.redirect_uri_allowlist;
+    if allowlist.is_empty() {
+        allowlist.push(DEFAULT_REDIRECT_URI.to_string());
+    }
+    allowlist.extend(oauth_env_redirect_uri());
+    validate_oauth_redirect_uri(&redirect_uri, &allowlist)?;
+    Ok(redirect_uri)
+}
+
+/// Accept `redirect_uri` only if it matches an allowlisted URI after URL
+/// normalization (scheme/host case, default port).
+fn validate_oauth_redirect_uri(redirect_uri: &str, allowlist: &[String]) -> Result<(), String> {
+    let parsed = url::Url::parse(redirect_uri)
+        .map_err(|e| format!("Invalid redirect_uri '{redirect_uri}': {e}"))?;
+    let allowed = allowlist.iter().any(|entry| {
+        url::Url::parse(entry.trim())
+            .map(|candidate| candidate == parsed)
+            .unwrap_or(false)
+    });
+    if allowed {
+        Ok(())
+    } else {
+        Err(format!(
+            "redirect_uri '{redirect_uri}' is not allowed; add it to [oauth] redirect_uri_allowlist"
+        ))
+    }
 }
 
 fn oauth_auth_url() -> String {
@@ -584,62 +637,137 @@ fn url_encode(s: &str) -> String {
     url::form_urlencoded::byte_serialize(s.as_bytes()).collect()
 }
 
-async fn exchange_code(
-    code: &str,
-    verifier: &str,
-    redirect_uri: &str,
-    client_id: &str,
-    source: &str,
-) -> Result<StoredCodexAuth, String> {
-    let client = reqwest::Client::new();
+/// Per-request timeout for token endpoint calls (`OPENAI_OAUTH_TOKEN_TIMEOUT_SECS`).
+fn oauth_token_timeout() -> std::time::Duration {
+    let secs = std::env::var("OPENAI_OAUTH_TOKEN_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_TOKEN_TIMEOUT_SECS);
+    std::time::Duration::from_secs(secs)
+}
+
+/// Whether token endpoints are raced concurrently (`OPENAI_OAUTH_TOKEN_RACE`).
+fn oauth_token_race() -> bool {
+    std::env::var("OPENAI_OAUTH_TOKEN_RACE")
+        .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Host of a token endpoint, used to tag the auth `source`.
+fn token_endpoint_host(token_url: &str) -> String {
+    url::Url::parse(token_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| token_url.to_string())
+}
+
+async fn post_token_form(
+    client: &reqwest::Client,
+    token_url: &str,
+    form: &[(&str, &str)],
+) -> Result<TokenResponse, String> {
+    let resp = client
+        .post(token_url)
+        .form(form)
+        .send()
+        .await
+        .map_err(|e| format!("{token_url}: request failed: {e}"))?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("{token_url}: {status} {body}"));
+    }
+    resp.json::<TokenResponse>()
+        .await
+        .map_err(|e| format!("{token_url}: parse failed: {e}"))
+}
+
+/// POST `form` to the configured token endpoints; see [`request_token_from`].
+async fn request_token(form: &[(&str, &str)]) -> Result<(TokenResponse, String), Vec<String>> {
+    request_token_from(
+        &oauth_token_urls(),
+        oauth_token_race(),
+        oauth_token_timeout(),
+        form,
+    )
+    .await
+}
+
+/// POST `form` to `token_urls`, each request bounded by `timeout`. Endpoints
+/// are tried in order, or raced when `race` is set; the first success wins.
+/// Returns the token and the endpoint that issued it, or every endpoint's error.
+async fn request_token_from(
+    token_urls: &[String],
+    race: bool,
+    timeout: std::time::Duration,
+    form: &[(&str, &str)],
+) -> Result<(TokenResponse, String), Vec<String>> {
+    use futures::stream::{FuturesUnordered, StreamExt};
+
+    let client = pulsivo_salesman_runtime::http_client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| vec![format!("Failed to build HTTP client: {e}")])?;
     let mut errors: Vec<String> = Vec::new();
-    let mut token: Option<TokenResponse> = None;
-
-    for token_url in oauth_token_urls() {
-        let resp = match client
-            .post(&token_url)
-            .form(&[
-                ("grant_type", "authorization_code"),
-                ("code", code),
-                ("redirect_uri", redirect_uri),
-                ("client_id", client_id),
-                ("code_verifier", verifier),
-            ])
-            .send()
-            .await
-        {
-            Ok(resp) => resp,
-            Err(e) => {
-                errors.push(format!("{token_url}: request failed: {e}"));
-                continue;
-            }
-        };
 
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let body = resp.text().await.unwrap_or_default();
-            errors.push(format!("{token_url}: {status} {body}"));
-            continue;
+    if race && token_urls.len() > 1 {
+        let mut pending: FuturesUnordered<_> = token_urls
+            .iter()
+            .map(|token_url| {
+                let client = &client;
+                async move {
+                    (
+                        token_url.clone(),
+                        post_token_form(client, token_url, form).await,
+                    )
+                }
+            })
+            .collect();
+        while let Some((token_url, result)) = pending.next().await {
+            match result {
+                Ok(token) => return Ok((token, token_url)),
+                Err(e) => errors.push(e),
+            }
         }
+        return Err(errors);
+    }
 
-        match resp.json::<TokenResponse>().await {
-            Ok(t) => {
-                token = Some(t);
-                break;
-            }
+    for token_url in token_urls {
+        match post_token_form(&client, token_url, form).await {
+            Ok(token) => return Ok((token, token_url.clone())),
             Err(e) => {
-                errors.push(format!("{token_url}: parse failed: {e}"));
-                continue;
+                warn!("OAuth token endpoint failed, trying next: {e}");
+                errors.push(e);
             }
         }
     }
+    Err(errors)
+}
 
-    let token = token.ok_or_else(|| {
+async fn exchange_code(
+    code: &str,
+    verifier: &str,
+    redirect_uri: &str,
+    client_id: &str,
+    source: &str,
+) -> Result<StoredCodexAuth, String> {
+    let (token, token_url) = request_token(&[
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", redirect_uri),
+        ("client_id", client_id),
+        ("code_verifier", verifier),
+    ])
+    .await
+    .map_err(|errors| {
         format!(
             "Token exchange failed on all token endpoints: {}",
             errors.join(" | ")
         )
     })?;
+    let endpoint = token_endpoint_host(&token_url);
+    info!(endpoint = %endpoint, "Codex OAuth code exchanged");
 
     let issued_at = Utc::now();
     let expires_at = token
@@ -669,52 +797,30 @@ async fn exchange_code(
         client_id: derived_client_id,
         issued_at,
         expires_at,
-        source: source.to_string(),
+        source: format!("{source}@{endpoint}"),
     })
 }
 
+/// Refresh an access token. Returns the token and the endpoint host that issued it.
 async fn refresh_access_token(
     refresh_token: &str,
     client_id: &str,
-) -> Result<TokenResponse, String> {
-    let client = reqwest::Client::new();
-    let mut errors: Vec<String> = Vec::new();
-
-    for token_url in oauth_token_urls() {
-        let resp = match client
-            .post(&token_url)
-            .form(&[
-                ("grant_type", "refresh_token"),
-                ("refresh_token", refresh_token),
-                ("client_id", client_id),
-            ])
-            .send()
-            .await
-        {
-            Ok(resp) => resp,
-            Err(e) => {
-                errors.push(format!("{token_url}: request failed: {e}"));
-                continue;
-            }
-        };
-
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let body = resp.text().await.unwrap_or_default();
-            errors.push(format!("{token_url}: {status} {body}"));
-            continue;
-        }
-
-        return resp
-            .json::<TokenResponse>()
-            .await
-            .map_err(|e| format!("Refresh response parse failed: {e}"));
-    }
-
-    Err(format!(
-        "Refresh failed on all token endpoints: {}",
-        errors.join(" | ")
-    ))
+) -> Result<(TokenResponse, String), String> {
+    let (token, token_url) = request_token(&[
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+        ("client_id", client_id),
+    ])
+    .await
+    .map_err(|errors| {
+        format!(
+            "Refresh failed on all token endpoints: {}",
+            errors.join(" | ")
+        )
+    })?;
+    let endpoint = token_endpoint_host(&token_url);
+    info!(endpoint = %endpoint, "Codex OAuth token refreshed");
+    Ok((token, endpoint))
 }
 
 fn update_auth_from_token(auth: &mut StoredCodexAuth, token: TokenResponse, source: &str) {
@@ -752,8 +858,8 @@ pub(crate) async fn refresh_auth_if_possible(
     };
     let client_id = auth_client_id(auth, fallback_client_id);
     match refresh_access_token(&refresh, &client_id).await {
-        Ok(token) => {
-            update_auth_from_token(auth, token, "refresh_token");
+        Ok((token, endpoint)) => {
+            update_auth_from_token(auth, token, &format!("refresh_token@{endpoint}"));
             if auth.client_id.is_none() {
                 auth.client_id = Some(client_id);
             }
@@ -865,7 +971,10 @@ pub fn has_codex_cli_auth(home_dir: &Path) -> bool {
     find_codex_cli_auth_path(home_dir).is_some()
 }
 
-pub fn load_preferred_codex_auth(home_dir: &Path) -> Result<Option<StoredCodexAuth>, String> {
+pub fn load_preferred_codex_auth(
+    home_dir: &Path,
+    import_cli: bool,
+) -> Result<Option<StoredCodexAuth>, String> {
     if logout_marker_exists(home_dir) {
         return Ok(None);
     }
@@ -874,7 +983,7 @@ pub fn load_preferred_codex_auth(home_dir: &Path) -> Result<Option<StoredCodexAu
         return Ok(Some(auth));
     }
 
-    if has_codex_cli_auth(home_dir) {
+    if import_cli && has_codex_cli_auth(home_dir) {
         return import_codex_cli_auth(home_dir).map(Some);
     }
 
@@ -958,11 +1067,17 @@ pub fn import_codex_cli_auth(home_dir: &Path) -> Result<StoredCodexAuth, String>
 }
 
 pub(crate) async fn initialize_codex_auth(state: &AppState) {
+    if state.kernel.config_snapshot().offline {
+        info!("Offline mode: skipping Codex OAuth initialization");
+        refresh_llm_driver_pool(state);
+        return;
+    }
     let home = state.kernel.home_dir();
     let fallback_client_id =
         std::env::var("OPENAI_OAUTH_CLIENT_ID").unwrap_or_else(|_| DEFAULT_CLIENT_ID.to_string());
 
-    let mut auth = match load_preferred_codex_auth(&home) {
+    let import_cli = state.kernel.config_snapshot().oauth.import_codex_cli_auth;
+    let mut auth = match load_preferred_codex_auth(&home, import_cli) {
         Ok(Some(auth)) => auth,
         Ok(None) => {
             clear_codex_auth_from_runtime(state);
@@ -1000,7 +1115,16 @@ pub async fn codex_oauth_start(
     let req = body.map(|b| b.0).unwrap_or_default();
 
     let client_id = oauth_client_id(&req);
-    let redirect_uri = oauth_redirect_uri(&state, &req);
+    let redirect_uri = match oauth_redirect_uri(&state, &req) {
+        Ok(redirect_uri) => redirect_uri,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e })),
+            )
+                .into_response();
+        }
+    };
     if let Err(e) = ensure_loopback_callback_listener(state.clone(), &redirect_uri).await {
         return (
             StatusCode::BAD_REQUEST,
@@ -1264,7 +1388,22 @@ pub async fn codex_oauth_import_cli(State(state): State<Arc<AppState>>) -> impl
     }
 }
 
-pub async fn codex_oauth_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+pub async fn codex_oauth_status(
+    State(state): State<Arc<AppState>>,
+    extensions: axum::http::Extensions,
+) -> impl IntoResponse {
+    if state.kernel.config_snapshot().offline {
+        return (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "connected": false,
+                "provider": "openai-codex",
+                "model": "gpt-5.3-codex",
+                "reason": crate::offline::offline_error("Codex OAuth"),
+                "source": "offline"
+            })),
+        );
+    }
     let home = state.kernel.home_dir();
     let fallback_client_id =
         std::env::var("OPENAI_OAUTH_CLIENT_ID").unwrap_or_else(|_| DEFAULT_CLIENT_ID.to_string());
@@ -1284,6 +1423,17 @@ pub async fn codex_oauth_status(State(state): State<Arc<AppState>>) -> impl Into
                     })),
                 );
             }
+            if !state.kernel.config_snapshot().oauth.import_codex_cli_auth {
+                clear_codex_auth_from_runtime(&state);
+                return (
+                    StatusCode::OK,
+                    Json(serde_json::json!({
+                        "connected": false,
+                        "provider": "openai-codex",
+                        "model": "gpt-5.3-codex"
+                    })),
+                );
+            }
             match import_codex_cli_auth(&home) {
                 Ok(mut auth) => {
                     if let Err(e) =
@@ -1368,16 +1518,159 @@ pub async fn codex_oauth_status(State(state): State<Arc<AppState>>) -> impl Into
 
     apply_codex_auth_to_runtime(&state, &auth);
 
+    let mut status = serde_json::json!({
+        "connected": true,
+        "provider": "openai-codex",
+        "model": "gpt-5.3-codex",
+        "source": auth.source,
+        "issued_at": auth.issued_at.to_rfc3339(),
+        "expires_at": auth.expires_at.map(|d| d.to_rfc3339()),
+        "has_refresh_token": auth.refresh_token.is_some(),
+    });
+    if caller_may_see_account(&extensions) {
+        status["account"] = codex_account_summary(&auth);
+    }
+    (StatusCode::OK, Json(status))
+}
+
+/// The status route is public, so the connected account is only shown to
+/// loopback callers and requests the auth middleware resolved a token for.
+fn caller_may_see_account(extensions: &axum::http::Extensions) -> bool {
+    extensions
+        .get::<crate::middleware::AuthPrincipal>()
+        .is_some()
+        || extensions
+            .get::<axum::extract::ConnectInfo<SocketAddr>>()
+            .is_some_and(|ci| ci.0.ip().is_loopback())
+}
+
+/// Show the leading characters and length of a secret, never the full value.
+fn redact_secret(value: &str) -> String {
+    let value = value.trim();
+    let prefix: String = value.chars().take(4).collect();
+    format!("{prefix}… ({} chars)", value.chars().count())
+}
+
+/// Mask the local part of an email, keeping its first character and domain.
+fn redact_email(email: &str) -> String {
+    match email.trim().split_once('@') {
+        Some((local, domain)) => {
+            let first: String = local.chars().take(1).collect();
+            format!("{first}***@{domain}")
+        }
+        None => redact_secret(email),
+    }
+}
+
+/// First non-empty string claim at any of `pointers`, checking the id token
+/// before the access token.
+fn auth_claim(auth: &StoredCodexAuth, pointers: &[&str]) -> Option<String> {
+    [auth.id_token.as_deref(), Some(auth.access_token.as_str())]
+        .into_iter()
+        .flatten()
+        .filter_map(parse_jwt_payload)
+        .find_map(|payload| {
+            pointers.iter().find_map(|pointer| {
+                payload
+                    .pointer(pointer)
+                    .and_then(|v| v.as_str())
+                    .map(str::trim)
+                    .filter(|v| !v.is_empty())
+                    .map(ToString::to_string)
+            })
+        })
+}
+
+/// Which ChatGPT account the stored tokens belong to, from their claims:
+/// masked email, display name, organization, plan and a redacted account id.
+fn codex_account_summary(auth: &StoredCodexAuth) -> serde_json::Value {
+    let organization = [auth.id_token.as_deref(), Some(auth.access_token.as_str())]
+        .into_iter()
+        .flatten()
+        .filter_map(parse_jwt_payload)
+        .find_map(|payload| {
+            let orgs = payload
+                .pointer("/https:~1~1api.openai.com~1auth/organizations")?
+                .as_array()?
+                .clone();
+            orgs.iter()
+                .find(|org| org.get("is_default").and_then(|v| v.as_bool()) == Some(true))
+                .or_else(|| orgs.first())
+                .and_then(|org| org.get("title").and_then(|v| v.as_str()))
+                .map(ToString::to_string)
+        });
+    serde_json::json!({
+        "email": auth_claim(auth, &["/email", "/https:~1~1api.openai.com~1profile/email"])
+            .map(|email| redact_email(&email)),
+        "name": auth_claim(auth, &["/name"]),
+        "organization": organization,
+        "plan": auth_claim(auth, &["/https:~1~1api.openai.com~1auth/chatgpt_plan_type"]),
+        "account_id": auth_account_id(auth).map(|id| redact_secret(&id)),
+    })
+}
+
+/// Summarize a JWT's `exp` and account claim without exposing the token.
+fn token_debug_summary(token: &str) -> serde_json::Value {
+    let exp = parse_jwt_payload(token)
+        .and_then(|payload| payload.get("exp").and_then(|v| v.as_i64()))
+        .and_then(|exp| DateTime::<Utc>::from_timestamp(exp, 0));
+    serde_json::json!({
+        "redacted": redact_secret(token),
+        "is_jwt": parse_jwt_payload(token).is_some(),
+        "exp": exp.map(|d| d.to_rfc3339()),
+        "expired": exp.map(|d| d <= Utc::now()),
+        "has_chatgpt_account_id_claim": jwt_chatgpt_account_id(token).is_some(),
+    })
+}
+
+/// GET /api/auth/codex/debug — redacted view of the auth state the Codex
+/// driver sees (env vars, stored auth file, token claims, client id).
+pub async fn codex_oauth_debug(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let home = state.kernel.home_dir();
+    let env_var = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let env_token = env_var("OPENAI_CODEX_ACCESS_TOKEN");
+    let env_account_id = env_var("OPENAI_CODEX_ACCOUNT_ID");
+    let fallback_client_id =
+        env_var("OPENAI_OAUTH_CLIENT_ID").unwrap_or_else(|| DEFAULT_CLIENT_ID.to_string());
+
+    let path = auth_file(&home);
+    let (stored, stored_error) = match load_stored_auth(&home) {
+        Ok(auth) => (auth, None),
+        Err(e) => (None, Some(e)),
+    };
+    let client_id = stored
+        .as_ref()
+        .map(|auth| auth_client_id(auth, &fallback_client_id))
+        .unwrap_or_else(|| fallback_client_id.clone());
+
     (
         StatusCode::OK,
         Json(serde_json::json!({
-            "connected": true,
-            "provider": "openai-codex",
-            "model": "gpt-5.3-codex",
-            "source": auth.source,
-            "issued_at": auth.issued_at.to_rfc3339(),
-            "expires_at": auth.expires_at.map(|d| d.to_rfc3339()),
-            "has_refresh_token": auth.refresh_token.is_some(),
+            "env": {
+                "OPENAI_CODEX_ACCESS_TOKEN": env_token.as_deref().map(token_debug_summary),
+                "OPENAI_CODEX_ACCOUNT_ID": env_account_id.as_deref().map(redact_secret),
+                "OPENAI_OAUTH_CLIENT_ID_set": env_var("OPENAI_OAUTH_CLIENT_ID").is_some(),
+            },
+            "stored": {
+                "path": path.display().to_string(),
+                "exists": path.exists(),
+                "error": stored_error,
+                "source": stored.as_ref().map(|auth| auth.source.clone()),
+                "expires_at": stored.as_ref().and_then(|auth| auth.expires_at.map(|d| d.to_rfc3339())),
+                "has_refresh_token": stored.as_ref().map(|auth| auth.refresh_token.is_some()),
+                "has_account_id": stored.as_ref().map(|auth| auth_account_id(auth).is_some()),
+                "access_token": stored.as_ref().map(|auth| token_debug_summary(&auth.access_token)),
+                "account": stored.as_ref().map(codex_account_summary),
+            },
+            "logged_out": logout_marker_exists(&home),
+            "codex_cli_auth_found": has_codex_cli_auth(&home),
+            "codex_cli_auto_import": state.kernel.config_snapshot().oauth.import_codex_cli_auth,
+            "client_id": client_id,
         })),
     )
 }
@@ -1399,3 +1692,223 @@ pub async fn codex_oauth_logout(State(state): State<Arc<AppState>>) -> impl Into
         Json(serde_json::json!({"status": "logged_out"})),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redirect_uri_must_match_allowlist() {
+        let allowlist = vec![DEFAULT_REDIRECT_URI.to_string()];
+        assert!(validate_oauth_redirect_uri(DEFAULT_REDIRECT_URI, &allowlist).is_ok());
+        assert!(
+            validate_oauth_redirect_uri("HTTP://LOCALHOST:1455/auth/callback", &allowlist).is_ok()
+        );
+        assert!(
+            validate_oauth_redirect_uri("http://localhost:1456/auth/callback", &allowlist).is_err()
+        );
+        assert!(
+            validate_oauth_redirect_uri("https://evil.example/auth/callback", &allowlist).is_err()
+        );
+        assert!(validate_oauth_redirect_uri("not a url", &allowlist).is_err());
+    }
+
+    #[test]
+    fn test_debug_summary_redacts_tokens() {
+        let payload = base64_url_encode(
+            br#"{"exp":1700000000,"https://api.openai.com/auth":{"chatgpt_account_id":"acct_123"}}"#,
+        );
+        let token = format!("eyJhbGciOiJub25lIn0.{payload}.signature");
+        let summary = token_debug_summary(&token);
+        assert_eq!(summary["exp"], "2023-11-14T22:13:20+00:00");
+        assert_eq!(summary["expired"], true);
+        assert_eq!(summary["has_chatgpt_account_id_claim"], true);
+        assert!(!summary.to_string().contains(&payload));
+        assert_eq!(redact_secret("secret-value"), "secr… (12 chars)");
+    }
+
+    #[test]
+    fn test_account_summary_masks_identity_claims() {
+        let jwt = |claims: &[u8]| format!("eyJhbGciOiJub25lIn0.{}.sig", base64_url_encode(claims));
+        let id_token = jwt(br#"{"email":"aylin@machinity.ai","name":"Aylin Demir","https://api.openai.com/auth":{"chatgpt_account_id":"acct_1234567890","chatgpt_plan_type":"team","organizations":[{"id":"org-1","title":"Personal","is_default":false},{"id":"org-2","title":"Machinity","is_default":true}]}}"#);
+        let auth = StoredCodexAuth {
+            openai_api_key: None,
+            chatgpt_account_id: None,
+            access_token: jwt(br#"{"exp":1700000000}"#),
+            refresh_token: Some("refresh-secret".to_string()),
+            id_token: Some(id_token.clone()),
+            token_type: "Bearer".to_string(),
+            scope: String::new(),
+            client_id: None,
+            issued_at: Utc::now(),
+            expires_at: None,
+            source: "oauth".to_string(),
+        };
+        let account = codex_account_summary(&auth);
+        assert_eq!(account["email"], "a***@machinity.ai");
+        assert_eq!(account["name"], "Aylin Demir");
+        assert_eq!(account["organization"], "Machinity");
+        assert_eq!(account["plan"], "team");
+        assert_eq!(account["account_id"], "acct… (15 chars)");
+        let rendered = account.to_string();
+        assert!(!rendered.contains("acct_1234567890"));
+        assert!(!rendered.contains(&id_token));
+        assert!(!rendered.contains("refresh-secret"));
+    }
+
+    #[tokio::test]
+    async fn test_status_shows_account_only_to_known_callers() {
+        use axum::extract::ConnectInfo;
+        use axum::routing::get;
+        use tower::ServiceExt;
+
+        let temp = tempfile::tempdir().unwrap();
+        let kernel = pulsivo_salesman_kernel::PulsivoSalesmanKernel::boot_with_config(
+            pulsivo_salesman_types::config::KernelConfig {
+                home_dir: temp.path().to_path_buf(),
+                data_dir: temp.path().join("data"),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let state = Arc::new(AppState {
+            kernel: Arc::new(kernel),
+            started_at: std::time::Instant::now(),
+            shutdown_notify: Arc::new(tokio::sync::Notify::new()),
+            sales_runs: crate::sales::SalesRunSlot::default(),
+        });
+        let jwt = |claims: &[u8]| format!("eyJhbGciOiJub25lIn0.{}.sig", base64_url_encode(claims));
+        let token = jwt(br#"{"email":"aylin@machinity.ai","name":"Aylin Demir","https://api.openai.com/auth":{"chatgpt_account_id":"acct_1234567890","chatgpt_plan_type":"team"}}"#);
+        save_stored_auth(
+            temp.path(),
+            &StoredCodexAuth {
+                openai_api_key: None,
+                chatgpt_account_id: None,
+                access_token: token.clone(),
+                refresh_token: None,
+                id_token: Some(token),
+                token_type: "Bearer".to_string(),
+                scope: String::new(),
+                client_id: Some(DEFAULT_CLIENT_ID.to_string()),
+                issued_at: Utc::now(),
+                expires_at: None,
+                source: "oauth".to_string(),
+            },
+        )
+        .unwrap();
+
+        let app = axum::Router::new()
+            .route("/api/auth/codex/status", get(codex_oauth_status))
+            .layer(axum::middleware::from_fn_with_state(
+                crate::middleware::ApiAuth {
+                    api_key: "secret".to_string(),
+                    tokens: Vec::new(),
+                    metrics_loopback_public: false,
+                },
+                crate::middleware::auth,
+            ))
+            .with_state(state.clone());
+        let status = |peer: [u8; 4], token: Option<&str>| {
+            let mut request = axum::http::Request::get("/api/auth/codex/status");
+            if let Some(token) = token {
+                request = request.header("authorization", format!("Bearer {token}"));
+            }
+            let mut request = request.body(axum::body::Body::empty()).unwrap();
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from((peer, 40000))));
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+            }
+        };
+
+        let remote = status([203, 0, 113, 7], None).await;
+        let authed = status([203, 0, 113, 7], Some("secret")).await;
+        let local = status([127, 0, 0, 1], None).await;
+        clear_codex_auth_from_runtime(&state);
+
+        assert_eq!(remote["connected"], true);
+        assert!(remote.get("account").is_none());
+        let rendered = remote.to_string();
+        for identity in ["machinity.ai", "Aylin", "acct", "team"] {
+            assert!(!rendered.contains(identity), "status leaked {identity}");
+        }
+        assert_eq!(authed["account"]["email"], "a***@machinity.ai");
+        assert_eq!(local["account"]["name"], "Aylin Demir");
+    }
+
+    #[test]
+    fn test_cli_auth_is_not_imported_when_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".codex")).unwrap();
+        std::fs::write(
+            dir.path().join(".codex").join("auth.json"),
+            r#"{"tokens":{"access_token":"cli-token"}}"#,
+        )
+        .unwrap();
+        assert!(has_codex_cli_auth(dir.path()));
+        assert!(load_preferred_codex_auth(dir.path(), false)
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_token_request_races_past_a_hung_endpoint() {
+        use axum::routing::post;
+
+        async fn serve(app: axum::Router) -> String {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                let _ = axum::serve(listener, app).await;
+            });
+            format!("http://{addr}/oauth/token")
+        }
+
+        let hung = serve(axum::Router::new().route(
+            "/oauth/token",
+            post(|| async {
+                tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+                "{}"
+            }),
+        ))
+        .await;
+        let fast = serve(axum::Router::new().route(
+            "/oauth/token",
+            post(|| async {
+                Json(serde_json::json!({"access_token": "fast-token", "expires_in": 60}))
+            }),
+        ))
+        .await;
+
+        let form = [("grant_type", "refresh_token")];
+        let timeout = std::time::Duration::from_secs(1);
+        let urls = vec![hung.clone(), fast.clone()];
+
+        let started = std::time::Instant::now();
+        let (token, endpoint) = request_token_from(&urls, true, timeout, &form)
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "fast-token");
+        assert_eq!(endpoint, fast);
+        assert!(started.elapsed() < timeout);
+
+        let (token, endpoint) = request_token_from(&urls, false, timeout, &form)
+            .await
+            .unwrap();
+        assert_eq!(token.access_token, "fast-token");
+        assert_eq!(endpoint, fast);
+
+        let errors = request_token_from(&[hung], false, timeout, &form)
+            .await
+            .unwrap_err();
+        assert!(errors[0].contains("request failed"), "{errors:?}");
+        assert_eq!(token_endpoint_host(&fast), "127.0.0.1");
+    }
+}
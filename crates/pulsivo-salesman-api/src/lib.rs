@@ -1,9 +1,13 @@
 //! Sales-focused HTTP API server for the PulsivoSalesman daemon.
 
 pub mod codex_oauth;
+pub mod logging;
+pub mod metrics;
 pub mod middleware;
+pub mod offline;
 pub mod rate_limiter;
 pub mod routes;
+pub mod run_limiter;
 pub mod sales;
 pub mod server;
 pub mod webchat;
@@ -0,0 +1,121 @@
+//! Process-wide tracing subscriber with a runtime-adjustable filter.
+//!
+//! The daemon entrypoint calls [`init_logging`] once with the configured
+//! `log_level` and `log_format`. The format is fixed for the lifetime of the
+//! process; the filter sits behind a `reload` layer so that
+//! `POST /api/admin/log-level` can swap it without a restart.
+
+use pulsivo_salesman_types::config::LogFormat;
+use std::sync::{Mutex, OnceLock};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
+
+struct LogReload {
+    handle: reload::Handle<EnvFilter, Registry>,
+    /// Directive string currently applied, echoed back by the admin API.
+    current: Mutex<String>,
+}
+
+static LOG_RELOAD: OnceLock<LogReload> = OnceLock::new();
+
+/// Parse a filter directive such as `info` or `warn,pulsivo_salesman_api=debug`.
+pub fn parse_log_filter(directives: &str) -> Result<EnvFilter, String> {
+    let directives = directives.trim();
+    if directives.is_empty() {
+        return Err("log level must not be empty".to_string());
+    }
+    EnvFilter::try_new(directives).map_err(|e| format!("invalid log filter '{directives}': {e}"))
+}
+
+/// Install the global subscriber. `RUST_LOG`, when set, wins over `level`.
+///
+/// Fails if the level is invalid or a global subscriber is already installed.
+pub fn init_logging(level: &str, format: LogFormat) -> Result<(), String> {
+    let directives = std::env::var("RUST_LOG")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| level.to_string());
+    let filter = parse_log_filter(&directives)?;
+    let (filter_layer, handle) = reload::Layer::new(filter);
+    let registry = tracing_subscriber::registry().with(filter_layer);
+    let installed = match format {
+        LogFormat::Pretty => registry.with(fmt::layer()).try_init(),
+        LogFormat::Json => registry.with(fmt::layer().json()).try_init(),
+    };
+    installed.map_err(|e| format!("failed to install log subscriber: {e}"))?;
+
+    let _ = LOG_RELOAD.set(LogReload {
+        handle,
+        current: Mutex::new(directives.trim().to_string()),
+    });
+    Ok(())
+}
+
+/// Filter directives currently in effect, if [`init_logging`] ran.
+pub fn current_log_level() -> Option<String> {
+    LOG_RELOAD.get().map(|reload| {
+        reload
+            .current
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    })
+}
+
+/// Swap the active filter. Returns the applied directive string.
+pub fn set_log_level(directives: &str) -> Result<String, LogLevelError> {
+    let filter = parse_log_filter(directives).map_err(LogLevelError::Invalid)?;
+    let reload = LOG_RELOAD.get().ok_or(LogLevelError::Unavailable)?;
+    reload
+        .handle
+        .reload(filter)
+        .map_err(|e| LogLevelError::ReloadFailed(e.to_string()))?;
+    let applied = directives.trim().to_string();
+    *reload.current.lock().unwrap_or_else(|e| e.into_inner()) = applied.clone();
+    Ok(applied)
+}
+
+/// Why a runtime log level change was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogLevelError {
+    /// The directive string did not parse.
+    Invalid(String),
+    /// Logging was not initialised through [`init_logging`] in this process.
+    Unavailable,
+    /// The reload handle rejected the new filter.
+    ReloadFailed(String),
+}
+
+impl std::fmt::Display for LogLevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevelError::Invalid(msg) => f.write_str(msg),
+            LogLevelError::Unavailable => {
+                f.write_str("runtime log level control is not available in this process")
+            }
+            LogLevelError::ReloadFailed(msg) => write!(f, "failed to apply log level: {msg}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_log_filter_accepts_levels_and_directives() {
+        assert!(parse_log_filter("debug").is_ok());
+        assert!(parse_log_filter("warn,pulsivo_salesman_api=trace").is_ok());
+        assert!(parse_log_filter("  ").is_err());
+        assert!(parse_log_filter("pulsivo_salesman_api=loud").is_err());
+    }
+
+    #[test]
+    fn set_log_level_rejects_invalid_before_checking_availability() {
+        assert!(matches!(
+            set_log_level("pulsivo_salesman_api=loud"),
+            Err(LogLevelError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+//! Process-wide Prometheus metrics for the API server.
+//!
+//! Counters and histograms are kept in memory and rendered in the Prometheus
+//! text exposition format by `GET /metrics` (and `/api/metrics`). Recording is
+//! cheap enough to call on every request: a short mutex hold per sample.
+
+use pulsivo_salesman_types::message::TokenUsage;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+/// Upper bounds (seconds) of the HTTP latency histogram buckets.
+const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Label used for requests that did not match any route.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Cumulative counts, one per entry in [`LATENCY_BUCKETS`].
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; LATENCY_BUCKETS.len()];
+        }
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if value <= *bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    /// `(method, route, status)` -> request count.
+    http_requests: Mutex<BTreeMap<(String, String, u16), u64>>,
+    /// `route` -> request latency.
+    http_latency: Mutex<BTreeMap<String, Histogram>>,
+    /// `(provider, direction)` -> tokens.
+    llm_tokens: Mutex<BTreeMap<(String, &'static str), u64>>,
+    /// `(channel, status)` -> delivery attempts.
+    sales_deliveries: Mutex<BTreeMap<(String, String), u64>>,
+    rate_limit_rejections: AtomicU64,
+}
+
+static METRICS: LazyLock<Registry> = LazyLock::new(Registry::default);
+
+fn bump<K: Ord>(map: &Mutex<BTreeMap<K, u64>>, key: K, by: u64) {
+    let mut map = map.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    *map.entry(key).or_insert(0) += by;
+}
+
+/// Record one finished HTTP request. `route` is the matched route pattern
+/// (e.g. `/api/sales/runs/{id}`), never the raw path, to bound cardinality.
+pub fn record_http_request(method: &str, route: Option<&str>, status: u16, latency: Duration) {
+    let route = route.unwrap_or(UNMATCHED_ROUTE).to_string();
+    bump(
+        &METRICS.http_requests,
+        (method.to_string(), route.clone(), status),
+        1,
+    );
+    METRICS
+        .http_latency
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .entry(route)
+        .or_default()
+        .observe(latency.as_secs_f64());
+}
+
+/// Record a request rejected by the rate limiter.
+pub fn record_rate_limit_rejection() {
+    METRICS
+        .rate_limit_rejections
+        .fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record LLM token usage for the provider that served the completion.
+pub fn record_llm_tokens(provider: &str, usage: &TokenUsage) {
+    bump(
+        &METRICS.llm_tokens,
+        (provider.to_string(), "input"),
+        usage.input_tokens,
+    );
+    bump(
+        &METRICS.llm_tokens,
+        (provider.to_string(), "output"),
+        usage.output_tokens,
+    );
+}
+
+/// Record a sales delivery outcome (`sent`, `failed`, `blocked_suppressed`, ...).
+pub fn record_sales_delivery(channel: &str, status: &str) {
+    bump(
+        &METRICS.sales_deliveries,
+        (channel.to_string(), status.to_string()),
+        1,
+    );
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// Render all recorded metrics in the Prometheus text format.
+pub fn render() -> String {
+    let mut out = String::new();
+
+    write_header(
+        &mut out,
+        "pulsivo_salesman_http_requests_total",
+        "counter",
+        "HTTP requests by method, route and status.",
+    );
+    for ((method, route, status), count) in METRICS
+        .http_requests
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_http_requests_total{{method=\"{}\",route=\"{}\",status=\"{status}\"}} {count}",
+            escape_label(method),
+            escape_label(route),
+        );
+    }
+
+    write_header(
+        &mut out,
+        "pulsivo_salesman_http_request_duration_seconds",
+        "histogram",
+        "HTTP request latency by route.",
+    );
+    for (route, histogram) in METRICS
+        .http_latency
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        let route = escape_label(route);
+        for (bound, count) in LATENCY_BUCKETS.iter().zip(&histogram.buckets) {
+            let _ = writeln!(
+                out,
+                "pulsivo_salesman_http_request_duration_seconds_bucket{{route=\"{route}\",le=\"{bound}\"}} {count}"
+            );
+        }
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_http_request_duration_seconds_bucket{{route=\"{route}\",le=\"+Inf\"}} {}",
+            histogram.count
+        );
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_http_request_duration_seconds_sum{{route=\"{route}\"}} {}",
+            histogram.sum
+        );
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_http_request_duration_seconds_count{{route=\"{route}\"}} {}",
+            histogram.count
+        );
+    }
+
+    write_header(
+        &mut out,
+        "pulsivo_salesman_rate_limit_rejections_total",
+        "counter",
+        "Requests rejected by the rate limiter.",
+    );
+    let _ = writeln!(
+        out,
+        "pulsivo_salesman_rate_limit_rejections_total {}",
+        METRICS.rate_limit_rejections.load(Ordering::Relaxed)
+    );
+
+    write_header(
+        &mut out,
+        "pulsivo_salesman_llm_tokens_total",
+        "counter",
+        "LLM tokens used by provider and direction.",
+    );
+    for ((provider, direction), count) in METRICS
+        .llm_tokens
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_llm_tokens_total{{provider=\"{}\",direction=\"{direction}\"}} {count}",
+            escape_label(provider),
+        );
+    }
+
+    write_header(
+        &mut out,
+        "pulsivo_salesman_stream_events_dropped_total",
+        "counter",
+        "LLM stream events the consumer never received, by event kind.",
+    );
+    for (event, count) in pulsivo_salesman_runtime::llm_driver::dropped_stream_events() {
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_stream_events_dropped_total{{event=\"{event}\"}} {count}"
+        );
+    }
+
+    write_header(
+        &mut out,
+        "pulsivo_salesman_sales_deliveries_total",
+        "counter",
+        "Sales delivery attempts by channel and status.",
+    );
+    for ((channel, status), count) in METRICS
+        .sales_deliveries
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+    {
+        let _ = writeln!(
+            out,
+            "pulsivo_salesman_sales_deliveries_total{{channel=\"{}\",status=\"{}\"}} {count}",
+            escape_label(channel),
+            escape_label(status),
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let mut histogram = Histogram::default();
+        histogram.observe(0.003);
+        histogram.observe(0.2);
+        histogram.observe(30.0);
+        assert_eq!(histogram.buckets[0], 1);
+        assert_eq!(histogram.buckets[5], 2);
+        assert_eq!(*histogram.buckets.last().unwrap(), 2);
+        assert_eq!(histogram.count, 3);
+    }
+
+    #[test]
+    fn test_render_includes_recorded_samples() {
+        record_http_request(
+            "GET",
+            Some("/api/test-metrics"),
+            200,
+            Duration::from_millis(3),
+        );
+        record_llm_tokens(
+            "test-provider",
+            &TokenUsage {
+                input_tokens: 12,
+                output_tokens: 5,
+            },
+        );
+        record_sales_delivery("email", "sent");
+        record_rate_limit_rejection();
+
+        let body = render();
+        assert!(body.contains(
+            "pulsivo_salesman_http_requests_total{method=\"GET\",route=\"/api/test-metrics\",status=\"200\"}"
+        ));
+        assert!(body.contains(
+            "pulsivo_salesman_http_request_duration_seconds_bucket{route=\"/api/test-metrics\",le=\"0.005\"} 1"
+        ));
+        assert!(body.contains(
+            "pulsivo_salesman_llm_tokens_total{provider=\"test-provider\",direction=\"input\"}"
+        ));
+        assert!(body.contains(
+            "pulsivo_salesman_sales_deliveries_total{channel=\"email\",status=\"sent\"}"
+        ));
+        assert!(body.contains("# TYPE pulsivo_salesman_rate_limit_rejections_total counter"));
+    }
+}
@@ -1,27 +1,119 @@
 //! Production middleware for the PulsivoSalesman API server.
 //!
 //! Provides:
-//! - Request ID generation and propagation
-//! - Per-endpoint structured request logging
+//! - Request ID generation and propagation (tracing span, task-local and
+//!   JSON error bodies)
+//! - Per-endpoint structured request logging and HTTP metrics
+//! - Bearer token authentication with per-token scopes
 //! - In-memory rate limiting (per IP)
+//! - JSON 413 responses for oversized request bodies
 
 use axum::body::Body;
+use axum::extract::MatchedPath;
 use axum::http::{Request, Response, StatusCode};
 use axum::middleware::Next;
+use pulsivo_salesman_types::config::{ApiTokenConfig, KernelConfig};
 use std::time::Instant;
-use tracing::info;
+use tracing::{info, Instrument};
 
 /// Request ID header name (standard).
 pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
-/// Middleware: inject a unique request ID and log the request/response.
+/// Largest error body rewritten to carry the request ID.
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// ID of the HTTP request the current task is serving, if any.
+///
+/// Work spawned off the request task does not inherit it; such tasks keep
+/// the request's tracing span via `in_current_span()` instead.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Reuse a caller-supplied `x-request-id` when it is a short token, so IDs
+/// from a proxy or client correlate end to end.
+fn incoming_request_id(request: &Request<Body>) -> Option<String> {
+    let value = request
+        .headers()
+        .get(REQUEST_ID_HEADER)?
+        .to_str()
+        .ok()?
+        .trim();
+    let valid = !value.is_empty()
+        && value.len() <= 128
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'));
+    valid.then(|| value.to_string())
+}
+
+/// Add `request_id` to a JSON object error body. Other bodies are returned
+/// unchanged.
+async fn with_request_id_in_error_body(
+    response: Response<Body>,
+    request_id: &str,
+) -> Response<Body> {
+    let is_json = response
+        .headers()
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json || !(response.status().is_client_error() || response.status().is_server_error()) {
+        return response;
+    }
+    // Only buffer bodies of a known, small size; anything else passes through
+    // unchanged so a large or streamed error is never truncated.
+    let small = axum::body::HttpBody::size_hint(response.body())
+        .exact()
+        .is_some_and(|len| len <= MAX_ERROR_BODY_BYTES as u64);
+    if !small {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, MAX_ERROR_BODY_BYTES).await else {
+        parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+        return Response::from_parts(parts, Body::empty());
+    };
+    let body = match serde_json::from_slice::<serde_json::Value>(&bytes) {
+        Ok(serde_json::Value::Object(mut object)) => {
+            object
+                .entry("request_id")
+                .or_insert_with(|| serde_json::Value::String(request_id.to_string()));
+            parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+            Body::from(serde_json::Value::Object(object).to_string())
+        }
+        _ => Body::from(bytes),
+    };
+    Response::from_parts(parts, body)
+}
+
+/// Middleware: assign a request ID, log the request/response, and record
+/// HTTP metrics keyed by the matched route.
+///
+/// The handler runs inside an `http_request` tracing span carrying the ID,
+/// so `warn!`/`error!` events from the sales engine and LLM drivers are
+/// correlated with the request. The ID is echoed in `x-request-id` and added
+/// to JSON error bodies.
 pub async fn request_logging(request: Request<Body>, next: Next) -> Response<Body> {
-    let request_id = uuid::Uuid::new_v4().to_string();
+    let request_id =
+        incoming_request_id(&request).unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
     let method = request.method().clone();
     let uri = request.uri().path().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string());
     let start = Instant::now();
 
-    let mut response = next.run(request).await;
+    let span = tracing::info_span!("http_request", request_id = %request_id, method = %method);
+    let response = REQUEST_ID
+        .scope(request_id.clone(), next.run(request).instrument(span))
+        .await;
+    let mut response = with_request_id_in_error_body(response, &request_id).await;
 
     let elapsed = start.elapsed();
     let status = response.status().as_u16();
@@ -34,6 +126,7 @@ pub async fn request_logging(request: Request<Body>, next: Next) -> Response<Bod
         latency_ms = elapsed.as_millis() as u64,
         "API request"
     );
+    crate::metrics::record_http_request(method.as_str(), route.as_deref(), status, elapsed);
 
     // Inject the request ID into the response
     if let Ok(header_val) = request_id.parse() {
@@ -43,17 +136,119 @@ pub async fn request_logging(request: Request<Body>, next: Next) -> Response<Bod
     response
 }
 
-/// Bearer token authentication middleware.
+/// Scope that grants access to every protected route.
+pub const WILDCARD_SCOPE: &str = "*";
+
+/// Authenticated caller, inserted into request extensions by [`auth`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthPrincipal {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+impl AuthPrincipal {
+    fn full_access(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            scopes: vec![WILDCARD_SCOPE.to_string()],
+        }
+    }
+
+    /// Whether this principal may call routes guarded by `scope`.
+    pub fn allows(&self, scope: &str) -> bool {
+        self.scopes
+            .iter()
+            .any(|granted| granted == WILDCARD_SCOPE || granted == scope)
+    }
+}
+
+/// Credentials accepted by the [`auth`] middleware.
+#[derive(Clone, Default)]
+pub struct ApiAuth {
+    /// Master key; grants every scope.
+    pub api_key: String,
+    /// Named, scoped team tokens.
+    pub tokens: Vec<ApiTokenConfig>,
+    /// Serve the metrics endpoints to loopback callers without a token.
+    pub metrics_loopback_public: bool,
+}
+
+impl ApiAuth {
+    pub fn from_config(config: &KernelConfig) -> Self {
+        Self {
+            api_key: config.api_key.clone(),
+            tokens: config.api_tokens.clone(),
+            metrics_loopback_public: config.metrics.loopback_public,
+        }
+    }
+
+    /// No credentials configured: the API is restricted to loopback callers.
+    pub fn is_open(&self) -> bool {
+        self.api_key.is_empty() && self.tokens.is_empty()
+    }
+
+    /// Resolve a presented bearer token to its principal.
+    pub fn resolve(&self, token: &str) -> Option<AuthPrincipal> {
+        if !self.api_key.is_empty() && token_matches(token, &self.api_key) {
+            return Some(AuthPrincipal::full_access("api_key"));
+        }
+        self.tokens
+            .iter()
+            .filter(|configured| !configured.token.is_empty())
+            .find(|configured| token_matches(token, &configured.token))
+            .map(|configured| AuthPrincipal {
+                name: configured.name.clone(),
+                scopes: configured.scopes.clone(),
+            })
+    }
+}
+
+/// Scope required to call a protected route.
+pub fn required_scope(path: &str) -> &'static str {
+    if path.starts_with("/api/sales/") {
+        "sales"
+    } else {
+        "admin"
+    }
+}
+
+/// SECURITY: Use constant-time comparison to prevent timing attacks.
+fn token_matches(candidate: &str, expected: &str) -> bool {
+    use subtle::ConstantTimeEq;
+    if candidate.len() != expected.len() {
+        return false;
+    }
+    candidate.as_bytes().ct_eq(expected.as_bytes()).into()
+}
+
+fn json_error(status: StatusCode, message: &str) -> Response<Body> {
+    let mut builder = Response::builder()
+        .status(status)
+        .header("content-type", "application/json");
+    if status == StatusCode::UNAUTHORIZED {
+        builder = builder.header("www-authenticate", "Bearer");
+    }
+    builder
+        .body(Body::from(
+            serde_json::json!({"error": message}).to_string(),
+        ))
+        .unwrap_or_default()
+}
+
+/// Bearer token authentication and scope authorization middleware.
 ///
-/// When `api_key` is non-empty, all requests must include
-/// `Authorization: Bearer <api_key>`. If the key is empty, auth is bypassed.
+/// With no `api_key` and no `api_tokens` configured, only loopback callers are
+/// allowed. Otherwise protected routes need `Authorization: Bearer <token>` (or
+/// `?token=`) matching the master key or a scoped token: unknown tokens get
+/// 401, tokens without the route's [`required_scope`] get 403. The resolved
+/// [`AuthPrincipal`] is attached to the request extensions.
 pub async fn auth(
-    axum::extract::State(api_key): axum::extract::State<String>,
-    request: Request<Body>,
+    axum::extract::State(auth): axum::extract::State<ApiAuth>,
+    mut request: Request<Body>,
     next: Next,
 ) -> Response<Body> {
-    // If no API key configured, restrict to loopback addresses only.
-    if api_key.is_empty() {
+    // If no credentials configured, restrict to loopback addresses only.
+    if auth.is_open() {
         let is_loopback = request
             .extensions()
             .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
@@ -76,21 +271,9 @@ pub async fn auth(
                 ))
                 .unwrap_or_default();
         }
-        return next.run(request).await;
-    }
-
-    // Public endpoints required to load the sales cockpit and OAuth flow.
-    let path = request.uri().path();
-    if path == "/"
-        || path == "/logo.png"
-        || path == "/favicon.ico"
-        || path == "/api/health"
-        || path == "/api/health/detail"
-        || path == "/api/status"
-        || path == "/api/version"
-        || path == "/auth/callback"
-        || path.starts_with("/api/auth/codex/")
-    {
+        request
+            .extensions_mut()
+            .insert(AuthPrincipal::full_access("local"));
         return next.run(request).await;
     }
 
@@ -101,48 +284,104 @@ pub async fn auth(
         .and_then(|v| v.to_str().ok())
         .and_then(|v| v.strip_prefix("Bearer "));
 
-    // SECURITY: Use constant-time comparison to prevent timing attacks.
-    let header_auth = bearer_token.map(|token| {
-        use subtle::ConstantTimeEq;
-        if token.len() != api_key.len() {
-            return false;
-        }
-        token.as_bytes().ct_eq(api_key.as_bytes()).into()
-    });
-
     // Also check ?token= query parameter for clients that cannot set headers.
     let query_token = request
         .uri()
         .query()
         .and_then(|q| q.split('&').find_map(|pair| pair.strip_prefix("token=")));
 
-    // SECURITY: Use constant-time comparison to prevent timing attacks.
-    let query_auth = query_token.map(|token| {
-        use subtle::ConstantTimeEq;
-        if token.len() != api_key.len() {
-            return false;
-        }
-        token.as_bytes().ct_eq(api_key.as_bytes()).into()
-    });
+    // Accept if either auth method resolves to a principal
+    let credential_provided = bearer_token.is_some() || query_token.is_some();
+    let principal = bearer_token
+        .and_then(|token| auth.resolve(token))
+        .or_else(|| query_token.and_then(|token| auth.resolve(token)));
 
-    // Accept if either auth method matches
-    if header_auth == Some(true) || query_auth == Some(true) {
+    // Public endpoints required to load the sales cockpit and OAuth flow.
+    // The auth debug view stays behind the admin scope. A valid token is
+    // still attached so public handlers can show more to known callers.
+    let path = request.uri().path().to_string();
+    if path == "/"
+        || path == "/logo.png"
+        || path == "/favicon.ico"
+        || path == "/api/health"
+        || path == "/api/health/detail"
+        || path == "/api/status"
+        || path == "/api/version"
+        || path == "/auth/callback"
+        || (path.starts_with("/api/auth/codex/") && path != "/api/auth/codex/debug")
+    {
+        if let Some(principal) = principal {
+            request.extensions_mut().insert(principal);
+        }
         return next.run(request).await;
     }
 
-    // Determine error message: was a credential provided but wrong, or missing entirely?
-    let credential_provided = header_auth.is_some() || query_auth.is_some();
-    let error_msg = if credential_provided {
-        "Invalid API key"
-    } else {
-        "Missing Authorization: Bearer <api_key> header"
+    // Metrics scrapes from the same host need no token unless disabled.
+    if auth.metrics_loopback_public && (path == "/metrics" || path == "/api/metrics") {
+        let is_loopback = request
+            .extensions()
+            .get::<axum::extract::ConnectInfo<std::net::SocketAddr>>()
+            .is_some_and(|ci| ci.0.ip().is_loopback());
+        if is_loopback {
+            return next.run(request).await;
+        }
+    }
+
+    let Some(principal) = principal else {
+        let error_msg = if credential_provided {
+            "Invalid API key"
+        } else {
+            "Missing Authorization: Bearer <api_key> header"
+        };
+        return json_error(StatusCode::UNAUTHORIZED, error_msg);
     };
 
+    let scope = required_scope(&path);
+    if !principal.allows(scope) {
+        tracing::warn!(principal = %principal.name, scope, path = %path, "API token lacks scope");
+        return json_error(
+            StatusCode::FORBIDDEN,
+            &format!("Token '{}' lacks the '{scope}' scope", principal.name),
+        );
+    }
+
+    request.extensions_mut().insert(principal);
+    next.run(request).await
+}
+
+/// Rewrite body-limit rejections into the API's JSON error shape.
+///
+/// Axum's `DefaultBodyLimit` answers oversized bodies with a plain-text 413.
+/// This keeps the status but returns `{"error": ..., "max_body_bytes": N}` so
+/// the cockpit can show a useful message. `max_body_bytes` is the router-wide
+/// default; routes with their own `DefaultBodyLimit` still get the JSON shape.
+pub async fn payload_too_large_json(
+    axum::extract::State(max_body_bytes): axum::extract::State<usize>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let response = next.run(request).await;
+    if response.status() != StatusCode::PAYLOAD_TOO_LARGE {
+        return response;
+    }
+    let is_json = response
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    if is_json {
+        return response;
+    }
+
     Response::builder()
-        .status(StatusCode::UNAUTHORIZED)
-        .header("www-authenticate", "Bearer")
+        .status(StatusCode::PAYLOAD_TOO_LARGE)
+        .header("content-type", "application/json")
         .body(Body::from(
-            serde_json::json!({"error": error_msg}).to_string(),
+            serde_json::json!({
+                "error": format!("Request body too large (limit {max_body_bytes} bytes)"),
+                "max_body_bytes": max_body_bytes,
+            })
+            .to_string(),
         ))
         .unwrap_or_default()
 }
@@ -180,4 +419,208 @@ mod tests {
     fn test_request_id_header_constant() {
         assert_eq!(REQUEST_ID_HEADER, "x-request-id");
     }
+
+    #[tokio::test]
+    async fn test_request_id_reaches_handler_and_error_body() {
+        use axum::routing::get;
+        use tower::ServiceExt;
+
+        async fn fail() -> (StatusCode, axum::Json<serde_json::Value>) {
+            (
+                StatusCode::BAD_REQUEST,
+                axum::Json(serde_json::json!({
+                    "error": "bad input",
+                    "seen": current_request_id(),
+                })),
+            )
+        }
+
+        let app = axum::Router::new()
+            .route("/fail", get(fail))
+            .layer(axum::middleware::from_fn(request_logging));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get("/fail")
+                    .header(REQUEST_ID_HEADER, "trace-abc_1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "trace-abc_1");
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["request_id"], "trace-abc_1");
+        assert_eq!(body["seen"], "trace-abc_1");
+
+        let response = app
+            .oneshot(
+                Request::get("/fail")
+                    .header(REQUEST_ID_HEADER, "bad id\twith spaces")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let generated = response.headers()[REQUEST_ID_HEADER].to_str().unwrap();
+        assert!(uuid::Uuid::parse_str(generated).is_ok());
+        assert!(current_request_id().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_large_error_body_passes_through_unchanged() {
+        use axum::routing::get;
+        use tower::ServiceExt;
+
+        let detail = "x".repeat(MAX_ERROR_BODY_BYTES * 2);
+        let expected = serde_json::json!({ "error": detail }).to_string();
+        let app = axum::Router::new()
+            .route(
+                "/fail",
+                get(move || async move {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        axum::Json(serde_json::json!({ "error": detail })),
+                    )
+                }),
+            )
+            .layer(axum::middleware::from_fn(request_logging));
+
+        let response = app
+            .oneshot(Request::get("/fail").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(bytes, expected.as_bytes());
+    }
+
+    fn scoped_auth() -> ApiAuth {
+        ApiAuth {
+            api_key: "master-key".to_string(),
+            tokens: vec![ApiTokenConfig {
+                name: "alice".to_string(),
+                token: "alice-token".to_string(),
+                scopes: vec!["sales".to_string()],
+            }],
+            metrics_loopback_public: false,
+        }
+    }
+
+    #[test]
+    fn test_resolve_scoped_tokens() {
+        let auth = scoped_auth();
+        let master = auth.resolve("master-key").unwrap();
+        assert!(master.allows("admin"));
+        let alice = auth.resolve("alice-token").unwrap();
+        assert_eq!(alice.name, "alice");
+        assert!(alice.allows(required_scope("/api/sales/leads")));
+        assert!(!alice.allows(required_scope("/api/metrics")));
+        assert!(auth.resolve("wrong").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_auth_rejects_invalid_and_out_of_scope_tokens() {
+        use axum::routing::get;
+        use tower::ServiceExt;
+
+        async fn whoami(axum::Extension(principal): axum::Extension<AuthPrincipal>) -> String {
+            principal.name
+        }
+
+        let app = axum::Router::new()
+            .route("/api/sales/leads", get(whoami))
+            .route("/api/metrics", get(whoami))
+            .route("/api/auth/codex/status", get(|| async { "public" }))
+            .route("/api/auth/codex/debug", get(whoami))
+            .layer(axum::middleware::from_fn_with_state(scoped_auth(), auth));
+        let call = |path: &str, token: &str| {
+            Request::get(path)
+                .header("authorization", format!("Bearer {token}"))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let ok = app
+            .clone()
+            .oneshot(call("/api/sales/leads", "alice-token"))
+            .await
+            .unwrap();
+        assert_eq!(ok.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(ok.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&bytes[..], b"alice");
+
+        let forbidden = app
+            .clone()
+            .oneshot(call("/api/metrics", "alice-token"))
+            .await
+            .unwrap();
+        assert_eq!(forbidden.status(), StatusCode::FORBIDDEN);
+
+        let debug = app
+            .clone()
+            .oneshot(call("/api/auth/codex/debug", "alice-token"))
+            .await
+            .unwrap();
+        assert_eq!(debug.status(), StatusCode::FORBIDDEN);
+        let public = app
+            .clone()
+            .oneshot(
+                Request::get("/api/auth/codex/status")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(public.status(), StatusCode::OK);
+
+        let invalid = app.oneshot(call("/api/sales/leads", "nope")).await.unwrap();
+        assert_eq!(invalid.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_returns_json_413() {
+        use axum::extract::DefaultBodyLimit;
+        use axum::response::IntoResponse;
+        use axum::routing::post;
+        use tower::ServiceExt;
+
+        async fn echo(axum::Json(body): axum::Json<serde_json::Value>) -> impl IntoResponse {
+            axum::Json(body)
+        }
+
+        let app = axum::Router::new()
+            .route("/echo", post(echo))
+            .layer(DefaultBodyLimit::max(16))
+            .layer(axum::middleware::from_fn_with_state(
+                16usize,
+                payload_too_large_json,
+            ));
+
+        let response = app
+            .oneshot(
+                Request::post("/echo")
+                    .header("content-type", "application/json")
+                    .body(Body::from(format!("{{\"brief\":\"{}\"}}", "x".repeat(64))))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["max_body_bytes"], 16);
+        assert!(body["error"].as_str().unwrap().contains("too large"));
+    }
 }
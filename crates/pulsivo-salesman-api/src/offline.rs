@@ -0,0 +1,107 @@
+//! Offline mode for air-gapped installs.
+//!
+//! With `offline = true` routes that can only work against the network (web
+//! discovery runs, outbound sends, channel probes, OAuth token exchanges) fail
+//! fast with a 503 instead of timing out against unreachable hosts. Profile,
+//! onboarding and lead/approval bookkeeping keep working; sales LLM calls are
+//! limited to local providers.
+
+use crate::routes::AppState;
+use axum::body::Body;
+use axum::http::{Request, Response, StatusCode};
+use axum::middleware::Next;
+use std::sync::Arc;
+
+/// What a route needs the network for, or `None` when it works offline.
+pub fn offline_blocked_feature(method: &str, path: &str) -> Option<&'static str> {
+    match (method, path) {
+        ("POST", "/api/sales/run") => Some("sales runs"),
+        ("POST", "/api/sales/test-send") => Some("outbound sends"),
+        ("POST", "/api/sales/approvals/bulk-approve") => Some("outbound sends"),
+        ("GET", "/api/channels/status") => Some("channel probes"),
+        ("POST", "/api/auth/codex/start")
+        | ("GET", "/api/auth/codex/callback")
+        | ("GET", "/auth/callback")
+        | ("POST", "/api/auth/codex/paste-code")
+        | ("POST", "/api/auth/codex/import-cli") => Some("Codex OAuth"),
+        ("POST", p)
+            if (p.starts_with("/api/sales/jobs/") && p.ends_with("/retry"))
+                || (p.starts_with("/api/sales/campaigns/") && p.ends_with("/run")) =>
+        {
+            Some("sales runs")
+        }
+        ("POST", p) if p.starts_with("/api/sales/approvals/") && p.ends_with("/approve") => {
+            Some("outbound sends")
+        }
+        _ => None,
+    }
+}
+
+/// Error message for a feature that is unavailable in offline mode.
+pub fn offline_error(feature: &str) -> String {
+    format!("Offline mode is enabled; {feature} need network access")
+}
+
+/// Reject network-bound routes while `offline` is set.
+///
+/// Reads the live config so toggling `offline` with a config reload takes
+/// effect without a restart.
+pub async fn offline_guard(
+    axum::extract::State(state): axum::extract::State<Arc<AppState>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let Some(feature) = offline_blocked_feature(request.method().as_str(), request.uri().path())
+    else {
+        return next.run(request).await;
+    };
+    if !state.kernel.config_snapshot().offline {
+        return next.run(request).await;
+    }
+
+    Response::builder()
+        .status(StatusCode::SERVICE_UNAVAILABLE)
+        .header("content-type", "application/json")
+        .body(Body::from(
+            serde_json::json!({
+                "error": offline_error(feature),
+                "offline": true,
+            })
+            .to_string(),
+        ))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_bound_routes() {
+        assert_eq!(
+            offline_blocked_feature("POST", "/api/sales/run"),
+            Some("sales runs")
+        );
+        assert_eq!(
+            offline_blocked_feature("POST", "/api/sales/campaigns/c1/run"),
+            Some("sales runs")
+        );
+        assert_eq!(
+            offline_blocked_feature("POST", "/api/sales/approvals/a1/approve"),
+            Some("outbound sends")
+        );
+        assert_eq!(
+            offline_blocked_feature("POST", "/api/auth/codex/start"),
+            Some("Codex OAuth")
+        );
+        assert_eq!(
+            offline_blocked_feature("GET", "/api/channels/status"),
+            Some("channel probes")
+        );
+        assert!(offline_blocked_feature("POST", "/api/sales/profile/autofill").is_none());
+        assert!(offline_blocked_feature("POST", "/api/sales/onboarding/brief").is_none());
+        assert!(offline_blocked_feature("POST", "/api/sales/approvals/a1/reject").is_none());
+        assert!(offline_blocked_feature("GET", "/api/auth/codex/status").is_none());
+        assert!(offline_blocked_feature("GET", "/api/sales/leads").is_none());
+    }
+}
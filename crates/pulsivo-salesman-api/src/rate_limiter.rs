@@ -17,8 +17,14 @@ pub fn operation_cost(method: &str, path: &str) -> NonZeroU32 {
         (_, "/api/health/detail") => NonZeroU32::new(2).unwrap(),
         ("GET", "/api/status") => NonZeroU32::new(1).unwrap(),
         ("GET", "/api/version") => NonZeroU32::new(1).unwrap(),
-        ("GET", "/api/metrics") => NonZeroU32::new(2).unwrap(),
+        ("GET", "/api/metrics") | ("GET", "/metrics") => NonZeroU32::new(2).unwrap(),
+        ("POST", "/api/admin/reload") => NonZeroU32::new(20).unwrap(),
+        ("POST", "/api/admin/log-level") => NonZeroU32::new(5).unwrap(),
+        ("GET", "/api/channels/status") => NonZeroU32::new(4).unwrap(),
+        ("POST", "/api/admin/channels/validate") => NonZeroU32::new(2).unwrap(),
+        ("POST", "/api/admin/channels/apply") => NonZeroU32::new(10).unwrap(),
         ("GET", "/api/auth/codex/status") => NonZeroU32::new(1).unwrap(),
+        ("GET", "/api/auth/codex/debug") => NonZeroU32::new(2).unwrap(),
         ("POST", "/api/auth/codex/start") => NonZeroU32::new(5).unwrap(),
         ("POST", "/api/auth/codex/paste-code") => NonZeroU32::new(8).unwrap(),
         ("POST", "/api/auth/codex/import-cli") => NonZeroU32::new(8).unwrap(),
@@ -26,8 +32,11 @@ pub fn operation_cost(method: &str, path: &str) -> NonZeroU32 {
         ("GET", "/api/sales/profile") => NonZeroU32::new(2).unwrap(),
         ("PUT", "/api/sales/profile") => NonZeroU32::new(8).unwrap(),
         ("POST", "/api/sales/profile/autofill") => NonZeroU32::new(25).unwrap(),
+        ("POST", "/api/sales/profile/preview") => NonZeroU32::new(25).unwrap(),
+        ("POST", "/api/sales/preview-copy") => NonZeroU32::new(3).unwrap(),
         ("GET", "/api/sales/onboarding/status") => NonZeroU32::new(2).unwrap(),
         ("POST", "/api/sales/onboarding/brief") => NonZeroU32::new(15).unwrap(),
+        ("GET", "/api/sales/plan") => NonZeroU32::new(25).unwrap(),
         ("POST", "/api/sales/run") => NonZeroU32::new(60).unwrap(),
         ("GET", "/api/sales/jobs/active") => NonZeroU32::new(3).unwrap(),
         ("GET", p) if p.starts_with("/api/sales/jobs/") && p.ends_with("/progress") => {
@@ -37,14 +46,31 @@ pub fn operation_cost(method: &str, path: &str) -> NonZeroU32 {
             NonZeroU32::new(40).unwrap()
         }
         ("GET", "/api/sales/source-health") => NonZeroU32::new(4).unwrap(),
+        ("GET", "/api/sales/stats") => NonZeroU32::new(3).unwrap(),
         ("GET", "/api/sales/runs") => NonZeroU32::new(4).unwrap(),
         ("GET", "/api/sales/leads") => NonZeroU32::new(5).unwrap(),
+        ("GET", "/api/sales/leads/search") => NonZeroU32::new(6).unwrap(),
+        ("GET", p) if p.starts_with("/api/sales/leads/") && p.matches('/').count() == 4 => {
+            NonZeroU32::new(5).unwrap()
+        }
+        ("PATCH", p) if p.starts_with("/api/sales/leads/") && p.ends_with("/status") => {
+            NonZeroU32::new(10).unwrap()
+        }
+        ("POST", p) if p.starts_with("/api/sales/leads/") && p.ends_with("/block") => {
+            NonZeroU32::new(10).unwrap()
+        }
+        ("PATCH", p) if p.starts_with("/api/sales/leads/") && p.matches('/').count() == 4 => {
+            NonZeroU32::new(10).unwrap()
+        }
         ("GET", "/api/sales/prospects") => NonZeroU32::new(5).unwrap(),
         ("GET", p) if p.starts_with("/api/sales/accounts/") && p.ends_with("/dossier") => {
             NonZeroU32::new(6).unwrap()
         }
         ("GET", "/api/sales/approvals") => NonZeroU32::new(5).unwrap(),
         ("POST", "/api/sales/approvals/bulk-approve") => NonZeroU32::new(30).unwrap(),
+        ("POST", "/api/sales/approvals/expire") => NonZeroU32::new(10).unwrap(),
+        ("POST", "/api/sales/leads/rescore") => NonZeroU32::new(20).unwrap(),
+        ("POST", "/api/sales/leads/import") => NonZeroU32::new(20).unwrap(),
         ("PATCH", p) if p.starts_with("/api/sales/approvals/") && p.ends_with("/edit") => {
             NonZeroU32::new(15).unwrap()
         }
@@ -55,6 +81,12 @@ pub fn operation_cost(method: &str, path: &str) -> NonZeroU32 {
             NonZeroU32::new(12).unwrap()
         }
         ("GET", "/api/sales/deliveries") => NonZeroU32::new(5).unwrap(),
+        ("POST", p) if p.starts_with("/api/sales/campaigns/") && p.ends_with("/run") => {
+            NonZeroU32::new(60).unwrap()
+        }
+        ("PUT", p) if p.starts_with("/api/sales/campaigns/") && p.ends_with("/profile") => {
+            NonZeroU32::new(8).unwrap()
+        }
         _ => NonZeroU32::new(5).unwrap(),
     }
 }
@@ -90,6 +122,7 @@ pub async fn gcra_rate_limit(
 
     if limiter.check_key_n(&ip, cost).is_err() {
         tracing::warn!(ip = %ip, cost = cost.get(), path = %path, "GCRA rate limit exceeded");
+        crate::metrics::record_rate_limit_rejection();
         return Response::builder()
             .status(StatusCode::TOO_MANY_REQUESTS)
             .header("content-type", "application/json")
@@ -112,11 +145,34 @@ mod tests {
         assert_eq!(operation_cost("GET", "/api/health").get(), 1);
         assert_eq!(operation_cost("GET", "/api/status").get(), 1);
         assert_eq!(operation_cost("GET", "/api/auth/codex/status").get(), 1);
+        assert_eq!(operation_cost("GET", "/api/auth/codex/debug").get(), 2);
+        assert_eq!(operation_cost("POST", "/api/admin/reload").get(), 20);
+        assert_eq!(operation_cost("POST", "/api/admin/log-level").get(), 5);
+        assert_eq!(
+            operation_cost("POST", "/api/admin/channels/validate").get(),
+            2
+        );
+        assert_eq!(
+            operation_cost("POST", "/api/admin/channels/apply").get(),
+            10
+        );
         assert_eq!(operation_cost("PUT", "/api/sales/profile").get(), 8);
         assert_eq!(
             operation_cost("POST", "/api/sales/profile/autofill").get(),
             25
         );
+        assert_eq!(
+            operation_cost("POST", "/api/sales/profile/preview").get(),
+            25
+        );
+        assert_eq!(operation_cost("GET", "/api/sales/plan").get(), 25);
+        assert_eq!(operation_cost("POST", "/api/sales/preview-copy").get(), 3);
+        assert_eq!(operation_cost("PATCH", "/api/sales/leads/lead-1").get(), 10);
+        assert_eq!(operation_cost("GET", "/api/sales/leads/lead-1").get(), 5);
+        assert_eq!(
+            operation_cost("POST", "/api/sales/leads/lead-1/block").get(),
+            10
+        );
         assert_eq!(operation_cost("POST", "/api/sales/run").get(), 60);
         assert_eq!(
             operation_cost("GET", "/api/sales/jobs/123/progress").get(),
@@ -134,9 +190,23 @@ mod tests {
             operation_cost("POST", "/api/sales/approvals/bulk-approve").get(),
             30
         );
+        assert_eq!(
+            operation_cost("POST", "/api/sales/approvals/expire").get(),
+            10
+        );
+        assert_eq!(operation_cost("POST", "/api/sales/leads/rescore").get(), 20);
+        assert_eq!(operation_cost("POST", "/api/sales/leads/import").get(), 20);
         assert_eq!(
             operation_cost("PATCH", "/api/sales/approvals/a1/edit").get(),
             15
         );
+        assert_eq!(
+            operation_cost("POST", "/api/sales/campaigns/c1/run").get(),
+            60
+        );
+        assert_eq!(
+            operation_cost("PUT", "/api/sales/campaigns/c1/profile").get(),
+            8
+        );
     }
 }
@@ -1,12 +1,17 @@
 //! Shared state and core health/status routes for the sales-only API.
 
+use crate::logging;
+use crate::sales;
 use axum::extract::State;
+use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
+use pulsivo_salesman_kernel::config_reload::validate_channels_config;
 use pulsivo_salesman_kernel::PulsivoSalesmanKernel;
 use pulsivo_salesman_types::agent::{AgentId, AgentState};
-use std::sync::Arc;
-use std::time::Instant;
+use pulsivo_salesman_types::config::{ChannelsConfig, EmailConfig};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
 
 /// Shared application state for the sales daemon.
 pub struct AppState {
@@ -14,6 +19,8 @@ pub struct AppState {
     pub started_at: Instant,
     /// Notify handle used by the daemon and embedded server for graceful shutdown.
     pub shutdown_notify: Arc<tokio::sync::Notify>,
+    /// The sales run currently executing, shared with the scheduler.
+    pub sales_runs: sales::SalesRunSlot,
 }
 
 fn health_probe_agent_id() -> AgentId {
@@ -103,6 +110,215 @@ pub async fn health_detail(State(state): State<Arc<AppState>>) -> impl IntoRespo
     }))
 }
 
+/// POST /api/admin/reload — Re-read config.toml and re-init the sales DB in place.
+///
+/// Hot-reloadable changes (channels, web) are applied immediately; changes that
+/// need a restart are reported and the running config is left untouched. A
+/// config.toml with invalid fields is rejected with 422 naming each field.
+pub async fn reload_config(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let plan = match state.kernel.reload_config() {
+        Ok(plan) => plan,
+        Err(e) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    if plan.is_hot_reloadable() {
+        sales::warm_sales_llm_drivers(&state.kernel).await;
+    }
+    let sales_db = match sales::reinit_sales_db(&state) {
+        Ok(()) => "ok".to_string(),
+        Err(e) => {
+            tracing::warn!("Sales DB re-init failed during reload: {e}");
+            e
+        }
+    };
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "applied": !plan.restart_required,
+            "restart_required": plan.restart_required,
+            "restart_reasons": plan.restart_reasons,
+            "hot_actions": plan
+                .hot_actions
+                .iter()
+                .map(|action| format!("{action:?}"))
+                .collect::<Vec<_>>(),
+            "noop_changes": plan.noop_changes,
+            "sales_db": sales_db,
+        })),
+    )
+}
+
+/// Body for `POST /api/admin/log-level`.
+#[derive(Debug, serde::Deserialize)]
+pub struct LogLevelRequest {
+    /// Level or filter directives, e.g. `debug` or `info,pulsivo_salesman_api=trace`.
+    pub level: String,
+}
+
+/// POST /api/admin/log-level — Swap the tracing filter without a restart.
+///
+/// The change lasts until the next restart; `log_level` in config.toml still
+/// decides the level at startup.
+pub async fn set_log_level(Json(req): Json<LogLevelRequest>) -> impl IntoResponse {
+    let previous = logging::current_log_level();
+    match logging::set_log_level(&req.level) {
+        Ok(level) => {
+            tracing::info!(level = %level, "Log level changed via admin API");
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({"level": level, "previous": previous})),
+            )
+        }
+        Err(e) => {
+            let status = match e {
+                logging::LogLevelError::Invalid(_) => StatusCode::BAD_REQUEST,
+                logging::LogLevelError::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+                logging::LogLevelError::ReloadFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, Json(serde_json::json!({"error": e.to_string()})))
+        }
+    }
+}
+
+/// Upper bound for a single channel probe.
+const CHANNEL_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long probe results are reused so the status endpoint is cheap to poll.
+const CHANNEL_STATUS_TTL: Duration = Duration::from_secs(30);
+
+static CHANNEL_STATUS_CACHE: LazyLock<Mutex<Option<(Instant, serde_json::Value)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+fn channel_status(ok: bool, mode: &str, detail: impl Into<String>) -> serde_json::Value {
+    serde_json::json!({ "ok": ok, "mode": mode, "detail": detail.into() })
+}
+
+/// Connect to the SMTP relay using the configured TLS mode and authenticate.
+async fn probe_email_channel(cfg: &EmailConfig) -> serde_json::Value {
+    use lettre::transport::smtp::authentication::Credentials;
+
+    if cfg.smtp_host.trim().is_empty() {
+        return channel_status(false, "smtp", "smtp_host is empty");
+    }
+    let password = match std::env::var(&cfg.password_env) {
+        Ok(password) if !password.is_empty() => password,
+        _ => {
+            return channel_status(
+                false,
+                "smtp",
+                format!("Password env '{}' is not set", cfg.password_env),
+            )
+        }
+    };
+    let transport = match sales::build_smtp_transport(
+        &cfg.smtp_host,
+        cfg.smtp_port,
+        cfg.tls_mode,
+        Credentials::new(cfg.username.clone(), password),
+    ) {
+        Ok(transport) => transport,
+        Err(e) => return channel_status(false, "smtp", e),
+    };
+    let endpoint = format!("{}:{}", cfg.smtp_host, cfg.smtp_port);
+    match tokio::time::timeout(CHANNEL_PROBE_TIMEOUT, transport.test_connection()).await {
+        Ok(Ok(true)) => channel_status(true, "smtp", format!("Connected to {endpoint}")),
+        Ok(Ok(false)) => channel_status(false, "smtp", format!("{endpoint} rejected NOOP")),
+        Ok(Err(e)) => channel_status(false, "smtp", format!("{endpoint}: {e}")),
+        Err(_) => channel_status(
+            false,
+            "smtp",
+            format!(
+                "{endpoint}: timed out after {}s",
+                CHANNEL_PROBE_TIMEOUT.as_secs()
+            ),
+        ),
+    }
+}
+
+/// GET /api/channels/status — Probe each configured delivery channel.
+///
+/// Results are cached for [`CHANNEL_STATUS_TTL`]; `cached` reports whether
+/// this response reused an earlier probe.
+pub async fn channels_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    if let Ok(cache) = CHANNEL_STATUS_CACHE.lock() {
+        if let Some((at, channels)) = cache.as_ref() {
+            if at.elapsed() < CHANNEL_STATUS_TTL {
+                return Json(serde_json::json!({
+                    "channels": channels,
+                    "checked_secs_ago": at.elapsed().as_secs(),
+                    "cached": true,
+                }));
+            }
+        }
+    }
+
+    let config = state.kernel.config_snapshot();
+    let mut channels = serde_json::Map::new();
+    if let Some(email) = config.channels.email.as_ref() {
+        channels.insert("email".to_string(), probe_email_channel(email).await);
+    }
+    let channels = serde_json::Value::Object(channels);
+    if let Ok(mut cache) = CHANNEL_STATUS_CACHE.lock() {
+        *cache = Some((Instant::now(), channels.clone()));
+    }
+
+    Json(serde_json::json!({
+        "channels": channels,
+        "checked_secs_ago": 0,
+        "cached": false,
+    }))
+}
+
+/// POST /api/admin/channels/validate — Check a candidate channels config.
+///
+/// Reports per-channel errors and warnings, including missing secret env
+/// vars, without touching the running config.
+pub async fn validate_channels(Json(channels): Json<ChannelsConfig>) -> impl IntoResponse {
+    let results = validate_channels_config(&channels);
+    let valid = results.iter().all(|result| result.is_valid());
+    Json(serde_json::json!({
+        "valid": valid,
+        "channels": results,
+    }))
+}
+
+/// POST /api/admin/channels/apply — Validate and swap in a channels config.
+///
+/// Nothing changes unless every channel validates (422 otherwise). The swap
+/// is in memory only; config.toml is not rewritten.
+pub async fn apply_channels(
+    State(state): State<Arc<AppState>>,
+    Json(channels): Json<ChannelsConfig>,
+) -> impl IntoResponse {
+    match state.kernel.apply_channels_config(channels) {
+        Ok(results) => {
+            if let Ok(mut cache) = CHANNEL_STATUS_CACHE.lock() {
+                *cache = None;
+            }
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "applied": true,
+                    "channels": results,
+                })),
+            )
+        }
+        Err(results) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({
+                "applied": false,
+                "error": "channels config failed validation",
+                "channels": results,
+            })),
+        ),
+    }
+}
+
 /// GET /api/metrics — Minimal Prometheus metrics for the sales daemon.
 pub async fn prometheus_metrics(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let uptime = state.started_at.elapsed().as_secs();
@@ -113,7 +329,7 @@ pub async fn prometheus_metrics(State(state): State<Arc<AppState>>) -> impl Into
         .count();
     let supervisor = state.kernel.supervisor.health();
 
-    let body = format!(
+    let mut body = format!(
         "# HELP pulsivo_salesman_uptime_seconds Time since daemon started.\n\
          # TYPE pulsivo_salesman_uptime_seconds gauge\n\
          pulsivo_salesman_uptime_seconds {uptime}\n\n\
@@ -133,6 +349,8 @@ pub async fn prometheus_metrics(State(state): State<Arc<AppState>>) -> impl Into
         panic_count = supervisor.panic_count,
         restart_count = supervisor.restart_count,
     );
+    body.push('\n');
+    body.push_str(&crate::metrics::render());
 
     (
         [(
@@ -0,0 +1,172 @@
+//! Bounded concurrency for LLM-backed sales runs.
+//!
+//! Profile autofill and discovery jobs fan out to the model provider and web
+//! search. A shared semaphore caps how many execute at once; excess requests
+//! wait in a bounded queue and get a 503 with `Retry-After` once it is full.
+
+use axum::body::Body;
+use axum::http::{Request, Response, StatusCode};
+use axum::middleware::Next;
+use pulsivo_salesman_types::config::ApiLimitsConfig;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Routes that execute (or spawn) an LLM-backed run and share the limit.
+pub fn is_limited_run(method: &str, path: &str) -> bool {
+    match (method, path) {
+        ("POST", "/api/sales/profile/autofill") => true,
+        ("POST", "/api/sales/profile/preview") => true,
+        ("POST", "/api/sales/onboarding/brief") => true,
+        ("POST", "/api/sales/run") => true,
+        ("POST", p) => {
+            (p.starts_with("/api/sales/jobs/") && p.ends_with("/retry"))
+                || (p.starts_with("/api/sales/campaigns/") && p.ends_with("/run"))
+        }
+        _ => false,
+    }
+}
+
+/// Run slot held for the lifetime of a limited request.
+///
+/// Inserted into request extensions so handlers that spawn background jobs can
+/// move a clone into the task; the slot frees once every clone is dropped.
+#[derive(Clone)]
+pub struct RunPermit {
+    _slot: Arc<OwnedSemaphorePermit>,
+}
+
+/// Shared in-flight limit plus a bounded wait queue.
+pub struct RunLimiter {
+    permits: Arc<Semaphore>,
+    waiting: AtomicUsize,
+    max_queued: usize,
+    retry_after_secs: u64,
+}
+
+struct WaitingGuard<'a>(&'a AtomicUsize);
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl RunLimiter {
+    pub fn new(max_concurrent: usize, max_queued: usize, retry_after_secs: u64) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_concurrent.max(1))),
+            waiting: AtomicUsize::new(0),
+            max_queued,
+            retry_after_secs,
+        }
+    }
+
+    pub fn from_config(config: &ApiLimitsConfig) -> Self {
+        Self::new(
+            config.max_concurrent_runs,
+            config.max_queued_runs,
+            config.queue_retry_after_secs,
+        )
+    }
+
+    /// Number of runs currently waiting for a slot.
+    pub fn queued(&self) -> usize {
+        self.waiting.load(Ordering::SeqCst)
+    }
+
+    /// Take a run slot, waiting in the queue if needed.
+    ///
+    /// Returns `None` when every slot is busy and the queue is already full.
+    pub async fn acquire(&self) -> Option<RunPermit> {
+        let permit = match self.permits.clone().try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                if self.waiting.fetch_add(1, Ordering::SeqCst) >= self.max_queued {
+                    self.waiting.fetch_sub(1, Ordering::SeqCst);
+                    return None;
+                }
+                let _guard = WaitingGuard(&self.waiting);
+                self.permits.clone().acquire_owned().await.ok()?
+            }
+        };
+        Some(RunPermit {
+            _slot: Arc::new(permit),
+        })
+    }
+}
+
+/// Concurrency limiting middleware for LLM-backed sales runs.
+///
+/// Requests outside [`is_limited_run`] pass straight through. Limited requests
+/// hold a [`RunPermit`] until the response is produced, or longer if the
+/// handler hands a clone to a background job.
+pub async fn run_limit(
+    axum::extract::State(limiter): axum::extract::State<Arc<RunLimiter>>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    if !is_limited_run(request.method().as_str(), request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let Some(permit) = limiter.acquire().await else {
+        tracing::warn!(
+            path = %request.uri().path(),
+            queued = limiter.queued(),
+            "Run queue full"
+        );
+        return Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header("content-type", "application/json")
+            .header("retry-after", limiter.retry_after_secs.to_string())
+            .body(Body::from(
+                serde_json::json!({
+                    "error": "Too many sales runs in progress; retry shortly",
+                    "retry_after_secs": limiter.retry_after_secs,
+                })
+                .to_string(),
+            ))
+            .unwrap_or_default();
+    };
+
+    request.extensions_mut().insert(permit.clone());
+    let response = next.run(request).await;
+    drop(permit);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limited_routes() {
+        assert!(is_limited_run("POST", "/api/sales/run"));
+        assert!(is_limited_run("POST", "/api/sales/profile/autofill"));
+        assert!(is_limited_run("POST", "/api/sales/profile/preview"));
+        assert!(is_limited_run("POST", "/api/sales/jobs/abc/retry"));
+        assert!(is_limited_run("POST", "/api/sales/campaigns/c1/run"));
+        assert!(!is_limited_run("GET", "/api/sales/jobs/abc/progress"));
+        assert!(!is_limited_run("GET", "/api/sales/profile"));
+    }
+
+    #[tokio::test]
+    async fn test_queue_full_rejects_and_release_frees_slot() {
+        let limiter = Arc::new(RunLimiter::new(1, 1, 5));
+        let first = limiter.acquire().await.expect("first slot");
+
+        let waiter = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire().await.is_some() })
+        };
+        while limiter.queued() == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert!(limiter.acquire().await.is_none());
+
+        drop(first);
+        assert!(waiter.await.unwrap());
+        assert_eq!(limiter.queued(), 0);
+    }
+}
@@ -12,6 +12,7 @@ include!("sales/engine.rs");
 include!("sales/prospects.rs");
 include!("sales/directories.rs");
 include!("sales/strategy.rs");
+include!("sales/i18n.rs");
 include!("sales/enrichment.rs");
 include!("sales/onboarding.rs");
 include!("sales/discovery.rs");
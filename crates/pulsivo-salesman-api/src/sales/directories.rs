@@ -101,6 +101,7 @@ fn parse_tmb_member_candidates(
                     ))
                     .collect(),
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 contact_name: chairman_name,
@@ -182,6 +183,7 @@ fn parse_eud_member_candidates(
                 ]),
                 source_links: vec!["https://www.eud.org.tr/en/members".to_string()],
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("EUD members page".to_string()),
@@ -321,6 +323,7 @@ fn parse_asmud_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.asmud.org.tr/Uyeler.asp".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 email,
@@ -471,6 +474,7 @@ fn parse_platformder_directory_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.platformder.org.tr/rehber/".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("Platformder rehber".to_string()),
@@ -677,6 +681,7 @@ fn parse_mib_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: detail_url.into_iter().collect(),
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 email,
@@ -972,6 +977,7 @@ fn parse_imder_member_detail_candidate(
             matched_keywords: dedupe_strings(matched_keywords),
             source_links: vec![detail_url.to_string()],
             phone: phone.as_deref().and_then(normalize_phone),
+            evidence_urls: HashMap::new(),
         },
         contact_hint: SourceContactHint {
             contact_name,
@@ -1234,6 +1240,7 @@ fn parse_isder_member_detail_candidate(
             matched_keywords: dedupe_strings(matched_keywords),
             source_links: vec![detail_url.to_string()],
             phone: phone.as_deref().and_then(normalize_phone),
+            evidence_urls: HashMap::new(),
         },
         contact_hint: SourceContactHint {
             contact_name,
@@ -1382,6 +1389,7 @@ fn parse_thbb_yazismali_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.thbb.org/uyelerimiz/yazismali-uyeler/".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 email,
@@ -1500,6 +1508,7 @@ fn parse_eder_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://eder.org.tr/uyelerimiz/".to_string()],
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("EDER members page".to_string()),
@@ -1653,6 +1662,7 @@ fn parse_lojider_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.lojider.org.tr/Member-List".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 contact_name,
@@ -1771,6 +1781,7 @@ fn parse_tfyd_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.tfyd.org.tr/uyelerimiz".to_string()],
                 phone,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("TFYD members page".to_string()),
@@ -1897,6 +1908,7 @@ fn parse_oss_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.oss.org.tr/en/members/".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("OSS members page".to_string()),
@@ -2040,6 +2052,7 @@ fn parse_ida_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.ida.org.tr/ornek-sayfa/uyelerimiz/".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 contact_name,
@@ -2147,6 +2160,7 @@ fn parse_tesid_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://tesid.org.tr/uyelerimiz".to_string()],
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("TESID members page".to_string()),
@@ -2265,6 +2279,7 @@ fn parse_tudis_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.tudis.org.tr/uyelerimiz".to_string()],
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 email,
@@ -2426,6 +2441,7 @@ fn parse_emsad_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.emsad.org.tr/TR,753/uyelerimiz.html".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 contact_name,
@@ -2546,6 +2562,7 @@ fn parse_tgsd_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://tgsd.org.tr/uyelerimiz/".to_string()],
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 contact_name,
@@ -2712,6 +2729,7 @@ fn parse_ared_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://www.ared.org.tr/uyelerimiz".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 contact_name,
@@ -2841,6 +2859,7 @@ fn parse_todeb_member_candidates(
                 matched_keywords: dedupe_strings(matched_keywords),
                 source_links: vec!["https://todeb.org.tr/sayfa/birlik-uyeleri/39/".to_string()],
                 phone: phone.as_deref().and_then(normalize_phone),
+                evidence_urls: HashMap::new(),
             },
             contact_hint: SourceContactHint {
                 source: Some("TODEB members page".to_string()),
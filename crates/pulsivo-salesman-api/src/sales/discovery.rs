@@ -1,5 +1,6 @@
 /// Run web search discovery: primary queries + fallback queries + Brave rescue.
-/// Returns (candidates, source_contact_hints, search_unavailable).
+/// Returns (candidates, source_contact_hints, search_unavailable) and records
+/// search time and query/error counts into `telemetry`.
 async fn discover_via_web_search(
     search_engine: &WebSearchEngine,
     brave_search_engine: &Option<WebSearchEngine>,
@@ -7,11 +8,15 @@ async fn discover_via_web_search(
     profile: &SalesProfile,
     max_candidates: usize,
     is_field_ops: bool,
+    telemetry: &mut SalesRunTelemetry,
 ) -> (
     Vec<DomainCandidate>,
     HashMap<String, SourceContactHint>,
     bool,
 ) {
+    let started = Instant::now();
+    let search_limits = SalesSearchLimits::from_profile(profile);
+    let reason_limits = SalesReasonLimits::from_profile(profile);
     let discovery_fail_fast_threshold = if brave_search_engine.is_some() {
         MAX_DISCOVERY_FAILURES_BEFORE_FAST_FALLBACK
     } else {
@@ -39,7 +44,7 @@ async fn discover_via_web_search(
     for (query, result) in run_sales_search_batch(
         search_engine,
         &discovery_queries,
-        max_candidates,
+        search_limits.discovery(max_candidates),
         Duration::from_secs(SALES_DISCOVERY_SEARCH_TIMEOUT_SECS),
     )
     .await
@@ -55,6 +60,8 @@ async fn discover_via_web_search(
                     &lead_plan.exclude_keywords,
                     &profile.target_geo,
                     is_field_ops,
+                    profile.keep_subdomains,
+                    reason_limits,
                 );
             }
             Err(e) => {
@@ -65,6 +72,7 @@ async fn discover_via_web_search(
     }
 
     for domain in domains {
+        let domain = candidate_domain_key(&domain, profile.keep_subdomains);
         if is_blocked_company_domain(&domain) {
             continue;
         }
@@ -94,7 +102,7 @@ async fn discover_via_web_search(
             for (query, result) in run_sales_search_batch(
                 search_engine,
                 &adaptive_queries,
-                max_candidates.min(24),
+                search_limits.discovery(max_candidates.min(24)),
                 Duration::from_secs(SALES_DISCOVERY_SEARCH_TIMEOUT_SECS),
             )
             .await
@@ -110,6 +118,8 @@ async fn discover_via_web_search(
                             &lead_plan.exclude_keywords,
                             &profile.target_geo,
                             is_field_ops,
+                            profile.keep_subdomains,
+                            reason_limits,
                         );
                     }
                     Err(e) => {
@@ -120,6 +130,7 @@ async fn discover_via_web_search(
             }
 
             for domain in adaptive_domains {
+                let domain = candidate_domain_key(&domain, profile.keep_subdomains);
                 if is_blocked_company_domain(&domain) {
                     continue;
                 }
@@ -163,7 +174,7 @@ async fn discover_via_web_search(
         for (query, result) in run_sales_search_batch(
             search_engine,
             &fallback_queries,
-            20,
+            search_limits.fallback(20),
             Duration::from_secs(SALES_DISCOVERY_SEARCH_TIMEOUT_SECS),
         )
         .await
@@ -183,6 +194,7 @@ async fn discover_via_web_search(
             discovery_successes == 0 && discovery_failures >= discovery_fail_fast_threshold;
         let mut seen = HashSet::<String>::new();
         for domain in fallback_domains {
+            let domain = candidate_domain_key(&domain, profile.keep_subdomains);
             if is_blocked_company_domain(&domain) || !seen.insert(domain.clone()) {
                 continue;
             }
@@ -196,6 +208,7 @@ async fn discover_via_web_search(
                 matched_keywords: vec![profile.target_industry.clone()],
                 source_links: Vec::new(),
                 phone: None,
+                evidence_urls: HashMap::new(),
             };
             if normalize_candidate_gateway(&mut candidate) {
                 candidate_list.push(candidate);
@@ -209,11 +222,12 @@ async fn discover_via_web_search(
             let mut brave_domains = Vec::<String>::new();
             let mut brave_candidates = HashMap::<String, DomainCandidate>::new();
             let mut brave_successes = 0u32;
+            let mut brave_failures = 0u32;
 
             for (query, result) in run_sales_search_batch(
                 brave_engine,
                 &discovery_queries,
-                max_candidates,
+                search_limits.discovery(max_candidates),
                 Duration::from_secs(SALES_DISCOVERY_SEARCH_TIMEOUT_SECS),
             )
             .await
@@ -229,16 +243,22 @@ async fn discover_via_web_search(
                             &lead_plan.exclude_keywords,
                             &profile.target_geo,
                             is_field_ops,
+                            profile.keep_subdomains,
+                            reason_limits,
                         );
                     }
                     Err(e) => {
+                        brave_failures += 1;
                         warn!(query = %query, error = %e, "Brave rescue query failed");
                     }
                 }
             }
 
+            telemetry.queries_issued += brave_successes + brave_failures;
+            telemetry.search_errors += brave_failures;
             if brave_successes > 0 {
                 for domain in brave_domains {
+                    let domain = candidate_domain_key(&domain, profile.keep_subdomains);
                     if is_blocked_company_domain(&domain) {
                         continue;
                     }
@@ -256,6 +276,10 @@ async fn discover_via_web_search(
         }
     }
 
+    telemetry.discovery_search_ms = elapsed_ms(started);
+    telemetry.queries_issued += discovery_successes + discovery_failures;
+    telemetry.search_errors += discovery_failures;
+
     (
         dedupe_domain_candidates(candidate_list),
         source_contact_hints,
@@ -264,12 +288,32 @@ async fn discover_via_web_search(
 }
 
 /// Merge candidates from all discovery sources with cross-source confirmation bonus.
+///
+/// Every source funnels through here, so this is where the profile's
+/// `blocked_company_domains` are applied on top of the built-in blocklist.
 fn merge_all_discovery_sources(
     llm_candidates: Vec<DomainCandidate>,
     web_candidates: Vec<DomainCandidate>,
     free_candidates: Vec<FreeDiscoveryCandidate>,
     source_contact_hints: &mut HashMap<String, SourceContactHint>,
+    blocked_company_domains: &[String],
 ) -> Vec<DomainCandidate> {
+    let blocklist = normalize_blocked_domain_entries(blocked_company_domains);
+    let blocked = |domain: &str| is_profile_blocked_domain(domain, &blocklist);
+    let llm_candidates = llm_candidates
+        .into_iter()
+        .filter(|c| !blocked(&c.domain))
+        .collect::<Vec<_>>();
+    let web_candidates = web_candidates
+        .into_iter()
+        .filter(|c| !blocked(&c.domain))
+        .collect::<Vec<_>>();
+    let free_candidates = free_candidates
+        .into_iter()
+        .filter(|fc| !blocked(&fc.candidate.domain))
+        .collect::<Vec<_>>();
+    source_contact_hints.retain(|domain, _| !blocked(domain));
+
     let mut merged: HashMap<String, DomainCandidate> = HashMap::new();
     let mut source_counts: HashMap<String, u32> = HashMap::new();
 
@@ -289,6 +333,7 @@ fn merge_all_discovery_sources(
     // Merge LLM candidates (primary source)
     for c in llm_candidates {
         let entry = merged.entry(c.domain.clone()).or_default();
+        entry.merge_evidence_urls(&c);
         if entry.domain.is_empty() {
             entry.domain = c.domain;
         }
@@ -308,6 +353,7 @@ fn merge_all_discovery_sources(
     // Merge web search candidates
     for c in web_candidates {
         let entry = merged.entry(c.domain.clone()).or_default();
+        entry.merge_evidence_urls(&c);
         if entry.domain.is_empty() {
             entry.domain = c.domain;
         }
@@ -686,6 +732,8 @@ fn b2c_candidate_from_search_entry(
     let lower = blob.to_lowercase();
     let relevance_terms = b2c_relevance_terms(profile);
 
+    // A URL the first adapter cannot normalize yields no candidate.
+    #[allow(clippy::never_loop)]
     for adapter in social_scraper_adapters() {
         let profile_url = adapter.normalize_profile_url(&entry.url)?;
         let handle = adapter.extract_handle(&profile_url)?;
@@ -787,6 +835,7 @@ fn b2c_candidates_to_checkpoint(candidates: &[B2cDiscoveryCandidate]) -> Vec<Dom
             matched_keywords: candidate.matched_signals.clone(),
             source_links: vec![candidate.profile_url.clone()],
             phone: None,
+            evidence_urls: HashMap::new(),
         })
         .collect()
 }
@@ -803,11 +852,12 @@ async fn discover_b2c_social_candidates(
     } else {
         lead_plan.discovery_queries.clone()
     };
+    let search_limits = SalesSearchLimits::from_profile(profile);
     let mut merged = HashMap::<String, B2cDiscoveryCandidate>::new();
     let mut outputs = run_sales_search_batch(
         search_engine,
         &queries,
-        8,
+        search_limits.discovery(8),
         Duration::from_secs(SALES_DISCOVERY_SEARCH_TIMEOUT_SECS),
     )
     .await;
@@ -816,7 +866,7 @@ async fn discover_b2c_social_candidates(
             outputs = run_sales_search_batch(
                 brave,
                 &queries,
-                8,
+                search_limits.discovery(8),
                 Duration::from_secs(SALES_DISCOVERY_SEARCH_TIMEOUT_SECS),
             )
             .await;
@@ -898,10 +948,8 @@ async fn enrich_b2c_candidates_with_local_market(
     if candidates.is_empty() {
         return candidates;
     }
-    let client = reqwest::Client::builder()
-        .user_agent(
-            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Safari/537.36",
-        )
+    let search_limits = SalesSearchLimits::from_profile(profile);
+    let client = http_client::builder_with_user_agent(SALES_BROWSER_USER_AGENT)
         .timeout(Duration::from_millis(DIRECT_ENRICH_TIMEOUT_MS))
         .build()
         .ok();
@@ -926,7 +974,7 @@ async fn enrich_b2c_candidates_with_local_market(
         let mut outputs = run_sales_search_batch(
             search_engine,
             &queries,
-            4,
+            search_limits.contact(4),
             Duration::from_secs(SALES_OSINT_SEARCH_TIMEOUT_SECS),
         )
         .await
@@ -938,7 +986,7 @@ async fn enrich_b2c_candidates_with_local_market(
                 outputs = run_sales_search_batch(
                     brave,
                     &queries,
-                    4,
+                    search_limits.contact(4),
                     Duration::from_secs(SALES_OSINT_SEARCH_TIMEOUT_SECS),
                 )
                 .await
@@ -1096,6 +1144,9 @@ fn heuristic_lead_query_plan(profile: &SalesProfile) -> LeadQueryPlanDraft {
                 ),
             ]
         }
+    } else if discovery_topic.trim().is_empty() {
+        // Without an industry the queries below are just "companies <geo>".
+        Vec::new()
     } else {
         vec![format!("{discovery_topic} companies {}", geo_aliases[0])]
     };
@@ -1151,7 +1202,7 @@ fn heuristic_lead_query_plan(profile: &SalesProfile) -> LeadQueryPlanDraft {
                 ),
             ]);
         }
-    } else {
+    } else if !discovery_topic.trim().is_empty() {
         discovery_queries.extend([
             format!(
                 "{} organizations {} project operations teams",
@@ -1240,6 +1291,78 @@ fn heuristic_lead_query_plan(profile: &SalesProfile) -> LeadQueryPlanDraft {
     }
 }
 
+/// Words too generic to steer a company search on their own.
+const LAST_RESORT_STOPWORDS: &[&str] = &[
+    "about",
+    "also",
+    "based",
+    "best",
+    "from",
+    "helps",
+    "into",
+    "more",
+    "platform",
+    "product",
+    "service",
+    "services",
+    "software",
+    "solution",
+    "solutions",
+    "that",
+    "their",
+    "this",
+    "tool",
+    "tools",
+    "using",
+    "with",
+    "your",
+];
+
+/// Maximum product keywords the last-resort plan searches on.
+const LAST_RESORT_MAX_KEYWORDS: usize = 4;
+
+/// Final fallback when neither the LLM planner nor the heuristic produced
+/// queries: search on keywords from `product_name` and
+/// `product_description`. Always returns at least one query.
+fn last_resort_lead_query_plan(profile: &SalesProfile) -> LeadQueryPlanDraft {
+    let mut plan = heuristic_lead_query_plan(profile);
+    let text = format!("{} {}", profile.product_name, profile.product_description);
+    let keywords = dedupe_strings(
+        text.split(|c: char| !c.is_alphanumeric() && c != '-')
+            .map(|word| word.trim_matches('-').to_lowercase())
+            .filter(|word| {
+                word.chars().count() >= 4
+                    && !word.chars().all(|c| c.is_ascii_digit())
+                    && !LAST_RESORT_STOPWORDS.contains(&word.as_str())
+            })
+            .collect(),
+    )
+    .into_iter()
+    .take(LAST_RESORT_MAX_KEYWORDS)
+    .collect::<Vec<_>>();
+    let geo = profile.target_geo.trim();
+
+    let mut queries = Vec::new();
+    if keywords.len() >= 2 {
+        queries.push(format!("{} {} companies {geo}", keywords[0], keywords[1]));
+    }
+    queries.extend(
+        keywords
+            .iter()
+            .map(|keyword| format!("companies needing {keyword} {geo}")),
+    );
+    queries.push(format!("small and mid-sized businesses {geo}"));
+
+    plan.discovery_queries = dedupe_strings(
+        queries
+            .into_iter()
+            .filter_map(|query| sanitize_discovery_query(&query))
+            .collect(),
+    );
+    plan.must_include_keywords = expand_keywords(keywords);
+    plan
+}
+
 fn sanitize_discovery_query(raw: &str) -> Option<String> {
     let mut cleaned = raw.trim().to_string();
     let title_noise_patterns = [
@@ -1275,12 +1398,15 @@ fn sanitize_discovery_query(raw: &str) -> Option<String> {
     Some(cleaned)
 }
 
-fn load_sales_codex_auth(home_dir: &FsPath) -> Option<StoredCodexAuth> {
+fn load_sales_codex_auth(home_dir: &FsPath, import_cli: bool) -> Option<StoredCodexAuth> {
     let path = home_dir.join("auth").join("codex_oauth.json");
     if let Ok(raw) = std::fs::read_to_string(path) {
         if let Ok(auth) = serde_json::from_str::<StoredCodexAuth>(&raw) {
             return Some(auth);
         }
     }
+    if !import_cli {
+        return None;
+    }
     crate::codex_oauth::import_codex_cli_auth(home_dir).ok()
 }
@@ -5,13 +5,39 @@ impl SalesEngine {
         }
     }
 
-    fn open(&self) -> Result<Connection, String> {
-        Connection::open(&self.db_path)
-            .map_err(|e| format!("Failed to open sales db {}: {e}", self.db_path.display()))
+    /// Check out a connection from the shared pool, opening a new one if none is idle.
+    ///
+    /// New connections are configured once with WAL and a busy timeout so the
+    /// scheduler and API handlers can write concurrently without "database is
+    /// locked" errors.
+    fn open(&self) -> Result<PooledConnection, String> {
+        let idle = SALES_DB_POOL
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter_mut()
+            .find(|(path, _)| *path == self.db_path)
+            .and_then(|(_, idle)| idle.pop());
+        let conn = match idle {
+            Some(conn) => conn,
+            None => {
+                let conn = Connection::open(&self.db_path).map_err(|e| {
+                    format!("Failed to open sales db {}: {e}", self.db_path.display())
+                })?;
+                conn.busy_timeout(Duration::from_millis(SALES_DB_BUSY_TIMEOUT_MS))
+                    .map_err(|e| format!("Failed to set sales db busy timeout: {e}"))?;
+                conn.pragma_update(None, "journal_mode", "WAL")
+                    .map_err(|e| format!("Failed to enable WAL on sales db: {e}"))?;
+                conn
+            }
+        };
+        Ok(PooledConnection {
+            conn: Some(conn),
+            db_path: self.db_path.clone(),
+        })
     }
 
     pub fn init(&self) -> Result<(), String> {
-        let conn = self.open()?;
+        let mut conn = self.open()?;
         conn.execute_batch(
             r#"
             CREATE TABLE IF NOT EXISTS sales_profile (
@@ -68,6 +94,14 @@ impl SalesEngine {
                 decided_at TEXT
             );
 
+            CREATE TABLE IF NOT EXISTS approval_events (
+                id TEXT PRIMARY KEY,
+                approval_id TEXT NOT NULL,
+                from_status TEXT NOT NULL,
+                to_status TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+
             CREATE TABLE IF NOT EXISTS deliveries (
                 id TEXT PRIMARY KEY,
                 approval_id TEXT NOT NULL,
@@ -483,19 +517,17 @@ impl SalesEngine {
         "#,
         )
         .map_err(|e| format!("Failed to initialize sales db: {e}"))?;
-        ensure_sqlite_column(&conn, "sales_runs", "segment", "TEXT NOT NULL DEFAULT 'b2b'")?;
-        ensure_sqlite_column(
-            &conn,
-            "prospect_profiles",
-            "segment",
-            "TEXT NOT NULL DEFAULT 'b2b'",
-        )?;
-        ensure_sqlite_column(&conn, "job_runs", "segment", "TEXT NOT NULL DEFAULT 'b2b'")?;
+        run_sales_db_migrations(&mut conn)?;
         self.migrate_legacy_to_canonical_core()?;
         seed_contextual_factors(&conn);
         Ok(())
     }
 
+    /// Current sales DB schema version (`PRAGMA user_version`).
+    pub fn schema_version(&self) -> Result<u32, String> {
+        sales_db_schema_version(&*self.open()?)
+    }
+
     pub fn get_profile(&self, segment: SalesSegment) -> Result<Option<SalesProfile>, String> {
         let conn = self.open()?;
         let row = conn
@@ -544,6 +576,140 @@ impl SalesEngine {
         Ok(())
     }
 
+    /// All campaigns, default first, without their profiles.
+    pub fn list_campaigns(&self) -> Result<Vec<SalesCampaign>, String> {
+        let conn = self.open()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, name, active, created_at, updated_at FROM sales_campaigns
+                 ORDER BY id = ?1 DESC, created_at ASC",
+            )
+            .map_err(|e| format!("Prepare list campaigns failed: {e}"))?;
+        let rows = stmt
+            .query_map(params![DEFAULT_SALES_CAMPAIGN_ID], sales_campaign_from_row)
+            .map_err(|e| format!("List campaigns query failed: {e}"))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("List campaigns row failed: {e}"))
+    }
+
+    /// One campaign with its profile, if it exists.
+    pub fn get_campaign(&self, campaign_id: &str) -> Result<Option<SalesCampaign>, String> {
+        let campaign = self
+            .open()?
+            .query_row(
+                "SELECT id, name, active, created_at, updated_at FROM sales_campaigns WHERE id = ?1",
+                params![campaign_id],
+                sales_campaign_from_row,
+            )
+            .optional()
+            .map_err(|e| format!("Campaign query failed: {e}"))?;
+        let Some(mut campaign) = campaign else {
+            return Ok(None);
+        };
+        campaign.profile = self.get_campaign_profile(campaign_id)?;
+        Ok(Some(campaign))
+    }
+
+    /// Profile a campaign runs with. The default campaign reads the B2B
+    /// segment profile, so the legacy `/api/sales/profile` stays in sync.
+    pub fn get_campaign_profile(&self, campaign_id: &str) -> Result<Option<SalesProfile>, String> {
+        if campaign_id == DEFAULT_SALES_CAMPAIGN_ID {
+            return self.get_profile(SalesSegment::B2B);
+        }
+        let json = self
+            .open()?
+            .query_row(
+                "SELECT profile_json FROM sales_campaigns WHERE id = ?1",
+                params![campaign_id],
+                |r| r.get::<_, Option<String>>(0),
+            )
+            .optional()
+            .map_err(|e| format!("Campaign profile query failed: {e}"))?
+            .flatten();
+        json.map(|json| {
+            serde_json::from_str::<SalesProfile>(&json)
+                .map_err(|e| format!("Invalid campaign profile JSON in DB: {e}"))
+        })
+        .transpose()
+    }
+
+    pub fn upsert_campaign_profile(
+        &self,
+        campaign_id: &str,
+        profile: &SalesProfile,
+    ) -> Result<(), String> {
+        let conn = self.open()?;
+        let json = if campaign_id == DEFAULT_SALES_CAMPAIGN_ID {
+            self.upsert_profile(SalesSegment::B2B, profile)?;
+            None
+        } else {
+            let normalized = normalize_sales_profile(profile.clone())?;
+            Some(serde_json::to_string(&normalized).map_err(|e| format!("Serialize failed: {e}"))?)
+        };
+        let updated = conn
+            .execute(
+                "UPDATE sales_campaigns SET profile_json = COALESCE(?2, profile_json), updated_at = ?3
+                 WHERE id = ?1",
+                params![campaign_id, json, Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| format!("Failed to save campaign profile: {e}"))?;
+        if updated == 0 {
+            return Err("Campaign not found".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn create_campaign(&self, req: &SalesCampaignRequest) -> Result<SalesCampaign, String> {
+        let name = normalize_campaign_name(req.name.as_deref().unwrap_or_default())?;
+        let profile_json = req
+            .profile
+            .clone()
+            .map(normalize_sales_profile)
+            .transpose()?
+            .map(|profile| serde_json::to_string(&profile))
+            .transpose()
+            .map_err(|e| format!("Serialize failed: {e}"))?;
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+        self.open()?
+            .execute(
+                "INSERT INTO sales_campaigns (id, name, profile_json, active, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?5)",
+                params![id, name, profile_json, req.active.unwrap_or(true), now],
+            )
+            .map_err(|e| format!("Failed to create campaign: {e}"))?;
+        self.get_campaign(&id)?
+            .ok_or_else(|| "Campaign not found".to_string())
+    }
+
+    /// Rename, pause/resume or re-profile a campaign.
+    pub fn update_campaign(
+        &self,
+        campaign_id: &str,
+        req: &SalesCampaignRequest,
+    ) -> Result<SalesCampaign, String> {
+        if self.get_campaign(campaign_id)?.is_none() {
+            return Err("Campaign not found".to_string());
+        }
+        let name = req
+            .name
+            .as_deref()
+            .map(normalize_campaign_name)
+            .transpose()?;
+        if let Some(profile) = &req.profile {
+            self.upsert_campaign_profile(campaign_id, profile)?;
+        }
+        self.open()?
+            .execute(
+                "UPDATE sales_campaigns SET name = COALESCE(?2, name), active = COALESCE(?3, active), updated_at = ?4
+                 WHERE id = ?1",
+                params![campaign_id, name, req.active, Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| format!("Failed to update campaign: {e}"))?;
+        self.get_campaign(campaign_id)?
+            .ok_or_else(|| "Campaign not found".to_string())
+    }
+
     pub fn set_onboarding_brief(&self, segment: SalesSegment, brief: &str) -> Result<(), String> {
         let conn = self.open()?;
         conn.execute(
@@ -776,6 +942,9 @@ impl SalesEngine {
             inserted,
             approvals_queued,
             error: error_note,
+            telemetry: None,
+            candidates_evaluated: 0,
+            candidates_qualified: 0,
         }))
     }
 
@@ -797,11 +966,19 @@ impl SalesEngine {
     }
 
     fn begin_run(&self, segment: SalesSegment) -> Result<String, String> {
+        self.begin_campaign_run(segment, DEFAULT_SALES_CAMPAIGN_ID)
+    }
+
+    fn begin_campaign_run(
+        &self,
+        segment: SalesSegment,
+        campaign_id: &str,
+    ) -> Result<String, String> {
         let conn = self.open()?;
         let run_id = uuid::Uuid::new_v4().to_string();
         conn.execute(
-            "INSERT INTO sales_runs (id, segment, status, started_at) VALUES (?1, ?2, 'running', ?3)",
-            params![run_id, segment.as_str(), Utc::now().to_rfc3339()],
+            "INSERT INTO sales_runs (id, segment, campaign_id, status, started_at) VALUES (?1, ?2, ?3, 'running', ?4)",
+            params![run_id, segment.as_str(), campaign_id, Utc::now().to_rfc3339()],
         )
         .map_err(|e| format!("Failed to create run row: {e}"))?;
         Ok(run_id)
@@ -833,6 +1010,37 @@ impl SalesEngine {
         Ok(())
     }
 
+    fn record_run_funnel(
+        &self,
+        run_id: &str,
+        candidates_evaluated: u32,
+        candidates_qualified: u32,
+    ) -> Result<(), String> {
+        let conn = self.open()?;
+        conn.execute(
+            "UPDATE sales_runs SET candidates_evaluated = ?, candidates_qualified = ? WHERE id = ?",
+            params![candidates_evaluated, candidates_qualified, run_id],
+        )
+        .map_err(|e| format!("Failed to record run funnel: {e}"))?;
+        Ok(())
+    }
+
+    fn record_run_telemetry(
+        &self,
+        run_id: &str,
+        telemetry: &SalesRunTelemetry,
+    ) -> Result<(), String> {
+        let conn = self.open()?;
+        let json = serde_json::to_string(telemetry)
+            .map_err(|e| format!("Serialize run telemetry failed: {e}"))?;
+        conn.execute(
+            "UPDATE sales_runs SET telemetry_json = ? WHERE id = ?",
+            params![json, run_id],
+        )
+        .map_err(|e| format!("Failed to record run telemetry: {e}"))?;
+        Ok(())
+    }
+
     fn create_job_run(&self, job_type: &str, segment: SalesSegment) -> Result<String, String> {
         let conn = self.open()?;
         let job_id = uuid::Uuid::new_v4().to_string();
@@ -1127,10 +1335,16 @@ impl SalesEngine {
         .map_err(|e| format!("Pending approval lookup failed: {e}"))
     }
 
+    /// Whether a contact value, or the domain of an email address, is suppressed.
     fn is_suppressed(&self, conn: &Connection, contact_value: &str) -> Result<bool, String> {
+        let value = contact_value.trim().to_lowercase();
+        let domain = value
+            .rsplit_once('@')
+            .map(|(_, domain)| domain.to_string())
+            .unwrap_or_else(|| value.clone());
         conn.query_row(
-            "SELECT COUNT(*) FROM suppressions WHERE contact_method_value = ?1",
-            params![contact_value.trim().to_lowercase()],
+            "SELECT COUNT(*) FROM suppressions WHERE contact_method_value IN (?1, ?2)",
+            params![value, domain],
             |row| row.get::<_, i64>(0),
         )
         .map(|count| count > 0)
@@ -1233,14 +1447,334 @@ impl SalesEngine {
 
     fn update_lead_status(&self, lead_id: &str, status: &str) -> Result<(), String> {
         let conn = self.open()?;
+        let previous = conn
+            .query_row(
+                "SELECT status FROM leads WHERE id = ?",
+                params![lead_id],
+                |r| r.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|e| format!("Lead status lookup failed: {e}"))?;
         conn.execute(
             "UPDATE leads SET status = ?2 WHERE id = ?1",
             params![lead_id, status],
         )
         .map_err(|e| format!("Failed to update lead status: {e}"))?;
+        if let Some(previous) = previous.filter(|previous| previous != status) {
+            record_lead_status_change(&conn, lead_id, &previous, status, None)?;
+        }
         Ok(())
     }
 
+    /// Move a lead along its lifecycle, rejecting transitions the pipeline does not allow.
+    pub fn transition_lead_status(
+        &self,
+        lead_id: &str,
+        to_status: &str,
+        note: Option<&str>,
+    ) -> Result<LeadStatusChange, String> {
+        let to_status = to_status.trim();
+        if !LEAD_LIFECYCLE_STATUSES.contains(&to_status) {
+            return Err(format!(
+                "Unknown lead status '{to_status}' (expected one of: {})",
+                LEAD_LIFECYCLE_STATUSES.join(", ")
+            ));
+        }
+        let mut conn = self.open()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start lead status update: {e}"))?;
+        let from_status = tx
+            .query_row(
+                "SELECT status FROM leads WHERE id = ?",
+                params![lead_id],
+                |r| r.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|e| format!("Lead status lookup failed: {e}"))?
+            .ok_or_else(|| "Lead not found".to_string())?;
+        if !lead_status_transition_allowed(&from_status, to_status) {
+            return Err(format!(
+                "Invalid lead status transition: {from_status} -> {to_status}"
+            ));
+        }
+        tx.execute(
+            "UPDATE leads SET status = ?2 WHERE id = ?1",
+            params![lead_id, to_status],
+        )
+        .map_err(|e| format!("Failed to update lead status: {e}"))?;
+        let change = record_lead_status_change(&tx, lead_id, &from_status, to_status, note)?;
+        tx.commit()
+            .map_err(|e| format!("Failed to commit lead status update: {e}"))?;
+        Ok(change)
+    }
+
+    /// "Do not contact again": permanently suppress the lead's email (or its
+    /// domain when it has none), reject its pending approvals and archive it.
+    pub fn block_lead(&self, lead_id: &str, note: Option<&str>) -> Result<SalesLeadBlock, String> {
+        let lead = self
+            .get_lead_by_id(lead_id)?
+            .ok_or_else(|| "Lead not found".to_string())?;
+        let value = lead
+            .email
+            .as_deref()
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .unwrap_or_else(|| lead.company_domain.trim())
+            .to_lowercase();
+        if value.is_empty() {
+            return Err("Lead has no email or domain to suppress".to_string());
+        }
+
+        let mut conn = self.open()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start lead block: {e}"))?;
+        self.suppress_contact(&tx, &value, "do_not_contact", true, None)?;
+        tx.execute(
+            "UPDATE contact_methods SET suppressed = 1 WHERE value = ?1",
+            params![value],
+        )
+        .map_err(|e| format!("Failed to update suppressed contact method: {e}"))?;
+        let suppression = tx
+            .query_row(
+                "SELECT id, contact_method_value, reason, source_outcome_id, suppressed_at, permanent
+                 FROM suppressions WHERE contact_method_value = ?1",
+                params![value],
+                |r| {
+                    Ok(SalesSuppression {
+                        id: r.get(0)?,
+                        contact_method_value: r.get(1)?,
+                        reason: r.get(2)?,
+                        source_outcome_id: r.get(3)?,
+                        suppressed_at: r.get(4)?,
+                        permanent: r.get::<_, i64>(5)? == 1,
+                    })
+                },
+            )
+            .map_err(|e| format!("Suppression lookup failed: {e}"))?;
+        let approvals_rejected = tx
+            .execute(
+                "UPDATE approvals SET status = 'rejected', decided_at = ?2
+                 WHERE lead_id = ?1 AND status = 'pending'",
+                params![lead_id, Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| format!("Failed to reject pending approvals: {e}"))?;
+        let status_change = if lead.status == "archived" {
+            None
+        } else {
+            tx.execute(
+                "UPDATE leads SET status = 'archived' WHERE id = ?1",
+                params![lead_id],
+            )
+            .map_err(|e| format!("Failed to archive lead: {e}"))?;
+            Some(record_lead_status_change(
+                &tx,
+                lead_id,
+                &lead.status,
+                "archived",
+                Some(note.unwrap_or("do not contact")),
+            )?)
+        };
+        tx.commit()
+            .map_err(|e| format!("Failed to commit lead block: {e}"))?;
+        Ok(SalesLeadBlock {
+            suppression,
+            approvals_rejected,
+            status_change,
+        })
+    }
+
+    /// Import CRM leads as drafts keyed by their `external_id`, so running the
+    /// same import again refreshes drafts instead of duplicating them.
+    /// Invalid rows are reported in [`SalesLeadImport::errors`] and skipped.
+    pub fn import_leads(&self, items: &[SalesLeadImportItem]) -> Result<SalesLeadImport, String> {
+        let mut summary = SalesLeadImport::default();
+        let created_at = Utc::now().to_rfc3339();
+        for (index, item) in items.iter().enumerate() {
+            let lead = match lead_from_import(item, &created_at) {
+                Ok(lead) => lead,
+                Err(e) => {
+                    summary.errors.push(format!("leads[{index}]: {e}"));
+                    continue;
+                }
+            };
+            match self.insert_lead(&lead, 0)? {
+                LeadWriteOutcome::Inserted => summary.inserted += 1,
+                LeadWriteOutcome::Updated | LeadWriteOutcome::Reengaged { .. } => {
+                    summary.updated += 1
+                }
+                LeadWriteOutcome::Duplicate => summary.duplicates += 1,
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Apply operator corrections to a lead and carry changed outreach fields
+    /// into that lead's pending approvals. Returns the updated lead and the
+    /// number of approvals rewritten.
+    pub fn edit_lead(
+        &self,
+        lead_id: &str,
+        edit: &SalesLeadEditRequest,
+    ) -> Result<(SalesLead, usize), String> {
+        let mut lead = self
+            .get_lead_by_id(lead_id)?
+            .ok_or_else(|| "Lead not found".to_string())?;
+        let required = |field: &str, value: &Option<String>| -> Result<Option<String>, String> {
+            match value.as_deref().map(str::trim) {
+                None => Ok(None),
+                Some("") => Err(format!("{field} cannot be empty")),
+                Some(value) => Ok(Some(value.to_string())),
+            }
+        };
+        if let Some(name) = required("contact_name", &edit.contact_name)? {
+            lead.contact_name = name;
+        }
+        if let Some(title) = required("contact_title", &edit.contact_title)? {
+            lead.contact_title = title;
+        }
+        if let Some(email) = required("email", &edit.email)? {
+            let normalized = normalize_email_candidate(Some(email.clone()))
+                .filter(|value| email_syntax_valid(value))
+                .ok_or_else(|| format!("Invalid email address: {email}"))?;
+            if lead.email.as_deref() != Some(normalized.as_str()) {
+                // The operator vouches for the address they typed.
+                lead.contact_confidence = None;
+            }
+            lead.email = Some(normalized);
+        }
+        if let Some(url) = required("linkedin_url", &edit.linkedin_url)? {
+            let normalized = normalize_outreach_linkedin_url(&url).ok_or_else(|| {
+                format!("Invalid LinkedIn profile URL (expected linkedin.com/in/...): {url}")
+            })?;
+            lead.linkedin_url = Some(normalized);
+        }
+        if let Some(subject) = required("email_subject", &edit.email_subject)? {
+            lead.email_subject = subject;
+        }
+        if let Some(body) = required("email_body", &edit.email_body)? {
+            lead.email_body = body;
+        }
+        if let Some(message) = required("linkedin_message", &edit.linkedin_message)? {
+            lead.linkedin_message = message;
+        }
+
+        let mut conn = self.open()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start lead edit: {e}"))?;
+        tx.execute(
+            "UPDATE leads SET contact_name = ?2, contact_title = ?3, email = ?4, linkedin_url = ?5,
+                 email_subject = ?6, email_body = ?7, linkedin_message = ?8, contact_confidence = ?9
+             WHERE id = ?1",
+            params![
+                lead.id,
+                lead.contact_name,
+                lead.contact_title,
+                lead.email,
+                lead.linkedin_url,
+                lead.email_subject,
+                lead.email_body,
+                lead.linkedin_message,
+                lead.contact_confidence
+                    .map(|confidence| confidence.as_str()),
+            ],
+        )
+        .map_err(|e| {
+            if matches!(
+                &e,
+                rusqlite::Error::SqliteFailure(err, _)
+                    if err.code == rusqlite::ErrorCode::ConstraintViolation
+            ) {
+                "Another lead at this company already has that contact name and title".to_string()
+            } else {
+                format!("Failed to update lead: {e}")
+            }
+        })?;
+
+        let email_changed =
+            edit.email.is_some() || edit.email_subject.is_some() || edit.email_body.is_some();
+        let linkedin_changed = edit.linkedin_url.is_some() || edit.linkedin_message.is_some();
+        let pending = {
+            let mut stmt = tx
+                .prepare(
+                    "SELECT id, channel, payload_json FROM approvals
+                     WHERE lead_id = ?1 AND status = 'pending'",
+                )
+                .map_err(|e| format!("Prepare pending approvals failed: {e}"))?;
+            let rows = stmt
+                .query_map(params![lead.id], |r| {
+                    Ok((
+                        r.get::<_, String>(0)?,
+                        r.get::<_, String>(1)?,
+                        r.get::<_, String>(2)?,
+                    ))
+                })
+                .map_err(|e| format!("Pending approvals query failed: {e}"))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Pending approvals decode failed: {e}"))?
+        };
+        let mut updated = 0usize;
+        for (approval_id, channel, payload_json) in pending {
+            let mut payload = serde_json::from_str::<serde_json::Value>(&payload_json)
+                .unwrap_or_else(|_| serde_json::json!({}));
+            match channel.as_str() {
+                "email" if email_changed => {
+                    payload["to"] = serde_json::json!(lead.email);
+                    payload["subject"] = serde_json::json!(lead.email_subject);
+                    payload["body"] = serde_json::json!(lead.email_body);
+                }
+                "linkedin" | "linkedin_assist" | "linkedin_connect" if linkedin_changed => {
+                    payload["profile_url"] = serde_json::json!(lead.linkedin_url);
+                    payload["message"] = serde_json::json!(lead.linkedin_message);
+                }
+                _ => continue,
+            }
+            let sanitized = sanitize_approval_payload(&channel, payload).ok_or_else(|| {
+                format!("Edited lead produces an invalid {channel} approval payload")
+            })?;
+            let payload_json = sanitized.to_string();
+            tx.execute(
+                "UPDATE approvals SET payload_json = ?2 WHERE id = ?1",
+                params![approval_id, payload_json],
+            )
+            .map_err(|e| format!("Failed to update approval payload: {e}"))?;
+            let _ = tx.execute(
+                "UPDATE touches SET message_payload = ?2 WHERE id = ?1",
+                params![approval_id, payload_json],
+            );
+            updated += 1;
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to commit lead edit: {e}"))?;
+        Ok((lead, updated))
+    }
+
+    pub fn lead_status_history(&self, lead_id: &str) -> Result<Vec<LeadStatusChange>, String> {
+        let conn = self.open()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, lead_id, from_status, to_status, note, changed_at
+                 FROM lead_status_history WHERE lead_id = ? ORDER BY changed_at ASC, rowid ASC",
+            )
+            .map_err(|e| format!("Prepare lead status history failed: {e}"))?;
+        let rows = stmt
+            .query_map(params![lead_id], |r| {
+                Ok(LeadStatusChange {
+                    id: r.get(0)?,
+                    lead_id: r.get(1)?,
+                    from_status: r.get(2)?,
+                    to_status: r.get(3)?,
+                    note: r.get(4)?,
+                    changed_at: r.get(5)?,
+                })
+            })
+            .map_err(|e| format!("Lead status history query failed: {e}"))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Lead status history decode failed: {e}"))
+    }
+
     fn select_accounts_for_activation(
         &self,
         conn: &Connection,
@@ -2779,7 +3313,7 @@ impl SalesEngine {
         let now = Utc::now().to_rfc3339();
         let initial_step = if channel == "email" {
             1
-        } else if channel == "linkedin_assist" {
+        } else if channel == "linkedin_assist" || channel == "linkedin_connect" {
             4
         } else {
             2
@@ -2867,7 +3401,7 @@ impl SalesEngine {
                 .flatten()
             })
             .unwrap_or_else(|| "[]".to_string());
-        let risk_flags = if channel == "linkedin_assist" {
+        let risk_flags = if channel == "linkedin_assist" || channel == "linkedin_connect" {
             serde_json::json!(["manual_action"]).to_string()
         } else {
             serde_json::json!([]).to_string()
@@ -3126,14 +3660,112 @@ impl SalesEngine {
         Ok(rows)
     }
 
-    fn insert_lead(&self, lead: &SalesLead) -> Result<bool, String> {
+    /// Insert a lead, or refresh the existing draft when `external_id` matches.
+    ///
+    /// Leads without an external id keep the company/contact/title dedup:
+    /// a repeat is reported as [`LeadWriteOutcome::Duplicate`]. The stored
+    /// id, status and created_at of an updated lead are left untouched, and
+    /// a lead that has left the draft stage is not rewritten (`Duplicate`).
+    ///
+    /// `window_days` scopes that dedup to a rolling window; 0 keeps it
+    /// all-time. Within a window, a lead is suppressed when its domain already
+    /// has a lead created or an outreach delivered since the cutoff, and a
+    /// company/contact match older than that is refreshed in place (new run,
+    /// draft, status and created_at) as [`LeadWriteOutcome::Reengaged`].
+    fn insert_lead(&self, lead: &SalesLead, window_days: u32) -> Result<LeadWriteOutcome, String> {
+        self.insert_scored_lead(lead, window_days, LeadScoreFloors::default())
+    }
+
+    /// [`Self::insert_lead`] for run-discovered leads, storing the score
+    /// `floors` with the row.
+    fn insert_scored_lead(
+        &self,
+        lead: &SalesLead,
+        window_days: u32,
+        floors: LeadScoreFloors,
+    ) -> Result<LeadWriteOutcome, String> {
         let conn = self.open()?;
         let reasons_json = serde_json::to_string(&lead.reasons)
             .map_err(|e| format!("Failed to encode reasons: {e}"))?;
+        let external_id = lead
+            .external_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty());
+        let contact_confidence = lead
+            .contact_confidence
+            .map(|confidence| confidence.as_str());
+
+        let is_constraint_violation = |e: &rusqlite::Error| {
+            matches!(
+                e,
+                rusqlite::Error::SqliteFailure(err, _)
+                    if err.code == rusqlite::ErrorCode::ConstraintViolation
+            )
+        };
+
+        if let Some(external_id) = external_id {
+            let updated = conn.execute(
+                "UPDATE leads
+                 SET company = ?2, website = ?3, company_domain = ?4, contact_name = ?5,
+                     contact_title = ?6, linkedin_url = ?7, email = ?8, phone = ?9,
+                     reasons_json = ?10, email_subject = ?11, email_body = ?12,
+                     linkedin_message = ?13, score = ?14, contact_confidence = ?15
+                 WHERE external_id = ?1
+                   AND status IN ('draft_ready', 'activation_candidate', 'activation_backlog')",
+                params![
+                    external_id,
+                    lead.company,
+                    lead.website,
+                    lead.company_domain,
+                    lead.contact_name,
+                    lead.contact_title,
+                    lead.linkedin_url,
+                    lead.email,
+                    lead.phone,
+                    reasons_json,
+                    lead.email_subject,
+                    lead.email_body,
+                    lead.linkedin_message,
+                    lead.score,
+                    contact_confidence,
+                ],
+            );
+            match updated {
+                Ok(0) => {
+                    let exists = conn
+                        .query_row(
+                            "SELECT 1 FROM leads WHERE external_id = ?1",
+                            params![external_id],
+                            |_| Ok(()),
+                        )
+                        .optional()
+                        .map_err(|e| format!("Lead external id lookup failed: {e}"))?
+                        .is_some();
+                    if exists {
+                        return Ok(LeadWriteOutcome::Duplicate);
+                    }
+                }
+                Ok(_) => return Ok(LeadWriteOutcome::Updated),
+                Err(e) if is_constraint_violation(&e) => return Ok(LeadWriteOutcome::Duplicate),
+                Err(e) => return Err(format!("Lead update failed: {e}")),
+            }
+        }
+
+        let window_days = window_days.min(SALES_DEDUPE_WINDOW_DAYS_MAX);
+        if window_days > 0
+            && domain_contacted_since(
+                &conn,
+                &lead.company_domain,
+                &(Utc::now() - chrono::Duration::days(i64::from(window_days))).to_rfc3339(),
+            )?
+        {
+            return Ok(LeadWriteOutcome::Duplicate);
+        }
 
         match conn.execute(
-            "INSERT INTO leads (id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO leads (id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at, external_id, contact_confidence, discovery_score, activation_score)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 lead.id,
                 lead.run_id,
@@ -3152,24 +3784,36 @@ impl SalesEngine {
                 lead.score,
                 lead.status,
                 lead.created_at,
+                external_id,
+                contact_confidence,
+                floors.discovery,
+                floors.activation,
             ],
         ) {
-            Ok(_) => Ok(true),
-            Err(rusqlite::Error::SqliteFailure(err, _))
-                if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-            {
-                Ok(false)
+            Ok(_) => Ok(LeadWriteOutcome::Inserted),
+            Err(e) if is_constraint_violation(&e) && window_days > 0 => {
+                reengage_lead(&conn, lead, &reasons_json, floors)
             }
+            Err(e) if is_constraint_violation(&e) => Ok(LeadWriteOutcome::Duplicate),
             Err(e) => Err(format!("Lead insert failed: {e}")),
         }
     }
 
-    fn queue_approvals_for_lead(&self, lead: &SalesLead) -> Result<u32, String> {
+    /// Queue email and LinkedIn approvals for `lead` under the run's `profile`
+    /// (campaign or segment), skipping channels that already have one pending.
+    fn queue_approvals_for_lead(
+        &self,
+        lead: &SalesLead,
+        profile: &SalesProfile,
+    ) -> Result<u32, String> {
         let conn = self.open()?;
         let created_at = Utc::now().to_rfc3339();
         let mut queued = 0u32;
 
-        if let Some(email) = &lead.email {
+        let email = lead.email.as_ref().filter(|_| {
+            email_meets_contact_confidence(lead.contact_confidence, profile.min_contact_confidence)
+        });
+        if let Some(email) = email {
             if !self.approval_already_pending(&conn, "email", email)? {
                 let approval_id = uuid::Uuid::new_v4().to_string();
                 let payload = serde_json::json!({
@@ -3199,23 +3843,38 @@ impl SalesEngine {
             .as_deref()
             .and_then(normalize_outreach_linkedin_url)
         {
-            if !self.approval_already_pending(&conn, "linkedin_assist", &linkedin_url)? {
+            let (channel, payload) = if profile.linkedin_connect_with_note {
+                (
+                    "linkedin_connect",
+                    serde_json::json!({
+                        "profile_url": linkedin_url,
+                        "message": linkedin_connect_note(&lead.linkedin_message),
+                        "action": "connect_with_note",
+                        "manual_action": true,
+                    }),
+                )
+            } else {
+                (
+                    "linkedin_assist",
+                    serde_json::json!({
+                        "profile_url": linkedin_url,
+                        "message": lead.linkedin_message,
+                        "manual_action": true,
+                    }),
+                )
+            };
+            if !self.approval_already_pending(&conn, channel, &linkedin_url)? {
                 let approval_id = uuid::Uuid::new_v4().to_string();
-                let payload = serde_json::json!({
-                    "profile_url": linkedin_url,
-                    "message": lead.linkedin_message,
-                    "manual_action": true,
-                });
                 conn.execute(
-                    "INSERT INTO approvals (id, lead_id, channel, payload_json, status, created_at) VALUES (?, ?, 'linkedin_assist', ?, 'pending', ?)",
-                    params![approval_id, lead.id, payload.to_string(), created_at],
+                    "INSERT INTO approvals (id, lead_id, channel, payload_json, status, created_at) VALUES (?, ?, ?, ?, 'pending', ?)",
+                    params![approval_id, lead.id, channel, payload.to_string(), created_at],
                 )
                 .map_err(|e| format!("Queue LinkedIn approval failed: {e}"))?;
                 self.ensure_touch_for_approval(
                     &conn,
                     lead,
                     &approval_id,
-                    "linkedin_assist",
+                    channel,
                     &payload.to_string(),
                 )?;
                 queued += 1;
@@ -3229,20 +3888,38 @@ impl SalesEngine {
         &self,
         segment: SalesSegment,
         limit: usize,
+    ) -> Result<Vec<SalesRunRecord>, String> {
+        self.query_runs(segment, None, limit)
+    }
+
+    pub fn list_campaign_runs(
+        &self,
+        campaign_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SalesRunRecord>, String> {
+        self.query_runs(SalesSegment::B2B, Some(campaign_id), limit)
+    }
+
+    fn query_runs(
+        &self,
+        segment: SalesSegment,
+        campaign_id: Option<&str>,
+        limit: usize,
     ) -> Result<Vec<SalesRunRecord>, String> {
         let conn = self.open()?;
         let mut stmt = conn
             .prepare(
-                "SELECT id, status, started_at, completed_at, discovered, inserted, approvals_queued, error
+                "SELECT id, status, started_at, completed_at, discovered, inserted, approvals_queued, error, telemetry_json,
+                        candidates_evaluated, candidates_qualified
                  FROM sales_runs
-                 WHERE segment = ?1
+                 WHERE segment = ?1 AND (?3 IS NULL OR campaign_id = ?3)
                  ORDER BY started_at DESC
                  LIMIT ?2",
             )
             .map_err(|e| format!("Prepare list runs failed: {e}"))?;
 
         let mut rows = stmt
-            .query(params![segment.as_str(), limit as i64])
+            .query(params![segment.as_str(), limit as i64, campaign_id])
             .map_err(|e| format!("List runs query failed: {e}"))?;
 
         let mut out = Vec::new();
@@ -3276,6 +3953,13 @@ impl SalesEngine {
                 inserted,
                 approvals_queued,
                 error: r.get(7).ok(),
+                telemetry: r
+                    .get::<_, Option<String>>(8)
+                    .ok()
+                    .flatten()
+                    .and_then(|json| serde_json::from_str(&json).ok()),
+                candidates_evaluated: r.get::<_, i64>(9).unwrap_or(0) as u32,
+                candidates_qualified: r.get::<_, i64>(10).unwrap_or(0) as u32,
             });
         }
 
@@ -3283,59 +3967,233 @@ impl SalesEngine {
     }
 
     pub fn list_leads(&self, limit: usize, run_id: Option<&str>) -> Result<Vec<SalesLead>, String> {
-        let conn = self.open()?;
-        let sql_with_run = "SELECT id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at
-                 FROM leads WHERE run_id = ? ORDER BY created_at DESC LIMIT ?";
-        let sql_all = "SELECT id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at
-                 FROM leads ORDER BY created_at DESC LIMIT ?";
+        self.query_leads(None, run_id, limit)
+    }
 
-        let mut stmt = conn
-            .prepare(if run_id.is_some() {
-                sql_with_run
-            } else {
-                sql_all
-            })
+    pub fn list_campaign_leads(
+        &self,
+        campaign_id: &str,
+        run_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SalesLead>, String> {
+        self.query_leads(Some(campaign_id), run_id, limit)
+    }
+
+    fn query_leads(
+        &self,
+        campaign_id: Option<&str>,
+        run_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SalesLead>, String> {
+        let conn = self.open()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at, external_id, contact_confidence
+                 FROM leads
+                 WHERE (?1 IS NULL OR campaign_id = ?1) AND (?2 IS NULL OR run_id = ?2)
+                 ORDER BY created_at DESC LIMIT ?3",
+            )
             .map_err(|e| format!("Prepare list leads failed: {e}"))?;
 
-        let mut rows = if let Some(rid) = run_id {
-            stmt.query(params![rid, limit as i64])
-                .map_err(|e| format!("List leads query failed: {e}"))?
-        } else {
-            stmt.query(params![limit as i64])
-                .map_err(|e| format!("List leads query failed: {e}"))?
-        };
+        let mut rows = stmt
+            .query(params![campaign_id, run_id, limit as i64])
+            .map_err(|e| format!("List leads query failed: {e}"))?;
 
         let mut out = Vec::new();
         while let Some(r) = rows
             .next()
             .map_err(|e| format!("List leads row read failed: {e}"))?
         {
-            let reasons_json: String = r.get(10).unwrap_or_else(|_| "[]".to_string());
-            let reasons = serde_json::from_str::<Vec<String>>(&reasons_json).unwrap_or_default();
-            out.push(SalesLead {
-                id: r.get(0).unwrap_or_default(),
-                run_id: r.get(1).unwrap_or_default(),
-                company: r.get(2).unwrap_or_default(),
-                website: r.get(3).unwrap_or_default(),
-                company_domain: r.get(4).unwrap_or_default(),
-                contact_name: r.get(5).unwrap_or_default(),
-                contact_title: r.get(6).unwrap_or_default(),
-                linkedin_url: r.get(7).ok(),
-                email: r.get(8).ok(),
-                phone: r.get(9).ok(),
-                reasons,
-                email_subject: r.get(11).unwrap_or_default(),
-                email_body: r.get(12).unwrap_or_default(),
-                linkedin_message: r.get(13).unwrap_or_default(),
-                score: r.get::<_, i64>(14).unwrap_or(0) as i32,
-                status: r.get(15).unwrap_or_default(),
-                created_at: r.get(16).unwrap_or_default(),
-            });
+            out.push(sales_lead_from_row(r));
         }
 
         Ok(out)
     }
 
+    /// Keyword search over lead company, reasons, subject and body.
+    ///
+    /// Uses the `leads_fts` index ranked by bm25, creating it first if it is
+    /// missing, otherwise a `LIKE` scan ordered by score. Returns the matches
+    /// and the mode used.
+    pub fn search_leads(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<(Vec<SalesLead>, &'static str), String> {
+        let conn = self.open()?;
+        let has_fts = conn
+            .query_row(
+                "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'leads_fts'",
+                [],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_err(|e| format!("Lead search index lookup failed: {e}"))?
+            .is_some();
+        let has_fts = has_fts || create_leads_fts(&conn)?;
+
+        let (sql, pattern, mode) = match leads_fts_query(query) {
+            Some(fts_query) if has_fts => (
+                "SELECT l.id, l.run_id, l.company, l.website, l.company_domain, l.contact_name, l.contact_title, l.linkedin_url, l.email, l.phone, l.reasons_json, l.email_subject, l.email_body, l.linkedin_message, l.score, l.status, l.created_at, l.external_id, l.contact_confidence
+                 FROM leads_fts JOIN leads l ON l.rowid = leads_fts.rowid
+                 WHERE leads_fts MATCH ?1 ORDER BY bm25(leads_fts) LIMIT ?2",
+                fts_query,
+                "fts",
+            ),
+            Some(_) => (
+                "SELECT id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at, external_id, contact_confidence
+                 FROM leads
+                 WHERE company LIKE ?1 ESCAPE '\\' OR reasons_json LIKE ?1 ESCAPE '\\'
+                    OR email_subject LIKE ?1 ESCAPE '\\' OR email_body LIKE ?1 ESCAPE '\\'
+                 ORDER BY score DESC, created_at DESC LIMIT ?2",
+                format!(
+                    "%{}%",
+                    query
+                        .trim()
+                        .replace('\\', "\\\\")
+                        .replace('%', "\\%")
+                        .replace('_', "\\_")
+                ),
+                "like",
+            ),
+            None => return Ok((Vec::new(), if has_fts { "fts" } else { "like" })),
+        };
+
+        let mut stmt = conn
+            .prepare(sql)
+            .map_err(|e| format!("Prepare lead search failed: {e}"))?;
+        let leads = stmt
+            .query_map(params![pattern, limit as i64], |r| {
+                Ok(sales_lead_from_row(r))
+            })
+            .map_err(|e| format!("Lead search query failed: {e}"))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Lead search row read failed: {e}"))?;
+        Ok((leads, mode))
+    }
+
+    fn get_lead_by_id(&self, lead_id: &str) -> Result<Option<SalesLead>, String> {
+        let conn = self.open()?;
+        conn.query_row(
+            "SELECT id, run_id, company, website, company_domain, contact_name, contact_title, linkedin_url, email, phone, reasons_json, email_subject, email_body, linkedin_message, score, status, created_at, external_id, contact_confidence
+             FROM leads WHERE id = ?",
+            params![lead_id],
+            |r| Ok(sales_lead_from_row(r)),
+        )
+        .optional()
+        .map_err(|e| format!("Lead lookup failed: {e}"))
+    }
+
+    /// Recompute `score` for one page of stored leads, ordered by id, against
+    /// the profile of the campaign (or segment) whose run produced each lead.
+    /// The discovery and activation floors stored at run time still apply, so
+    /// a lead never drops below what its run found. Pass the returned
+    /// `next_after` as `after` to continue; only rows whose score moved are
+    /// written.
+    pub fn rescore_leads(
+        &self,
+        status: Option<&str>,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<SalesLeadRescore, String> {
+        let limit = limit.clamp(1, LEAD_RESCORE_PAGE_LIMIT);
+        let status = status.map(str::trim).filter(|status| !status.is_empty());
+        let after = after.map(str::trim).filter(|after| !after.is_empty());
+
+        let mut conn = self.open()?;
+        let rows = {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT l.id, l.run_id, l.company, l.website, l.company_domain, l.contact_name, l.contact_title, l.linkedin_url, l.email, l.phone, l.reasons_json, l.email_subject, l.email_body, l.linkedin_message, l.score, l.status, l.created_at, l.external_id, l.contact_confidence,
+                            l.discovery_score, l.activation_score, COALESCE(r.segment, 'b2b'), l.campaign_id
+                     FROM leads l
+                     LEFT JOIN sales_runs r ON r.id = l.run_id
+                     WHERE (?1 IS NULL OR l.status = ?1) AND (?2 IS NULL OR l.id > ?2)
+                     ORDER BY l.id LIMIT ?3",
+                )
+                .map_err(|e| format!("Prepare rescore query failed: {e}"))?;
+            let rows = stmt
+                .query_map(params![status, after, limit as i64], |r| {
+                    Ok((
+                        sales_lead_from_row(r),
+                        LeadScoreFloors {
+                            discovery: r.get(19)?,
+                            activation: r.get(20)?,
+                        },
+                        SalesSegment::from_optional(r.get::<_, String>(21).ok().as_deref()),
+                        r.get::<_, String>(22)?,
+                    ))
+                })
+                .map_err(|e| format!("Rescore query failed: {e}"))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Rescore row read failed: {e}"))?
+        };
+
+        let mut scores = Vec::with_capacity(rows.len());
+        let mut scorers = HashMap::new();
+        for (lead, floors, segment, campaign_id) in &rows {
+            let key = (segment.as_str(), campaign_id.clone());
+            if !scorers.contains_key(&key) {
+                let profile = if segment.is_b2c() {
+                    self.get_profile(*segment)?
+                } else {
+                    self.get_campaign_profile(campaign_id)?
+                }
+                .ok_or_else(|| RESCORE_PROFILE_MISSING.to_string())?;
+                let plan = heuristic_lead_query_plan(&profile);
+                let is_field_ops = profile_targets_field_ops(&profile);
+                scorers.insert(key.clone(), (profile, plan, is_field_ops));
+            }
+            let (profile, plan, is_field_ops) = &scorers[&key];
+            let score = lead_fit_score(lead, profile, plan, *is_field_ops)
+                .max(floors.discovery)
+                .max(floors.activation);
+            scores.push(score);
+        }
+        let leads = rows.into_iter().map(|(lead, ..)| lead).collect::<Vec<_>>();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start rescore transaction: {e}"))?;
+        let mut changed = 0;
+        for (lead, &score) in leads.iter().zip(&scores) {
+            if score == lead.score {
+                continue;
+            }
+            tx.execute(
+                "UPDATE leads SET score = ?2 WHERE id = ?1",
+                params![lead.id, score],
+            )
+            .map_err(|e| format!("Failed to update lead score: {e}"))?;
+            changed += 1;
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to commit rescore: {e}"))?;
+
+        Ok(SalesLeadRescore {
+            scanned: leads.len(),
+            changed,
+            next_after: (leads.len() == limit)
+                .then(|| leads.last().map(|lead| lead.id.clone()))
+                .flatten(),
+        })
+    }
+
+    /// A lead with its approvals and the deliveries made from them, or
+    /// `None` for an unknown id.
+    pub fn lead_detail(&self, lead_id: &str) -> Result<Option<SalesLeadDetail>, String> {
+        let Some(lead) = self.get_lead_by_id(lead_id)? else {
+            return Ok(None);
+        };
+        let approvals = self.query_approvals(None, Some(lead_id), None, LEAD_DETAIL_LIMIT)?;
+        let deliveries = self.query_deliveries(None, Some(lead_id), LEAD_DETAIL_LIMIT)?;
+        Ok(Some(SalesLeadDetail {
+            lead,
+            approvals,
+            deliveries,
+        }))
+    }
+
     pub fn list_prospect_profiles(
         &self,
         segment: SalesSegment,
@@ -3479,30 +4337,38 @@ impl SalesEngine {
         status: Option<&str>,
         limit: usize,
     ) -> Result<Vec<SalesApproval>, String> {
-        let conn = self.open()?;
-        let (sql, args): (&str, Vec<String>) = if let Some(s) = status {
-            (
-                "SELECT id, lead_id, channel, payload_json, status, created_at, decided_at FROM approvals WHERE status = ? ORDER BY created_at DESC LIMIT ?",
-                vec![s.to_string(), limit.to_string()],
-            )
-        } else {
-            (
-                "SELECT id, lead_id, channel, payload_json, status, created_at, decided_at FROM approvals ORDER BY created_at DESC LIMIT ?",
-                vec![limit.to_string()],
-            )
-        };
+        self.query_approvals(None, None, status, limit)
+    }
+
+    pub fn list_campaign_approvals(
+        &self,
+        campaign_id: &str,
+        status: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SalesApproval>, String> {
+        self.query_approvals(Some(campaign_id), None, status, limit)
+    }
 
+    fn query_approvals(
+        &self,
+        campaign_id: Option<&str>,
+        lead_id: Option<&str>,
+        status: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SalesApproval>, String> {
+        let conn = self.open()?;
         let mut stmt = conn
-            .prepare(sql)
+            .prepare(
+                "SELECT id, lead_id, channel, payload_json, status, created_at, decided_at FROM approvals
+                 WHERE (?1 IS NULL OR campaign_id = ?1) AND (?2 IS NULL OR status = ?2)
+                   AND (?4 IS NULL OR lead_id = ?4)
+                 ORDER BY created_at DESC LIMIT ?3",
+            )
             .map_err(|e| format!("Prepare approvals query failed: {e}"))?;
 
-        let mut rows = if args.len() == 2 {
-            stmt.query(params![args[0], args[1]])
-                .map_err(|e| format!("Approvals query failed: {e}"))?
-        } else {
-            stmt.query(params![args[0]])
-                .map_err(|e| format!("Approvals query failed: {e}"))?
-        };
+        let mut rows = stmt
+            .query(params![campaign_id, status, limit as i64, lead_id])
+            .map_err(|e| format!("Approvals query failed: {e}"))?;
 
         let mut out = Vec::new();
         while let Some(r) = rows
@@ -4085,15 +4951,36 @@ impl SalesEngine {
     }
 
     pub fn list_deliveries(&self, limit: usize) -> Result<Vec<SalesDelivery>, String> {
+        self.query_deliveries(None, None, limit)
+    }
+
+    pub fn list_campaign_deliveries(
+        &self,
+        campaign_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SalesDelivery>, String> {
+        self.query_deliveries(Some(campaign_id), None, limit)
+    }
+
+    fn query_deliveries(
+        &self,
+        campaign_id: Option<&str>,
+        lead_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SalesDelivery>, String> {
         let conn = self.open()?;
         let mut stmt = conn
             .prepare(
-                "SELECT id, approval_id, channel, recipient, status, error, sent_at FROM deliveries ORDER BY sent_at DESC LIMIT ?",
+                "SELECT id, approval_id, channel, recipient, status, error, sent_at, attachment_count
+                 FROM deliveries
+                 WHERE (?1 IS NULL OR campaign_id = ?1)
+                   AND (?3 IS NULL OR approval_id IN (SELECT id FROM approvals WHERE lead_id = ?3))
+                 ORDER BY sent_at DESC LIMIT ?2",
             )
             .map_err(|e| format!("Prepare deliveries query failed: {e}"))?;
 
         let mut rows = stmt
-            .query(params![limit as i64])
+            .query(params![campaign_id, limit as i64, lead_id])
             .map_err(|e| format!("Deliveries query failed: {e}"))?;
 
         let mut out = Vec::new();
@@ -4109,6 +4996,7 @@ impl SalesEngine {
                 status: r.get(4).unwrap_or_default(),
                 error: r.get(5).ok(),
                 sent_at: r.get(6).unwrap_or_default(),
+                attachment_count: r.get(7).unwrap_or_default(),
             });
         }
 
@@ -4116,26 +5004,78 @@ impl SalesEngine {
     }
 
     fn deliveries_today(&self, timezone_mode: &str) -> Result<u32, String> {
+        let (start, end) = current_sales_day_utc_bounds(timezone_mode);
+        self.open()?
+            .query_row(
+                "SELECT COUNT(*) FROM deliveries
+                 WHERE status = 'sent' AND sent_at >= ?1 AND sent_at < ?2",
+                params![start, end],
+                |r| r.get::<_, i64>(0),
+            )
+            .map(|count| count.max(0) as u32)
+            .map_err(|e| format!("Deliveries count query failed: {e}"))
+    }
+
+    /// Dashboard aggregates computed with `COUNT`/`GROUP BY` queries.
+    ///
+    /// Leads, approvals and deliveries are shared across segments; the send
+    /// cap comes from the B2B profile (the one approvals are sent under) and
+    /// the last run is the segment's most recent one.
+    pub fn stats(&self, segment: SalesSegment) -> Result<SalesStats, String> {
         let conn = self.open()?;
-        let today = current_sales_day(timezone_mode);
-        let mut stmt = conn
-            .prepare("SELECT sent_at FROM deliveries WHERE status = 'sent'")
-            .map_err(|e| format!("Deliveries count prepare failed: {e}"))?;
-        let mut rows = stmt
-            .query([])
-            .map_err(|e| format!("Deliveries count query failed: {e}"))?;
+        let count_by_status = |table: &str| -> Result<BTreeMap<String, u64>, String> {
+            let mut stmt = conn
+                .prepare(&format!(
+                    "SELECT status, COUNT(*) FROM {table} GROUP BY status"
+                ))
+                .map_err(|e| format!("Prepare {table} stats failed: {e}"))?;
+            let rows = stmt
+                .query_map([], |r| {
+                    Ok((r.get::<_, String>(0)?, r.get::<_, i64>(1)?.max(0) as u64))
+                })
+                .map_err(|e| format!("{table} stats query failed: {e}"))?;
+            rows.collect::<Result<BTreeMap<_, _>, _>>()
+                .map_err(|e| format!("{table} stats row failed: {e}"))
+        };
 
-        let mut count = 0u32;
-        while let Some(row) = rows
-            .next()
-            .map_err(|e| format!("Deliveries count row failed: {e}"))?
-        {
-            let sent_at: String = row.get(0).unwrap_or_default();
-            if timestamp_matches_sales_day(&sent_at, today, timezone_mode) {
-                count += 1;
-            }
-        }
-        Ok(count)
+        let leads_by_status = count_by_status("leads")?;
+        let approvals_by_status = count_by_status("approvals")?;
+        let deliveries_by_status = count_by_status("deliveries")?;
+
+        let profile = self.get_profile(SalesSegment::B2B)?.unwrap_or_default();
+        let (start, end) = current_sales_day_utc_bounds(&profile.timezone_mode);
+        let (sent_today, failed_today) = conn
+            .query_row(
+                "SELECT COALESCE(SUM(status = 'sent'), 0), COALESCE(SUM(status = 'failed'), 0)
+                 FROM deliveries
+                 WHERE sent_at >= ?1 AND sent_at < ?2",
+                params![start, end],
+                |r| Ok((r.get::<_, i64>(0)?, r.get::<_, i64>(1)?)),
+            )
+            .map_err(|e| format!("Deliveries today query failed: {e}"))?;
+        let sent_today = sent_today.max(0) as u64;
+        let status_count =
+            |map: &BTreeMap<String, u64>, status: &str| map.get(status).copied().unwrap_or(0);
+
+        Ok(SalesStats {
+            total_leads: leads_by_status.values().sum(),
+            approvals_pending: status_count(&approvals_by_status, "pending"),
+            approvals_approved: status_count(&approvals_by_status, "approved"),
+            approvals_rejected: status_count(&approvals_by_status, "rejected"),
+            deliveries_sent_today: sent_today,
+            deliveries_failed_today: failed_today.max(0) as u64,
+            deliveries_sent_total: status_count(&deliveries_by_status, "sent"),
+            deliveries_failed_total: status_count(&deliveries_by_status, "failed"),
+            deliveries_sandboxed_total: status_count(&deliveries_by_status, "sandboxed"),
+            sandbox_mode: false,
+            daily_send_cap: profile.daily_send_cap,
+            sends_remaining_today: profile
+                .daily_send_cap
+                .saturating_sub(sent_today.min(u32::MAX as u64) as u32),
+            last_run: self.list_runs(segment, 1)?.into_iter().next(),
+            leads_by_status,
+            approvals_by_status,
+        })
     }
 
     /// Load sender config from DB sender_policies table if available.
@@ -4229,18 +5169,27 @@ impl SalesEngine {
         Ok(())
     }
 
+    /// Send `email`, retrying transient SMTP failures up to
+    /// `channels.email.send_retries` times with doubling backoff. Returns the
+    /// number of attempts made.
     async fn send_email(
         &self,
         state: &AppState,
         profile: &SalesProfile,
-        to: &str,
-        subject: &str,
-        body: &str,
-    ) -> Result<(), String> {
+        email: &OutboundEmail<'_>,
+    ) -> Result<u32, String> {
+        let OutboundEmail {
+            to,
+            subject,
+            body,
+            html_body,
+            attachments,
+            dedupe_key,
+        } = *email;
         // Try mailbox pool first (TASK-13), fall back to global config
         let mut sender_cfg = self.load_sender_config();
         let selected_mailbox = sender_cfg.select_mailbox().cloned();
-        let (smtp_host, smtp_port, smtp_user, smtp_pass, from_email, used_mailbox_pool) =
+        let (smtp_host, smtp_port, smtp_user, smtp_pass, from_email, tls_mode, used_mailbox_pool) =
             if let Some(mb) = selected_mailbox {
                 let pass_env = if mb.smtp_pass_env.is_empty() {
                     // Fall through to global config
@@ -4257,21 +5206,45 @@ impl SalesEngine {
                         mb.smtp_user.clone()
                     };
                     let email = mb.email.clone();
-                    (host, port, user, pass, email, true)
+                    let tls_mode = mb.tls_mode.unwrap_or_else(|| {
+                        state
+                            .kernel
+                            .config_snapshot()
+                            .channels
+                            .email
+                            .as_ref()
+                            .map(|cfg| cfg.tls_mode)
+                            .unwrap_or_default()
+                    });
+                    (host, port, user, pass, email, tls_mode, true)
                 } else {
-                    let (host, port, user, pass, email) =
+                    let (host, port, user, pass, email, tls_mode) =
                         self.resolve_global_email_config(state).await?;
-                    (host, port, user, pass, email, false)
+                    (host, port, user, pass, email, tls_mode, false)
                 }
             } else {
-                let (host, port, user, pass, email) =
+                let (host, port, user, pass, email, tls_mode) =
                     self.resolve_global_email_config(state).await?;
-                (host, port, user, pass, email, false)
+                (host, port, user, pass, email, tls_mode, false)
             };
 
         let from: Mailbox = from_email
             .parse()
             .map_err(|e| format!("Invalid sender email '{}': {e}", from_email))?;
+        let email_cfg = state
+            .kernel
+            .config_snapshot()
+            .channels
+            .email
+            .clone()
+            .unwrap_or_default();
+        let from_name = if email_cfg.from_name.trim().is_empty() {
+            profile.sender_name.trim().to_string()
+        } else {
+            email_cfg.from_name.trim().to_string()
+        };
+        validate_sender_display_name(&from_name)?;
+        let from = Mailbox::new((!from_name.is_empty()).then_some(from_name), from.email);
         let recipient_email = to.trim().to_string();
         let to: Mailbox = to
             .parse()
@@ -4294,40 +5267,119 @@ impl SalesEngine {
             generate_unsubscribe_token(&recipient_email, &from_email)
         );
 
-        let msg = Message::builder()
+        let unsubscribe_mailto = match email_cfg.unsubscribe_mailto.trim() {
+            "" => None,
+            raw => Some(
+                raw.parse::<Address>()
+                    .map_err(|e| format!("Invalid unsubscribe_mailto '{raw}': {e}"))?,
+            ),
+        };
+        let custom_headers = sanitize_custom_email_headers(&email_cfg.custom_headers)?;
+
+        let mut builder = Message::builder()
             .from(from)
             .to(to)
             .subject(subject)
-            .header(ListUnsubscribeHeader(format!("<{}>", unsubscribe_url)))
+            .header(ListUnsubscribeHeader(list_unsubscribe_value(
+                &unsubscribe_url,
+                unsubscribe_mailto.as_ref(),
+            )))
             .header(ListUnsubscribePostHeader(
                 "List-Unsubscribe=One-Click".to_string(),
-            ))
-            .body(body.to_string())
-            .map_err(|e| format!("Failed to build email message: {e}"))?;
+            ));
+        if let Some(key) = dedupe_key {
+            builder = builder.message_id(Some(outbound_message_id(key, &sender_domain)));
+        }
+        if !email_cfg.reply_to.trim().is_empty() {
+            let reply_to: Mailbox = email_cfg
+                .reply_to
+                .trim()
+                .parse()
+                .map_err(|e| format!("Invalid reply_to '{}': {e}", email_cfg.reply_to))?;
+            builder = builder.reply_to(reply_to);
+        }
+        let alternative = html_body
+            .map(|html| MultiPart::alternative_plain_html(body.to_string(), html.to_string()));
+        let msg = if attachments.is_empty() {
+            match alternative {
+                Some(alternative) => builder.multipart(alternative),
+                None => builder.body(body.to_string()),
+            }
+        } else {
+            let parts = build_email_attachment_parts(
+                attachments,
+                &state.kernel.home_dir().join(SALES_ATTACHMENTS_DIR),
+                email_cfg.max_attachment_bytes,
+            )?;
+            let mixed = match alternative {
+                Some(alternative) => MultiPart::mixed().multipart(alternative),
+                None => MultiPart::mixed().singlepart(SinglePart::plain(body.to_string())),
+            };
+            builder.multipart(
+                parts
+                    .into_iter()
+                    .fold(mixed, |multipart, part| multipart.singlepart(part)),
+            )
+        }
+        .map_err(|e| format!("Failed to build email message: {e}"))?;
 
-        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp_host)
-            .map_err(|e| format!("Failed to initialize SMTP relay '{}': {e}", smtp_host))?
-            .port(smtp_port)
-            .credentials(Credentials::new(smtp_user, smtp_pass))
-            .build();
+        let transport = build_smtp_transport(
+            &smtp_host,
+            smtp_port,
+            tls_mode,
+            Credentials::new(smtp_user, smtp_pass),
+        )?;
 
-        transport
-            .send(msg)
-            .await
-            .map_err(|e| format!("SMTP send failed: {e}"))?;
+        let raw = with_custom_email_headers(msg.formatted(), &custom_headers);
+        let max_attempts = email_cfg.send_retries.saturating_add(1);
+        let mut attempt = 1;
+        loop {
+            match transport.send_raw(msg.envelope(), &raw).await {
+                Ok(_) => break,
+                Err(e) if attempt < max_attempts && smtp_error_is_retryable(&e) => {
+                    let delay = smtp_retry_delay(attempt);
+                    warn!(
+                        attempt,
+                        max_attempts,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %e,
+                        "Transient SMTP failure, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) if attempt > 1 => {
+                    return Err(format!("SMTP send failed after {attempt} attempts: {e}"))
+                }
+                Err(e) => return Err(format!("SMTP send failed: {e}")),
+            }
+        }
 
         if used_mailbox_pool {
             self.record_mailbox_send(&from_email)?;
         }
 
-        Ok(())
+        Ok(attempt)
+    }
+
+    /// Whether a delivery for `approval_id` already went out, so a retried
+    /// approve must not send it again.
+    fn approval_already_delivered(&self, approval_id: &str) -> Result<bool, String> {
+        self.open()?
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM deliveries
+                 WHERE approval_id = ?1 AND status IN ('sent', 'sandboxed'))",
+                params![approval_id],
+                |row| row.get::<_, bool>(0),
+            )
+            .map_err(|e| format!("Delivery lookup failed: {e}"))
     }
 
     /// Resolve SMTP config from global email channel configuration.
     async fn resolve_global_email_config(
         &self,
         state: &AppState,
-    ) -> Result<(String, u16, String, String, String), String> {
+    ) -> Result<(String, u16, String, String, String, SmtpTlsMode), String> {
         let config = state.kernel.config_snapshot();
         let cfg = config
             .channels
@@ -4342,22 +5394,18 @@ impl SalesEngine {
             cfg.username.clone(),
             password,
             cfg.username.clone(),
+            cfg.tls_mode,
         ))
     }
 
     async fn send_linkedin(
         &self,
         approval_id: &str,
+        channel: &str,
         profile_url: &str,
         _message: &str,
     ) -> Result<(), String> {
-        self.record_delivery(
-            approval_id,
-            "linkedin_assist",
-            profile_url,
-            "operator_pending",
-            None,
-        )
+        self.record_delivery(approval_id, channel, profile_url, "operator_pending", None)
     }
 
     fn record_delivery(
@@ -4368,10 +5416,24 @@ impl SalesEngine {
         status: &str,
         error_msg: Option<&str>,
     ) -> Result<(), String> {
+        self.record_delivery_with_attachments(approval_id, channel, recipient, status, error_msg, 0)
+    }
+
+    fn record_delivery_with_attachments(
+        &self,
+        approval_id: &str,
+        channel: &str,
+        recipient: &str,
+        status: &str,
+        error_msg: Option<&str>,
+        attachment_count: usize,
+    ) -> Result<(), String> {
+        crate::metrics::record_sales_delivery(channel, status);
         let conn = self.open()?;
         let sent_at = Utc::now().to_rfc3339();
         conn.execute(
-            "INSERT INTO deliveries (id, approval_id, channel, recipient, status, error, sent_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO deliveries (id, approval_id, channel, recipient, status, error, sent_at, attachment_count)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 uuid::Uuid::new_v4().to_string(),
                 approval_id,
@@ -4380,6 +5442,7 @@ impl SalesEngine {
                 status,
                 error_msg,
                 sent_at,
+                attachment_count as i64,
             ],
         )
         .map_err(|e| format!("Failed to record delivery: {e}"))?;
@@ -4433,7 +5496,7 @@ impl SalesEngine {
             .query_row(
                 "SELECT a.id, a.channel, a.payload_json, l.id, l.run_id, l.company, l.website, l.company_domain,
                         l.contact_name, l.contact_title, l.linkedin_url, l.email, l.phone, l.reasons_json,
-                        l.email_subject, l.email_body, l.linkedin_message, l.score, l.status, l.created_at
+                        l.email_subject, l.email_body, l.linkedin_message, l.score, l.status, l.created_at, l.external_id, l.contact_confidence
                  FROM approvals a
                  JOIN leads l ON l.id = a.lead_id
                  WHERE a.id = ?1",
@@ -4463,6 +5526,10 @@ impl SalesEngine {
                             score: row.get::<_, i64>(17)? as i32,
                             status: row.get::<_, String>(18)?,
                             created_at: row.get::<_, String>(19)?,
+                            external_id: row.get::<_, Option<String>>(20)?,
+                            contact_confidence: row
+                                .get::<_, Option<String>>(21)?
+                                .and_then(|value| ContactConfidence::parse(&value)),
                         },
                     ))
                 },
@@ -4641,11 +5708,24 @@ impl SalesEngine {
         &self,
         state: &AppState,
         approval_id: &str,
+    ) -> Result<serde_json::Value, String> {
+        self.approve_and_send_with_options(state, approval_id, false)
+            .await
+    }
+
+    /// Approve and send, deferring email outside the profile's business-hours
+    /// window unless `override_business_hours` is set. A deferred approval
+    /// stays pending and the result reports `next_eligible_at`.
+    pub async fn approve_and_send_with_options(
+        &self,
+        state: &AppState,
+        approval_id: &str,
+        override_business_hours: bool,
     ) -> Result<serde_json::Value, String> {
         let row = {
             let conn = self.open()?;
             conn.query_row(
-                "SELECT id, channel, payload_json, status FROM approvals WHERE id = ?",
+                "SELECT id, channel, payload_json, status, campaign_id FROM approvals WHERE id = ?",
                 params![approval_id],
                 |r| {
                     Ok((
@@ -4653,6 +5733,7 @@ impl SalesEngine {
                         r.get::<_, String>(1)?,
                         r.get::<_, String>(2)?,
                         r.get::<_, String>(3)?,
+                        r.get::<_, String>(4)?,
                     ))
                 },
             )
@@ -4660,7 +5741,7 @@ impl SalesEngine {
             .map_err(|e| format!("Approval lookup failed: {e}"))?
         };
 
-        let (id, channel, payload_raw, status) =
+        let (id, channel, payload_raw, status, campaign_id) =
             row.ok_or_else(|| "Approval not found".to_string())?;
         if status != "pending" {
             return Err(format!(
@@ -4680,8 +5761,30 @@ impl SalesEngine {
             ));
         }
 
+        // LinkedIn channels are operator-assisted, so only email is held back.
+        if channel == "email" && !override_business_hours {
+            if let Some(window) = SendWindow::from_profile(&profile) {
+                let now = Utc::now();
+                if !window.allows(now) {
+                    let next_eligible_at = window.next_open(now).map(|at| at.to_rfc3339());
+                    info!(
+                        approval_id = %id,
+                        next_eligible_at = ?next_eligible_at,
+                        "Deferred email outside business hours"
+                    );
+                    return Ok(serde_json::json!({
+                        "channel": channel,
+                        "status": "deferred",
+                        "reason": "outside_business_hours",
+                        "next_eligible_at": next_eligible_at,
+                    }));
+                }
+            }
+        }
+
         let payload: serde_json::Value = serde_json::from_str(&payload_raw)
             .map_err(|e| format!("Invalid approval payload JSON: {e}"))?;
+        let sandbox = outbound_sandbox(&state.kernel);
 
         let result = match channel.as_str() {
             "email" => {
@@ -4697,6 +5800,17 @@ impl SalesEngine {
                     .get("body")
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| "Missing payload.body".to_string())?;
+                let html_body = self.approval_email_html(&campaign_id, &payload, body)?;
+                let attachments = email_attachments_from_payload(&payload)?;
+                if self.approval_already_delivered(&id)? {
+                    self.update_approval_status(&id, "approved")?;
+                    info!(approval_id = %id, "Email already delivered; not sending again");
+                    return Ok(serde_json::json!({
+                        "channel": "email",
+                        "recipient": to,
+                        "status": "already_sent",
+                    }));
+                }
                 let (suppressed, bounce_count) = {
                     let conn = self.open()?;
                     let suppressed = self.is_suppressed(&conn, to)?;
@@ -4737,29 +5851,92 @@ impl SalesEngine {
                         validation.classification, validation.mx_valid
                     ));
                 }
-                if let Err(send_err) = self.send_email(state, &profile, to, subject, body).await {
-                    if let Err(record_err) =
-                        self.record_delivery(&id, "email", to, "failed", Some(&send_err))
-                    {
-                        warn!(
-                            approval_id = %id,
-                            error = %record_err,
-                            "Failed to record email delivery failure"
-                        );
+                let redirect_to = sandbox
+                    .as_ref()
+                    .map(|sandbox| sandbox.redirect_to.as_str())
+                    .filter(|redirect| !redirect.is_empty());
+                let email = OutboundEmail {
+                    to,
+                    subject,
+                    body,
+                    html_body: html_body.as_deref(),
+                    attachments: &attachments,
+                    dedupe_key: Some(&id),
+                };
+                let send_result = match (&sandbox, redirect_to) {
+                    (None, _) => self.send_email(state, &profile, &email).await,
+                    (Some(_), Some(redirect)) => {
+                        let subject = format!("[SANDBOX for {to}] {subject}");
+                        let email = OutboundEmail {
+                            to: redirect,
+                            subject: &subject,
+                            ..email
+                        };
+                        self.send_email(state, &profile, &email).await
                     }
-                    return Err(send_err);
+                    (Some(_), None) => Ok(0),
+                };
+                let attempts = match send_result {
+                    Ok(attempts) => attempts,
+                    Err(send_err) => {
+                        if let Err(record_err) = self.record_delivery_with_attachments(
+                            &id,
+                            "email",
+                            to,
+                            "failed",
+                            Some(&send_err),
+                            attachments.len(),
+                        ) {
+                            warn!(
+                                approval_id = %id,
+                                error = %record_err,
+                                "Failed to record email delivery failure"
+                            );
+                        }
+                        return Err(send_err);
+                    }
+                };
+                let (delivery_status, mut note) = match (&sandbox, redirect_to) {
+                    (None, _) => ("sent", None),
+                    (Some(_), Some(redirect)) => (
+                        "sandboxed",
+                        Some(format!("Sandbox: redirected to {redirect}")),
+                    ),
+                    (Some(_), None) => ("sandboxed", Some("Sandbox: send skipped".to_string())),
+                };
+                if attempts > 1 {
+                    let retried = format!("Delivered after {attempts} attempts");
+                    note = Some(match note {
+                        Some(note) => format!("{note}; {retried}"),
+                        None => retried,
+                    });
+                }
+                if sandbox.is_some() {
+                    info!(approval_id = %id, recipient = %to, redirect_to = ?redirect_to, "Sandboxed outbound email");
                 }
                 self.update_approval_status(&id, "approved")?;
-                if let Err(record_err) = self.record_delivery(&id, "email", to, "sent", None) {
+                if let Err(record_err) = self.record_delivery_with_attachments(
+                    &id,
+                    "email",
+                    to,
+                    delivery_status,
+                    note.as_deref(),
+                    attachments.len(),
+                ) {
                     warn!(
                         approval_id = %id,
                         error = %record_err,
                         "Failed to record email delivery after successful send"
                     );
                 }
-                serde_json::json!({"channel": "email", "recipient": to, "status": "sent"})
+                serde_json::json!({"channel": "email", "recipient": to, "status": delivery_status, "redirected_to": redirect_to})
             }
-            "linkedin" | "linkedin_assist" => {
+            "linkedin" | "linkedin_assist" | "linkedin_connect" => {
+                let delivery_channel = if channel == "linkedin_connect" {
+                    "linkedin_connect"
+                } else {
+                    "linkedin_assist"
+                };
                 let profile_url = payload
                     .get("profile_url")
                     .and_then(|v| v.as_str())
@@ -4768,10 +5945,27 @@ impl SalesEngine {
                     .get("message")
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| "Missing payload.message".to_string())?;
-                if let Err(send_err) = self.send_linkedin(&id, profile_url, message).await {
+                if sandbox.is_some() {
+                    info!(approval_id = %id, recipient = %profile_url, "Sandboxed LinkedIn action");
+                    self.record_delivery(
+                        &id,
+                        delivery_channel,
+                        profile_url,
+                        "sandboxed",
+                        Some("Sandbox: operator action skipped"),
+                    )?;
+                    self.update_approval_status(&id, "approved")?;
+                    return Ok(
+                        serde_json::json!({"channel": delivery_channel, "recipient": profile_url, "status": "sandboxed"}),
+                    );
+                }
+                if let Err(send_err) = self
+                    .send_linkedin(&id, delivery_channel, profile_url, message)
+                    .await
+                {
                     if let Err(record_err) = self.record_delivery(
                         &id,
-                        "linkedin_assist",
+                        delivery_channel,
                         profile_url,
                         "failed",
                         Some(&send_err),
@@ -4785,13 +5979,102 @@ impl SalesEngine {
                     return Err(send_err);
                 }
                 self.update_approval_status(&id, "approved")?;
-                serde_json::json!({"channel": "linkedin_assist", "recipient": profile_url, "status": "operator_pending"})
+                serde_json::json!({"channel": delivery_channel, "recipient": profile_url, "status": "operator_pending"})
             }
             other => return Err(format!("Unsupported channel: {other}")),
         };
         Ok(result)
     }
 
+    /// HTML part for an email approval: the payload's sanitized `html_body`
+    /// when set, otherwise `body` rendered at send time from the campaign's
+    /// `email_html_template`, so edits to the body carry through.
+    fn approval_email_html(
+        &self,
+        campaign_id: &str,
+        payload: &serde_json::Value,
+        body: &str,
+    ) -> Result<Option<String>, String> {
+        if let Some(html_body) = payload
+            .get("html_body")
+            .and_then(|v| v.as_str())
+            .map(sanitize_email_html)
+            .filter(|html| !html.trim().is_empty())
+        {
+            return Ok(Some(html_body));
+        }
+        Ok(self
+            .get_campaign_profile(campaign_id)?
+            .and_then(|profile| render_email_html(&profile.email_html_template, body)))
+    }
+
+    /// Render the outreach email for a lead (or a synthetic sample) and send it
+    /// to the operator's own `sender_email`. Bypasses the daily send cap and does
+    /// not record a delivery, so real recipients and caps are untouched.
+    pub async fn send_test_email(
+        &self,
+        state: &AppState,
+        lead_id: Option<&str>,
+    ) -> Result<serde_json::Value, String> {
+        let profile = self
+            .get_profile(SalesSegment::B2B)?
+            .ok_or_else(|| "Sales profile is not configured".to_string())?;
+        let lead = match lead_id.map(str::trim).filter(|id| !id.is_empty()) {
+            Some(id) => Some(
+                self.get_lead_by_id(id)?
+                    .ok_or_else(|| "Lead not found".to_string())?,
+            ),
+            None => None,
+        };
+        let (subject, body) = build_test_send_email(&profile, lead.as_ref());
+        let to = profile.sender_email.trim().to_string();
+        let html_body = render_email_html(&profile.email_html_template, &body);
+        let email = OutboundEmail {
+            to: &to,
+            subject: &subject,
+            body: &body,
+            html_body: html_body.as_deref(),
+            attachments: &[],
+            dedupe_key: None,
+        };
+        self.send_email(state, &profile, &email).await?;
+        Ok(serde_json::json!({
+            "recipient": to,
+            "subject": subject,
+            "lead_id": lead.map(|lead| lead.id),
+            "status": "sent",
+            "test": true,
+        }))
+    }
+
+    fn count_pending_approvals(&self) -> Result<u32, String> {
+        let conn = self.open()?;
+        conn.query_row(
+            "SELECT COUNT(*) FROM approvals WHERE status = 'pending'",
+            [],
+            |r| r.get::<_, i64>(0),
+        )
+        .map(|count| count.max(0) as u32)
+        .map_err(|e| format!("Pending approval count failed: {e}"))
+    }
+
+    /// Mark pending approvals created more than `older_than_days` ago as
+    /// `expired`. Returns how many were expired.
+    pub fn expire_stale_approvals(&self, older_than_days: u32) -> Result<usize, String> {
+        if older_than_days == 0 {
+            return Err("older_than_days must be at least 1".to_string());
+        }
+        let now = Utc::now();
+        let cutoff = now - chrono::Duration::days(i64::from(older_than_days));
+        let conn = self.open()?;
+        conn.execute(
+            "UPDATE approvals SET status = 'expired', decided_at = ?2
+             WHERE status = 'pending' AND created_at < ?1",
+            params![cutoff.to_rfc3339(), now.to_rfc3339()],
+        )
+        .map_err(|e| format!("Failed to expire stale approvals: {e}"))
+    }
+
     pub fn reject_approval(&self, approval_id: &str) -> Result<(), String> {
         let conn = self.open()?;
         let status = conn
@@ -4811,14 +6094,121 @@ impl SalesEngine {
         self.update_approval_status(approval_id, "rejected")
     }
 
+    /// Store the verdict for a guessed lead email: `Some(confidence)` upgrades
+    /// it, `None` drops the address from the lead and its contact record.
+    fn apply_email_verification(
+        &self,
+        lead_id: &str,
+        email: &str,
+        confidence: Option<ContactConfidence>,
+    ) -> Result<(), String> {
+        let mut conn = self.open()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start email verification update: {e}"))?;
+        match confidence {
+            Some(confidence) => {
+                tx.execute(
+                    "UPDATE leads SET contact_confidence = ?2 WHERE id = ?1 AND email = ?3",
+                    params![lead_id, confidence.as_str(), email],
+                )
+                .map_err(|e| format!("Failed to update lead email confidence: {e}"))?;
+            }
+            None => {
+                tx.execute(
+                    "UPDATE leads SET email = NULL, contact_confidence = NULL WHERE id = ?1 AND email = ?2",
+                    params![lead_id, email],
+                )
+                .map_err(|e| format!("Failed to drop lead email: {e}"))?;
+                tx.execute(
+                    "DELETE FROM contact_methods WHERE channel_type = 'email' AND value = ?1",
+                    params![email],
+                )
+                .map_err(|e| format!("Failed to drop contact email: {e}"))?;
+            }
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to commit email verification update: {e}"))
+    }
+
+    /// Move a `rejected` or `failed` approval back to `pending` so it can be
+    /// reviewed again. Approved approvals are never reopened because their
+    /// message has already gone out.
+    pub fn reopen_approval(&self, approval_id: &str) -> Result<(), String> {
+        let mut conn = self.open()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start approval reopen: {e}"))?;
+        let status = tx
+            .query_row(
+                "SELECT status FROM approvals WHERE id = ?",
+                params![approval_id],
+                |r| r.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|e| format!("Approval lookup failed: {e}"))?
+            .ok_or_else(|| "Approval not found".to_string())?;
+        if !matches!(status.as_str(), "rejected" | "failed") {
+            return Err(format!(
+                "Only rejected or failed approvals can be reopened (current status: {status})"
+            ));
+        }
+        let changed = tx
+            .execute(
+                "UPDATE approvals SET status = 'pending', decided_at = NULL WHERE id = ? AND status = ?",
+                params![approval_id, status],
+            )
+            .map_err(|e| {
+                if e.to_string().contains("UNIQUE constraint failed") {
+                    "Another pending approval already exists for this recipient".to_string()
+                } else {
+                    format!("Failed to reopen approval: {e}")
+                }
+            })?;
+        if changed == 0 {
+            return Err(format!(
+                "Approval is no longer {status}; it was changed while reopening"
+            ));
+        }
+        tx.execute(
+            "INSERT INTO approval_events (id, approval_id, from_status, to_status, created_at)
+             VALUES (?, ?, ?, 'pending', ?)",
+            params![
+                uuid::Uuid::new_v4().to_string(),
+                approval_id,
+                status,
+                Utc::now().to_rfc3339()
+            ],
+        )
+        .map_err(|e| format!("Failed to record approval event: {e}"))?;
+        tx.commit()
+            .map_err(|e| format!("Failed to commit approval reopen: {e}"))?;
+        Ok(())
+    }
+
     pub fn already_ran_today(&self, timezone_mode: &str) -> Result<bool, String> {
+        self.ran_today(None, timezone_mode)
+    }
+
+    pub fn campaign_already_ran_today(
+        &self,
+        campaign_id: &str,
+        timezone_mode: &str,
+    ) -> Result<bool, String> {
+        self.ran_today(Some(campaign_id), timezone_mode)
+    }
+
+    fn ran_today(&self, campaign_id: Option<&str>, timezone_mode: &str) -> Result<bool, String> {
         let conn = self.open()?;
         let today = current_sales_day(timezone_mode);
         let mut stmt = conn
-            .prepare("SELECT started_at FROM sales_runs WHERE status = 'completed'")
+            .prepare(
+                "SELECT started_at FROM sales_runs
+                 WHERE status = 'completed' AND (?1 IS NULL OR campaign_id = ?1)",
+            )
             .map_err(|e| format!("Run-day check prepare failed: {e}"))?;
         let mut rows = stmt
-            .query([])
+            .query(params![campaign_id])
             .map_err(|e| format!("Run-day check query failed: {e}"))?;
 
         while let Some(row) = rows
@@ -4904,7 +6294,8 @@ impl SalesEngine {
         kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
     ) -> Result<usize, String> {
         // Discovery reuses the full pipeline but the reservoir pattern already
-        // discovers without daily_target limits (DISCOVERY_RESERVOIR_CANDIDATES).
+        // discovers without daily_target limits (DISCOVERY_RESERVOIR_CANDIDATES,
+        // or the profile's max_candidates override).
         // This wrapper makes the intent explicit for callers.
         let record = self.run_generation(kernel).await?;
         Ok(record.discovered as usize)
@@ -4967,12 +6358,28 @@ impl SalesEngine {
         job_id: Option<&str>,
         segment: SalesSegment,
     ) -> Result<SalesRunRecord, String> {
-        self.init()?;
         if segment.is_b2c() {
-            return self.run_b2c_generation_with_job(kernel, job_id, segment).await;
+            self.init()?;
+            return self
+                .run_b2c_generation_with_job(kernel, job_id, segment)
+                .await;
         }
+        self.run_campaign_generation_with_job(kernel, job_id, DEFAULT_SALES_CAMPAIGN_ID)
+            .await
+    }
+
+    /// Run B2B prospecting with one campaign's profile; the run and everything
+    /// it produces is tagged with that campaign.
+    pub async fn run_campaign_generation_with_job(
+        &self,
+        kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+        job_id: Option<&str>,
+        campaign_id: &str,
+    ) -> Result<SalesRunRecord, String> {
+        self.init()?;
+        let segment = SalesSegment::B2B;
         let profile = self
-            .get_profile(segment)?
+            .get_campaign_profile(campaign_id)?
             .ok_or_else(|| "Sales profile not configured".to_string())?;
 
         if profile.product_name.trim().is_empty()
@@ -4984,40 +6391,38 @@ impl SalesEngine {
         if profile.target_geo.trim().is_empty() {
             return Err("target_geo must be set before running".to_string());
         }
+        let search_limits = SalesSearchLimits::from_profile(&profile);
+        if let Some(home_dir) = self.db_path.parent() {
+            load_sales_message_catalogs(home_dir);
+        }
 
         let run_sequence = self.completed_runs_count(segment)? as usize;
-        let run_id = self.begin_run(segment)?;
+        let run_id = self.begin_campaign_run(segment, campaign_id)?;
         let started_at = Utc::now().to_rfc3339();
 
-        let max_candidates = DISCOVERY_RESERVOIR_CANDIDATES;
+        let max_candidates =
+            SalesSearchLimits::from_profile(&profile).candidates(DISCOVERY_RESERVOIR_CANDIDATES);
         let is_field_ops = profile_targets_field_ops(&profile);
         let skip_llm_discovery = is_field_ops && geo_is_turkey(&profile.target_geo);
 
+        let mut telemetry = SalesRunTelemetry {
+            max_candidates: max_candidates as u32,
+            ..SalesRunTelemetry::default()
+        };
+        let mut funnel = SalesRunFunnel::default();
+
         // --- STAGE 1: Query Plan (LLM or heuristic fallback) ---
         if let Some(job_id) = job_id {
             self.set_job_stage_running(job_id, PipelineStage::QueryPlanning)?;
         }
-        let lead_plan = if skip_llm_discovery {
-            heuristic_lead_query_plan(&profile)
-        } else {
-            match tokio::time::timeout(
-                Duration::from_secs(LEAD_QUERY_PLAN_TIMEOUT_SECS),
-                llm_build_lead_query_plan(kernel, &profile),
-            )
-            .await
-            {
-                Ok(Ok(plan)) if !plan.discovery_queries.is_empty() => plan,
-                Ok(Ok(_)) => heuristic_lead_query_plan(&profile),
-                Ok(Err(e)) => {
-                    warn!(error = %e, "Lead query planner failed, using heuristic plan");
-                    heuristic_lead_query_plan(&profile)
-                }
-                Err(_) => {
-                    warn!("Lead query planner timed out, using heuristic plan");
-                    heuristic_lead_query_plan(&profile)
-                }
-            }
-        };
+        let llm_plan_started = Instant::now();
+        let resolved_plan = resolve_lead_query_plan(kernel, &profile).await;
+        // Field-ops runs in Turkey skip the LLM planner by design; not a cause.
+        funnel.plan_fallback = resolved_plan
+            .fallback_reason
+            .filter(|_| !skip_llm_discovery);
+        let lead_plan = resolved_plan.plan;
+        telemetry.llm_plan_ms = elapsed_ms(llm_plan_started);
         if let Some(job_id) = job_id {
             self.complete_job_stage(job_id, PipelineStage::QueryPlanning, &lead_plan)?;
         }
@@ -5058,13 +6463,15 @@ impl SalesEngine {
             .unwrap_or(false);
 
         let (
-            llm_candidates,
+            (llm_candidates, llm_candidate_ms, llm_discovery_error),
             (web_search_candidates, mut source_contact_hints, search_unavailable),
             free_candidates,
         ) = tokio::join!(
             // PRIMARY: LLM company generation
             async {
-                if skip_llm_discovery || skip_source_llm {
+                let started = Instant::now();
+                let mut discovery_error = None;
+                let candidates = if skip_llm_discovery || skip_source_llm {
                     Vec::new()
                 } else {
                     match tokio::time::timeout(
@@ -5088,14 +6495,17 @@ impl SalesEngine {
                         }
                         Ok(Err(e)) => {
                             warn!(error = %e, "LLM primary company generation failed");
+                            discovery_error = Some(e.to_string());
                             Vec::new()
                         }
                         Err(_) => {
                             warn!("LLM primary company generation timed out");
+                            discovery_error = Some("timed out".to_string());
                             Vec::new()
                         }
                     }
-                }
+                };
+                (candidates, elapsed_ms(started), discovery_error)
             },
             // SECONDARY: Web search discovery
             async {
@@ -5109,6 +6519,7 @@ impl SalesEngine {
                         &profile,
                         max_candidates,
                         is_field_ops,
+                        &mut telemetry,
                     )
                     .await
                 }
@@ -5122,6 +6533,9 @@ impl SalesEngine {
                 }
             },
         );
+        telemetry.llm_candidate_ms = llm_candidate_ms;
+        funnel.llm_discovery_error = llm_discovery_error;
+        funnel.search_unavailable = search_unavailable;
         let mut directory_source_counts = expected_source_counts_for_profile(&profile);
         for candidate in &free_candidates {
             if let Some(source) = candidate.contact_hint.source.as_deref() {
@@ -5175,11 +6589,14 @@ impl SalesEngine {
         if let Some(job_id) = job_id {
             self.set_job_stage_running(job_id, PipelineStage::Merging)?;
         }
+        funnel.discovered_by_source =
+            llm_candidates.len() + web_search_candidates.len() + free_candidates.len();
         let mut candidate_list = merge_all_discovery_sources(
             llm_candidates,
             web_search_candidates,
             free_candidates,
             &mut source_contact_hints,
+            &profile.blocked_company_domains,
         );
         if let Some(job_id) = job_id {
             self.complete_job_stage(
@@ -5229,6 +6646,29 @@ impl SalesEngine {
                 Err(_) => warn!("LLM validation timed out, proceeding without"),
             }
         }
+        if profile.llm_rerank_candidates && candidate_list.len() > 1 {
+            let rerank_count = candidate_list
+                .len()
+                .min(LLM_CANDIDATE_RERANK_MAX_CANDIDATES);
+            match tokio::time::timeout(
+                Duration::from_secs(LLM_CANDIDATE_RERANK_TIMEOUT_SECS),
+                llm_rerank_candidates(kernel, &profile, &candidate_list[..rerank_count]),
+            )
+            .await
+            {
+                Ok(Ok(rankings)) => {
+                    let before = candidate_list.len();
+                    apply_llm_rerank_scores(&mut candidate_list, &rankings);
+                    info!(
+                        ranked = rankings.len(),
+                        dropped = before - candidate_list.len(),
+                        "LLM re-ranking completed"
+                    );
+                }
+                Ok(Err(e)) => warn!(error = %e, "LLM re-ranking failed, keeping heuristic scores"),
+                Err(_) => warn!("LLM re-ranking timed out, keeping heuristic scores"),
+            }
+        }
         if let Some(job_id) = job_id {
             self.complete_job_stage(
                 job_id,
@@ -5249,8 +6689,18 @@ impl SalesEngine {
             self.set_job_stage_running(job_id, PipelineStage::Filtering)?;
         }
         let min_candidate_score = candidate_quality_floor(&profile);
+        funnel.filtered_input = candidate_list.len();
+        funnel.min_score = min_candidate_score;
         candidate_list.retain(|c| {
-            c.score >= min_candidate_score && !candidate_should_skip_for_profile(c, &profile)
+            if c.score < min_candidate_score {
+                funnel.below_min_score += 1;
+                false
+            } else if candidate_should_skip_for_profile(c, &profile) {
+                funnel.profile_filtered += 1;
+                false
+            } else {
+                true
+            }
         });
         candidate_list.sort_by(|a, b| {
             candidate_preseed_priority(b, source_contact_hints.get(&b.domain), &profile)
@@ -5262,12 +6712,27 @@ impl SalesEngine {
                 .then_with(|| b.score.cmp(&a.score))
                 .then_with(|| a.domain.cmp(&b.domain))
         });
+        let company_capped =
+            cap_candidates_per_company(&mut candidate_list, search_limits.per_company());
+        if company_capped > 0 {
+            info!(
+                run_id = %run_id,
+                dropped = company_capped,
+                per_company = search_limits.per_company(),
+                "Dropped sibling-domain candidates over the per-company cap"
+            );
+        }
 
         if candidate_list.is_empty() {
-            let err_msg = format!(
+            telemetry.diagnostics = funnel.diagnostics();
+            let mut err_msg = format!(
                 "No suitable companies found for '{}' in '{}'. Check network/search provider/LLM connectivity and retry.",
                 profile.target_industry, profile.target_geo
             );
+            if let Some(summary) = sales_run_diagnostics_summary(&telemetry.diagnostics) {
+                err_msg = format!("{err_msg} {summary}");
+            }
+            let _ = self.record_run_telemetry(&run_id, &telemetry);
             self.finish_run(&run_id, "failed", 0, 0, 0, Some(&err_msg))?;
             if let Some(job_id) = job_id {
                 let _ = self.fail_job_stage(job_id, current_stage, &err_msg);
@@ -5364,6 +6829,7 @@ impl SalesEngine {
             )?;
         }
 
+        let mut candidates_evaluated = 0u32;
         let mut discovered = 0u32;
         let mut inserted = 0u32;
         let mut approvals_queued = 0u32;
@@ -5372,10 +6838,7 @@ impl SalesEngine {
             reqwest::header::ACCEPT_LANGUAGE,
             reqwest::header::HeaderValue::from_static("tr-TR,tr;q=0.9,en-US;q=0.8,en;q=0.7"),
         );
-        let site_client = reqwest::Client::builder()
-            .user_agent(
-                "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Safari/537.36",
-            )
+        let site_client = http_client::builder_with_user_agent(SALES_BROWSER_USER_AGENT)
             .default_headers(site_headers)
             .timeout(Duration::from_millis(DIRECT_ENRICH_TIMEOUT_MS))
             .redirect(reqwest::redirect::Policy::limited(4))
@@ -5405,6 +6868,7 @@ impl SalesEngine {
         let mut web_contact_search_attempts = 0usize;
         let mut prospect_profile_updates = HashMap::<String, SalesProspectProfile>::new();
         let mut activation_candidates = HashMap::<String, ActivationLeadCandidate>::new();
+        let mut guessed_emails = Vec::<(String, String)>::new();
 
         for candidate in candidate_list.iter().take(max_candidates) {
             if let Some(job_id) = job_id {
@@ -5413,7 +6877,7 @@ impl SalesEngine {
                     PipelineStage::LeadGeneration,
                     &LeadGenerationCheckpoint {
                         total_candidates,
-                        processed_candidates: discovered as usize,
+                        processed_candidates: candidates_evaluated as usize,
                         profiled_accounts,
                         inserted,
                         approvals_queued,
@@ -5421,7 +6885,7 @@ impl SalesEngine {
                     },
                 )?;
             }
-            discovered += 1;
+            candidates_evaluated += 1;
             if candidate.score < min_candidate_score {
                 if let Some(job_id) = job_id {
                     self.update_job_stage_checkpoint(
@@ -5429,7 +6893,7 @@ impl SalesEngine {
                         PipelineStage::LeadGeneration,
                         &LeadGenerationCheckpoint {
                             total_candidates,
-                            processed_candidates: discovered as usize,
+                            processed_candidates: candidates_evaluated as usize,
                             profiled_accounts,
                             inserted,
                             approvals_queued,
@@ -5439,6 +6903,7 @@ impl SalesEngine {
                 }
                 continue;
             }
+            discovered += 1;
 
             let domain = &candidate.domain;
             let company = domain_to_company(domain);
@@ -5481,6 +6946,7 @@ impl SalesEngine {
             let skip_web_contact_search = seeded_actionable_contact
                 || search_unavailable
                 || web_contact_search_attempts >= max_web_contact_search_attempts;
+            let contact_lookup_started = Instant::now();
             let (
                 mut contact_name,
                 mut contact_title,
@@ -5552,29 +7018,36 @@ impl SalesEngine {
                 ]);
 
                 let mut contact_outputs = Vec::<String>::new();
+                telemetry.queries_issued += contact_queries.len() as u32;
                 for (_query, result) in run_sales_search_batch(
                     &search_engine,
                     &contact_queries,
-                    6,
+                    search_limits.contact(6),
                     Duration::from_secs(SALES_CONTACT_SEARCH_TIMEOUT_SECS),
                 )
                 .await
                 {
-                    if let Ok(out) = result {
-                        if !out.trim().is_empty() {
+                    match result {
+                        Ok(out) if !out.trim().is_empty() => {
                             search_outputs.push(out.clone());
                             contact_outputs.push(out);
                         }
+                        Ok(_) => {}
+                        Err(_) => telemetry.search_errors += 1,
                     }
                 }
                 let contact_res = contact_outputs.join("\n");
 
-                let (search_name, search_title, search_linkedin) =
-                    extract_contact_from_search(&contact_res, profile.target_title_policy.as_str());
+                let (search_name, search_title, search_linkedin) = extract_contact_from_search(
+                    &contact_res,
+                    profile.target_title_policy.as_str(),
+                    &lead_plan.contact_titles,
+                );
                 let (entry_name, entry_title, entry_linkedin, entry_email) =
                     extract_contact_from_search_for_company(
                         &contact_res,
                         profile.target_title_policy.as_str(),
+                        &lead_plan.contact_titles,
                         &company,
                         domain,
                     );
@@ -5614,14 +7087,18 @@ impl SalesEngine {
                             "\"{}\" \"{}\" {} (CEO OR \"Chief Executive Officer\" OR Founder OR COO OR \"Head of Operations\") (LinkedIn OR Wikipedia OR leadership OR executive team OR email)",
                             company_search_name, domain, profile.target_geo
                         );
+                    telemetry.queries_issued += 1;
                     let fallback_contact_res = run_sales_search(
                         &search_engine,
                         &fallback_contact_query,
-                        10,
+                        search_limits.fallback(10),
                         Duration::from_secs(SALES_CONTACT_SEARCH_TIMEOUT_SECS),
                     )
                     .await
-                    .unwrap_or_default();
+                    .unwrap_or_else(|_| {
+                        telemetry.search_errors += 1;
+                        String::new()
+                    });
                     if !fallback_contact_res.trim().is_empty() {
                         search_outputs.push(fallback_contact_res.clone());
                     }
@@ -5629,6 +7106,7 @@ impl SalesEngine {
                         extract_contact_from_search(
                             &fallback_contact_res,
                             profile.target_title_policy.as_str(),
+                            &lead_plan.contact_titles,
                         );
                     let (
                         fallback_entry_name,
@@ -5638,6 +7116,7 @@ impl SalesEngine {
                     ) = extract_contact_from_search_for_company(
                         &fallback_contact_res,
                         profile.target_title_policy.as_str(),
+                        &lead_plan.contact_titles,
                         &company,
                         domain,
                     );
@@ -5678,15 +7157,20 @@ impl SalesEngine {
                     best_search_contact_enrichment(
                         &search_outputs,
                         profile.target_title_policy.as_str(),
+                        &lead_plan.contact_titles,
                         &company_search_name,
                         domain,
                     )
                 };
                 if search_osint_enrichment.company_linkedin_url.is_none() {
                     // 4-Layer LinkedIn search fallback (TASK-24)
-                    search_osint_enrichment.company_linkedin_url =
-                        find_company_linkedin_url(&company_search_name, domain, &search_engine)
-                            .await;
+                    search_osint_enrichment.company_linkedin_url = find_company_linkedin_url(
+                        &company_search_name,
+                        domain,
+                        &search_engine,
+                        search_limits.contact(5),
+                    )
+                    .await;
                 }
                 search_osint_enrichment.osint_links = merge_osint_links(
                     search_osint_enrichment.osint_links.clone(),
@@ -5700,6 +7184,7 @@ impl SalesEngine {
                     search_osint_enrichment,
                 )
             };
+            telemetry.contact_lookup_ms += elapsed_ms(contact_lookup_started);
             let mut company_linkedin_url = seeded_company_linkedin;
             let mut site_evidence: Option<String> = None;
             if let Some(hint) = source_contact_hints.get(domain) {
@@ -5829,6 +7314,16 @@ impl SalesEngine {
             } else {
                 normalize_contact_email_for_domain(email, domain)
             };
+            let contact_confidence = email.as_ref().map(|address| {
+                if !email_from_verified_site
+                    && guess_personal_email_patterns(contact_name.as_deref(), domain)
+                        .contains(address)
+                {
+                    ContactConfidence::Guessed
+                } else {
+                    ContactConfidence::Discovered
+                }
+            });
             osint_links = merge_osint_links(
                 osint_links,
                 vec![
@@ -5898,6 +7393,7 @@ impl SalesEngine {
                 site_evidence.as_deref(),
                 is_llm_validated || is_verified_by_memory,
             ) {
+                funnel.no_company_signal += 1;
                 continue;
             }
 
@@ -5909,6 +7405,7 @@ impl SalesEngine {
             }
 
             if !lead_has_outreach_channel(email.as_ref(), linkedin_url.as_ref()) {
+                funnel.no_outreach_channel += 1;
                 continue;
             }
             // Search-time LLM validation or cached dossier memory can proceed without a real person name.
@@ -5916,6 +7413,7 @@ impl SalesEngine {
                 || is_verified_by_memory
                 || lead_has_person_identity(contact_name.as_deref(), linkedin_url.as_ref()))
             {
+                funnel.no_contact_identity += 1;
                 continue;
             }
 
@@ -5924,6 +7422,12 @@ impl SalesEngine {
                 score = (score + 4).min(100);
             }
 
+            // Site evidence comes from the company's own pages.
+            let evidence_url = match (&site_evidence, candidate.evidence.first()) {
+                (Some(_), _) => Some(format!("https://{domain}")),
+                (None, Some(snippet)) => candidate.evidence_url(snippet).map(str::to_string),
+                (None, None) => None,
+            };
             let evidence = site_evidence
                 .or_else(|| candidate.evidence.first().cloned())
                 .unwrap_or_else(|| {
@@ -5949,42 +7453,27 @@ impl SalesEngine {
                 &company,
                 &matched,
                 &evidence,
+                evidence_url.as_deref(),
                 contact_title.as_deref(),
             );
 
             // Evidence-bound message generation (TASK-29): try strategy+copy first,
             // fall back to direct templates if evidence is insufficient.
-            let strategy = generate_message_strategy(
+            let SalesOutreachCopy {
+                email_subject,
+                email_body,
+                linkedin_message,
+            } = render_outreach_copy(
                 &profile,
                 &company,
                 contact_name.as_deref(),
-                &evidence,
                 &matched,
+                &evidence,
+                evidence_url.as_deref(),
             );
-            let (email_subject, email_body, linkedin_message) =
-                match generate_message_copy(&strategy, &profile, &company, contact_name.as_deref())
-                {
-                    Ok(copy) => (copy.subject, copy.body, copy.linkedin_copy),
-                    Err(_) => (
-                        build_sales_email_subject(&profile, &company),
-                        build_sales_email_body(
-                            &profile,
-                            &company,
-                            contact_name.as_deref(),
-                            &matched,
-                            &evidence,
-                        ),
-                        build_sales_linkedin_message(
-                            &profile,
-                            &company,
-                            contact_name.as_deref(),
-                            &evidence,
-                        ),
-                    ),
-                };
 
             let canonical = match self.sync_canonical_state(
-                &self.open()?,
+                &*self.open()?,
                 &profile,
                 candidate,
                 &company,
@@ -6009,12 +7498,17 @@ impl SalesEngine {
 
             let activation_score =
                 ((activation_priority(&canonical.score) * 100.0).round() as i32).clamp(0, 100);
+            let floors = LeadScoreFloors {
+                discovery: score,
+                activation: activation_score,
+            };
             score = score.max(activation_score);
 
             if !matches!(canonical.gate, SendGateDecision::Activate) {
+                funnel.held_by_send_gate += 1;
                 continue;
             }
-            let lead = SalesLead {
+            let mut lead = SalesLead {
                 id: uuid::Uuid::new_v4().to_string(),
                 run_id: run_id.clone(),
                 company,
@@ -6038,11 +7532,25 @@ impl SalesEngine {
                 score,
                 status: "activation_candidate".to_string(),
                 created_at: Utc::now().to_rfc3339(),
+                external_id: None,
+                contact_confidence,
             };
 
-            match self.insert_lead(&lead) {
-                Ok(true) => {
+            let outcome = self.insert_scored_lead(&lead, profile.dedupe_window_days, floors);
+            if let Ok(LeadWriteOutcome::Reengaged { id }) = &outcome {
+                info!(domain = %domain, lead_id = %id, "Re-engaging lead outside the dedupe window");
+                lead.id = id.clone();
+            }
+            match outcome {
+                Ok(LeadWriteOutcome::Inserted | LeadWriteOutcome::Reengaged { .. }) => {
                     inserted += 1;
+                    if profile.verify_emails
+                        && lead.contact_confidence == Some(ContactConfidence::Guessed)
+                    {
+                        if let Some(address) = lead.email.clone() {
+                            guessed_emails.push((lead.id.clone(), address));
+                        }
+                    }
                     let _ = self.record_discovered_domain(segment, domain, &run_id);
                     let account_id = stable_sales_id("acct", &[domain]);
                     let entry = activation_candidates
@@ -6060,8 +7568,8 @@ impl SalesEngine {
                         };
                     }
                 }
-                Ok(false) => {
-                    // duplicate, skip silently
+                Ok(LeadWriteOutcome::Updated | LeadWriteOutcome::Duplicate) => {
+                    funnel.already_known += 1;
                 }
                 Err(e) => warn!(domain = %domain, error = %e, "Lead insert failed"),
             }
@@ -6072,7 +7580,7 @@ impl SalesEngine {
                     PipelineStage::LeadGeneration,
                     &LeadGenerationCheckpoint {
                         total_candidates,
-                        processed_candidates: discovered as usize,
+                        processed_candidates: candidates_evaluated as usize,
                         profiled_accounts,
                         inserted,
                         approvals_queued,
@@ -6082,6 +7590,47 @@ impl SalesEngine {
             }
         }
 
+        if !guessed_emails.is_empty() {
+            let helo_domain =
+                email_domain(&profile.sender_email).unwrap_or_else(|| "localhost".to_string());
+            let verdicts = verify_email_addresses(
+                guessed_emails
+                    .iter()
+                    .map(|(_, email)| email.clone())
+                    .collect(),
+                profile.verify_emails_smtp_probe,
+                &helo_domain,
+            )
+            .await;
+            for (lead_id, address) in &guessed_emails {
+                let confidence = match verdicts.get(address) {
+                    Some(EmailVerification::Undeliverable) => {
+                        info!(
+                            lead_id = %lead_id,
+                            email = %address,
+                            "Dropping guessed email that failed verification"
+                        );
+                        None
+                    }
+                    Some(EmailVerification::Deliverable) => Some(ContactConfidence::Verified),
+                    Some(EmailVerification::Unknown) | None => continue,
+                };
+                if let Err(e) = self.apply_email_verification(lead_id, address, confidence) {
+                    warn!(lead_id = %lead_id, error = %e, "Failed to store email verification");
+                    continue;
+                }
+                if let Some(candidate) = activation_candidates
+                    .values_mut()
+                    .find(|candidate| candidate.lead.id == *lead_id)
+                {
+                    if confidence.is_none() {
+                        candidate.lead.email = None;
+                    }
+                    candidate.lead.contact_confidence = confidence;
+                }
+            }
+        }
+
         if !activation_candidates.is_empty() {
             let conn = self.open()?;
             let candidate_priorities = activation_candidates
@@ -6094,11 +7643,23 @@ impl SalesEngine {
                 profile.daily_target,
             )?;
             let selected_set = selected_accounts.into_iter().collect::<HashSet<_>>();
+            let pending_cap = profile.max_pending_approvals;
+            let mut pending_approvals = if pending_cap > 0 {
+                self.count_pending_approvals()?
+            } else {
+                0
+            };
             for candidate in activation_candidates.into_values() {
-                let lead_status = if selected_set.contains(&candidate.account_id) {
-                    match self.queue_approvals_for_lead(&candidate.lead) {
+                let lead_status = if !selected_set.contains(&candidate.account_id) {
+                    "activation_backlog"
+                } else if pending_cap > 0 && pending_approvals >= pending_cap {
+                    telemetry.approvals_capped += 1;
+                    "activation_backlog"
+                } else {
+                    match self.queue_approvals_for_lead(&candidate.lead, &profile) {
                         Ok(q) => {
                             approvals_queued += q;
+                            pending_approvals += q;
                             "approval_pending"
                         }
                         Err(e) => {
@@ -6106,20 +7667,27 @@ impl SalesEngine {
                             "activation_candidate"
                         }
                     }
-                } else {
-                    "activation_backlog"
                 };
                 if let Err(e) = self.update_lead_status(&candidate.lead.id, lead_status) {
                     warn!(lead_id = %candidate.lead.id, error = %e, "Failed to update activation lead status");
                 }
             }
+            if telemetry.approvals_capped > 0 {
+                warn!(
+                    run_id = %run_id,
+                    pending = pending_approvals,
+                    cap = pending_cap,
+                    skipped = telemetry.approvals_capped,
+                    "Pending approval ceiling reached; selected leads left in the activation backlog"
+                );
+            }
             if let Some(job_id) = job_id {
                 self.update_job_stage_checkpoint(
                     job_id,
                     PipelineStage::LeadGeneration,
                     &LeadGenerationCheckpoint {
                         total_candidates,
-                        processed_candidates: discovered as usize,
+                        processed_candidates: candidates_evaluated as usize,
                         profiled_accounts,
                         inserted,
                         approvals_queued,
@@ -6136,8 +7704,23 @@ impl SalesEngine {
             }
         }
 
+        let diagnostics_summary = if inserted == 0 {
+            telemetry.diagnostics = funnel.diagnostics();
+            sales_run_diagnostics_summary(&telemetry.diagnostics)
+        } else {
+            None
+        };
+        if let Err(e) = self.record_run_telemetry(&run_id, &telemetry) {
+            warn!(run_id = %run_id, error = %e, "Failed to persist run telemetry");
+        }
+        if let Err(e) = self.record_run_funnel(&run_id, candidates_evaluated, discovered) {
+            warn!(run_id = %run_id, error = %e, "Failed to persist run funnel");
+        }
         if inserted == 0 && seeded_prospect_profiles.is_empty() {
-            let err_msg = "Prospecting run completed discovery, but no durable prospect dossiers or actionable contacts could be saved for the current ICP/geo.".to_string();
+            let mut err_msg = "Prospecting run completed discovery, but no durable prospect dossiers or actionable contacts could be saved for the current ICP/geo.".to_string();
+            if let Some(summary) = &diagnostics_summary {
+                err_msg = format!("{err_msg} {summary}");
+            }
             self.finish_run(
                 &run_id,
                 "failed",
@@ -6153,10 +7736,14 @@ impl SalesEngine {
         }
 
         let run_note = if inserted == 0 {
-            Some(format!(
+            let note = format!(
                 "Prospecting run completed with {} profiled accounts, but no action-ready contacts were verified in this pass.",
                 seeded_prospect_profiles.len()
-            ))
+            );
+            Some(match &diagnostics_summary {
+                Some(summary) => format!("{note} {summary}"),
+                None => note,
+            })
         } else {
             None
         };
@@ -6176,8 +7763,10 @@ impl SalesEngine {
                 &serde_json::json!({
                     "run_id": run_id,
                     "total_candidates": total_candidates,
-                    "processed_candidates": discovered,
+                    "processed_candidates": candidates_evaluated,
                     "profiled_accounts": profiled_accounts,
+                    "candidates_evaluated": candidates_evaluated,
+                    "candidates_qualified": discovered,
                     "discovered": discovered,
                     "inserted": inserted,
                     "approvals_queued": approvals_queued
@@ -6204,6 +7793,9 @@ impl SalesEngine {
             inserted,
             approvals_queued,
             error: run_note,
+            telemetry: Some(telemetry),
+            candidates_evaluated,
+            candidates_qualified: discovered,
         })
     }
 
@@ -6267,7 +7859,8 @@ impl SalesEngine {
             brave_search_engine.as_ref(),
             &profile,
             &lead_plan,
-            DISCOVERY_RESERVOIR_CANDIDATES.min(80),
+            SalesSearchLimits::from_profile(&profile)
+                .candidates(DISCOVERY_RESERVOIR_CANDIDATES.min(80)),
         )
         .await;
         let _ = self.update_source_health("b2c_social_search", discovered_candidates.len());
@@ -6323,10 +7916,12 @@ impl SalesEngine {
             self.set_job_stage_running(job_id, PipelineStage::Filtering)?;
         }
 
+        let candidates_evaluated = discovered_candidates.len() as u32;
         let mut filtered_candidates = discovered_candidates
             .into_iter()
             .filter(|candidate| candidate.score >= 40)
             .collect::<Vec<_>>();
+        let candidates_qualified = filtered_candidates.len() as u32;
         filtered_candidates.sort_by(|left, right| {
             right
                 .score
@@ -6378,6 +7973,7 @@ impl SalesEngine {
                             matched_keywords: profile.matched_signals.clone(),
                             source_links: profile.osint_links.clone(),
                             phone: None,
+                            evidence_urls: HashMap::new(),
                         })
                         .collect::<Vec<_>>(),
                     source_contact_hints: HashMap::new(),
@@ -6410,6 +8006,10 @@ impl SalesEngine {
             0,
             Some("B2C discovery completed with social and local-market profile matches."),
         )?;
+        if let Err(e) = self.record_run_funnel(&run_id, candidates_evaluated, candidates_qualified)
+        {
+            warn!(run_id = %run_id, error = %e, "Failed to persist run funnel");
+        }
 
         Ok(SalesRunRecord {
             id: run_id,
@@ -6420,9 +8020,13 @@ impl SalesEngine {
             inserted: 0,
             approvals_queued: 0,
             error: Some("B2C discovery run stores profile matches only; no outbound leads were generated.".to_string()),
+            telemetry: None,
+            candidates_evaluated,
+            candidates_qualified,
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn seed_prospect_profiles_for_run(
         &self,
         segment: SalesSegment,
@@ -6496,10 +8100,7 @@ impl SalesEngine {
             return profiles;
         }
 
-        let client = match reqwest::Client::builder()
-            .user_agent(
-                "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Safari/537.36",
-            )
+        let client = match http_client::builder_with_user_agent(SALES_BROWSER_USER_AGENT)
             .timeout(Duration::from_millis(DIRECT_ENRICH_TIMEOUT_MS))
             .redirect(reqwest::redirect::Policy::limited(4))
             .build()
@@ -6606,6 +8207,7 @@ impl SalesEngine {
 
         let search_engine_ref = &search_engine;
         let brave_search_engine_ref = brave_search_engine.as_ref();
+        let search_limits = SalesSearchLimits::from_profile(sales_profile);
         let enrichments = stream::iter(targets.into_iter().map(
             |(domain, company, primary_name, matched_signals)| {
                 let title_policy = sales_profile.target_title_policy.clone();
@@ -6631,6 +8233,7 @@ impl SalesEngine {
                         &title_policy,
                         &target_geo,
                         primary_name.as_deref(),
+                        search_limits,
                     )
                     .await;
                     (domain, enrichment)
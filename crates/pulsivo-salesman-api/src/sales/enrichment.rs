@@ -43,6 +43,7 @@ fn build_company_osint_queries(
     dedupe_strings(queries)
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn search_company_osint_enrichment(
     search_engine: &WebSearchEngine,
     brave_search_engine: Option<&WebSearchEngine>,
@@ -51,6 +52,7 @@ async fn search_company_osint_enrichment(
     title_policy: &str,
     target_geo: &str,
     primary_contact_name: Option<&str>,
+    search_limits: SalesSearchLimits,
 ) -> SiteContactEnrichment {
     let queries = build_company_osint_queries(
         company,
@@ -62,7 +64,7 @@ async fn search_company_osint_enrichment(
     let mut outputs = run_sales_search_batch(
         search_engine,
         &queries,
-        6,
+        search_limits.contact(6),
         Duration::from_secs(SALES_OSINT_SEARCH_TIMEOUT_SECS),
     )
     .await
@@ -71,13 +73,14 @@ async fn search_company_osint_enrichment(
     .filter(|value| !value.trim().is_empty())
     .collect::<Vec<_>>();
 
-    let mut enrichment = best_search_contact_enrichment(&outputs, title_policy, company, domain);
+    let mut enrichment =
+        best_search_contact_enrichment(&outputs, title_policy, &[], company, domain);
     if !site_contact_enrichment_has_signal(&enrichment) {
         if let Some(brave_engine) = brave_search_engine {
             let brave_outputs = run_sales_search_batch(
                 brave_engine,
                 &queries,
-                6,
+                search_limits.contact(6),
                 Duration::from_secs(SALES_OSINT_SEARCH_TIMEOUT_SECS),
             )
             .await
@@ -88,14 +91,14 @@ async fn search_company_osint_enrichment(
             if !brave_outputs.is_empty() {
                 outputs.extend(brave_outputs);
                 enrichment =
-                    best_search_contact_enrichment(&outputs, title_policy, company, domain);
+                    best_search_contact_enrichment(&outputs, title_policy, &[], company, domain);
             }
         }
     }
 
     // Run job posting signal search (TASK-27) using the primary search engine
     enrichment.job_posting_signals =
-        search_job_posting_signals(company, domain, search_engine).await;
+        search_job_posting_signals(company, domain, search_engine, search_limits.contact(5)).await;
 
     enrichment
 }
@@ -579,7 +582,7 @@ fn decode_html_email_entities(text: &str) -> String {
 }
 
 fn decode_cloudflare_email(encoded: &str) -> Option<String> {
-    if encoded.len() < 4 || encoded.len() % 2 != 0 {
+    if encoded.len() < 4 || !encoded.len().is_multiple_of(2) {
         return None;
     }
     let key = u8::from_str_radix(&encoded[0..2], 16).ok()?;
@@ -697,12 +700,25 @@ fn sanitize_approval_payload(
             if subject.is_empty() || body.is_empty() {
                 return None;
             }
-            Some(serde_json::json!({
+            let html_body = payload
+                .get("html_body")
+                .and_then(|value| value.as_str())
+                .map(sanitize_email_html)
+                .filter(|html| !html.trim().is_empty());
+            let attachments = email_attachments_from_payload(&payload).ok()?;
+            let mut sanitized = serde_json::json!({
                 "to": to,
                 "subject": subject,
                 "body": body,
                 "classification": classify_email(&to, email_domain(&to).as_deref().unwrap_or_default()),
-            }))
+            });
+            if let Some(html_body) = html_body {
+                sanitized["html_body"] = serde_json::Value::String(html_body);
+            }
+            if !attachments.is_empty() {
+                sanitized["attachments"] = serde_json::to_value(attachments).ok()?;
+            }
+            Some(sanitized)
         }
         "linkedin" | "linkedin_assist" => {
             let profile_url = payload
@@ -722,6 +738,23 @@ fn sanitize_approval_payload(
                 "manual_action": true,
             }))
         }
+        "linkedin_connect" => {
+            let profile_url = payload
+                .get("profile_url")
+                .and_then(|value| value.as_str())
+                .and_then(normalize_outreach_linkedin_url)?;
+            let note =
+                linkedin_connect_note(payload.get("message").and_then(|value| value.as_str())?);
+            if note.is_empty() {
+                return None;
+            }
+            Some(serde_json::json!({
+                "profile_url": profile_url,
+                "message": note,
+                "action": "connect_with_note",
+                "manual_action": true,
+            }))
+        }
         _ => Some(payload),
     }
 }
@@ -1043,12 +1076,171 @@ fn is_valid_sending_subdomain(sender_domain: &str, brand_domain: &str) -> bool {
 }
 
 async fn check_mx_record(domain: &str) -> bool {
-    match hickory_resolver::TokioAsyncResolver::tokio_from_system_conf() {
-        Ok(resolver) => resolver.mx_lookup(domain).await.is_ok(),
-        Err(_) => true,
+    lookup_mx_hosts(domain)
+        .await
+        .is_none_or(|hosts| !hosts.is_empty())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmailVerification {
+    Deliverable,
+    Undeliverable,
+    Unknown,
+}
+
+/// MX hosts for `domain`, lowest preference first. A domain without MX
+/// records but with an A/AAAA record is its own mail host (RFC 5321 §5.1).
+/// `None` means the lookup could not be performed (resolver unavailable or
+/// transient DNS failure).
+async fn lookup_mx_hosts(domain: &str) -> Option<Vec<String>> {
+    let resolver = hickory_resolver::TokioAsyncResolver::tokio_from_system_conf().ok()?;
+    match resolver.mx_lookup(domain).await {
+        Ok(lookup) => {
+            let mut records = lookup
+                .iter()
+                .map(|mx| {
+                    (
+                        mx.preference(),
+                        mx.exchange().to_utf8().trim_end_matches('.').to_string(),
+                    )
+                })
+                .filter(|(_, host)| !host.is_empty())
+                .collect::<Vec<_>>();
+            records.sort();
+            Some(records.into_iter().map(|(_, host)| host).collect())
+        }
+        Err(e) => match e.kind() {
+            hickory_resolver::error::ResolveErrorKind::NoRecordsFound { .. } => {
+                match resolver.lookup_ip(domain).await {
+                    Ok(_) => Some(vec![domain.to_string()]),
+                    Err(e) => match e.kind() {
+                        hickory_resolver::error::ResolveErrorKind::NoRecordsFound { .. } => {
+                            Some(Vec::new())
+                        }
+                        _ => None,
+                    },
+                }
+            }
+            _ => None,
+        },
     }
 }
 
+fn smtp_reply_code(reply: &str) -> Option<u16> {
+    reply.get(..3)?.parse().ok()
+}
+
+async fn read_smtp_reply<R>(reader: &mut R) -> Option<u16>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::AsyncBufReadExt;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await.ok()? == 0 {
+            return None;
+        }
+        // Multi-line replies use `250-` until the final `250 ` line.
+        if line.as_bytes().get(3) != Some(&b'-') {
+            return smtp_reply_code(&line);
+        }
+    }
+}
+
+/// Ask the MX host whether it accepts `RCPT TO:<email>` without sending any
+/// message. Greylisting, temp failures and I/O errors are `Unknown`.
+async fn smtp_rcpt_probe(
+    host: &str,
+    port: u16,
+    helo_domain: &str,
+    email: &str,
+) -> EmailVerification {
+    use tokio::io::AsyncWriteExt;
+    let Ok(stream) = tokio::net::TcpStream::connect((host, port)).await else {
+        return EmailVerification::Unknown;
+    };
+    let (read_half, mut write_half) = stream.into_split();
+    let mut reader = tokio::io::BufReader::new(read_half);
+    if read_smtp_reply(&mut reader).await != Some(220) {
+        return EmailVerification::Unknown;
+    }
+    for command in [
+        format!("EHLO {helo_domain}\r\n"),
+        "MAIL FROM:<>\r\n".to_string(),
+    ] {
+        if write_half.write_all(command.as_bytes()).await.is_err()
+            || read_smtp_reply(&mut reader).await != Some(250)
+        {
+            return EmailVerification::Unknown;
+        }
+    }
+    if write_half
+        .write_all(format!("RCPT TO:<{email}>\r\n").as_bytes())
+        .await
+        .is_err()
+    {
+        return EmailVerification::Unknown;
+    }
+    let verdict = match read_smtp_reply(&mut reader).await {
+        Some(250 | 251) => EmailVerification::Deliverable,
+        Some(550 | 551 | 553) => EmailVerification::Undeliverable,
+        _ => EmailVerification::Unknown,
+    };
+    let _ = write_half.write_all(b"QUIT\r\n").await;
+    verdict
+}
+
+/// Verify `emails` with at most [`SALES_EMAIL_VERIFY_CONCURRENCY`] checks in
+/// flight. Addresses still pending when the overall budget runs out are left
+/// out of the result, which callers treat as `Unknown`.
+async fn verify_email_addresses(
+    emails: Vec<String>,
+    smtp_probe: bool,
+    helo_domain: &str,
+) -> HashMap<String, EmailVerification> {
+    let deadline =
+        tokio::time::Instant::now() + Duration::from_secs(SALES_EMAIL_VERIFY_BUDGET_SECS);
+    let mut checks = stream::iter(emails)
+        .map(|email| async move {
+            let verdict = verify_email_address(&email, smtp_probe, helo_domain).await;
+            (email, verdict)
+        })
+        .buffer_unordered(SALES_EMAIL_VERIFY_CONCURRENCY);
+    let mut verdicts = HashMap::new();
+    while let Ok(Some((email, verdict))) = tokio::time::timeout_at(deadline, checks.next()).await {
+        verdicts.insert(email, verdict);
+    }
+    verdicts
+}
+
+/// Time-bounded deliverability check for a guessed address: a domain with
+/// no mail host is `Undeliverable`; with `smtp_probe` the primary MX is asked
+/// about the mailbox.
+async fn verify_email_address(
+    email: &str,
+    smtp_probe: bool,
+    helo_domain: &str,
+) -> EmailVerification {
+    let Some(domain) = email_domain(email) else {
+        return EmailVerification::Undeliverable;
+    };
+    let check = async {
+        let Some(hosts) = lookup_mx_hosts(&domain).await else {
+            return EmailVerification::Unknown;
+        };
+        let Some(primary) = hosts.first() else {
+            return EmailVerification::Undeliverable;
+        };
+        if !smtp_probe {
+            return EmailVerification::Deliverable;
+        }
+        smtp_rcpt_probe(primary, 25, helo_domain, email).await
+    };
+    tokio::time::timeout(Duration::from_secs(SALES_EMAIL_VERIFY_TIMEOUT_SECS), check)
+        .await
+        .unwrap_or(EmailVerification::Unknown)
+}
+
 async fn assess_domain_health(domain: &str) -> f64 {
     let mut score: f64 = 0.45;
     if domain.ends_with(".com") || domain.ends_with(".net") || domain.ends_with(".org") {
@@ -1323,6 +1515,7 @@ async fn search_job_posting_signals(
     account_name: &str,
     domain: &str,
     search_engine: &WebSearchEngine,
+    max_results: usize,
 ) -> Vec<(String, String, f64, String)> {
     // (signal_text, source_url, confidence, signal_type)
     let transliterated = transliterate_turkish_ascii(account_name);
@@ -1360,7 +1553,7 @@ async fn search_job_posting_signals(
 
     let mut signals = Vec::new();
     for q in &queries {
-        let raw = match run_sales_search(search_engine, q, 5, timeout).await {
+        let raw = match run_sales_search(search_engine, q, max_results, timeout).await {
             Ok(r) => r,
             Err(_) => continue,
         };
@@ -1458,39 +1651,41 @@ async fn find_company_linkedin_url(
     company_name: &str,
     domain: &str,
     search_engine: &WebSearchEngine,
+    max_results: usize,
 ) -> Option<String> {
     let timeout = Duration::from_secs(SALES_CONTACT_SEARCH_TIMEOUT_SECS);
     let transliterated = transliterate_turkish_ascii(company_name);
 
     // Layer 1: Domain match — most precise
     let q1 = format!("site:linkedin.com/company/ \"{}\"", domain);
-    if let Some(url) = linkedin_search_attempt(search_engine, &q1, timeout).await {
+    if let Some(url) = linkedin_search_attempt(search_engine, &q1, max_results, timeout).await {
         return Some(url);
     }
 
     // Layer 2: Transliterated company name (handles Turkish chars)
     let q2 = format!("site:linkedin.com/company/ \"{}\"", transliterated);
-    if let Some(url) = linkedin_search_attempt(search_engine, &q2, timeout).await {
+    if let Some(url) = linkedin_search_attempt(search_engine, &q2, max_results, timeout).await {
         return Some(url);
     }
 
     // Layer 3: Company name + CEO search
     let q3 = format!("\"{}\" linkedin CEO OR \"Genel Müdür\"", company_name);
-    if let Some(url) = linkedin_search_attempt(search_engine, &q3, timeout).await {
+    if let Some(url) = linkedin_search_attempt(search_engine, &q3, max_results, timeout).await {
         return Some(url);
     }
 
     // Layer 4: Turkish LinkedIn subdomain
     let q4 = format!("site:tr.linkedin.com \"{}\"", domain);
-    linkedin_search_attempt(search_engine, &q4, timeout).await
+    linkedin_search_attempt(search_engine, &q4, max_results, timeout).await
 }
 
 async fn linkedin_search_attempt(
     search_engine: &WebSearchEngine,
     query: &str,
+    max_results: usize,
     timeout: Duration,
 ) -> Option<String> {
-    match run_sales_search(search_engine, query, 5, timeout).await {
+    match run_sales_search(search_engine, query, max_results, timeout).await {
         Ok(res) if !res.trim().is_empty() => extract_company_linkedin_from_text(&res)
             .and_then(|value| normalize_company_linkedin_url(&value)),
         _ => None,
@@ -1821,7 +2016,7 @@ fn get_experiment_results(
 /// Verify LLM-generated domain actually exists with a HEAD request (TASK-40).
 async fn verify_domain_exists(domain: &str) -> bool {
     let url = format!("https://{domain}");
-    let client = match reqwest::Client::builder()
+    let client = match http_client::builder()
         .timeout(Duration::from_secs(5))
         .redirect(reqwest::redirect::Policy::limited(3))
         .build()
@@ -3647,6 +3842,19 @@ fn extract_contact_from_company_site_html(
     )
 }
 
+/// Whether an address of `confidence` may be queued for email under the
+/// profile's `minimum`. Leads without a recorded confidence (imports, older
+/// rows) are not gated.
+fn email_meets_contact_confidence(
+    confidence: Option<ContactConfidence>,
+    minimum: Option<ContactConfidence>,
+) -> bool {
+    match (confidence, minimum) {
+        (Some(confidence), Some(minimum)) => confidence >= minimum,
+        _ => true,
+    }
+}
+
 fn guessed_email(contact_name: Option<&str>, domain: &str) -> Option<String> {
     guess_personal_email_patterns(contact_name, domain)
         .into_iter()
@@ -3712,6 +3920,465 @@ fn lead_score(linkedin: &Option<String>, email: &Option<String>) -> i32 {
     s
 }
 
+fn record_lead_status_change(
+    conn: &Connection,
+    lead_id: &str,
+    from_status: &str,
+    to_status: &str,
+    note: Option<&str>,
+) -> Result<LeadStatusChange, String> {
+    let change = LeadStatusChange {
+        id: uuid::Uuid::new_v4().to_string(),
+        lead_id: lead_id.to_string(),
+        from_status: from_status.to_string(),
+        to_status: to_status.to_string(),
+        note: note
+            .map(str::trim)
+            .filter(|note| !note.is_empty())
+            .map(str::to_string),
+        changed_at: Utc::now().to_rfc3339(),
+    };
+    conn.execute(
+        "INSERT INTO lead_status_history (id, lead_id, from_status, to_status, note, changed_at)
+         VALUES (?, ?, ?, ?, ?, ?)",
+        params![
+            change.id,
+            change.lead_id,
+            change.from_status,
+            change.to_status,
+            change.note,
+            change.changed_at
+        ],
+    )
+    .map_err(|e| format!("Failed to record lead status change: {e}"))?;
+    Ok(change)
+}
+
+/// Whether `domain` got a lead or a delivered outreach at or after `since`
+/// (RFC 3339). Failed and blocked deliveries never reached the prospect and
+/// do not count.
+fn domain_contacted_since(conn: &Connection, domain: &str, since: &str) -> Result<bool, String> {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM leads WHERE company_domain = ?1 AND created_at >= ?2)
+             OR EXISTS(
+                SELECT 1 FROM deliveries d
+                JOIN approvals a ON a.id = d.approval_id
+                JOIN leads l ON l.id = a.lead_id
+                WHERE l.company_domain = ?1 AND d.sent_at >= ?2
+                  AND d.status IN ('sent', 'operator_pending')
+             )",
+        params![domain, since],
+        |r| r.get::<_, bool>(0),
+    )
+    .map_err(|e| format!("Recent contact lookup failed: {e}"))
+}
+
+/// Build a draft lead from an import row, validating and normalizing its
+/// identity and contact fields.
+fn lead_from_import(item: &SalesLeadImportItem, created_at: &str) -> Result<SalesLead, String> {
+    let required = |field: &str, value: &str| -> Result<String, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(format!("{field} is required"));
+        }
+        Ok(value.to_string())
+    };
+    let optional = |value: &Option<String>| -> Option<String> {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let company_domain = required("company_domain", &item.company_domain)?.to_lowercase();
+    let email = match optional(&item.email) {
+        Some(email) => Some(
+            normalize_email_candidate(Some(email.clone()))
+                .filter(|value| email_syntax_valid(value))
+                .ok_or_else(|| format!("Invalid email address: {email}"))?,
+        ),
+        None => None,
+    };
+    let linkedin_url = match optional(&item.linkedin_url) {
+        Some(url) => Some(normalize_outreach_linkedin_url(&url).ok_or_else(|| {
+            format!("Invalid LinkedIn profile URL (expected linkedin.com/in/...): {url}")
+        })?),
+        None => None,
+    };
+    Ok(SalesLead {
+        id: uuid::Uuid::new_v4().to_string(),
+        run_id: "import".to_string(),
+        company: required("company", &item.company)?,
+        website: optional(&item.website).unwrap_or_else(|| format!("https://{company_domain}")),
+        company_domain,
+        contact_name: required("contact_name", &item.contact_name)?,
+        contact_title: required("contact_title", &item.contact_title)?,
+        linkedin_url,
+        email,
+        phone: optional(&item.phone),
+        reasons: item.reasons.clone(),
+        email_subject: item.email_subject.trim().to_string(),
+        email_body: item.email_body.trim().to_string(),
+        linkedin_message: item.linkedin_message.trim().to_string(),
+        score: 0,
+        status: "draft_ready".to_string(),
+        created_at: created_at.to_string(),
+        external_id: Some(required("external_id", &item.external_id)?),
+        contact_confidence: None,
+    })
+}
+
+/// Refresh the company/contact/title match of `lead` in place as a new lead.
+/// The row keeps its id so earlier approvals and deliveries stay attached.
+fn reengage_lead(
+    conn: &Connection,
+    lead: &SalesLead,
+    reasons_json: &str,
+    floors: LeadScoreFloors,
+) -> Result<LeadWriteOutcome, String> {
+    let (id, from_status): (String, String) = conn
+        .query_row(
+            "SELECT id, status FROM leads
+             WHERE company_domain = ?1 AND contact_name = ?2 AND contact_title = ?3",
+            params![lead.company_domain, lead.contact_name, lead.contact_title],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .map_err(|e| format!("Lead re-engage lookup failed: {e}"))?;
+    conn.execute(
+        "UPDATE leads
+         SET run_id = ?2, company = ?3, website = ?4, linkedin_url = ?5, email = ?6,
+             phone = ?7, reasons_json = ?8, email_subject = ?9, email_body = ?10,
+             linkedin_message = ?11, score = ?12, status = ?13, created_at = ?14,
+             contact_confidence = ?15, discovery_score = ?16, activation_score = ?17
+         WHERE id = ?1",
+        params![
+            id,
+            lead.run_id,
+            lead.company,
+            lead.website,
+            lead.linkedin_url,
+            lead.email,
+            lead.phone,
+            reasons_json,
+            lead.email_subject,
+            lead.email_body,
+            lead.linkedin_message,
+            lead.score,
+            lead.status,
+            lead.created_at,
+            lead.contact_confidence
+                .map(|confidence| confidence.as_str()),
+            floors.discovery,
+            floors.activation,
+        ],
+    )
+    .map_err(|e| format!("Lead re-engage failed: {e}"))?;
+    record_lead_status_change(
+        conn,
+        &id,
+        &from_status,
+        &lead.status,
+        Some("re-engaged after dedupe window"),
+    )?;
+    Ok(LeadWriteOutcome::Reengaged { id })
+}
+
+fn sales_lead_from_row(r: &rusqlite::Row<'_>) -> SalesLead {
+    let reasons_json: String = r.get(10).unwrap_or_else(|_| "[]".to_string());
+    let reasons = serde_json::from_str::<Vec<String>>(&reasons_json).unwrap_or_default();
+    SalesLead {
+        id: r.get(0).unwrap_or_default(),
+        run_id: r.get(1).unwrap_or_default(),
+        company: r.get(2).unwrap_or_default(),
+        website: r.get(3).unwrap_or_default(),
+        company_domain: r.get(4).unwrap_or_default(),
+        contact_name: r.get(5).unwrap_or_default(),
+        contact_title: r.get(6).unwrap_or_default(),
+        linkedin_url: r.get(7).ok(),
+        email: r.get(8).ok(),
+        phone: r.get(9).ok(),
+        reasons,
+        email_subject: r.get(11).unwrap_or_default(),
+        email_body: r.get(12).unwrap_or_default(),
+        linkedin_message: r.get(13).unwrap_or_default(),
+        score: r.get::<_, i64>(14).unwrap_or(0) as i32,
+        status: r.get(15).unwrap_or_default(),
+        created_at: r.get(16).unwrap_or_default(),
+        external_id: r.get::<_, Option<String>>(17).ok().flatten(),
+        contact_confidence: r
+            .get::<_, Option<String>>(18)
+            .ok()
+            .flatten()
+            .and_then(|value| ContactConfidence::parse(&value)),
+    }
+}
+
+/// Campaign row without its profile (`id, name, active, created_at, updated_at`).
+fn sales_campaign_from_row(r: &rusqlite::Row<'_>) -> rusqlite::Result<SalesCampaign> {
+    Ok(SalesCampaign {
+        id: r.get(0)?,
+        name: r.get(1)?,
+        active: r.get(2)?,
+        created_at: r.get(3)?,
+        updated_at: r.get(4)?,
+        profile: None,
+    })
+}
+
+fn xml_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render deliveries (newest first) as an Atom feed of outbound activity.
+fn render_deliveries_atom_feed(deliveries: &[SalesDelivery], self_url: &str) -> String {
+    let updated = deliveries
+        .first()
+        .map(|delivery| delivery.sent_at.clone())
+        .unwrap_or_else(|| Utc::now().to_rfc3339());
+    let mut feed = format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+         <feed xmlns=\"http://www.w3.org/2005/Atom\">\n\
+         <title>Pulsivo Salesman outbound activity</title>\n\
+         <id>urn:pulsivo-salesman:deliveries</id>\n\
+         <link rel=\"self\" href=\"{}\"/>\n\
+         <updated>{}</updated>\n",
+        xml_escape(self_url),
+        xml_escape(&updated)
+    );
+    for delivery in deliveries {
+        let mut summary = format!(
+            "Channel: {}\nRecipient: {}\nStatus: {}\nSent: {}",
+            delivery.channel, delivery.recipient, delivery.status, delivery.sent_at
+        );
+        if let Some(error) = delivery.error.as_deref().filter(|e| !e.trim().is_empty()) {
+            summary.push_str(&format!("\nError: {error}"));
+        }
+        feed.push_str(&format!(
+            "<entry>\n\
+             <id>urn:pulsivo-salesman:delivery:{}</id>\n\
+             <title>{} to {}: {}</title>\n\
+             <updated>{}</updated>\n\
+             <category term=\"{}\"/>\n\
+             <category term=\"{}\"/>\n\
+             <content type=\"text\">{}</content>\n\
+             </entry>\n",
+            xml_escape(&delivery.id),
+            xml_escape(&delivery.channel),
+            xml_escape(&delivery.recipient),
+            xml_escape(&delivery.status),
+            xml_escape(&delivery.sent_at),
+            xml_escape(&delivery.channel),
+            xml_escape(&delivery.status),
+            xml_escape(&summary)
+        ));
+    }
+    feed.push_str("</feed>\n");
+    feed
+}
+
+/// True when the `Accept` header ranks `text/csv` above JSON. Anything else,
+/// including a missing header or `*/*`, keeps the JSON default.
+fn accepts_csv(headers: &axum::http::HeaderMap) -> bool {
+    let Some(accept) = headers
+        .get(axum::http::header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return false;
+    };
+    let mut csv_q = 0.0f32;
+    let mut json_q = 0.0f32;
+    for range in accept.split(',') {
+        let mut parts = range.split(';');
+        let media = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+        let q = parts
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .find_map(|value| value.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        match media.as_str() {
+            "text/csv" => csv_q = csv_q.max(q),
+            "application/json" => json_q = json_q.max(q),
+            _ => {}
+        }
+    }
+    csv_q > 0.0 && csv_q > json_q
+}
+
+/// A list row that can be exported as CSV with a fixed column order.
+trait SalesCsvRow {
+    const CSV_COLUMNS: &'static [&'static str];
+    fn csv_fields(&self) -> Vec<String>;
+}
+
+impl SalesCsvRow for SalesLead {
+    const CSV_COLUMNS: &'static [&'static str] = &[
+        "id",
+        "run_id",
+        "company",
+        "website",
+        "company_domain",
+        "contact_name",
+        "contact_title",
+        "linkedin_url",
+        "email",
+        "phone",
+        "reasons",
+        "email_subject",
+        "email_body",
+        "linkedin_message",
+        "score",
+        "status",
+        "created_at",
+        "external_id",
+        "contact_confidence",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.run_id.clone(),
+            self.company.clone(),
+            self.website.clone(),
+            self.company_domain.clone(),
+            self.contact_name.clone(),
+            self.contact_title.clone(),
+            self.linkedin_url.clone().unwrap_or_default(),
+            self.email.clone().unwrap_or_default(),
+            self.phone.clone().unwrap_or_default(),
+            self.reasons.join(" | "),
+            self.email_subject.clone(),
+            self.email_body.clone(),
+            self.linkedin_message.clone(),
+            self.score.to_string(),
+            self.status.clone(),
+            self.created_at.clone(),
+            self.external_id.clone().unwrap_or_default(),
+            self.contact_confidence
+                .map(|confidence| confidence.as_str().to_string())
+                .unwrap_or_default(),
+        ]
+    }
+}
+
+impl SalesCsvRow for SalesApproval {
+    const CSV_COLUMNS: &'static [&'static str] = &[
+        "id",
+        "lead_id",
+        "channel",
+        "status",
+        "created_at",
+        "decided_at",
+        "payload",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.lead_id.clone(),
+            self.channel.clone(),
+            self.status.clone(),
+            self.created_at.clone(),
+            self.decided_at.clone().unwrap_or_default(),
+            self.payload.to_string(),
+        ]
+    }
+}
+
+impl SalesCsvRow for SalesDelivery {
+    const CSV_COLUMNS: &'static [&'static str] = &[
+        "id",
+        "approval_id",
+        "channel",
+        "recipient",
+        "status",
+        "error",
+        "sent_at",
+        "attachment_count",
+    ];
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.id.clone(),
+            self.approval_id.clone(),
+            self.channel.clone(),
+            self.recipient.clone(),
+            self.status.clone(),
+            self.error.clone().unwrap_or_default(),
+            self.sent_at.clone(),
+            self.attachment_count.to_string(),
+        ]
+    }
+}
+
+/// Quote a CSV field per RFC 4180. Non-numeric values a spreadsheet would
+/// evaluate as a formula get a leading apostrophe.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) && value.parse::<f64>().is_err() {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Render rows as UTF-8 CSV with a BOM (so Excel detects the encoding) and
+/// CRLF line endings.
+fn render_sales_csv<T: SalesCsvRow>(rows: &[T]) -> String {
+    let mut out = String::from("\u{FEFF}");
+    out.push_str(&T::CSV_COLUMNS.join(","));
+    out.push_str("\r\n");
+    for row in rows {
+        let fields = row
+            .csv_fields()
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>();
+        out.push_str(&fields.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn sales_csv_response<T: SalesCsvRow>(rows: &[T], filename: &str) -> axum::response::Response {
+    (
+        StatusCode::OK,
+        [
+            (
+                axum::http::header::CONTENT_TYPE,
+                "text/csv; charset=utf-8".to_string(),
+            ),
+            (
+                axum::http::header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+            (axum::http::header::VARY, "Accept".to_string()),
+        ],
+        render_sales_csv(rows),
+    )
+        .into_response()
+}
+
+fn normalize_campaign_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Campaign name is required".to_string());
+    }
+    if name.chars().count() > MAX_CAMPAIGN_NAME_CHARS {
+        return Err(format!(
+            "Campaign name must be at most {MAX_CAMPAIGN_NAME_CHARS} characters"
+        ));
+    }
+    Ok(name.to_string())
+}
+
 fn engine_from_state(state: &AppState) -> Result<SalesEngine, String> {
     let home_dir = state.kernel.home_dir();
     let engine = SalesEngine::new(&home_dir);
@@ -3719,6 +4386,13 @@ fn engine_from_state(state: &AppState) -> Result<SalesEngine, String> {
     Ok(engine)
 }
 
+/// Re-open the sales DB, re-apply its schema and reload message catalogs
+/// (used by the admin reload endpoint).
+pub fn reinit_sales_db(state: &AppState) -> Result<(), String> {
+    load_sales_message_catalogs(&state.kernel.home_dir());
+    engine_from_state(state).map(|_| ())
+}
+
 fn sales_segment_from_query(query: Option<&str>) -> SalesSegment {
     SalesSegment::from_optional(query)
 }
@@ -3750,12 +4424,301 @@ fn ensure_sqlite_column(
     Ok(())
 }
 
+/// One versioned step applied on top of the base `CREATE TABLE IF NOT EXISTS` schema.
+///
+/// Steps must be idempotent: databases created before versioning existed start
+/// at `user_version = 0` and replay every step, even ones already applied by hand.
+struct SalesDbMigration {
+    version: u32,
+    name: &'static str,
+    apply: fn(&Connection) -> Result<(), String>,
+}
+
+/// Ordered schema migrations. Append new steps; never renumber or edit old ones.
+const SALES_DB_MIGRATIONS: &[SalesDbMigration] = &[
+    SalesDbMigration {
+        version: 1,
+        name: "segment columns",
+        apply: |conn| {
+            ensure_sqlite_column(conn, "sales_runs", "segment", "TEXT NOT NULL DEFAULT 'b2b'")?;
+            ensure_sqlite_column(
+                conn,
+                "prospect_profiles",
+                "segment",
+                "TEXT NOT NULL DEFAULT 'b2b'",
+            )?;
+            ensure_sqlite_column(conn, "job_runs", "segment", "TEXT NOT NULL DEFAULT 'b2b'")
+        },
+    },
+    SalesDbMigration {
+        version: 2,
+        name: "run telemetry",
+        apply: |conn| ensure_sqlite_column(conn, "sales_runs", "telemetry_json", "TEXT"),
+    },
+    SalesDbMigration {
+        version: 3,
+        name: "lead full-text index",
+        apply: |conn| {
+            if !create_leads_fts(conn)? {
+                warn!("FTS5 unavailable, lead search will use LIKE");
+            }
+            Ok(())
+        },
+    },
+    SalesDbMigration {
+        version: 4,
+        name: "lead status history",
+        apply: |conn| {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS lead_status_history (
+                    id TEXT PRIMARY KEY,
+                    lead_id TEXT NOT NULL,
+                    from_status TEXT NOT NULL,
+                    to_status TEXT NOT NULL,
+                    note TEXT,
+                    changed_at TEXT NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS idx_lead_status_history_lead
+                    ON lead_status_history(lead_id, changed_at);",
+            )
+            .map_err(|e| format!("Failed to create lead_status_history: {e}"))
+        },
+    },
+    SalesDbMigration {
+        version: 5,
+        name: "lead external id",
+        apply: |conn| {
+            ensure_sqlite_column(conn, "leads", "external_id", "TEXT")?;
+            conn.execute_batch(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_leads_external_id
+                    ON leads(external_id) WHERE external_id IS NOT NULL;",
+            )
+            .map_err(|e| format!("Failed to create lead external id index: {e}"))
+        },
+    },
+    SalesDbMigration {
+        version: 6,
+        name: "sales campaigns",
+        apply: create_sales_campaigns,
+    },
+    SalesDbMigration {
+        version: 7,
+        name: "lead contact confidence",
+        apply: |conn| ensure_sqlite_column(conn, "leads", "contact_confidence", "TEXT"),
+    },
+    SalesDbMigration {
+        version: 8,
+        name: "run candidate funnel",
+        apply: |conn| {
+            ensure_sqlite_column(
+                conn,
+                "sales_runs",
+                "candidates_evaluated",
+                "INTEGER NOT NULL DEFAULT 0",
+            )?;
+            ensure_sqlite_column(
+                conn,
+                "sales_runs",
+                "candidates_qualified",
+                "INTEGER NOT NULL DEFAULT 0",
+            )
+        },
+    },
+    SalesDbMigration {
+        version: 9,
+        name: "delivery attachment count",
+        apply: |conn| {
+            ensure_sqlite_column(
+                conn,
+                "deliveries",
+                "attachment_count",
+                "INTEGER NOT NULL DEFAULT 0",
+            )
+        },
+    },
+    SalesDbMigration {
+        version: 10,
+        name: "lead score floors",
+        apply: |conn| {
+            ensure_sqlite_column(
+                conn,
+                "leads",
+                "discovery_score",
+                "INTEGER NOT NULL DEFAULT 0",
+            )?;
+            ensure_sqlite_column(
+                conn,
+                "leads",
+                "activation_score",
+                "INTEGER NOT NULL DEFAULT 0",
+            )
+        },
+    },
+];
+
+/// Create `sales_campaigns`, tag runs, leads, approvals and deliveries with a
+/// `campaign_id`, and seed the default campaign.
+///
+/// Existing rows land in the default campaign, whose profile stays in
+/// `sales_profiles_v2`. Triggers copy the campaign down from run to lead to
+/// approval to delivery, so insert paths need not carry it.
+fn create_sales_campaigns(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sales_campaigns (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            profile_json TEXT,
+            active INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );",
+    )
+    .map_err(|e| format!("Failed to create sales_campaigns: {e}"))?;
+    for table in ["sales_runs", "leads", "approvals", "deliveries"] {
+        ensure_sqlite_column(
+            conn,
+            table,
+            "campaign_id",
+            "TEXT NOT NULL DEFAULT 'default'",
+        )?;
+    }
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT OR IGNORE INTO sales_campaigns (id, name, profile_json, active, created_at, updated_at)
+         VALUES (?1, 'Default', NULL, 1, ?2, ?2)",
+        params![DEFAULT_SALES_CAMPAIGN_ID, now],
+    )
+    .map_err(|e| format!("Failed to seed default campaign: {e}"))?;
+    conn.execute_batch(
+        r#"
+        CREATE INDEX IF NOT EXISTS idx_sales_runs_campaign ON sales_runs(campaign_id, started_at DESC);
+        CREATE INDEX IF NOT EXISTS idx_leads_campaign ON leads(campaign_id, created_at DESC);
+        CREATE INDEX IF NOT EXISTS idx_approvals_campaign ON approvals(campaign_id, created_at DESC);
+        CREATE INDEX IF NOT EXISTS idx_deliveries_campaign ON deliveries(campaign_id, sent_at DESC);
+        CREATE TRIGGER IF NOT EXISTS leads_campaign_from_run AFTER INSERT ON leads BEGIN
+            UPDATE leads SET campaign_id = COALESCE(
+                (SELECT campaign_id FROM sales_runs WHERE id = new.run_id), 'default'
+            ) WHERE rowid = new.rowid;
+        END;
+        CREATE TRIGGER IF NOT EXISTS approvals_campaign_from_lead AFTER INSERT ON approvals BEGIN
+            UPDATE approvals SET campaign_id = COALESCE(
+                (SELECT campaign_id FROM leads WHERE id = new.lead_id), 'default'
+            ) WHERE rowid = new.rowid;
+        END;
+        CREATE TRIGGER IF NOT EXISTS deliveries_campaign_from_approval AFTER INSERT ON deliveries BEGIN
+            UPDATE deliveries SET campaign_id = COALESCE(
+                (SELECT campaign_id FROM approvals WHERE id = new.approval_id), 'default'
+            ) WHERE rowid = new.rowid;
+        END;
+        "#,
+    )
+    .map_err(|e| format!("Failed to create campaign indexes/triggers: {e}"))
+}
+
+/// Create the `leads_fts` FTS5 index and the triggers that keep it in sync,
+/// indexing existing leads. Returns `false` on SQLite builds without FTS5.
+///
+/// `search_leads` retries this when the index is missing, so a database
+/// migrated without FTS5 picks it up once it is available.
+fn create_leads_fts(conn: &Connection) -> Result<bool, String> {
+    if conn
+        .execute_batch(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS leads_fts USING fts5(
+                company, reasons_json, email_subject, email_body,
+                content='leads', content_rowid='rowid'
+            );",
+        )
+        .is_err()
+    {
+        return Ok(false);
+    }
+    conn.execute_batch(
+        r#"
+        CREATE TRIGGER IF NOT EXISTS leads_fts_insert AFTER INSERT ON leads BEGIN
+            INSERT INTO leads_fts(rowid, company, reasons_json, email_subject, email_body)
+            VALUES (new.rowid, new.company, new.reasons_json, new.email_subject, new.email_body);
+        END;
+        CREATE TRIGGER IF NOT EXISTS leads_fts_delete AFTER DELETE ON leads BEGIN
+            INSERT INTO leads_fts(leads_fts, rowid, company, reasons_json, email_subject, email_body)
+            VALUES ('delete', old.rowid, old.company, old.reasons_json, old.email_subject, old.email_body);
+        END;
+        CREATE TRIGGER IF NOT EXISTS leads_fts_update
+            AFTER UPDATE OF company, reasons_json, email_subject, email_body ON leads BEGIN
+            INSERT INTO leads_fts(leads_fts, rowid, company, reasons_json, email_subject, email_body)
+            VALUES ('delete', old.rowid, old.company, old.reasons_json, old.email_subject, old.email_body);
+            INSERT INTO leads_fts(rowid, company, reasons_json, email_subject, email_body)
+            VALUES (new.rowid, new.company, new.reasons_json, new.email_subject, new.email_body);
+        END;
+        INSERT INTO leads_fts(leads_fts) VALUES ('rebuild');
+        "#,
+    )
+    .map_err(|e| format!("Failed to create lead search triggers: {e}"))?;
+    Ok(true)
+}
+
+/// Turn free text into an FTS5 query: every term must match, as a prefix.
+fn leads_fts_query(query: &str) -> Option<String> {
+    let terms = query
+        .split_whitespace()
+        .map(|term| term.replace('"', ""))
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{term}\"*"))
+        .collect::<Vec<_>>();
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+fn sales_db_schema_version(conn: &Connection) -> Result<u32, String> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get::<_, u32>(0))
+        .map_err(|e| format!("Failed to read sales db schema version: {e}"))
+}
+
+/// Apply pending migrations, each in its own transaction, and record the new
+/// `PRAGMA user_version`. Returns the resulting schema version.
+fn run_sales_db_migrations(conn: &mut Connection) -> Result<u32, String> {
+    let mut current = sales_db_schema_version(conn)?;
+    for migration in SALES_DB_MIGRATIONS {
+        if migration.version <= current {
+            continue;
+        }
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start migration {}: {e}", migration.version))?;
+        (migration.apply)(&tx).map_err(|e| {
+            format!(
+                "Sales db migration {} ({}) failed: {e}",
+                migration.version, migration.name
+            )
+        })?;
+        tx.execute_batch(&format!("PRAGMA user_version = {}", migration.version))
+            .map_err(|e| format!("Failed to record migration {}: {e}", migration.version))?;
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration {}: {e}", migration.version))?;
+        info!(
+            version = migration.version,
+            name = migration.name,
+            "Applied sales db migration"
+        );
+        current = migration.version;
+    }
+    Ok(current)
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SalesRejectRequest {
     #[serde(default)]
     pub reason: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesTestSendRequest {
+    #[serde(default)]
+    pub lead_id: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SalesProfileAutofillRequest {
     pub brief: String,
@@ -3763,6 +4726,31 @@ pub struct SalesProfileAutofillRequest {
     pub persist: Option<bool>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SalesProfilePreviewRequest {
+    pub brief: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesCopyPreviewRequest {
+    #[serde(default)]
+    pub company: String,
+    #[serde(default)]
+    pub domain: String,
+    #[serde(default)]
+    pub contact_name: Option<String>,
+    #[serde(default)]
+    pub contact_title: Option<String>,
+    /// Evidence snippet to cite; defaults to a generic search-match line.
+    #[serde(default)]
+    pub evidence: Option<String>,
+    #[serde(default)]
+    pub evidence_url: Option<String>,
+    /// Render with this profile instead of the saved one.
+    #[serde(default)]
+    pub profile: Option<SalesProfile>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SalesOnboardingBriefRequest {
     pub brief: String,
@@ -30,7 +30,7 @@ pub async fn autofill_sales_profile(
         );
     }
     let persist = body.persist.unwrap_or(true);
-    let (profile, source, warnings) =
+    let (profile, source, served_models, warnings) =
         match apply_brief_to_profile(&state, &engine, segment, body.brief.trim(), persist).await {
             Ok(v) => v,
             Err(e) => {
@@ -68,12 +68,102 @@ pub async fn autofill_sales_profile(
             "profile": profile,
             "persisted": persist,
             "source": source,
+            "llm_model": served_model_json(&state.kernel, &served_models),
             "warnings": warnings,
             "onboarding": onboarding
         })),
     )
 }
 
+/// POST /api/sales/profile/preview — Show what autofill would infer from a
+/// brief without saving the profile or the brief.
+pub async fn preview_sales_profile(
+    State(state): State<Arc<AppState>>,
+    Query(segment_query): Query<SalesSegmentQuery>,
+    Json(body): Json<SalesProfilePreviewRequest>,
+) -> impl IntoResponse {
+    let segment = sales_segment_from_query(segment_query.segment.as_deref());
+    if body.brief.trim().len() < 20 {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(
+                serde_json::json!({"error": "Provide a richer company brief (at least 20 chars)."}),
+            ),
+        );
+    }
+
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    let (profile, source, served_models, warnings) =
+        match apply_brief_to_profile(&state, &engine, segment, body.brief.trim(), false).await {
+            Ok(v) => v,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({"error": e})),
+                )
+            }
+        };
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "profile": profile,
+            "persisted": false,
+            "source": source,
+            "llm_model": served_model_json(&state.kernel, &served_models),
+            "warnings": warnings
+        })),
+    )
+}
+
+pub async fn preview_sales_copy(
+    State(state): State<Arc<AppState>>,
+    Query(segment_query): Query<SalesSegmentQuery>,
+    Json(body): Json<SalesCopyPreviewRequest>,
+) -> impl IntoResponse {
+    let profile = match body.profile.clone() {
+        Some(profile) => profile,
+        None => {
+            let segment = sales_segment_from_query(segment_query.segment.as_deref());
+            let engine = match engine_from_state(&state) {
+                Ok(e) => e,
+                Err(e) => {
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(serde_json::json!({"error": e})),
+                    )
+                }
+            };
+            match engine.get_profile(segment) {
+                Ok(profile) => profile.unwrap_or_default(),
+                Err(e) => {
+                    return (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(serde_json::json!({"error": e})),
+                    )
+                }
+            }
+        }
+    };
+
+    match preview_outreach_copy(&profile, &body) {
+        Ok(preview) => (StatusCode::OK, Json(serde_json::json!(preview))),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
 pub async fn put_sales_onboarding_brief(
     State(state): State<Arc<AppState>>,
     Query(segment_query): Query<SalesSegmentQuery>,
@@ -103,7 +193,7 @@ pub async fn put_sales_onboarding_brief(
         );
     }
     let persist = body.persist.unwrap_or(true);
-    let (profile, source, warnings) =
+    let (profile, source, served_models, warnings) =
         match apply_brief_to_profile(&state, &engine, segment, brief, persist).await {
             Ok(v) => v,
             Err(e) => {
@@ -134,6 +224,7 @@ pub async fn put_sales_onboarding_brief(
             "profile": profile,
             "persisted": persist,
             "source": source,
+            "llm_model": served_model_json(&state.kernel, &served_models),
             "warnings": warnings,
             "onboarding": status
         })),
@@ -239,6 +330,16 @@ pub async fn put_sales_profile(
         }
     };
 
+    let field_errors = validate_sales_profile_fields(&profile);
+    if !field_errors.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "Sales profile has invalid fields",
+                "fields": field_errors,
+            })),
+        );
+    }
     let profile = match normalize_sales_profile(profile) {
         Ok(v) => v,
         Err(e) => {
@@ -261,7 +362,9 @@ pub async fn put_sales_profile(
     }
 }
 
-pub async fn run_sales_now(
+/// Preview the lead query plan a run would use for the current profile,
+/// without searching or queueing anything.
+pub async fn get_sales_query_plan(
     State(state): State<Arc<AppState>>,
     Query(segment_query): Query<SalesSegmentQuery>,
 ) -> impl IntoResponse {
@@ -277,6 +380,72 @@ pub async fn run_sales_now(
     };
 
     let profile = match engine.get_profile(segment) {
+        Ok(Some(profile)) => profile,
+        Ok(None) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "Sales profile not configured"})),
+            )
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    let resolved = resolve_lead_query_plan(&state.kernel, &profile).await;
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "source": resolved.source,
+            "fallback_reason": resolved.fallback_reason,
+            "discovery_queries": resolved.plan.discovery_queries,
+            "must_include_keywords": resolved.plan.must_include_keywords,
+            "exclude_keywords": resolved.plan.exclude_keywords,
+            "contact_titles": resolved.plan.contact_titles,
+        })),
+    )
+}
+
+pub async fn run_sales_now(
+    State(state): State<Arc<AppState>>,
+    Query(segment_query): Query<SalesSegmentQuery>,
+    run_permit: Option<Extension<RunPermit>>,
+) -> impl IntoResponse {
+    let segment = sales_segment_from_query(segment_query.segment.as_deref());
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    let profile = engine.get_profile(segment);
+    start_sales_run_job(
+        &state,
+        &engine,
+        segment,
+        DEFAULT_SALES_CAMPAIGN_ID,
+        profile,
+        run_permit,
+    )
+}
+
+/// Validate `profile`, take the run guard and spawn a discovery job for
+/// `campaign_id` (ignored for B2C runs).
+fn start_sales_run_job(
+    state: &AppState,
+    engine: &SalesEngine,
+    segment: SalesSegment,
+    campaign_id: &str,
+    profile: Result<Option<SalesProfile>, String>,
+    run_permit: Option<Extension<RunPermit>>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let profile = match profile {
         Ok(Some(profile)) => profile,
         Ok(None) => {
             return (
@@ -304,6 +473,25 @@ pub async fn run_sales_now(
         );
     }
 
+    if let Err(e) = validate_sales_llm_providers(&state.kernel.config_snapshot()) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        );
+    }
+
+    let mut run_guard = match SalesRunGuard::try_acquire(&state.sales_runs, "pending") {
+        Ok(guard) => guard,
+        Err(active_run_id) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({
+                    "error": "A sales run is already in progress",
+                    "run_id": active_run_id,
+                })),
+            )
+        }
+    };
     let job_id = match engine.create_job_run("discovery", segment) {
         Ok(job_id) => job_id,
         Err(e) => {
@@ -318,15 +506,32 @@ pub async fn run_sales_now(
     let home_dir = state.kernel.home_dir();
     let engine_for_task = SalesEngine::new(&home_dir);
     let spawned_job_id = job_id.clone();
-    tokio::spawn(async move {
-        if let Err(err) = engine_for_task
-            .run_generation_with_job(&kernel, Some(&spawned_job_id), segment)
-            .await
-        {
-            let _ =
-                engine_for_task.fail_job_stage(&spawned_job_id, PipelineStage::QueryPlanning, &err);
+    let campaign_id = campaign_id.to_string();
+    run_guard.rebind(&job_id);
+    tokio::spawn(
+        async move {
+            let _run_permit = run_permit;
+            let _run_guard = run_guard;
+            let result = if segment.is_b2c() {
+                engine_for_task
+                    .run_generation_with_job(&kernel, Some(&spawned_job_id), segment)
+                    .await
+            } else {
+                engine_for_task
+                    .run_campaign_generation_with_job(&kernel, Some(&spawned_job_id), &campaign_id)
+                    .await
+            };
+            if let Err(err) = result {
+                warn!(job_id = %spawned_job_id, error = %err, "Sales run failed");
+                let _ = engine_for_task.fail_job_stage(
+                    &spawned_job_id,
+                    PipelineStage::QueryPlanning,
+                    &err,
+                );
+            }
         }
-    });
+        .in_current_span(),
+    );
 
     (
         StatusCode::ACCEPTED,
@@ -393,6 +598,7 @@ pub async fn retry_sales_job(
     State(state): State<Arc<AppState>>,
     Path(job_id): Path<String>,
     Query(segment_query): Query<SalesSegmentQuery>,
+    run_permit: Option<Extension<RunPermit>>,
     Json(body): Json<JobRetryRequest>,
 ) -> impl IntoResponse {
     let segment = sales_segment_from_query(segment_query.segment.as_deref());
@@ -414,6 +620,25 @@ pub async fn retry_sales_job(
             .flatten()
             .map(|(stage, _)| stage.as_str().to_string())
     };
+    if let Err(e) = validate_sales_llm_providers(&state.kernel.config_snapshot()) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        );
+    }
+
+    let mut run_guard = match SalesRunGuard::try_acquire(&state.sales_runs, "pending") {
+        Ok(guard) => guard,
+        Err(active_run_id) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(serde_json::json!({
+                    "error": "A sales run is already in progress",
+                    "run_id": active_run_id,
+                })),
+            )
+        }
+    };
     let new_job_id = match engine.create_job_run("discovery", segment) {
         Ok(job_id) => job_id,
         Err(e) => {
@@ -428,15 +653,25 @@ pub async fn retry_sales_job(
     let home_dir = state.kernel.home_dir();
     let engine_for_task = SalesEngine::new(&home_dir);
     let spawned_job_id = new_job_id.clone();
-    tokio::spawn(async move {
-        if let Err(err) = engine_for_task
-            .run_generation_with_job(&kernel, Some(&spawned_job_id), segment)
-            .await
-        {
-            let _ =
-                engine_for_task.fail_job_stage(&spawned_job_id, PipelineStage::QueryPlanning, &err);
+    run_guard.rebind(&new_job_id);
+    tokio::spawn(
+        async move {
+            let _run_permit = run_permit;
+            let _run_guard = run_guard;
+            if let Err(err) = engine_for_task
+                .run_generation_with_job(&kernel, Some(&spawned_job_id), segment)
+                .await
+            {
+                warn!(job_id = %spawned_job_id, error = %err, "Sales run failed");
+                let _ = engine_for_task.fail_job_stage(
+                    &spawned_job_id,
+                    PipelineStage::QueryPlanning,
+                    &err,
+                );
+            }
         }
-    });
+        .in_current_span(),
+    );
 
     (
         StatusCode::ACCEPTED,
@@ -880,9 +1115,9 @@ pub async fn run_sales_calibration(State(state): State<Arc<AppState>>) -> impl I
     }
 }
 
-pub async fn list_sales_runs(
+pub async fn get_sales_stats(
     State(state): State<Arc<AppState>>,
-    Query(q): Query<SalesLeadQuery>,
+    Query(q): Query<SalesSegmentQuery>,
 ) -> impl IntoResponse {
     let segment = sales_segment_from_query(q.segment.as_deref());
     let engine = match engine_from_state(&state) {
@@ -894,14 +1129,12 @@ pub async fn list_sales_runs(
             )
         }
     };
-    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
-    let _ = engine.recover_latest_timed_out_run_if_stale(segment, SALES_RUN_RECOVERY_STALE_SECS);
 
-    match engine.list_runs(segment, limit) {
-        Ok(runs) => (
-            StatusCode::OK,
-            Json(serde_json::json!({"runs": runs, "total": runs.len()})),
-        ),
+    match engine.stats(segment) {
+        Ok(mut stats) => {
+            stats.sandbox_mode = outbound_sandbox(&state.kernel).is_some();
+            (StatusCode::OK, Json(serde_json::json!(stats)))
+        }
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e})),
@@ -909,7 +1142,7 @@ pub async fn list_sales_runs(
     }
 }
 
-pub async fn list_sales_leads(
+pub async fn list_sales_runs(
     State(state): State<Arc<AppState>>,
     Query(q): Query<SalesLeadQuery>,
 ) -> impl IntoResponse {
@@ -926,10 +1159,10 @@ pub async fn list_sales_leads(
     let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
     let _ = engine.recover_latest_timed_out_run_if_stale(segment, SALES_RUN_RECOVERY_STALE_SECS);
 
-    match engine.list_leads(limit, q.run_id.as_deref()) {
-        Ok(leads) => (
+    match engine.list_runs(segment, limit) {
+        Ok(runs) => (
             StatusCode::OK,
-            Json(serde_json::json!({"leads": leads, "total": leads.len()})),
+            Json(serde_json::json!({"runs": runs, "total": runs.len()})),
         ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -938,10 +1171,11 @@ pub async fn list_sales_leads(
     }
 }
 
-pub async fn list_sales_prospects(
+pub async fn list_sales_leads(
     State(state): State<Arc<AppState>>,
     Query(q): Query<SalesLeadQuery>,
-) -> impl IntoResponse {
+    headers: axum::http::HeaderMap,
+) -> axum::response::Response {
     let segment = sales_segment_from_query(q.segment.as_deref());
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
@@ -950,27 +1184,37 @@ pub async fn list_sales_prospects(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(serde_json::json!({"error": e})),
             )
+                .into_response()
         }
     };
     let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
     let _ = engine.recover_latest_timed_out_run_if_stale(segment, SALES_RUN_RECOVERY_STALE_SECS);
 
-    match engine.list_prospect_profiles(segment, limit, q.run_id.as_deref()) {
-        Ok(prospects) => (
+    match engine.list_leads(limit, q.run_id.as_deref()) {
+        Ok(leads) if accepts_csv(&headers) => sales_csv_response(&leads, "sales-leads.csv"),
+        Ok(leads) => (
             StatusCode::OK,
-            Json(serde_json::json!({"prospects": prospects, "total": prospects.len()})),
-        ),
+            Json(serde_json::json!({"leads": leads, "total": leads.len()})),
+        )
+            .into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e})),
-        ),
+        )
+            .into_response(),
     }
 }
 
-pub async fn list_sales_approvals(
+pub async fn search_sales_leads(
     State(state): State<Arc<AppState>>,
-    Query(q): Query<SalesApprovalQuery>,
+    Query(q): Query<SalesLeadSearchQuery>,
 ) -> impl IntoResponse {
+    if q.q.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Query parameter q is required"})),
+        );
+    }
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
         Err(e) => {
@@ -982,10 +1226,10 @@ pub async fn list_sales_approvals(
     };
     let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
 
-    match engine.list_approvals(q.status.as_deref(), limit) {
-        Ok(items) => (
+    match engine.search_leads(&q.q, limit) {
+        Ok((leads, mode)) => (
             StatusCode::OK,
-            Json(serde_json::json!({"approvals": items, "total": items.len()})),
+            Json(serde_json::json!({"leads": leads, "total": leads.len(), "mode": mode})),
         ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -994,9 +1238,10 @@ pub async fn list_sales_approvals(
     }
 }
 
-pub async fn bulk_approve_sales_approvals(
+pub async fn update_sales_lead_status(
     State(state): State<Arc<AppState>>,
-    Json(body): Json<SalesApprovalBulkApproveRequest>,
+    Path(id): Path<String>,
+    Json(body): Json<SalesLeadStatusRequest>,
 ) -> impl IntoResponse {
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
@@ -1007,44 +1252,24 @@ pub async fn bulk_approve_sales_approvals(
             )
         }
     };
-    let ids = dedupe_strings(body.ids);
-    if ids.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "ids must not be empty"})),
-        );
-    }
 
-    let mut approved = Vec::<serde_json::Value>::new();
-    let mut failed = Vec::<serde_json::Value>::new();
-    for id in ids {
-        match engine.approve_and_send(&state, &id).await {
-            Ok(result) => approved.push(serde_json::json!({
-                "id": id,
-                "result": result,
-            })),
-            Err(error) => failed.push(serde_json::json!({
-                "id": id,
-                "error": error,
-            })),
+    match engine.transition_lead_status(&id, &body.status, body.note.as_deref()) {
+        Ok(change) => (StatusCode::OK, Json(serde_json::json!(change))),
+        Err(e) if e == "Lead not found" => {
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e})))
         }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
     }
-
-    (
-        StatusCode::OK,
-        Json(serde_json::json!({
-            "approved": approved,
-            "failed": failed,
-            "approved_count": approved.len(),
-            "failed_count": failed.len(),
-        })),
-    )
 }
 
-pub async fn edit_sales_approval(
+/// POST /api/sales/leads/rescore — recompute stored lead scores against the
+/// current profile, one page at a time.
+pub async fn rescore_sales_leads(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<String>,
-    Json(body): Json<SalesApprovalEditRequest>,
+    Json(body): Json<SalesLeadRescoreRequest>,
 ) -> impl IntoResponse {
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
@@ -1056,22 +1281,36 @@ pub async fn edit_sales_approval(
         }
     };
 
-    match engine.edit_approval(&id, body.edited_payload) {
-        Ok(approval) => (
-            StatusCode::OK,
-            Json(serde_json::json!({"approval": approval})),
+    match engine.rescore_leads(
+        body.status.as_deref(),
+        body.after.as_deref(),
+        body.limit.unwrap_or(LEAD_RESCORE_PAGE_LIMIT),
+    ) {
+        Ok(rescore) => (StatusCode::OK, Json(serde_json::json!(rescore))),
+        Err(e) if e == RESCORE_PROFILE_MISSING => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
         ),
         Err(e) => (
-            StatusCode::BAD_REQUEST,
+            StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e})),
         ),
     }
 }
 
-pub async fn get_sales_account_dossier(
+/// POST /api/sales/leads/import — idempotent CRM import keyed by `external_id`.
+pub async fn import_sales_leads(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<String>,
+    Json(body): Json<SalesLeadImportRequest>,
 ) -> impl IntoResponse {
+    if body.leads.len() > LEAD_IMPORT_MAX {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!("At most {LEAD_IMPORT_MAX} leads can be imported per request")
+            })),
+        );
+    }
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
         Err(e) => {
@@ -1082,15 +1321,8 @@ pub async fn get_sales_account_dossier(
         }
     };
 
-    match engine.get_account_dossier(&id) {
-        Ok(Some(dossier)) => (
-            StatusCode::OK,
-            Json(serde_json::json!({"dossier": dossier})),
-        ),
-        Ok(None) => (
-            StatusCode::NOT_FOUND,
-            Json(serde_json::json!({"error": "Account dossier not found"})),
-        ),
+    match engine.import_leads(&body.leads) {
+        Ok(import) => (StatusCode::OK, Json(serde_json::json!(import))),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e})),
@@ -1098,9 +1330,12 @@ pub async fn get_sales_account_dossier(
     }
 }
 
-pub async fn approve_and_send(
+/// POST /api/sales/leads/{id}/block — "do not contact again": suppress the
+/// lead's email or domain, reject its pending approvals and archive it.
+pub async fn block_sales_lead(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
+    body: Option<Json<SalesLeadBlockRequest>>,
 ) -> impl IntoResponse {
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
@@ -1111,9 +1346,13 @@ pub async fn approve_and_send(
             )
         }
     };
+    let body = body.map(|b| b.0).unwrap_or_default();
 
-    match engine.approve_and_send(&state, &id).await {
-        Ok(result) => (StatusCode::OK, Json(serde_json::json!({"result": result}))),
+    match engine.block_lead(&id, body.note.as_deref()) {
+        Ok(block) => (StatusCode::OK, Json(serde_json::json!(block))),
+        Err(e) if e == "Lead not found" => {
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e})))
+        }
         Err(e) => (
             StatusCode::BAD_REQUEST,
             Json(serde_json::json!({"error": e})),
@@ -1121,10 +1360,9 @@ pub async fn approve_and_send(
     }
 }
 
-pub async fn reject_sales_approval(
+pub async fn get_sales_lead(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
-    Json(_body): Json<SalesRejectRequest>,
 ) -> impl IntoResponse {
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
@@ -1136,13 +1374,389 @@ pub async fn reject_sales_approval(
         }
     };
 
-    match engine.reject_approval(&id) {
-        Ok(()) => (
-            StatusCode::OK,
-            Json(serde_json::json!({"status": "rejected"})),
+    match engine.lead_detail(&id) {
+        Ok(Some(detail)) => (StatusCode::OK, Json(serde_json::json!(detail))),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Lead not found"})),
         ),
         Err(e) => (
-            StatusCode::BAD_REQUEST,
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn edit_sales_lead(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(body): Json<SalesLeadEditRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.edit_lead(&id, &body) {
+        Ok((lead, approvals_updated)) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"lead": lead, "approvals_updated": approvals_updated})),
+        ),
+        Err(e) if e == "Lead not found" => {
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e})))
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn get_sales_lead_status_history(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.lead_status_history(&id) {
+        Ok(history) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"history": history, "total": history.len()})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn list_sales_prospects(
+    State(state): State<Arc<AppState>>,
+    Query(q): Query<SalesLeadQuery>,
+) -> impl IntoResponse {
+    let segment = sales_segment_from_query(q.segment.as_deref());
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+    let _ = engine.recover_latest_timed_out_run_if_stale(segment, SALES_RUN_RECOVERY_STALE_SECS);
+
+    match engine.list_prospect_profiles(segment, limit, q.run_id.as_deref()) {
+        Ok(prospects) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"prospects": prospects, "total": prospects.len()})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn list_sales_approvals(
+    State(state): State<Arc<AppState>>,
+    Query(q): Query<SalesApprovalQuery>,
+    headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response()
+        }
+    };
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+
+    match engine.list_approvals(q.status.as_deref(), limit) {
+        Ok(items) if accepts_csv(&headers) => sales_csv_response(&items, "sales-approvals.csv"),
+        Ok(items) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"approvals": items, "total": items.len()})),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn bulk_approve_sales_approvals(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<SalesApprovalBulkApproveRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    let ids = dedupe_strings(body.ids);
+    if ids.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "ids must not be empty"})),
+        );
+    }
+
+    let mut approved = Vec::<serde_json::Value>::new();
+    let mut deferred = Vec::<serde_json::Value>::new();
+    let mut failed = Vec::<serde_json::Value>::new();
+    for id in ids {
+        match engine
+            .approve_and_send_with_options(&state, &id, body.override_business_hours)
+            .await
+        {
+            Ok(result) if result["status"] == "deferred" => deferred.push(serde_json::json!({
+                "id": id,
+                "result": result,
+            })),
+            Ok(result) => approved.push(serde_json::json!({
+                "id": id,
+                "result": result,
+            })),
+            Err(error) => failed.push(serde_json::json!({
+                "id": id,
+                "error": error,
+            })),
+        }
+    }
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "approved": approved,
+            "deferred": deferred,
+            "failed": failed,
+            "approved_count": approved.len(),
+            "deferred_count": deferred.len(),
+            "failed_count": failed.len(),
+        })),
+    )
+}
+
+pub async fn edit_sales_approval(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(body): Json<SalesApprovalEditRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.edit_approval(&id, body.edited_payload) {
+        Ok(approval) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"approval": approval})),
+        ),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn get_sales_account_dossier(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.get_account_dossier(&id) {
+        Ok(Some(dossier)) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"dossier": dossier})),
+        ),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Account dossier not found"})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn approve_and_send(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(q): Query<SalesApproveQuery>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine
+        .approve_and_send_with_options(&state, &id, q.override_business_hours)
+        .await
+    {
+        Ok(result) if result["status"] == "deferred" => (
+            StatusCode::ACCEPTED,
+            Json(serde_json::json!({"result": result})),
+        ),
+        Ok(result) => (StatusCode::OK, Json(serde_json::json!({"result": result}))),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn test_send_sales_email(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<SalesTestSendRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine
+        .send_test_email(&state, body.lead_id.as_deref())
+        .await
+    {
+        Ok(result) => (StatusCode::OK, Json(serde_json::json!({"result": result}))),
+        Err(e) if e == "Lead not found" => {
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e})))
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+/// POST /api/sales/approvals/expire — expire pending approvals older than
+/// `older_than_days` so an unattended queue does not grow without bound.
+pub async fn expire_sales_approvals(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<SalesApprovalExpireRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.expire_stale_approvals(body.older_than_days) {
+        Ok(expired) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"expired": expired})),
+        ),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn reject_sales_approval(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(_body): Json<SalesRejectRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.reject_approval(&id) {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"status": "rejected"})),
+        ),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn reopen_sales_approval(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.reopen_approval(&id) {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"status": "pending"})),
+        ),
+        Err(e) if e == "Approval not found" => {
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e})))
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
             Json(serde_json::json!({"error": e})),
         ),
     }
@@ -1151,6 +1765,323 @@ pub async fn reject_sales_approval(
 pub async fn list_sales_deliveries(
     State(state): State<Arc<AppState>>,
     Query(q): Query<SalesLeadQuery>,
+    headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response()
+        }
+    };
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+
+    match engine.list_deliveries(limit) {
+        Ok(items) if accepts_csv(&headers) => sales_csv_response(&items, "sales-deliveries.csv"),
+        Ok(items) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "deliveries": items,
+                "total": items.len(),
+                "sandbox_mode": outbound_sandbox(&state.kernel).is_some(),
+            })),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        )
+            .into_response(),
+    }
+}
+
+/// Atom feed of recent deliveries for feed readers. Responses carry a
+/// content-hash ETag so pollers get `304 Not Modified` until activity changes.
+pub async fn get_sales_deliveries_feed(
+    State(state): State<Arc<AppState>>,
+    Query(q): Query<SalesDeliveryFeedQuery>,
+    headers: axum::http::HeaderMap,
+) -> axum::response::Response {
+    use sha2::Digest;
+
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response()
+        }
+    };
+    let limit = q.limit.unwrap_or(DELIVERY_FEED_DEFAULT_LIMIT).clamp(1, 500);
+    let campaign_id = q
+        .campaign_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    let deliveries = match engine.query_deliveries(campaign_id, None, limit) {
+        Ok(items) => items,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response()
+        }
+    };
+
+    let self_url = match campaign_id {
+        Some(id) => format!("/api/sales/deliveries/feed?limit={limit}&campaign_id={id}"),
+        None => format!("/api/sales/deliveries/feed?limit={limit}"),
+    };
+    let body = render_deliveries_atom_feed(&deliveries, &self_url);
+    let etag = format!("\"{:x}\"", sha2::Sha256::digest(body.as_bytes()));
+    let cache_headers = [
+        (axum::http::header::ETAG, etag.clone()),
+        (
+            axum::http::header::CACHE_CONTROL,
+            "private, max-age=300, must-revalidate".to_string(),
+        ),
+    ];
+    let not_modified = headers
+        .get(axum::http::header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag));
+    if not_modified {
+        return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
+    }
+    (
+        StatusCode::OK,
+        cache_headers,
+        [(
+            axum::http::header::CONTENT_TYPE,
+            "application/atom+xml; charset=utf-8",
+        )],
+        body,
+    )
+        .into_response()
+}
+
+/// Look up a campaign, mapping a missing one to 404.
+fn require_sales_campaign(
+    engine: &SalesEngine,
+    campaign_id: &str,
+) -> Result<SalesCampaign, (StatusCode, Json<serde_json::Value>)> {
+    match engine.get_campaign(campaign_id) {
+        Ok(Some(campaign)) => Ok(campaign),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Campaign not found"})),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        )),
+    }
+}
+
+pub async fn list_sales_campaigns(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.list_campaigns() {
+        Ok(campaigns) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"campaigns": campaigns, "total": campaigns.len()})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn create_sales_campaign(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<SalesCampaignRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.create_campaign(&body) {
+        Ok(campaign) => (
+            StatusCode::CREATED,
+            Json(serde_json::json!({"campaign": campaign})),
+        ),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn get_sales_campaign(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match require_sales_campaign(&engine, &id) {
+        Ok(campaign) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"campaign": campaign})),
+        ),
+        Err(response) => response,
+    }
+}
+
+pub async fn update_sales_campaign(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(body): Json<SalesCampaignRequest>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.update_campaign(&id, &body) {
+        Ok(campaign) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"campaign": campaign})),
+        ),
+        Err(e) if e == "Campaign not found" => {
+            (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e})))
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn get_sales_campaign_profile(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match require_sales_campaign(&engine, &id) {
+        Ok(campaign) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"profile": campaign.profile.unwrap_or_default()})),
+        ),
+        Err(response) => response,
+    }
+}
+
+pub async fn put_sales_campaign_profile(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(profile): Json<SalesProfile>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    if let Err(response) = require_sales_campaign(&engine, &id) {
+        return response;
+    }
+
+    let profile = match normalize_sales_profile(profile) {
+        Ok(v) => v,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+
+    match engine.upsert_campaign_profile(&id, &profile) {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"status": "saved", "profile": profile})),
+        ),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn run_sales_campaign(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    run_permit: Option<Extension<RunPermit>>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    let campaign = match require_sales_campaign(&engine, &id) {
+        Ok(campaign) => campaign,
+        Err(response) => return response,
+    };
+
+    start_sales_run_job(
+        &state,
+        &engine,
+        SalesSegment::B2B,
+        &campaign.id,
+        Ok(campaign.profile),
+        run_permit,
+    )
+}
+
+pub async fn list_sales_campaign_runs(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(q): Query<SalesLeadQuery>,
 ) -> impl IntoResponse {
     let engine = match engine_from_state(&state) {
         Ok(e) => e,
@@ -1161,12 +2092,114 @@ pub async fn list_sales_deliveries(
             )
         }
     };
+    if let Err(response) = require_sales_campaign(&engine, &id) {
+        return response;
+    }
     let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+    let _ = engine
+        .recover_latest_timed_out_run_if_stale(SalesSegment::B2B, SALES_RUN_RECOVERY_STALE_SECS);
 
-    match engine.list_deliveries(limit) {
+    match engine.list_campaign_runs(&id, limit) {
+        Ok(runs) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"runs": runs, "total": runs.len()})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn list_sales_campaign_leads(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(q): Query<SalesLeadQuery>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    if let Err(response) = require_sales_campaign(&engine, &id) {
+        return response;
+    }
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+
+    match engine.list_campaign_leads(&id, q.run_id.as_deref(), limit) {
+        Ok(leads) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"leads": leads, "total": leads.len()})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn list_sales_campaign_approvals(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(q): Query<SalesApprovalQuery>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    if let Err(response) = require_sales_campaign(&engine, &id) {
+        return response;
+    }
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+
+    match engine.list_campaign_approvals(&id, q.status.as_deref(), limit) {
+        Ok(items) => (
+            StatusCode::OK,
+            Json(serde_json::json!({"approvals": items, "total": items.len()})),
+        ),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        ),
+    }
+}
+
+pub async fn list_sales_campaign_deliveries(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(q): Query<SalesLeadQuery>,
+) -> impl IntoResponse {
+    let engine = match engine_from_state(&state) {
+        Ok(e) => e,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e})),
+            )
+        }
+    };
+    if let Err(response) = require_sales_campaign(&engine, &id) {
+        return response;
+    }
+    let limit = q.limit.unwrap_or(DEFAULT_LIMIT).min(500);
+
+    match engine.list_campaign_deliveries(&id, limit) {
         Ok(items) => (
             StatusCode::OK,
-            Json(serde_json::json!({"deliveries": items, "total": items.len()})),
+            Json(serde_json::json!({
+                "deliveries": items,
+                "total": items.len(),
+                "sandbox_mode": outbound_sandbox(&state.kernel).is_some(),
+            })),
         ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -1175,10 +2208,17 @@ pub async fn list_sales_deliveries(
     }
 }
 
-pub fn spawn_sales_scheduler(kernel: Arc<pulsivo_salesman_kernel::PulsivoSalesmanKernel>) {
+pub fn spawn_sales_scheduler(
+    kernel: Arc<pulsivo_salesman_kernel::PulsivoSalesmanKernel>,
+    sales_runs: SalesRunSlot,
+) {
     tokio::spawn(async move {
         loop {
             tokio::time::sleep(Duration::from_secs(300)).await;
+            // Scheduled runs need web discovery; skip them while air-gapped.
+            if kernel.config_snapshot().offline {
+                continue;
+            }
 
             let home_dir = kernel.home_dir();
             let engine = SalesEngine::new(&home_dir);
@@ -1187,39 +2227,96 @@ pub fn spawn_sales_scheduler(kernel: Arc<pulsivo_salesman_kernel::PulsivoSalesma
                 continue;
             }
 
-            let profile = match engine.get_profile(SalesSegment::B2B) {
-                Ok(Some(p)) => p,
-                Ok(None) => continue,
+            let campaigns = match engine.list_campaigns() {
+                Ok(campaigns) => campaigns,
                 Err(e) => {
-                    warn!(error = %e, "Sales scheduler: profile read failed");
+                    warn!(error = %e, "Sales scheduler: campaign list failed");
                     continue;
                 }
             };
-
-            let now = Local::now();
-            if now.hour() as u8 != profile.schedule_hour_local || now.minute() > 10 {
-                continue;
+            for campaign in campaigns.into_iter().filter(|c| c.active) {
+                run_scheduled_campaign(&kernel, &engine, &sales_runs, &campaign.id).await;
             }
+        }
+    });
+}
 
-            match engine.already_ran_today(&profile.timezone_mode) {
-                Ok(true) => continue,
-                Ok(false) => {}
-                Err(e) => {
-                    warn!(error = %e, "Sales scheduler: run-day check failed");
-                    continue;
-                }
-            }
+/// Minute of the day (local time) a campaign's scheduled run starts on `day`:
+/// the schedule hour plus a jitter that is stable for a campaign and date.
+fn scheduled_trigger_minute(
+    campaign_id: &str,
+    day: chrono::NaiveDate,
+    schedule_hour_local: u8,
+    jitter_minutes: u32,
+) -> u32 {
+    let base = u32::from(schedule_hour_local.min(23)) * 60;
+    let jitter = jitter_minutes.min(MAX_SCHEDULE_JITTER_MINUTES);
+    if jitter == 0 {
+        return base;
+    }
+    let digest = Sha256::digest(format!("{campaign_id}:{day}").as_bytes());
+    let seed = u64::from_be_bytes(digest[..8].try_into().unwrap_or_default());
+    let offset = (seed % u64::from(jitter + 1)) as u32;
+    // Stay within the day so the run-once-per-day guard still applies.
+    (base + offset).min(24 * 60 - 1 - SCHEDULE_TRIGGER_WINDOW_MINUTES)
+}
 
-            info!("Sales scheduler: triggering daily run");
-            match tokio::time::timeout(Duration::from_secs(120), engine.run_generation(&kernel))
-                .await
-            {
-                Ok(Ok(_)) => {}
-                Ok(Err(e)) => error!(error = %e, "Sales scheduler: run failed"),
-                Err(_) => error!("Sales scheduler: run timed out"),
-            }
+/// Run one campaign's daily generation if its trigger minute has come and it
+/// has not completed a run today.
+async fn run_scheduled_campaign(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+    engine: &SalesEngine,
+    sales_runs: &SalesRunSlot,
+    campaign_id: &str,
+) {
+    let profile = match engine.get_campaign_profile(campaign_id) {
+        Ok(Some(p)) => p,
+        Ok(None) => return,
+        Err(e) => {
+            warn!(campaign_id, error = %e, "Sales scheduler: profile read failed");
+            return;
         }
-    });
+    };
+
+    let now = Local::now();
+    let trigger = scheduled_trigger_minute(
+        campaign_id,
+        now.date_naive(),
+        profile.schedule_hour_local,
+        profile.schedule_jitter_minutes,
+    );
+    let minute_of_day = now.hour() * 60 + now.minute();
+    if minute_of_day < trigger || minute_of_day > trigger + SCHEDULE_TRIGGER_WINDOW_MINUTES {
+        return;
+    }
+
+    match engine.campaign_already_ran_today(campaign_id, &profile.timezone_mode) {
+        Ok(true) => return,
+        Ok(false) => {}
+        Err(e) => {
+            warn!(campaign_id, error = %e, "Sales scheduler: run-day check failed");
+            return;
+        }
+    }
+
+    let _run_guard = match SalesRunGuard::try_acquire(sales_runs, "scheduler") {
+        Ok(guard) => guard,
+        Err(active_run_id) => {
+            info!(campaign_id, run_id = %active_run_id, "Sales scheduler: run already in progress");
+            return;
+        }
+    };
+    info!(campaign_id, "Sales scheduler: triggering daily run");
+    match tokio::time::timeout(
+        Duration::from_secs(120),
+        engine.run_campaign_generation_with_job(kernel, None, campaign_id),
+    )
+    .await
+    {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => error!(campaign_id, error = %e, "Sales scheduler: run failed"),
+        Err(_) => error!(campaign_id, "Sales scheduler: run timed out"),
+    }
 }
 
 trait OptionalRow<T> {
@@ -0,0 +1,208 @@
+/// Locale used when neither the profile nor the target geo picks one.
+const DEFAULT_SALES_LOCALE: &str = "en";
+
+/// Built-in outreach copy as `(locale, key, template)`.
+///
+/// Templates use `{name}` placeholders. Operators can override or add locales
+/// with `locales/<locale>.toml` / `locales/<locale>.json` in the home dir.
+const BUILTIN_SALES_MESSAGES: &[(&str, &str, &str)] = &[
+    ("en", "outreach.recipient_team", "{company} team"),
+    ("tr", "outreach.recipient_team", "{company} ekibi"),
+    (
+        "en",
+        "outreach.email_subject",
+        "{company}: field ops coordination",
+    ),
+    (
+        "tr",
+        "outreach.email_subject",
+        "{company} icin saha operasyon koordinasyonu",
+    ),
+    (
+        "en",
+        "outreach.email_body",
+        "Hi {recipient},\n\nI came across {company} through this public signal: {evidence}.\n\nFor teams running {matched}, the friction is usually around task ownership, follow-up, and delay recovery across email, phone, and chat. {product} could help here: {value}.\n\nIf useful, I can send a short 3-point workflow teardown for {company}.\n\nBest,\n{sender}",
+    ),
+    (
+        "tr",
+        "outreach.email_body",
+        "Merhaba {recipient},\n\n{company} ile ilgili su sinyali gordum: {evidence}.\n\n{company} tarafinda {matched} operasyonlarinda is atama, takip ve gecikme yonetimi kolayca daginik hale gelebiliyor. {product} burada su ise yarayabilir: {value}.\n\nUygunsa {company} icin 3 maddelik kisa bir operasyon akisi onerisi paylasabilirim.\n\nSelamlar,\n{sender}",
+    ),
+    (
+        "en",
+        "outreach.linkedin",
+        "Hi {recipient}, noticed {company} through this signal: {evidence}. {product} helps field teams tighten follow-up and coordination. Happy to share a short workflow teardown if relevant.",
+    ),
+    (
+        "tr",
+        "outreach.linkedin",
+        "Merhaba {recipient}, {company} ile ilgili su sinyali gordum: {evidence}. {product} saha ekiplerinde takip ve koordinasyonu toparlamaya yardimci oluyor. Uygunsa kisa bir akis onerisi paylasabilirim.",
+    ),
+    (
+        "en",
+        "outreach.cta",
+        "Happy to share a brief 2-page operational analysis if helpful.",
+    ),
+    (
+        "tr",
+        "outreach.cta",
+        "Uygunsa size 2 sayfalik kisa bir operasyon analizi paylasabilirim.",
+    ),
+    ("en", "outreach.evidence_source", "Source: {url}"),
+    ("tr", "outreach.evidence_source", "Kaynak: {url}"),
+];
+
+/// Operator catalogs loaded from the home dir, keyed by locale then message key.
+static SALES_MESSAGE_CATALOGS: LazyLock<RwLock<HashMap<String, HashMap<String, String>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn normalize_sales_locale(raw: &str) -> String {
+    raw.trim().to_ascii_lowercase().replace('_', "-")
+}
+
+/// Locale for a profile's outreach copy: the explicit `locale` setting, else
+/// Turkish for Turkey-targeted geos, else English.
+fn sales_locale(profile: &SalesProfile) -> String {
+    let explicit = normalize_sales_locale(&profile.locale);
+    if !explicit.is_empty() {
+        return explicit;
+    }
+    if geo_is_turkey(&profile.target_geo) {
+        "tr".to_string()
+    } else {
+        DEFAULT_SALES_LOCALE.to_string()
+    }
+}
+
+fn flatten_catalog_value(
+    prefix: &str,
+    value: &serde_json::Value,
+    out: &mut HashMap<String, String>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, nested) in map {
+                let full_key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_catalog_value(&full_key, nested, out);
+            }
+        }
+        serde_json::Value::String(text) if !prefix.is_empty() => {
+            out.insert(prefix.to_string(), text.clone());
+        }
+        _ => {}
+    }
+}
+
+/// Parse one catalog file. TOML tables and JSON objects are flattened to
+/// dotted keys, so `[outreach] email_subject = "..."` and
+/// `{"outreach.email_subject": "..."}` are equivalent.
+fn parse_sales_message_catalog(path: &FsPath) -> Result<HashMap<String, String>, String> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let value = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => {
+            let table: toml::Value =
+                toml::from_str(&raw).map_err(|e| format!("Invalid {}: {e}", path.display()))?;
+            serde_json::to_value(table).map_err(|e| format!("Invalid {}: {e}", path.display()))?
+        }
+        _ => serde_json::from_str::<serde_json::Value>(&raw)
+            .map_err(|e| format!("Invalid {}: {e}", path.display()))?,
+    };
+    let mut messages = HashMap::new();
+    flatten_catalog_value("", &value, &mut messages);
+    Ok(messages)
+}
+
+/// Reload operator message catalogs from `<home>/locales`.
+///
+/// A missing directory clears the overrides; unreadable files are skipped
+/// with a warning so a typo never blocks a run.
+fn load_sales_message_catalogs(home_dir: &FsPath) {
+    let mut catalogs = HashMap::<String, HashMap<String, String>>::new();
+    if let Ok(entries) = std::fs::read_dir(home_dir.join("locales")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_catalog = matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("toml") | Some("json")
+            );
+            let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if !is_catalog {
+                continue;
+            }
+            match parse_sales_message_catalog(&path) {
+                Ok(messages) => catalogs
+                    .entry(normalize_sales_locale(locale))
+                    .or_default()
+                    .extend(messages),
+                Err(e) => warn!(error = %e, "Skipping sales message catalog"),
+            }
+        }
+    }
+    if let Ok(mut guard) = SALES_MESSAGE_CATALOGS.write() {
+        *guard = catalogs;
+    }
+}
+
+fn lookup_sales_message(locale: &str, key: &str) -> Option<String> {
+    if let Some(text) = SALES_MESSAGE_CATALOGS
+        .read()
+        .ok()
+        .and_then(|catalogs| catalogs.get(locale).and_then(|m| m.get(key)).cloned())
+    {
+        return Some(text);
+    }
+    BUILTIN_SALES_MESSAGES
+        .iter()
+        .find(|(lang, k, _)| *lang == locale && *k == key)
+        .map(|(_, _, text)| text.to_string())
+}
+
+/// Substitute `{name}` placeholders in one pass; unknown placeholders are kept.
+fn render_sales_template(template: &str, args: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match args.iter().find(|(arg, _)| *arg == name) {
+                    Some((_, value)) => out.push_str(value),
+                    None => {
+                        out.push('{');
+                        out.push_str(name);
+                        out.push('}');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Render message `key` for `locale`, falling back to the base language
+/// (`pt-br` -> `pt`), then English, then the key itself.
+fn sales_message(locale: &str, key: &str, args: &[(&str, &str)]) -> String {
+    let locale = normalize_sales_locale(locale);
+    let base = locale.split('-').next().unwrap_or_default().to_string();
+    let template = [locale.as_str(), base.as_str(), DEFAULT_SALES_LOCALE]
+        .iter()
+        .filter(|candidate| !candidate.is_empty())
+        .find_map(|candidate| lookup_sales_message(candidate, key))
+        .unwrap_or_else(|| key.to_string());
+    render_sales_template(&template, args)
+}
@@ -1,6 +1,99 @@
+/// Primary candidate and fallbacks for sales LLM calls.
+async fn sales_llm_candidates(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+    config: &KernelConfig,
+) -> Result<(ModelCandidate, Vec<FallbackModel>), String> {
+    Ok(if config.offline {
+        let mut local = config
+            .default_model
+            .fallback_models
+            .iter()
+            .filter(|fallback| is_local_provider(&fallback.provider))
+            .cloned();
+        let primary = local.next().ok_or_else(|| {
+            crate::offline::offline_error(
+                "sales LLM calls without a local fallback model (ollama, vllm, lmstudio)",
+            )
+        })?;
+        (
+            ModelCandidate::from_fallback_model(&primary),
+            local.collect::<Vec<_>>(),
+        )
+    } else {
+        (
+            ModelCandidate::new(SALES_LLM_MODEL, sales_codex_driver_config(kernel).await),
+            config.default_model.fallback_models.clone(),
+        )
+    })
+}
+
+/// Build the sales LLM executor: the Codex primary followed by the configured
+/// `default_model.fallback_models`, so an expired Codex login degrades to the
+/// next provider instead of failing the run. In offline mode only the local
+/// fallbacks are used.
 async fn build_sales_llm_driver(
-    home_dir: &FsPath,
-) -> Result<Arc<dyn pulsivo_salesman_runtime::llm_driver::LlmDriver>, String> {
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+) -> Result<Arc<ModelFallbackChain>, String> {
+    let config = kernel.config_snapshot();
+    let (primary, fallbacks) = sales_llm_candidates(kernel, &config).await?;
+
+    let mut models = vec![primary.model.as_str()];
+    models.extend(fallbacks.iter().map(|fallback| fallback.model.as_str()));
+    let context_trim = kernel
+        .model_catalog
+        .read()
+        .map(|catalog| ContextTrimPolicy::from_config(&config.context_trim, &catalog, &models))
+        .unwrap_or_default();
+    let mut chain = ModelFallbackChain::with_fallbacks(primary, &fallbacks)
+        .with_context_trim(context_trim)
+        .with_system_prompt(SystemPromptWrap::from_config(&config.system_prompt));
+    if let Some(cache) = &kernel.llm_response_cache {
+        chain = chain.with_response_cache(cache.clone());
+    }
+    if let Some(pool) = &kernel.llm_driver_pool {
+        chain = chain.with_driver_factory(pool.factory());
+    }
+    if config.sales_llm.turn_timeout_secs > 0 {
+        chain = chain.with_turn_timeout(std::time::Duration::from_secs(
+            config.sales_llm.turn_timeout_secs,
+        ));
+    }
+    Ok(Arc::new(chain))
+}
+
+/// Drop pooled drivers and rebuild them for the current default and fallback
+/// models. A no-op when the driver pool is disabled.
+pub(crate) async fn warm_sales_llm_drivers(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+) {
+    let Some(pool) = &kernel.llm_driver_pool else {
+        return;
+    };
+    pool.invalidate();
+    let config = kernel.config_snapshot();
+    let (primary, fallbacks) = match sales_llm_candidates(kernel, &config).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            debug!("Skipping LLM driver warm-up: {e}");
+            return;
+        }
+    };
+    let mut configs = vec![primary.config];
+    configs.extend(
+        fallbacks
+            .iter()
+            .map(|fallback| ModelCandidate::from_fallback_model(fallback).config),
+    );
+    pool.warm(&configs);
+}
+
+/// Driver config for the Codex primary, refreshing a stored login that has
+/// expired.
+async fn sales_codex_driver_config(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+) -> DriverConfig {
+    let home_dir = kernel.home_dir();
+    let home_dir = home_dir.as_path();
     // Priority chain for API key:
     // 1. OPENAI_CODEX_ACCESS_TOKEN env var (set by codex CLI or user)
     // 2. OPENAI_API_KEY env var
@@ -17,8 +110,11 @@ async fn build_sales_llm_driver(
     let api_key = if let Some(token) = env_token {
         Some(token.trim().to_string())
     } else {
-        let fresh_import = crate::codex_oauth::import_codex_cli_auth(home_dir).ok();
-        let mut auth = fresh_import.or_else(|| load_sales_codex_auth(home_dir));
+        let import_cli = kernel.config_snapshot().oauth.import_codex_cli_auth;
+        let fresh_import = import_cli
+            .then(|| crate::codex_oauth::import_codex_cli_auth(home_dir).ok())
+            .flatten();
+        let mut auth = fresh_import.or_else(|| load_sales_codex_auth(home_dir, import_cli));
 
         // Auto-refresh if token looks expired
         if let Some(ref mut stored) = auth {
@@ -48,13 +144,146 @@ async fn build_sales_llm_driver(
             .filter(|token| !token.is_empty())
     };
 
-    let cfg = DriverConfig {
+    let default_model = kernel.config_snapshot().default_model;
+    DriverConfig {
         provider: SALES_LLM_PROVIDER.to_string(),
         api_key,
         base_url: None,
+        codex_originator: default_model.codex_originator,
+        codex_openai_beta: default_model.codex_openai_beta,
+        codex_truncation: default_model.codex_truncation,
+        connect_timeout_secs: default_model.connect_timeout_secs,
+        request_timeout_secs: default_model.request_timeout_secs,
+        stream_idle_timeout_secs: default_model.stream_idle_timeout_secs,
+    }
+}
+
+/// Temperature and output budget for one sales LLM call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SalesLlmSampling {
+    temperature: f32,
+    max_tokens: u32,
+}
+
+impl SalesLlmSampling {
+    /// Apply configured overrides, capping `max_tokens` at the model's
+    /// output limit when the catalog knows it (`0` = unknown).
+    fn with_overrides(self, step: &SalesLlmStepConfig, max_output_tokens: u64) -> Self {
+        let mut max_tokens = step.max_tokens.unwrap_or(self.max_tokens);
+        if max_output_tokens > 0 {
+            max_tokens = max_tokens.min(u32::try_from(max_output_tokens).unwrap_or(u32::MAX));
+        }
+        Self {
+            temperature: step.temperature.unwrap_or(self.temperature),
+            max_tokens,
+        }
+    }
+}
+
+/// Sampling for a sales step: `defaults` with the configured `step`
+/// overrides, bounded by the sales model's catalog limits.
+fn sales_llm_sampling(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+    defaults: SalesLlmSampling,
+    step: &SalesLlmStepConfig,
+) -> SalesLlmSampling {
+    let max_output_tokens = kernel
+        .model_catalog
+        .read()
+        .ok()
+        .and_then(|catalog| {
+            catalog
+                .find_model(SALES_LLM_MODEL)
+                .map(|entry| entry.max_output_tokens)
+        })
+        .unwrap_or(0);
+    defaults.with_overrides(step, max_output_tokens)
+}
+
+/// Run a sales completion and record its token usage against the provider
+/// that actually served it. A turn cut off by the turn timeout is an error.
+async fn complete_sales_llm(
+    driver: &ModelFallbackChain,
+    request: CompletionRequest,
+) -> Result<CompletionResponse, LlmError> {
+    let (response, served) = driver.complete_with_model(request).await?;
+    crate::metrics::record_llm_tokens(&served.provider, &response.usage);
+    // Sales prompts expect complete JSON, so a cut-off turn is a failure.
+    if response.stop_reason == pulsivo_salesman_types::message::StopReason::Timeout {
+        return Err(LlmError::Timeout(format!(
+            "{} turn exceeded the sales_llm.turn_timeout_secs limit",
+            served.model
+        )));
+    }
+    Ok(response)
+}
+
+/// Reject unknown fallback providers before a run starts, so a typo surfaces
+/// as one actionable message instead of a failure deep in discovery. Only the
+/// providers [`sales_llm_candidates`] puts in the chain are checked: the
+/// primary is always Codex, and offline runs use just the local fallbacks.
+/// Entries with a `base_url` are custom OpenAI-compatible endpoints.
+fn validate_sales_llm_providers(config: &KernelConfig) -> Result<(), String> {
+    let chained = config
+        .default_model
+        .fallback_models
+        .iter()
+        .filter(|fallback| !config.offline || is_local_provider(&fallback.provider));
+    for fallback in chained {
+        if fallback.base_url.is_none()
+            && !pulsivo_salesman_runtime::drivers::is_known_provider(&fallback.provider)
+        {
+            return Err(format!(
+                "Invalid default_model.fallback_models in config.toml: {}",
+                pulsivo_salesman_runtime::drivers::unknown_provider_message(&fallback.provider)
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Format an LLM failure for the operator. A Codex token without organization
+/// context gets the same reconnect guidance as the OAuth endpoints.
+fn sales_llm_error(context: &str, err: LlmError) -> String {
+    let message = err.to_string();
+    if message.contains("missing organization context") {
+        return format!(
+            "{context}: {}",
+            crate::codex_oauth::missing_org_context_error()
+        );
+    }
+    format!("{context}: {message}")
+}
+
+/// Response payload naming the provider/model that served the last LLM call,
+/// with token usage summed over every model the call used and an estimated
+/// USD cost priced per model (`null` when any of them has no known price in
+/// `model_pricing` or the built-in catalog).
+fn served_model_json(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+    served: &[ServedModel],
+) -> serde_json::Value {
+    let Some(last) = served.last() else {
+        return serde_json::Value::Null;
     };
-    pulsivo_salesman_runtime::drivers::create_driver(&cfg)
-        .map_err(|e| format!("LLM driver init failed: {e}"))
+    let pricing = kernel.config_snapshot().model_pricing;
+    let estimated_cost_usd = kernel.model_catalog.read().ok().and_then(|catalog| {
+        served.iter().try_fold(0.0, |total, model| {
+            catalog
+                .estimate_cost(&model.model, &model.usage, &pricing)
+                .map(|cost| total + cost)
+        })
+    });
+    serde_json::json!({
+        "provider": last.provider,
+        "model": last.model,
+        "fallback": last.is_fallback(),
+        "usage": {
+            "input_tokens": served.iter().map(|model| model.usage.input_tokens).sum::<u64>(),
+            "output_tokens": served.iter().map(|model| model.usage.output_tokens).sum::<u64>(),
+        },
+        "estimated_cost_usd": estimated_cost_usd,
+    })
 }
 
 fn token_jwt_expired(token: &str) -> bool {
@@ -87,8 +316,12 @@ async fn llm_build_lead_query_plan(
     kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
     profile: &SalesProfile,
 ) -> Result<LeadQueryPlanDraft, String> {
-    let home_dir = kernel.home_dir();
-    let driver = build_sales_llm_driver(&home_dir).await?;
+    let driver = build_sales_llm_driver(kernel).await?;
+    let sampling = sales_llm_sampling(
+        kernel,
+        LEAD_QUERY_PLAN_SAMPLING,
+        &kernel.config_snapshot().sales_llm.query_plan,
+    );
 
     let prompt = format!(
         "You are generating a B2B outbound lead discovery plan.\n\
@@ -121,8 +354,8 @@ async fn llm_build_lead_query_plan(
         model: SALES_LLM_MODEL.to_string(),
         messages: vec![LlmMessage::user(prompt)],
         tools: vec![],
-        max_tokens: 900,
-        temperature: 0.0,
+        max_tokens: sampling.max_tokens,
+        temperature: sampling.temperature,
         system: Some(
             "You are an elite outbound prospecting strategist and business development operator. Output strict valid JSON only."
                 .to_string(),
@@ -131,10 +364,9 @@ async fn llm_build_lead_query_plan(
         reasoning_effort: Some(ReasoningEffort::Medium),
     };
 
-    let resp = driver
-        .complete(req)
+    let resp = complete_sales_llm(&driver, req)
         .await
-        .map_err(|e| format!("Lead query planner failed: {e}"))?;
+        .map_err(|e| sales_llm_error("Lead query planner failed", e))?;
     let text = resp.text();
     let json_payload = extract_json_payload(&text)
         .ok_or_else(|| "Could not parse JSON payload from planner output".to_string())?;
@@ -186,6 +418,73 @@ async fn llm_build_lead_query_plan(
     Ok(draft)
 }
 
+/// A lead query plan together with the source that produced it.
+struct ResolvedLeadQueryPlan {
+    plan: LeadQueryPlanDraft,
+    /// `llm`, `heuristic` or `last_resort`.
+    source: &'static str,
+    /// Why the heuristic plan was used instead of the LLM planner.
+    fallback_reason: Option<String>,
+}
+
+/// Build the run's lead query plan: the LLM planner when it is applicable
+/// and answers in time, the heuristic plan otherwise.
+async fn resolve_lead_query_plan(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+    profile: &SalesProfile,
+) -> ResolvedLeadQueryPlan {
+    let heuristic = |reason: String| heuristic_or_last_resort_plan(profile, reason);
+    if profile_targets_field_ops(profile) && geo_is_turkey(&profile.target_geo) {
+        return heuristic(
+            "Field-ops profiles targeting Turkey use the heuristic planner".to_string(),
+        );
+    }
+    match tokio::time::timeout(
+        Duration::from_secs(LEAD_QUERY_PLAN_TIMEOUT_SECS),
+        llm_build_lead_query_plan(kernel, profile),
+    )
+    .await
+    {
+        Ok(Ok(plan)) if !plan.discovery_queries.is_empty() => ResolvedLeadQueryPlan {
+            plan,
+            source: "llm",
+            fallback_reason: None,
+        },
+        Ok(Ok(_)) => heuristic("Lead query planner returned no discovery queries".to_string()),
+        Ok(Err(e)) => {
+            warn!(error = %e, "Lead query planner failed, using heuristic plan");
+            heuristic(format!("Lead query planner failed: {e}"))
+        }
+        Err(_) => {
+            warn!("Lead query planner timed out, using heuristic plan");
+            heuristic("Lead query planner timed out".to_string())
+        }
+    }
+}
+
+/// The heuristic plan, or the last-resort product keyword plan when the
+/// heuristic has no usable queries (e.g. a blank target industry).
+fn heuristic_or_last_resort_plan(profile: &SalesProfile, reason: String) -> ResolvedLeadQueryPlan {
+    let plan = heuristic_lead_query_plan(profile);
+    if !plan.discovery_queries.is_empty() {
+        return ResolvedLeadQueryPlan {
+            plan,
+            source: "heuristic",
+            fallback_reason: Some(reason),
+        };
+    }
+    warn!(
+        "Heuristic lead query plan is empty, falling back to the last-resort product keyword plan"
+    );
+    ResolvedLeadQueryPlan {
+        plan: last_resort_lead_query_plan(profile),
+        source: "last_resort",
+        fallback_reason: Some(format!(
+            "{reason}; the heuristic plan had no usable queries, so searches were built from product keywords"
+        )),
+    }
+}
+
 async fn llm_generate_company_candidates(
     kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
     profile: &SalesProfile,
@@ -193,8 +492,12 @@ async fn llm_generate_company_candidates(
     run_sequence: usize,
     previously_discovered: &[String],
 ) -> Result<Vec<DomainCandidate>, String> {
-    let home_dir = kernel.home_dir();
-    let driver = build_sales_llm_driver(&home_dir).await?;
+    let driver = build_sales_llm_driver(kernel).await?;
+    let sampling = sales_llm_sampling(
+        kernel,
+        COMPANY_CANDIDATE_SAMPLING,
+        &kernel.config_snapshot().sales_llm.company_candidates,
+    );
 
     let prev_domains_section = if previously_discovered.is_empty() {
         String::new()
@@ -240,8 +543,8 @@ async fn llm_generate_company_candidates(
         model: SALES_LLM_MODEL.to_string(),
         messages: vec![LlmMessage::user(prompt)],
         tools: vec![],
-        max_tokens: 2400,
-        temperature: 0.2,
+        max_tokens: sampling.max_tokens,
+        temperature: sampling.temperature,
         system: Some(
             "You are an elite B2B market mapper and business development operator. Suggest realistic ICP-fit prospect companies with accurate domains. Output strict valid JSON only."
                 .to_string(),
@@ -250,10 +553,9 @@ async fn llm_generate_company_candidates(
         reasoning_effort: Some(ReasoningEffort::Medium),
     };
 
-    let resp = driver
-        .complete(req)
+    let resp = complete_sales_llm(&driver, req)
         .await
-        .map_err(|e| format!("LLM company candidate generation failed: {e}"))?;
+        .map_err(|e| sales_llm_error("LLM company candidate generation failed", e))?;
     let text = resp.text();
     let json_payload = extract_json_payload(&text)
         .ok_or_else(|| "Could not parse JSON payload from company candidate output".to_string())?;
@@ -288,6 +590,7 @@ async fn llm_generate_company_candidates(
             matched_keywords: dedupe_strings(matched),
             source_links: Vec::new(),
             phone: None,
+            evidence_urls: HashMap::new(),
         };
         if normalize_candidate_gateway(&mut candidate) {
             out.push(candidate);
@@ -320,8 +623,7 @@ async fn llm_validate_candidate_relevance(
     profile: &SalesProfile,
     candidates: &[DomainCandidate],
 ) -> Result<HashMap<String, (bool, f64, Option<String>)>, String> {
-    let home_dir = kernel.home_dir();
-    let driver = build_sales_llm_driver(&home_dir).await?;
+    let driver = build_sales_llm_driver(kernel).await?;
     let relevance_context = llm_candidate_relevance_prompt_context(profile);
 
     let companies_list = candidates
@@ -368,10 +670,9 @@ async fn llm_validate_candidate_relevance(
         reasoning_effort: Some(ReasoningEffort::Medium),
     };
 
-    let resp = driver
-        .complete(req)
+    let resp = complete_sales_llm(&driver, req)
         .await
-        .map_err(|e| format!("LLM validation failed: {e}"))?;
+        .map_err(|e| sales_llm_error("LLM validation failed", e))?;
     let text = resp.text();
     let json_payload = extract_json_payload(&text)
         .ok_or_else(|| "Could not parse JSON from validation output".to_string())?;
@@ -414,6 +715,123 @@ fn apply_llm_validation_scores(
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct LlmRerankItem {
+    #[serde(default)]
+    domain: String,
+    #[serde(default)]
+    score: i32,
+    #[serde(default = "default_true")]
+    keep: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct LlmRerankResponse {
+    #[serde(default)]
+    rankings: Vec<LlmRerankItem>,
+}
+
+/// Score the whole candidate pool in a single completion.
+///
+/// Returns `domain -> (relevance 0-100, keep)` for the domains the model ranked.
+async fn llm_rerank_candidates(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+    profile: &SalesProfile,
+    candidates: &[DomainCandidate],
+) -> Result<HashMap<String, (i32, bool)>, String> {
+    let driver = build_sales_llm_driver(kernel).await?;
+    let relevance_context = llm_candidate_relevance_prompt_context(profile);
+
+    let candidate_lines = candidates
+        .iter()
+        .map(|c| {
+            let evidence = c
+                .evidence
+                .iter()
+                .take(2)
+                .map(|e| truncate_text_for_reason(e, 160))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            format!("- {} (evidence: {})", c.domain, evidence)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let prompt = format!(
+        "Rank every candidate company by how well it fits our ideal customer profile.\n\n\
+         Our product: {} - {}\n\
+         Target industry: {}\n\
+         Target geography: {}\n\
+         {}\n\n\
+         Candidates:\n{}\n\n\
+         Score each domain 0-100 (100 = ideal buyer) and set keep=false for companies \
+         that are clearly not prospects (vendors, media, directories, wrong industry).\n\
+         Return strict JSON only:\n\
+         {{\"rankings\":[{{\"domain\":\"...\",\"score\":0,\"keep\":true}}]}}",
+        profile.product_name,
+        profile.product_description,
+        profile.target_industry,
+        profile.target_geo,
+        relevance_context,
+        candidate_lines
+    );
+
+    let req = CompletionRequest {
+        model: SALES_LLM_MODEL.to_string(),
+        messages: vec![LlmMessage::user(prompt)],
+        tools: vec![],
+        max_tokens: 2400,
+        temperature: 0.0,
+        system: Some(
+            "You are a B2B market analyst ranking prospect companies for an ICP. \
+             Output strict valid JSON only."
+                .to_string(),
+        ),
+        thinking: None,
+        reasoning_effort: Some(ReasoningEffort::Medium),
+    };
+
+    let resp = complete_sales_llm(&driver, req)
+        .await
+        .map_err(|e| sales_llm_error("LLM re-ranking failed", e))?;
+    let text = resp.text();
+    let json_payload = extract_json_payload(&text)
+        .ok_or_else(|| "Could not parse JSON from re-ranking output".to_string())?;
+    let parsed = serde_json::from_str::<LlmRerankResponse>(&json_payload)
+        .map_err(|e| format!("Invalid re-ranking JSON: {e}"))?;
+
+    Ok(parsed
+        .rankings
+        .into_iter()
+        .filter(|item| !item.domain.trim().is_empty())
+        .map(|item| {
+            (
+                item.domain.trim().to_lowercase(),
+                (item.score.clamp(0, 100), item.keep),
+            )
+        })
+        .collect())
+}
+
+/// Merge LLM rankings into heuristic scores: dropped domains are removed and
+/// kept ones move by up to ±10 around a neutral 50. Unranked candidates keep
+/// their heuristic score.
+fn apply_llm_rerank_scores(
+    candidates: &mut Vec<DomainCandidate>,
+    rankings: &HashMap<String, (i32, bool)>,
+) {
+    candidates.retain(|candidate| !matches!(rankings.get(&candidate.domain), Some((_, false))));
+    for candidate in candidates.iter_mut() {
+        if let Some((score, _)) = rankings.get(&candidate.domain) {
+            candidate.score += (score - 50) / 5;
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct ProspectResearchExtra {
     summary: String,
@@ -460,8 +878,7 @@ async fn llm_enrich_prospect_profiles(
         return Ok(HashMap::new());
     }
 
-    let home_dir = kernel.home_dir();
-    let driver = build_sales_llm_driver(&home_dir).await?;
+    let driver = build_sales_llm_driver(kernel).await?;
     let prospect_lines = prospects
         .iter()
         .map(|prospect| {
@@ -520,10 +937,9 @@ async fn llm_enrich_prospect_profiles(
         reasoning_effort: Some(ReasoningEffort::Medium),
     };
 
-    let resp = driver
-        .complete(req)
+    let resp = complete_sales_llm(&driver, req)
         .await
-        .map_err(|e| format!("Prospect dossier enrichment failed: {e}"))?;
+        .map_err(|e| sales_llm_error("Prospect dossier enrichment failed", e))?;
     let text = resp.text();
     let json_payload = extract_json_payload(&text)
         .ok_or_else(|| "Could not parse JSON payload from prospect dossier output".to_string())?;
@@ -774,14 +1190,65 @@ fn apply_llm_prospect_research(profile: &mut SalesProspectProfile, extra: &Prosp
     profile.research_confidence = extra.research_confidence.max(profile.research_confidence);
 }
 
+/// Why LLM profile autofill produced no draft. Callers fall back to the
+/// heuristic parser either way, but only `Unparseable` means the model is
+/// reachable and worth retrying with a clearer brief.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SalesAutofillError {
+    /// Provider misconfigured, unauthenticated or the call failed.
+    Unreachable(String),
+    /// The model answered, but neither it nor the repair attempts produced
+    /// a valid profile JSON.
+    Unparseable(String),
+}
+
+impl std::fmt::Display for SalesAutofillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SalesAutofillError::Unreachable(msg) => write!(f, "LLM unreachable: {msg}"),
+            SalesAutofillError::Unparseable(msg) => write!(f, "LLM output unparseable: {msg}"),
+        }
+    }
+}
+
+/// Characters of a bad autofill payload kept in logs and error messages.
+const AUTOFILL_PAYLOAD_LOG_CHARS: usize = 240;
+
 async fn llm_autofill_profile(
-    _state: &AppState,
+    state: &AppState,
     brief: &str,
     segment: SalesSegment,
-) -> Result<SalesProfileDraft, String> {
-    let home_dir = _state.kernel.home_dir();
-    let driver = build_sales_llm_driver(&home_dir).await?;
+) -> Result<(SalesProfileDraft, Vec<ServedModel>), SalesAutofillError> {
+    let config = state.kernel.config_snapshot();
+    validate_sales_llm_providers(&config).map_err(SalesAutofillError::Unreachable)?;
+    let driver = build_sales_llm_driver(&state.kernel)
+        .await
+        .map_err(SalesAutofillError::Unreachable)?;
+    let sampling = sales_llm_sampling(
+        &state.kernel,
+        PROFILE_AUTOFILL_SAMPLING,
+        &config.sales_llm.profile_autofill,
+    );
+    let draft = autofill_profile_with_driver(
+        &driver,
+        brief,
+        segment,
+        sampling,
+        config.sales_llm.autofill_repair_attempts,
+    )
+    .await?;
+    Ok((draft, driver.served_models()))
+}
 
+/// Ask `driver` for a profile draft, then up to `repair_attempts` times to
+/// repair output that does not parse.
+async fn autofill_profile_with_driver(
+    driver: &ModelFallbackChain,
+    brief: &str,
+    segment: SalesSegment,
+    sampling: SalesLlmSampling,
+    repair_attempts: u32,
+) -> Result<SalesProfileDraft, SalesAutofillError> {
     let prompt = if segment.is_b2c() {
         format!(
             "Extract a high-quality B2C lead discovery profile from the brief.\n\
@@ -824,8 +1291,8 @@ async fn llm_autofill_profile(
         model: SALES_LLM_MODEL.to_string(),
         messages: vec![LlmMessage::user(prompt)],
         tools: vec![],
-        max_tokens: 700,
-        temperature: 0.1,
+        max_tokens: sampling.max_tokens,
+        temperature: sampling.temperature,
         system: Some(if segment.is_b2c() {
             "You are a B2C growth analyst. Extract precise consumer niche and local-market targeting fields from noisy briefs. Output strict valid JSON only.".to_string()
         } else {
@@ -835,46 +1302,64 @@ async fn llm_autofill_profile(
         reasoning_effort: Some(ReasoningEffort::Medium),
     };
 
-    let resp = driver
-        .complete(req)
+    let resp = complete_sales_llm(driver, req)
         .await
-        .map_err(|e| format!("LLM autofill failed: {e}"))?;
-    let text = resp.text();
+        .map_err(|e| SalesAutofillError::Unreachable(sales_llm_error("LLM autofill failed", e)))?;
     let parse_payload = |raw: &str| -> Result<SalesProfileDraft, String> {
         let json_payload = extract_json_payload(raw)
             .ok_or_else(|| "Could not parse JSON payload from LLM output".to_string())?;
-        serde_json::from_str::<SalesProfileDraft>(&json_payload)
-            .map_err(|e| format!("Invalid autofill JSON: {e}; payload: {json_payload}"))
+        serde_json::from_str::<SalesProfileDraft>(&json_payload).map_err(|e| {
+            format!(
+                "Invalid autofill JSON: {e}; payload: {}",
+                truncate_cleaned_text(&json_payload, AUTOFILL_PAYLOAD_LOG_CHARS)
+            )
+        })
     };
 
-    match parse_payload(&text) {
-        Ok(draft) => Ok(draft),
-        Err(primary_err) => {
-            let repair_prompt = format!(
-                "Convert the following model output into strict JSON with these keys only:\n\
-                 product_name, product_description, target_industry, target_geo, sender_name, sender_email, sender_linkedin,\n\
-                 target_title_policy, daily_target, daily_send_cap, schedule_hour_local, timezone_mode.\n\
-                 Return JSON only, no prose.\n\nOutput to repair:\n{}",
-                text
-            );
-            let repair_req = CompletionRequest {
-                model: SALES_LLM_MODEL.to_string(),
-                messages: vec![LlmMessage::user(repair_prompt)],
-                tools: vec![],
-                max_tokens: 500,
-                temperature: 0.0,
-                system: Some(
-                    "You are a JSON repair assistant. Always output strict valid JSON.".to_string(),
-                ),
-                thinking: None,
-                reasoning_effort: Some(ReasoningEffort::Medium),
-            };
-            let repaired = driver
-                .complete(repair_req)
-                .await
-                .map_err(|e| format!("{primary_err}; repair call failed: {e}"))?;
-            parse_payload(&repaired.text())
-                .map_err(|e| format!("{primary_err}; repair failed: {e}"))
+    let mut text = resp.text();
+    let mut attempt = 0u32;
+    loop {
+        let parse_err = match parse_payload(&text) {
+            Ok(draft) => return Ok(draft),
+            Err(e) => e,
+        };
+        warn!(
+            attempt,
+            error = %parse_err,
+            payload = %truncate_cleaned_text(&text, AUTOFILL_PAYLOAD_LOG_CHARS),
+            "Sales profile autofill output did not parse"
+        );
+        if attempt >= repair_attempts {
+            return Err(SalesAutofillError::Unparseable(format!(
+                "{parse_err} (after {attempt} repair attempt(s))"
+            )));
         }
+        attempt += 1;
+
+        let repair_prompt = format!(
+            "Convert the following model output into strict JSON with these keys only:\n\
+             product_name, product_description, target_industry, target_geo, sender_name, sender_email, sender_linkedin,\n\
+             target_title_policy, daily_target, daily_send_cap, schedule_hour_local, timezone_mode.\n\
+             Return JSON only, no prose.\n\nOutput to repair:\n{}",
+            text
+        );
+        let repair_req = CompletionRequest {
+            model: SALES_LLM_MODEL.to_string(),
+            messages: vec![LlmMessage::user(repair_prompt)],
+            tools: vec![],
+            max_tokens: 500,
+            temperature: 0.0,
+            system: Some(
+                "You are a JSON repair assistant. Always output strict valid JSON.".to_string(),
+            ),
+            thinking: None,
+            reasoning_effort: Some(ReasoningEffort::Medium),
+        };
+        text = complete_sales_llm(driver, repair_req)
+            .await
+            .map_err(|e| {
+                SalesAutofillError::Unreachable(sales_llm_error("LLM autofill repair failed", e))
+            })?
+            .text();
     }
 }
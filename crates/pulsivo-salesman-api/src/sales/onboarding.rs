@@ -91,6 +91,65 @@ fn cleaned_opt(v: Option<String>) -> Option<String> {
     })
 }
 
+/// Title policies the contact search understands.
+const SALES_TITLE_POLICIES: &[&str] = &["ceo_then_founder", "ceo_only"];
+
+/// Clocks the sales day can follow.
+const SALES_TIMEZONE_MODES: &[&str] = &["local", "utc"];
+
+/// Field-level errors for values `normalize_sales_profile` would otherwise
+/// silently rewrite. Empty when the profile may be normalized and saved.
+fn validate_sales_profile_fields(profile: &SalesProfile) -> BTreeMap<String, String> {
+    let mut errors = BTreeMap::new();
+    let sender_email = profile.sender_email.trim();
+    if !sender_email.is_empty() {
+        if let Err(e) = sender_email.parse::<Mailbox>() {
+            errors.insert(
+                "sender_email".to_string(),
+                format!("'{sender_email}' is not a valid email address: {e}"),
+            );
+        }
+    }
+    let policy = profile.target_title_policy.trim();
+    if !policy.is_empty() && !SALES_TITLE_POLICIES.contains(&policy) {
+        errors.insert(
+            "target_title_policy".to_string(),
+            format!(
+                "Unknown title policy '{policy}' (expected one of: {})",
+                SALES_TITLE_POLICIES.join(", ")
+            ),
+        );
+    }
+    if profile.schedule_hour_local > 23 {
+        errors.insert(
+            "schedule_hour_local".to_string(),
+            format!(
+                "Hour must be between 0 and 23, got {}",
+                profile.schedule_hour_local
+            ),
+        );
+    }
+    let html_template = profile.email_html_template.trim();
+    if !html_template.is_empty() && !html_template.contains(EMAIL_HTML_BODY_PLACEHOLDER) {
+        errors.insert(
+            "email_html_template".to_string(),
+            format!("Template must contain the {EMAIL_HTML_BODY_PLACEHOLDER} placeholder"),
+        );
+    }
+    let timezone_mode = profile.timezone_mode.trim().to_lowercase();
+    if !timezone_mode.is_empty() && !SALES_TIMEZONE_MODES.contains(&timezone_mode.as_str()) {
+        errors.insert(
+            "timezone_mode".to_string(),
+            format!(
+                "Unknown timezone mode '{}' (expected one of: {})",
+                profile.timezone_mode.trim(),
+                SALES_TIMEZONE_MODES.join(", ")
+            ),
+        );
+    }
+    errors
+}
+
 fn normalize_sales_profile(profile: SalesProfile) -> Result<SalesProfile, String> {
     let mut normalized = profile;
     normalized.product_name = normalized.product_name.trim().to_string();
@@ -107,10 +166,20 @@ fn normalize_sales_profile(profile: SalesProfile) -> Result<SalesProfile, String
     normalized.daily_target = normalized.daily_target.clamp(1, 200);
     normalized.daily_send_cap = normalized.daily_send_cap.clamp(1, 200);
     normalized.schedule_hour_local = normalized.schedule_hour_local.min(23);
+    normalized.schedule_jitter_minutes = normalized
+        .schedule_jitter_minutes
+        .min(MAX_SCHEDULE_JITTER_MINUTES);
+    normalized.business_hours_start = normalized.business_hours_start.min(23);
+    normalized.business_hours_end = normalized.business_hours_end.min(24);
+    normalized.business_days.retain(|day| (1..=7).contains(day));
+    normalized.business_days.sort_unstable();
+    normalized.business_days.dedup();
     normalized.timezone_mode = match normalized.timezone_mode.trim().to_lowercase().as_str() {
         "utc" => "utc".to_string(),
         _ => "local".to_string(),
     };
+    normalized.blocked_company_domains =
+        normalize_blocked_domain_entries(&normalized.blocked_company_domains);
 
     if normalized.product_name.is_empty() {
         return Err("product_name is required".to_string());
@@ -127,6 +196,7 @@ fn normalize_sales_profile(profile: SalesProfile) -> Result<SalesProfile, String
     if normalized.sender_name.is_empty() {
         return Err("sender_name is required".to_string());
     }
+    validate_sender_display_name(&normalized.sender_name)?;
     if normalized.sender_email.is_empty() {
         return Err("sender_email is required".to_string());
     }
@@ -146,6 +216,155 @@ fn current_sales_day(timezone_mode: &str) -> chrono::NaiveDate {
     }
 }
 
+/// UTC bounds `[start, end)` of the current sales day, formatted like the
+/// `Utc::now().to_rfc3339()` timestamps stored in the DB so SQL can compare
+/// them as text.
+fn current_sales_day_utc_bounds(timezone_mode: &str) -> (String, String) {
+    let day = current_sales_day(timezone_mode);
+    let to_utc = |date: chrono::NaiveDate| {
+        let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+        let utc = if timezone_mode.trim().eq_ignore_ascii_case("utc") {
+            midnight.and_utc()
+        } else {
+            midnight
+                .and_local_timezone(Local)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|| midnight.and_utc())
+        };
+        utc.format("%Y-%m-%dT%H:%M:%S+00:00").to_string()
+    };
+    let next_day = day.succ_opt().unwrap_or(day);
+    (to_utc(day), to_utc(next_day))
+}
+
+/// Standard-time UTC offsets (minutes) for common target geos as
+/// `(country code, name, offset)`. Daylight saving is not modelled.
+const GEO_UTC_OFFSETS: &[(&str, &str, i32)] = &[
+    ("tr", "turkey", 180),
+    ("tr", "turkiye", 180),
+    ("tr", "türkiye", 180),
+    ("gb", "united kingdom", 0),
+    ("uk", "england", 0),
+    ("ie", "ireland", 0),
+    ("pt", "portugal", 0),
+    ("de", "germany", 60),
+    ("fr", "france", 60),
+    ("es", "spain", 60),
+    ("it", "italy", 60),
+    ("nl", "netherlands", 60),
+    ("be", "belgium", 60),
+    ("at", "austria", 60),
+    ("ch", "switzerland", 60),
+    ("pl", "poland", 60),
+    ("se", "sweden", 60),
+    ("no", "norway", 60),
+    ("dk", "denmark", 60),
+    ("cz", "czechia", 60),
+    ("gr", "greece", 120),
+    ("ro", "romania", 120),
+    ("fi", "finland", 120),
+    ("bg", "bulgaria", 120),
+    ("ua", "ukraine", 120),
+    ("il", "israel", 120),
+    ("eg", "egypt", 120),
+    ("sa", "saudi arabia", 180),
+    ("qa", "qatar", 180),
+    ("ae", "united arab emirates", 240),
+    ("ae", "uae", 240),
+    ("in", "india", 330),
+    ("sg", "singapore", 480),
+    ("jp", "japan", 540),
+    ("au", "australia", 600),
+    ("br", "brazil", -180),
+    ("us", "united states", -300),
+    ("us", "usa", -300),
+    ("ca", "canada", -300),
+    ("mx", "mexico", -360),
+];
+
+/// UTC offset (minutes) for the first recognised part of `geo`, e.g. `DE` or
+/// `Istanbul, Turkey`.
+fn geo_utc_offset_minutes(geo: &str) -> Option<i32> {
+    geo.split([',', '/', ';', '|'])
+        .map(|part| part.trim().to_lowercase())
+        .find_map(|part| {
+            GEO_UTC_OFFSETS
+                .iter()
+                .find(|(code, name, _)| part == *code || part == *name)
+                .map(|(_, _, offset)| *offset)
+        })
+}
+
+/// Recipient-local business-hours window that email sends must fall inside.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SendWindow {
+    start_hour: u8,
+    end_hour: u8,
+    days: Vec<u8>,
+    utc_offset_minutes: i32,
+}
+
+impl SendWindow {
+    /// Window for `profile`, or `None` when start and end hours are equal.
+    /// "Their time" comes from the target geo, falling back to the profile's
+    /// `timezone_mode` when the geo is not recognised.
+    fn from_profile(profile: &SalesProfile) -> Option<Self> {
+        if profile.business_hours_start == profile.business_hours_end {
+            return None;
+        }
+        let utc_offset_minutes = geo_utc_offset_minutes(&profile.target_geo).unwrap_or_else(|| {
+            if profile.timezone_mode.trim().eq_ignore_ascii_case("utc") {
+                0
+            } else {
+                Local::now().offset().local_minus_utc() / 60
+            }
+        });
+        Some(Self {
+            start_hour: profile.business_hours_start,
+            end_hour: profile.business_hours_end,
+            days: profile.business_days.clone(),
+            utc_offset_minutes,
+        })
+    }
+
+    fn allows_local(&self, local: chrono::NaiveDateTime) -> bool {
+        use chrono::Datelike;
+
+        let hour = local.hour() as u8;
+        let in_hours = if self.start_hour < self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        };
+        let weekday = local.weekday().number_from_monday() as u8;
+        in_hours && (self.days.is_empty() || self.days.contains(&weekday))
+    }
+
+    fn allows(&self, at: chrono::DateTime<Utc>) -> bool {
+        self.allows_local(self.to_local(at))
+    }
+
+    /// Start of the next open hour after `at`, searched over the coming week.
+    fn next_open(&self, at: chrono::DateTime<Utc>) -> Option<chrono::DateTime<Utc>> {
+        let local = self.to_local(at);
+        let mut candidate =
+            local.date().and_hms_opt(local.hour(), 0, 0)? + chrono::Duration::hours(1);
+        for _ in 0..(24 * 8) {
+            if self.allows_local(candidate) {
+                let utc = candidate - chrono::Duration::minutes(i64::from(self.utc_offset_minutes));
+                return Some(utc.and_utc());
+            }
+            candidate += chrono::Duration::hours(1);
+        }
+        None
+    }
+
+    fn to_local(&self, at: chrono::DateTime<Utc>) -> chrono::NaiveDateTime {
+        at.naive_utc() + chrono::Duration::minutes(i64::from(self.utc_offset_minutes))
+    }
+}
+
 fn timestamp_matches_sales_day(value: &str, day: chrono::NaiveDate, timezone_mode: &str) -> bool {
     let fallback_day = day.format("%Y-%m-%d").to_string();
     chrono::DateTime::parse_from_rfc3339(value)
@@ -273,25 +492,33 @@ async fn apply_brief_to_profile(
     segment: SalesSegment,
     brief: &str,
     persist: bool,
-) -> Result<(SalesProfile, &'static str, Vec<String>), String> {
+) -> Result<(SalesProfile, &'static str, Vec<ServedModel>, Vec<String>), String> {
     let base = match engine.get_profile(segment) {
         Ok(Some(p)) => p,
         Ok(None) => SalesProfile::default(),
         Err(e) => return Err(e),
     };
     let mut warnings = Vec::<String>::new();
-    let (profile, source) = match llm_autofill_profile(state, brief, segment).await {
-        Ok(draft) => (merge_profile(base, draft, brief, segment), "llm"),
+    let (profile, source, served_models) = match llm_autofill_profile(state, brief, segment).await {
+        Ok((draft, served_models)) => (
+            merge_profile(base, draft, brief, segment),
+            "llm",
+            served_models,
+        ),
         Err(e) => {
-            warnings.push(e);
-            (heuristic_profile_from_brief(base, brief, segment), "heuristic")
+            warnings.push(e.to_string());
+            (
+                heuristic_profile_from_brief(base, brief, segment),
+                "heuristic",
+                Vec::new(),
+            )
         }
     };
 
     if persist {
         engine.upsert_profile(segment, &profile)?;
     }
-    Ok((profile, source, warnings))
+    Ok((profile, source, served_models, warnings))
 }
 
 fn extract_json_payload(raw: &str) -> Option<String> {
@@ -907,6 +907,25 @@ fn extract_domain(raw_url: &str) -> Option<String> {
     Some(host)
 }
 
+/// Reduce a host to its registrable domain (eTLD+1) using the Public Suffix
+/// List, e.g. `shop.acme.co.uk` -> `acme.co.uk`. IP addresses and hosts that
+/// are themselves public suffixes are returned as-is.
+fn registrable_domain(host: &str) -> String {
+    let host = host.trim().trim_end_matches('.').to_lowercase();
+    if host.parse::<std::net::IpAddr>().is_ok() {
+        return host;
+    }
+    psl::domain_str(&host).unwrap_or(&host).to_string()
+}
+
+fn candidate_domain_key(domain: &str, keep_subdomains: bool) -> String {
+    if keep_subdomains {
+        domain.to_string()
+    } else {
+        registrable_domain(domain)
+    }
+}
+
 fn normalize_domain(raw: &str) -> String {
     extract_domain(raw).unwrap_or_else(|| {
         raw.trim()
@@ -1293,6 +1312,54 @@ fn is_blocked_company_domain(domain: &str) -> bool {
         || domain.contains("marketresearch")
 }
 
+fn normalize_blocked_domain_entry(raw: &str) -> Option<String> {
+    let entry = raw
+        .trim()
+        .to_lowercase()
+        .trim_start_matches("http://")
+        .trim_start_matches("https://")
+        .trim_start_matches("*.")
+        .trim_start_matches("www.")
+        .trim_end_matches('/')
+        .to_string();
+    let valid = entry.contains('.')
+        && !entry.starts_with('.')
+        && entry
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '*'));
+    valid.then_some(entry)
+}
+
+fn normalize_blocked_domain_entries(entries: &[String]) -> Vec<String> {
+    dedupe_strings(
+        entries
+            .iter()
+            .filter_map(|entry| normalize_blocked_domain_entry(entry))
+            .collect(),
+    )
+}
+
+fn blocked_domain_entry_matches(domain: &str, entry: &str) -> bool {
+    if let Some(name) = entry.strip_suffix(".*") {
+        // Wildcard TLD: `acme.*` matches acme.com, acme.com.tr and shop.acme.io,
+        // but not acme.example.com; `name` must sit right above the public suffix.
+        let registrable = registrable_domain(domain);
+        let Some((_, suffix)) = registrable.split_once('.') else {
+            return false;
+        };
+        let anchored = format!("{name}.{suffix}");
+        return domain == anchored || domain.ends_with(&format!(".{anchored}"));
+    }
+    domain == entry || domain.ends_with(&format!(".{entry}"))
+}
+
+/// Whether `domain` is excluded by a profile's `blocked_company_domains`.
+fn is_profile_blocked_domain(domain: &str, blocklist: &[String]) -> bool {
+    blocklist
+        .iter()
+        .any(|entry| blocked_domain_entry_matches(domain, entry))
+}
+
 fn extract_domains_from_text(text: &str) -> Vec<String> {
     let domain_re = regex_lite::Regex::new(
         r"(?i)\b(?:https?://)?(?:www\.)?[a-z0-9][a-z0-9-]{1,62}(?:\.[a-z0-9][a-z0-9-]{1,62})+\b",
@@ -1478,6 +1545,33 @@ fn score_search_entry(
     (score, dedupe_strings(matched))
 }
 
+/// Score a stored lead against `profile` from what the lead row already holds:
+/// channel coverage plus the search-relevance score of its company name and
+/// reasons under the profile's heuristic keyword plan. Makes no network calls.
+fn lead_fit_score(
+    lead: &SalesLead,
+    profile: &SalesProfile,
+    plan: &LeadQueryPlanDraft,
+    is_field_ops: bool,
+) -> i32 {
+    let reasons = lead.reasons.join(" ");
+    let (relevance, _) = score_search_entry(
+        &lead.company_domain,
+        &lead.company,
+        &reasons,
+        &plan.must_include_keywords,
+        &plan.exclude_keywords,
+        &profile.target_geo,
+        is_field_ops,
+    );
+    let mut score = (lead_score(&lead.linkedin_url, &lead.email) + relevance).min(100);
+    if is_field_ops && text_has_field_ops_signal(&reasons.to_lowercase()) {
+        score = (score + 4).min(100);
+    }
+    score
+}
+
+#[allow(clippy::too_many_arguments)]
 fn collect_domain_candidates_from_search(
     search_output: &str,
     out: &mut HashMap<String, DomainCandidate>,
@@ -1485,9 +1579,13 @@ fn collect_domain_candidates_from_search(
     exclude_keywords: &[String],
     target_geo: &str,
     is_field_ops: bool,
+    keep_subdomains: bool,
+    reason_limits: SalesReasonLimits,
 ) {
     for entry in parse_search_entries(search_output) {
-        let Some(result_domain) = extract_domain(&entry.url) else {
+        let Some(result_domain) =
+            extract_domain(&entry.url).map(|domain| candidate_domain_key(&domain, keep_subdomains))
+        else {
             continue;
         };
         if !is_valid_company_domain(&result_domain) {
@@ -1514,22 +1612,26 @@ fn collect_domain_candidates_from_search(
             candidate.score += score;
             candidate.source_links =
                 merge_osint_links(candidate.source_links.clone(), vec![entry.url.clone()]);
-            if !entry.snippet.trim().is_empty() {
-                if candidate.evidence.len() < 4 {
-                    candidate
-                        .evidence
-                        .push(truncate_text_for_reason(&entry.snippet, 220));
-                }
-            } else if !entry.title.trim().is_empty() && candidate.evidence.len() < 4 {
+            let snippet = if entry.snippet.trim().is_empty() {
+                entry.title.as_str()
+            } else {
+                entry.snippet.as_str()
+            };
+            if !snippet.trim().is_empty() && candidate.evidence.len() < reason_limits.evidence_items
+            {
+                let evidence = truncate_text_for_reason(snippet, reason_limits.evidence_chars);
                 candidate
-                    .evidence
-                    .push(truncate_text_for_reason(&entry.title, 220));
+                    .evidence_urls
+                    .entry(evidence.clone())
+                    .or_insert_with(|| entry.url.clone());
+                candidate.evidence.push(evidence);
             }
             candidate.matched_keywords.extend(matched);
             candidate.matched_keywords = dedupe_strings(candidate.matched_keywords.clone());
         }
 
         for referenced_domain in referenced_domains {
+            let referenced_domain = candidate_domain_key(&referenced_domain, keep_subdomains);
             if referenced_domain == result_domain || !is_valid_company_domain(&referenced_domain) {
                 continue;
             }
@@ -1559,11 +1661,16 @@ fn collect_domain_candidates_from_search(
             candidate.score += (score + website_bonus).max(2);
             candidate.source_links =
                 merge_osint_links(candidate.source_links.clone(), vec![entry.url.clone()]);
-            if candidate.evidence.len() < 4 {
-                candidate.evidence.push(truncate_text_for_reason(
+            if candidate.evidence.len() < reason_limits.evidence_items {
+                let evidence = truncate_text_for_reason(
                     &format!("{} references {}", entry.title.trim(), referenced_domain),
-                    220,
-                ));
+                    reason_limits.evidence_chars,
+                );
+                candidate
+                    .evidence_urls
+                    .entry(evidence.clone())
+                    .or_insert_with(|| entry.url.clone());
+                candidate.evidence.push(evidence);
             }
             candidate.matched_keywords.extend(matched);
             candidate.matched_keywords = dedupe_strings(candidate.matched_keywords.clone());
@@ -1580,6 +1687,7 @@ fn dedupe_domain_candidates(items: Vec<DomainCandidate>) -> Vec<DomainCandidate>
             entry.domain = normalize_domain(&item.domain);
         }
         entry.score = entry.score.max(item.score);
+        entry.merge_evidence_urls(&item);
         entry.evidence.extend(item.evidence);
         if entry.evidence.len() > 6 {
             entry.evidence.truncate(6);
@@ -1613,6 +1721,7 @@ fn merge_free_discovery_candidate(
         entry.domain = domain.clone();
     }
     entry.score = entry.score.max(directory_score);
+    entry.merge_evidence_urls(&free_candidate.candidate);
     entry.evidence.extend(free_candidate.candidate.evidence);
     if entry.evidence.len() > 6 {
         entry.evidence.truncate(6);
@@ -1720,12 +1829,7 @@ async fn fetch_free_discovery_candidates(
         return Vec::new();
     }
 
-    let client = match reqwest::Client::builder()
-        .user_agent(
-            "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Safari/537.36",
-        )
-        .build()
-    {
+    let client = match http_client::builder_with_user_agent(SALES_BROWSER_USER_AGENT).build() {
         Ok(client) => client,
         Err(_) => return Vec::new(),
     };
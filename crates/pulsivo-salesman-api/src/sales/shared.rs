@@ -1,34 +1,65 @@
 use crate::codex_oauth::StoredCodexAuth;
 use crate::routes::AppState;
+use crate::run_limiter::RunPermit;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::{Html, IntoResponse};
-use axum::Json;
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use axum::{Extension, Json};
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use base64::Engine as _;
 use chrono::{Local, Timelike, Utc};
 use futures::future::join_all;
 use futures::stream::{self, StreamExt};
-use lettre::message::header::{Header, HeaderName, HeaderValue};
-use lettre::message::{Mailbox, Message};
+use lettre::message::header::{ContentType, Header, HeaderName, HeaderValue};
+use lettre::message::{Attachment, Mailbox, Message, MultiPart, SinglePart};
 use lettre::transport::smtp::authentication::Credentials;
-use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
-use pulsivo_salesman_runtime::llm_driver::{CompletionRequest, DriverConfig};
+use lettre::{Address, AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use pulsivo_salesman_runtime::context_trim::ContextTrimPolicy;
+use pulsivo_salesman_runtime::drivers::fallback::{
+    ModelCandidate, ModelFallbackChain, ServedModel,
+};
+use pulsivo_salesman_runtime::drivers::is_local_provider;
+use pulsivo_salesman_runtime::http_client;
+use pulsivo_salesman_runtime::llm_driver::{
+    CompletionRequest, CompletionResponse, DriverConfig, LlmError,
+};
+use pulsivo_salesman_runtime::system_prompt::SystemPromptWrap;
 use pulsivo_salesman_runtime::web_cache::WebCache;
 use pulsivo_salesman_runtime::web_search::WebSearchEngine;
-use pulsivo_salesman_types::agent::ReasoningEffort;
-use pulsivo_salesman_types::config::SearchProvider;
+use pulsivo_salesman_types::agent::{FallbackModel, ReasoningEffort};
+use pulsivo_salesman_types::config::{
+    KernelConfig, OutboundSandboxConfig, SalesLlmStepConfig, SearchProvider, SmtpTlsMode,
+};
 use pulsivo_salesman_types::message::Message as LlmMessage;
 use rusqlite::{params, Connection};
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path as FsPath, PathBuf};
-use std::sync::Arc;
-use std::time::Duration;
-use tracing::{error, info, warn};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn, Instrument};
 
 const DEFAULT_LIMIT: usize = 100;
+/// Campaign that owns pre-campaign data and the legacy single-profile API.
+pub const DEFAULT_SALES_CAMPAIGN_ID: &str = "default";
+const MAX_CAMPAIGN_NAME_CHARS: usize = 120;
+const DELIVERY_FEED_DEFAULT_LIMIT: usize = 50;
+/// Cap on approvals and deliveries returned with a single lead.
+const LEAD_DETAIL_LIMIT: usize = 200;
+/// Leads rescored per `POST /api/sales/leads/rescore` page.
+const LEAD_RESCORE_PAGE_LIMIT: usize = 500;
+/// Error from `rescore_leads` when a lead's profile is missing; the only
+/// rescore failure that is the caller's to fix.
+const RESCORE_PROFILE_MISSING: &str = "Sales profile is not configured";
+/// Leads accepted per `POST /api/sales/leads/import` request.
+const LEAD_IMPORT_MAX: usize = 500;
+/// Upper bound for a profile's `schedule_jitter_minutes`.
+const MAX_SCHEDULE_JITTER_MINUTES: u32 = 120;
+/// How long after its trigger minute a scheduled run may still start; longer
+/// than the scheduler's 5-minute tick so no day is skipped.
+const SCHEDULE_TRIGGER_WINDOW_MINUTES: u32 = 10;
 const MIN_DOMAIN_RELEVANCE_SCORE: i32 = 5;
 const MAX_DISCOVERY_QUERIES: usize = 10;
 const MAX_ADAPTIVE_DISCOVERY_QUERIES: usize = 6;
@@ -71,16 +102,41 @@ const LEAD_QUERY_PLAN_TIMEOUT_SECS: u64 = 4;
 const LLM_COMPANY_GENERATION_TIMEOUT_SECS: u64 = 10;
 const LLM_RELEVANCE_VALIDATION_TIMEOUT_SECS: u64 = 6;
 const LLM_RELEVANCE_VALIDATION_BATCH_SIZE: usize = 40;
+const LLM_CANDIDATE_RERANK_TIMEOUT_SECS: u64 = 12;
+const LLM_CANDIDATE_RERANK_MAX_CANDIDATES: usize = 60;
 const MAX_LLM_PRIMARY_CANDIDATES: usize = 12;
 const SALES_DISCOVERY_SEARCH_TIMEOUT_SECS: u64 = 8;
 const SALES_CONTACT_SEARCH_TIMEOUT_SECS: u64 = 4;
 const SALES_OSINT_SEARCH_TIMEOUT_SECS: u64 = 5;
+const SALES_EMAIL_VERIFY_TIMEOUT_SECS: u64 = 6;
+const SALES_EMAIL_VERIFY_CONCURRENCY: usize = 8;
+const SALES_EMAIL_VERIFY_BUDGET_SECS: u64 = 45;
 const SALES_SEARCH_BATCH_CONCURRENCY: usize = 3;
+/// Upper clamp for the per-query result count knobs on the sales profile.
+const SALES_SEARCH_RESULTS_MAX: u32 = 50;
+const SALES_DEFAULT_MAX_REASONS: usize = 4;
+const SALES_MAX_REASONS_MAX: u32 = 8;
+const SALES_DEFAULT_EVIDENCE_ITEMS: usize = 4;
+const SALES_EVIDENCE_ITEMS_MAX: u32 = 12;
+const SALES_DEFAULT_EVIDENCE_CHARS: usize = 220;
+/// LinkedIn rejects connection notes longer than this.
+const LINKEDIN_CONNECT_NOTE_MAX_CHARS: usize = 300;
+const SALES_EVIDENCE_CHARS_MAX: u32 = 1000;
 const SALES_OSINT_PROFILE_CONCURRENCY: usize = 4;
 const PROSPECT_LLM_ENRICH_TIMEOUT_SECS: u64 = 18;
 const MAX_OSINT_LINKS_PER_PROSPECT: usize = 6;
 const MAX_OSINT_SEARCH_TARGETS: usize = 24;
 const DISCOVERY_RESERVOIR_CANDIDATES: usize = 90;
+/// User agent for company website fetches unless `http.user_agent` is set;
+/// many sites reject non-browser clients.
+const SALES_BROWSER_USER_AGENT: &str =
+    "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/123.0.0.0 Safari/537.36";
+/// Upper clamp for the profile's `max_candidates` override.
+const SALES_MAX_CANDIDATES_MAX: u32 = 500;
+/// Candidates kept per company name when the profile leaves it unset.
+const SALES_DEFAULT_CANDIDATES_PER_COMPANY: usize = 1;
+/// Upper clamp for the profile's `dedupe_window_days`.
+const SALES_DEDUPE_WINDOW_DAYS_MAX: u32 = 3650;
 const DISCOVERY_PROSPECT_SEED_LIMIT: usize = 160;
 const DISCOVERY_REFRESH_SCAN_LIMIT: usize = 240;
 const DISCOVERY_OSINT_TARGET_LIMIT: usize = MAX_OSINT_SEARCH_TARGETS;
@@ -89,10 +145,143 @@ const SALES_RUN_REQUEST_TIMEOUT_SECS: u64 = 240;
 const SALES_RUN_RECOVERY_STALE_SECS: i64 = SALES_RUN_REQUEST_TIMEOUT_SECS as i64 + 15;
 const SALES_LLM_PROVIDER: &str = "openai-codex";
 const SALES_LLM_MODEL: &str = "gpt-5.3-codex";
+/// Built-in sampling per sales LLM step; `sales_llm.<step>` overrides these.
+const LEAD_QUERY_PLAN_SAMPLING: SalesLlmSampling = SalesLlmSampling {
+    temperature: 0.0,
+    max_tokens: 900,
+};
+const COMPANY_CANDIDATE_SAMPLING: SalesLlmSampling = SalesLlmSampling {
+    temperature: 0.2,
+    max_tokens: 2400,
+};
+const PROFILE_AUTOFILL_SAMPLING: SalesLlmSampling = SalesLlmSampling {
+    temperature: 0.1,
+    max_tokens: 700,
+};
 const DEFAULT_SALES_BASE_URL: &str = "http://127.0.0.1:4200";
 const SALES_UNSUBSCRIBE_SALT: &str = "pulsivo-salesman-sales-unsubscribe";
 const SALES_SEGMENT_B2B: &str = "b2b";
 const SALES_SEGMENT_B2C: &str = "b2c";
+const SALES_DB_BUSY_TIMEOUT_MS: u64 = 5_000;
+const SALES_DB_POOL_MAX_IDLE: usize = 8;
+const SALES_DB_POOL_MAX_PATHS: usize = 8;
+/// Directory under the home dir that attachment `path`s must resolve into.
+const SALES_ATTACHMENTS_DIR: &str = "attachments";
+
+/// Id of the sales run executing in this process, if any. Held in `AppState`
+/// and shared with the scheduler; manual triggers, job retries and scheduled
+/// runs all take a [`SalesRunGuard`] on it so runs never overlap.
+#[derive(Clone, Default)]
+pub struct SalesRunSlot(Arc<Mutex<Option<String>>>);
+
+/// Exclusive claim on a [`SalesRunSlot`]; released on drop.
+struct SalesRunGuard {
+    slot: SalesRunSlot,
+    run_id: String,
+}
+
+impl SalesRunGuard {
+    /// Claim `slot` for `run_id`, or return the id already holding it.
+    fn try_acquire(slot: &SalesRunSlot, run_id: &str) -> Result<Self, String> {
+        let mut active = slot
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(current) = active.as_ref() {
+            return Err(current.clone());
+        }
+        *active = Some(run_id.to_string());
+        Ok(Self {
+            slot: slot.clone(),
+            run_id: run_id.to_string(),
+        })
+    }
+
+    /// Re-label the held slot (e.g. once the job id is known).
+    fn rebind(&mut self, run_id: &str) {
+        let mut active = self
+            .slot
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if active.as_deref() == Some(self.run_id.as_str()) {
+            *active = Some(run_id.to_string());
+        }
+        self.run_id = run_id.to_string();
+    }
+}
+
+impl Drop for SalesRunGuard {
+    fn drop(&mut self) {
+        let mut active = self
+            .slot
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if active.as_deref() == Some(self.run_id.as_str()) {
+            *active = None;
+        }
+    }
+}
+
+/// Idle sales DB connections per database path, shared by every `SalesEngine`.
+/// Least recently used path first; at most [`SALES_DB_POOL_MAX_PATHS`] paths
+/// are kept so databases that are no longer used do not hold connections.
+static SALES_DB_POOL: LazyLock<Mutex<Vec<IdleConnections>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Idle connections to one sales database.
+type IdleConnections = (PathBuf, Vec<Connection>);
+
+/// A pooled sales DB connection; returned to [`SALES_DB_POOL`] on drop.
+struct PooledConnection {
+    conn: Option<Connection>,
+    db_path: PathBuf,
+}
+
+impl std::ops::Deref for PooledConnection {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn
+            .as_ref()
+            .expect("pooled connection present until drop")
+    }
+}
+
+impl std::ops::DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn
+            .as_mut()
+            .expect("pooled connection present until drop")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        let Some(conn) = self.conn.take() else {
+            return;
+        };
+        // A connection left mid-transaction must not be handed to another caller.
+        if !conn.is_autocommit() {
+            return;
+        }
+        let mut pool = SALES_DB_POOL.lock().unwrap_or_else(|e| e.into_inner());
+        let mut entry = match pool.iter().position(|(path, _)| *path == self.db_path) {
+            Some(pos) => pool.remove(pos),
+            None => {
+                if pool.len() >= SALES_DB_POOL_MAX_PATHS {
+                    pool.remove(0);
+                }
+                (std::mem::take(&mut self.db_path), Vec::new())
+            }
+        };
+        if entry.1.len() < SALES_DB_POOL_MAX_IDLE {
+            entry.1.push(conn);
+        }
+        pool.push(entry);
+    }
+}
 
 #[derive(Debug, Clone)]
 struct ListUnsubscribeHeader(String);
@@ -159,6 +348,37 @@ impl SalesSegment {
     }
 }
 
+/// How much a lead's email address can be trusted, lowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContactConfidence {
+    /// Built from a name pattern such as `first.last@domain`.
+    Guessed,
+    /// Found on the company site, in search results or a directory.
+    Discovered,
+    /// A guessed address that passed MX/SMTP verification.
+    Verified,
+}
+
+impl ContactConfidence {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Guessed => "guessed",
+            Self::Discovered => "discovered",
+            Self::Verified => "verified",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "guessed" => Some(Self::Guessed),
+            "discovered" => Some(Self::Discovered),
+            "verified" => Some(Self::Verified),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SalesProfile {
     pub product_name: String,
@@ -178,6 +398,369 @@ pub struct SalesProfile {
     pub schedule_hour_local: u8,
     #[serde(default = "default_timezone_mode")]
     pub timezone_mode: String,
+    /// Recipient-local hour email sends may start (0-23). Equal start and
+    /// end hours disable the business-hours window.
+    #[serde(default)]
+    pub business_hours_start: u8,
+    /// Recipient-local hour email sends stop (exclusive, 0-24). A window with
+    /// end before start wraps past midnight.
+    #[serde(default)]
+    pub business_hours_end: u8,
+    /// Recipient-local weekdays sends are allowed on (1 = Monday .. 7 =
+    /// Sunday); empty allows every day.
+    #[serde(default)]
+    pub business_days: Vec<u8>,
+    /// Extra company domains to exclude from discovery. `acme.com` blocks the
+    /// domain and its subdomains; `acme.*` blocks `acme` under any TLD.
+    #[serde(default)]
+    pub blocked_company_domains: Vec<String>,
+    /// Treat subdomains as distinct companies instead of collapsing them to
+    /// their registrable domain (e.g. `shop.acme.co.uk` -> `acme.co.uk`).
+    #[serde(default)]
+    pub keep_subdomains: bool,
+    /// Verify guessed contact emails (MX lookup) before queueing; addresses
+    /// that fail are dropped so the lead falls back to LinkedIn.
+    #[serde(default)]
+    pub verify_emails: bool,
+    /// With `verify_emails`, also probe the MX host with an SMTP `RCPT TO`.
+    #[serde(default)]
+    pub verify_emails_smtp_probe: bool,
+    /// Ask the LLM to score the merged candidate pool in one call and drop the
+    /// companies it rejects. Costs one extra completion per run.
+    #[serde(default)]
+    pub llm_rerank_candidates: bool,
+    /// Locale for outreach copy (e.g. `en`, `tr`). Empty picks Turkish for
+    /// Turkey-targeted geos and English otherwise.
+    #[serde(default)]
+    pub locale: String,
+    /// Results requested per discovery query; 0 keeps the built-in counts
+    /// (the run's candidate budget for web discovery).
+    #[serde(default)]
+    pub discovery_results: u32,
+    /// Results requested per contact/OSINT lookup query; 0 keeps the
+    /// built-in counts.
+    #[serde(default)]
+    pub contact_results: u32,
+    /// Results requested per fallback query (generic discovery fallbacks and
+    /// the last-resort contact search); 0 keeps the built-in counts.
+    #[serde(default)]
+    pub fallback_results: u32,
+    /// Maximum reasons attached to each generated lead; 0 keeps the built-in
+    /// four (ICP fit, evidence, buyer role, value hypothesis).
+    #[serde(default)]
+    pub max_reasons: u32,
+    /// Search snippets kept as evidence per candidate domain; 0 keeps 4.
+    #[serde(default)]
+    pub evidence_items: u32,
+    /// Characters kept per evidence snippet (and in the evidence reason);
+    /// 0 keeps 220.
+    #[serde(default)]
+    pub evidence_chars: u32,
+    /// Queue LinkedIn outreach as a connection request with a note
+    /// (`linkedin_connect`) instead of a direct message, for prospects that
+    /// only accept messages from connections.
+    #[serde(default)]
+    pub linkedin_connect_with_note: bool,
+    /// Cap on candidate companies considered per run; 0 keeps the built-in
+    /// reservoir size. This bounds discovery and contact lookups (and so
+    /// search spend); `daily_target` still limits how many leads are
+    /// activated from the candidates that survive.
+    #[serde(default)]
+    pub max_candidates: u32,
+    /// Candidates kept per company name (as derived from the domain) after
+    /// scoring, highest score first; 0 keeps one per company. Stops a brand's
+    /// sibling domains (acme.com, acme.io) from filling the batch.
+    #[serde(default)]
+    pub max_candidates_per_company: u32,
+    /// Suppress a lead only when its domain got a lead or a delivery in the
+    /// last N days; older matches are re-engaged. 0 keeps all-time dedup.
+    #[serde(default)]
+    pub dedupe_window_days: u32,
+    /// Append the evidence's source URL to generated email copy.
+    #[serde(default)]
+    pub cite_evidence_source: bool,
+    /// Lowest contact confidence that may queue an email approval; leads
+    /// below it get LinkedIn outreach only, or stay drafts. Unset queues any
+    /// address.
+    #[serde(default)]
+    pub min_contact_confidence: Option<ContactConfidence>,
+    /// Ceiling on pending approvals across all leads; once reached, runs
+    /// leave newly selected leads in the activation backlog instead of
+    /// queueing more. 0 disables the ceiling.
+    #[serde(default)]
+    pub max_pending_approvals: u32,
+    /// Delay the scheduled run by up to this many minutes past
+    /// `schedule_hour_local:00`. The minute is derived from the campaign and
+    /// the date, so it changes daily but survives restarts. 0 runs on the
+    /// hour; capped at 120.
+    #[serde(default)]
+    pub schedule_jitter_minutes: u32,
+    /// HTML layout for outbound email, e.g. a signature with a link. The
+    /// `{{body}}` placeholder receives the escaped plaintext body. Empty sends
+    /// plaintext only; otherwise emails go out as multipart/alternative.
+    #[serde(default)]
+    pub email_html_template: String,
+}
+
+/// One operator-facing reason a run produced no leads, with a suggested fix.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SalesRunDiagnostic {
+    /// Stable machine-readable code, e.g. `below_min_score`.
+    pub code: String,
+    pub message: String,
+    pub suggestion: String,
+}
+
+/// Per-stage drop counts collected while a B2B run narrows its candidates.
+#[derive(Debug, Clone, Default)]
+struct SalesRunFunnel {
+    plan_fallback: Option<String>,
+    llm_discovery_error: Option<String>,
+    search_unavailable: bool,
+    discovered_by_source: usize,
+    filtered_input: usize,
+    min_score: i32,
+    below_min_score: usize,
+    profile_filtered: usize,
+    no_company_signal: usize,
+    no_outreach_channel: usize,
+    no_contact_identity: usize,
+    held_by_send_gate: usize,
+    already_known: usize,
+}
+
+impl SalesRunFunnel {
+    /// Turn the counts into diagnostics, most upstream cause first.
+    fn diagnostics(&self) -> Vec<SalesRunDiagnostic> {
+        let mut out = Vec::new();
+        let mut push = |code: &str, message: String, suggestion: &str| {
+            out.push(SalesRunDiagnostic {
+                code: code.to_string(),
+                message,
+                suggestion: suggestion.to_string(),
+            });
+        };
+        if let Some(reason) = &self.plan_fallback {
+            push(
+                "llm_plan_fallback",
+                format!("The LLM query planner was not used ({reason}); a heuristic plan ran instead."),
+                "Check the LLM connection (Codex login or fallback models) so search queries are tailored to the profile.",
+            );
+        }
+        if let Some(error) = &self.llm_discovery_error {
+            push(
+                "llm_discovery_failed",
+                format!("LLM company generation failed: {error}."),
+                "Check the LLM connection; web search and directories alone find fewer companies.",
+            );
+        }
+        if self.search_unavailable {
+            push(
+                "search_unavailable",
+                "Web search was unavailable for this run.".to_string(),
+                "Configure a search provider API key or check network access.",
+            );
+        }
+        if self.discovered_by_source == 0 {
+            push(
+                "no_candidates_discovered",
+                "Discovery returned no companies from any source.".to_string(),
+                "Broaden target_industry or target_geo, or describe the product with more concrete keywords.",
+            );
+        }
+        if self.below_min_score > 0 {
+            push(
+                "below_min_score",
+                format!(
+                    "{} of {} companies scored below the relevance floor ({}).",
+                    self.below_min_score, self.filtered_input, self.min_score
+                ),
+                "Make target_industry less narrow, using the words target companies use on their own sites.",
+            );
+        }
+        if self.profile_filtered > 0 {
+            push(
+                "profile_filter",
+                format!(
+                    "{} companies were dropped as holdings or large groups because the profile targets field-operations accounts.",
+                    self.profile_filtered
+                ),
+                "If large groups are in scope, reword target_industry away from field operations.",
+            );
+        }
+        if self.no_company_signal > 0 {
+            push(
+                "no_company_signal",
+                format!(
+                    "{} companies had no verifiable website evidence for the target industry.",
+                    self.no_company_signal
+                ),
+                "Add industry keywords to the profile that companies mention on their websites.",
+            );
+        }
+        if self.no_outreach_channel > 0 {
+            push(
+                "no_outreach_channel",
+                format!(
+                    "{} companies had no usable email address or personal LinkedIn profile.",
+                    self.no_outreach_channel
+                ),
+                "Use a broader target_title_policy so more decision makers qualify.",
+            );
+        }
+        if self.no_contact_identity > 0 {
+            push(
+                "no_contact_identity",
+                format!(
+                    "{} companies had no identifiable decision maker.",
+                    self.no_contact_identity
+                ),
+                "Use a broader target_title_policy so more decision makers qualify.",
+            );
+        }
+        if self.held_by_send_gate > 0 {
+            push(
+                "send_gate_held",
+                format!(
+                    "{} companies were held for research or nurture by the send gate.",
+                    self.held_by_send_gate
+                ),
+                "Review them under prospects; verified emails or stronger signals move them to activation.",
+            );
+        }
+        if self.already_known > 0 {
+            push(
+                "already_known",
+                format!(
+                    "{} leads already existed from earlier runs.",
+                    self.already_known
+                ),
+                "Widen target_geo or target_industry to reach companies not contacted yet.",
+            );
+        }
+        out
+    }
+}
+
+/// One-line summary of run diagnostics appended to run error messages.
+fn sales_run_diagnostics_summary(diagnostics: &[SalesRunDiagnostic]) -> Option<String> {
+    if diagnostics.is_empty() {
+        return None;
+    }
+    let causes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.message.trim_end_matches('.'))
+        .collect::<Vec<_>>()
+        .join("; ");
+    Some(format!("Likely causes: {causes}."))
+}
+
+/// Per-query search result counts resolved from the profile knobs.
+///
+/// Each call site passes its built-in count, which applies when the knob is
+/// unset; set knobs are clamped to `1..=SALES_SEARCH_RESULTS_MAX`. Providers
+/// that bill per result cost less with lower counts.
+#[derive(Debug, Clone, Copy, Default)]
+struct SalesSearchLimits {
+    discovery: u32,
+    contact: u32,
+    fallback: u32,
+    candidates: u32,
+    per_company: u32,
+}
+
+impl SalesSearchLimits {
+    fn from_profile(profile: &SalesProfile) -> Self {
+        Self {
+            discovery: profile.discovery_results,
+            contact: profile.contact_results,
+            fallback: profile.fallback_results,
+            candidates: profile.max_candidates,
+            per_company: profile.max_candidates_per_company,
+        }
+    }
+
+    fn resolve(configured: u32, default: usize) -> usize {
+        if configured == 0 {
+            default
+        } else {
+            configured.clamp(1, SALES_SEARCH_RESULTS_MAX) as usize
+        }
+    }
+
+    fn discovery(&self, default: usize) -> usize {
+        Self::resolve(self.discovery, default)
+    }
+
+    fn contact(&self, default: usize) -> usize {
+        Self::resolve(self.contact, default)
+    }
+
+    fn fallback(&self, default: usize) -> usize {
+        Self::resolve(self.fallback, default)
+    }
+
+    /// Per-run candidate cap, clamped to `1..=SALES_MAX_CANDIDATES_MAX`.
+    fn candidates(&self, default: usize) -> usize {
+        if self.candidates == 0 {
+            default
+        } else {
+            self.candidates.clamp(1, SALES_MAX_CANDIDATES_MAX) as usize
+        }
+    }
+
+    /// Candidates kept per company, clamped to `1..=SALES_MAX_CANDIDATES_MAX`.
+    fn per_company(&self) -> usize {
+        if self.per_company == 0 {
+            SALES_DEFAULT_CANDIDATES_PER_COMPANY
+        } else {
+            self.per_company.clamp(1, SALES_MAX_CANDIDATES_MAX) as usize
+        }
+    }
+}
+
+/// Lead rationale limits resolved from the profile knobs. Unset knobs keep
+/// the built-in defaults; set knobs are clamped to a sane range.
+#[derive(Debug, Clone, Copy)]
+struct SalesReasonLimits {
+    max_reasons: usize,
+    evidence_items: usize,
+    evidence_chars: usize,
+}
+
+impl SalesReasonLimits {
+    fn from_profile(profile: &SalesProfile) -> Self {
+        fn resolve(configured: u32, default: usize, max: u32) -> usize {
+            if configured == 0 {
+                default
+            } else {
+                configured.clamp(1, max) as usize
+            }
+        }
+        Self {
+            max_reasons: resolve(
+                profile.max_reasons,
+                SALES_DEFAULT_MAX_REASONS,
+                SALES_MAX_REASONS_MAX,
+            ),
+            evidence_items: resolve(
+                profile.evidence_items,
+                SALES_DEFAULT_EVIDENCE_ITEMS,
+                SALES_EVIDENCE_ITEMS_MAX,
+            ),
+            evidence_chars: resolve(
+                profile.evidence_chars,
+                SALES_DEFAULT_EVIDENCE_CHARS,
+                SALES_EVIDENCE_CHARS_MAX,
+            ),
+        }
+    }
+}
+
+impl Default for SalesReasonLimits {
+    fn default() -> Self {
+        Self::from_profile(&SalesProfile::default())
+    }
 }
 
 fn default_target_title_policy() -> String {
@@ -215,20 +798,131 @@ impl Default for SalesProfile {
             daily_send_cap: default_daily_send_cap(),
             schedule_hour_local: default_schedule_hour(),
             timezone_mode: default_timezone_mode(),
+            business_hours_start: 0,
+            business_hours_end: 0,
+            business_days: Vec::new(),
+            blocked_company_domains: Vec::new(),
+            keep_subdomains: false,
+            verify_emails: false,
+            verify_emails_smtp_probe: false,
+            llm_rerank_candidates: false,
+            locale: String::new(),
+            discovery_results: 0,
+            contact_results: 0,
+            fallback_results: 0,
+            max_reasons: 0,
+            evidence_items: 0,
+            evidence_chars: 0,
+            linkedin_connect_with_note: false,
+            max_candidates: 0,
+            max_candidates_per_company: 0,
+            dedupe_window_days: 0,
+            cite_evidence_source: false,
+            min_contact_confidence: None,
+            max_pending_approvals: 0,
+            schedule_jitter_minutes: 0,
+            email_html_template: String::new(),
         }
     }
 }
 
+/// At-a-glance counters for the sales dashboard (`GET /api/sales/stats`).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SalesStats {
+    pub total_leads: u64,
+    pub leads_by_status: BTreeMap<String, u64>,
+    pub approvals_pending: u64,
+    pub approvals_approved: u64,
+    pub approvals_rejected: u64,
+    pub approvals_by_status: BTreeMap<String, u64>,
+    pub deliveries_sent_today: u64,
+    pub deliveries_failed_today: u64,
+    pub deliveries_sent_total: u64,
+    pub deliveries_failed_total: u64,
+    /// Deliveries recorded while outbound sandbox mode was on.
+    pub deliveries_sandboxed_total: u64,
+    /// Whether outbound sandbox mode is currently on.
+    pub sandbox_mode: bool,
+    pub daily_send_cap: u32,
+    pub sends_remaining_today: u32,
+    pub last_run: Option<SalesRunRecord>,
+}
+
+/// A named prospecting campaign: its own profile, runs, leads, approvals and
+/// deliveries. The default campaign reads the segment-level B2B profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SalesCampaign {
+    pub id: String,
+    pub name: String,
+    pub active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<SalesProfile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesCampaignRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub active: Option<bool>,
+    #[serde(default)]
+    pub profile: Option<SalesProfile>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SalesRunRecord {
     pub id: String,
     pub status: String,
     pub started_at: String,
     pub completed_at: Option<String>,
+    /// Candidates that passed the minimum score gate.
     pub discovered: u32,
     pub inserted: u32,
     pub approvals_queued: u32,
     pub error: Option<String>,
+    #[serde(default)]
+    pub telemetry: Option<SalesRunTelemetry>,
+    /// Every candidate the run looked at, before any gate.
+    #[serde(default)]
+    pub candidates_evaluated: u32,
+    /// Candidates that passed the minimum score gate.
+    #[serde(default)]
+    pub candidates_qualified: u32,
+}
+
+/// Per-run stage timings (milliseconds) and search counters, stored as JSON
+/// on the `sales_runs` row.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SalesRunTelemetry {
+    #[serde(default)]
+    pub llm_plan_ms: u64,
+    #[serde(default)]
+    pub llm_candidate_ms: u64,
+    #[serde(default)]
+    pub discovery_search_ms: u64,
+    #[serde(default)]
+    pub contact_lookup_ms: u64,
+    #[serde(default)]
+    pub queries_issued: u32,
+    #[serde(default)]
+    pub search_errors: u32,
+    /// Effective per-run candidate cap (profile `max_candidates` or the
+    /// built-in reservoir size).
+    #[serde(default)]
+    pub max_candidates: u32,
+    /// Selected leads left in the activation backlog because pending
+    /// approvals were at the profile's `max_pending_approvals`.
+    #[serde(default)]
+    pub approvals_capped: u32,
+    /// Why the run produced no leads; only filled when nothing was inserted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<SalesRunDiagnostic>,
+}
+
+fn elapsed_ms(started: Instant) -> u64 {
+    started.elapsed().as_millis().min(u64::MAX as u128) as u64
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -250,6 +944,81 @@ pub struct SalesLead {
     pub score: i32,
     pub status: String,
     pub created_at: String,
+    /// Caller-supplied stable id (e.g. a CRM record id). Leads with one are
+    /// upserted on it instead of being deduplicated by company/contact.
+    #[serde(default)]
+    pub external_id: Option<String>,
+    /// Where `email` came from. `None` without an email, and for addresses
+    /// imported, entered by an operator or stored before confidence was
+    /// tracked; those are never gated by `min_contact_confidence`.
+    #[serde(default)]
+    pub contact_confidence: Option<ContactConfidence>,
+}
+
+/// Run-time score components a stored lead row cannot reproduce, kept so
+/// `rescore_leads` never drops a lead below what its run found.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct LeadScoreFloors {
+    /// Channel coverage plus discovery relevance (LLM re-rank and
+    /// cross-source confirmation included), with the field-ops bonus.
+    discovery: i32,
+    /// Canonical five-axis activation priority, 0-100.
+    activation: i32,
+}
+
+/// Outcome of [`SalesEngine::insert_lead`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LeadWriteOutcome {
+    /// A new lead row was created.
+    Inserted,
+    /// An existing lead with the same `external_id` had its draft refreshed.
+    Updated,
+    /// A lead for the same company/contact already exists; nothing changed.
+    Duplicate,
+    /// A lead for the same company/contact predates the dedupe window and was
+    /// refreshed in place as a new lead; `id` is the surviving row's id.
+    Reengaged { id: String },
+}
+
+/// Operator-facing lead lifecycle, in pipeline order.
+const LEAD_LIFECYCLE_STATUSES: &[&str] = &[
+    "draft_ready",
+    "queued",
+    "contacted",
+    "replied",
+    "bounced",
+    "archived",
+];
+
+/// Whether a lead may move from `from` to `to`.
+///
+/// The activation pipeline's own statuses map onto the lifecycle:
+/// `activation_candidate`/`activation_backlog` behave as `draft_ready` and
+/// `approval_pending` as `queued`.
+fn lead_status_transition_allowed(from: &str, to: &str) -> bool {
+    let from = match from {
+        "activation_candidate" | "activation_backlog" => "draft_ready",
+        "approval_pending" => "queued",
+        other => other,
+    };
+    matches!(
+        (from, to),
+        ("draft_ready", "queued" | "archived")
+            | ("queued", "draft_ready" | "contacted" | "archived")
+            | ("contacted", "replied" | "bounced" | "archived")
+            | ("replied" | "bounced", "archived")
+    )
+}
+
+/// One recorded lead status change from `lead_status_history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadStatusChange {
+    pub id: String,
+    pub lead_id: String,
+    pub from_status: String,
+    pub to_status: String,
+    pub note: Option<String>,
+    pub changed_at: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -305,6 +1074,56 @@ pub struct SalesDelivery {
     pub status: String,
     pub error: Option<String>,
     pub sent_at: String,
+    pub attachment_count: u32,
+}
+
+/// A lead with everything queued and sent for it, for the lead detail view.
+#[derive(Debug, Clone, Serialize)]
+pub struct SalesLeadDetail {
+    pub lead: SalesLead,
+    pub approvals: Vec<SalesApproval>,
+    pub deliveries: Vec<SalesDelivery>,
+}
+
+/// One entry of the `suppressions` table.
+#[derive(Debug, Clone, Serialize)]
+pub struct SalesSuppression {
+    pub id: String,
+    pub contact_method_value: String,
+    pub reason: String,
+    pub source_outcome_id: Option<String>,
+    pub suppressed_at: String,
+    pub permanent: bool,
+}
+
+/// One page of [`SalesEngine::rescore_leads`].
+#[derive(Debug, Clone, Serialize)]
+pub struct SalesLeadRescore {
+    pub scanned: usize,
+    pub changed: usize,
+    /// Lead id to pass as `after` for the next page; `None` on the last page.
+    pub next_after: Option<String>,
+}
+
+/// Outcome of [`SalesEngine::import_leads`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SalesLeadImport {
+    pub inserted: usize,
+    pub updated: usize,
+    /// Rows matching an existing contact, or an `external_id` whose lead has
+    /// left the draft stage; nothing was changed.
+    pub duplicates: usize,
+    /// Rows rejected by validation, as `leads[i]: reason`.
+    pub errors: Vec<String>,
+}
+
+/// Outcome of [`SalesEngine::block_lead`].
+#[derive(Debug, Clone, Serialize)]
+pub struct SalesLeadBlock {
+    pub suppression: SalesSuppression,
+    pub approvals_rejected: usize,
+    /// `None` when the lead was already archived.
+    pub status_change: Option<LeadStatusChange>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -323,6 +1142,37 @@ struct DomainCandidate {
     source_links: Vec<String>,
     #[serde(default)]
     phone: Option<String>,
+    /// Page each search snippet in `evidence` was taken from, keyed by the
+    /// snippet text.
+    #[serde(default)]
+    evidence_urls: HashMap<String, String>,
+}
+
+impl DomainCandidate {
+    /// Where `snippet` came from: its recorded search result, or the only
+    /// source link when the candidate has a single source (directories).
+    fn evidence_url(&self, snippet: &str) -> Option<&str> {
+        let single_source = match self.source_links.as_slice() {
+            [only] => Some(only.as_str()),
+            _ => None,
+        };
+        self.evidence_urls
+            .get(snippet)
+            .map(String::as_str)
+            .or(single_source)
+    }
+
+    /// Record where each of `other`'s snippets came from before its
+    /// evidence is merged in; existing entries win.
+    fn merge_evidence_urls(&mut self, other: &DomainCandidate) {
+        for snippet in &other.evidence {
+            if let Some(url) = other.evidence_url(snippet) {
+                self.evidence_urls
+                    .entry(snippet.clone())
+                    .or_insert_with(|| url.to_string());
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -614,6 +1464,9 @@ struct MailboxConfig {
     /// Env var name holding SMTP password
     #[serde(default)]
     smtp_pass_env: String,
+    /// SMTP TLS mode (falls back to global EmailConfig if unset)
+    #[serde(default)]
+    tls_mode: Option<SmtpTlsMode>,
     /// Per-mailbox daily send cap
     #[serde(default = "default_mailbox_daily_cap")]
     daily_cap: u32,
@@ -628,6 +1481,507 @@ struct MailboxConfig {
     counter_date: String,
 }
 
+/// Reject From display names that could inject extra headers. Quoting and
+/// encoding of ordinary names is left to lettre.
+fn validate_sender_display_name(name: &str) -> Result<(), String> {
+    if name.chars().any(char::is_control) {
+        return Err(
+            "Sender display name must not contain line breaks or control characters".to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// Headers lettre or the sales engine already set; custom headers may not
+/// override them.
+const RESERVED_EMAIL_HEADERS: &[&str] = &[
+    "from",
+    "to",
+    "cc",
+    "bcc",
+    "sender",
+    "reply-to",
+    "subject",
+    "date",
+    "message-id",
+    "mime-version",
+    "content-type",
+    "content-transfer-encoding",
+    "return-path",
+    "list-unsubscribe",
+    "list-unsubscribe-post",
+];
+
+/// Validate configured custom headers, rejecting names that are not RFC 5322
+/// field names or override standard headers, and values that could inject
+/// additional header lines.
+fn sanitize_custom_email_headers(
+    headers: &BTreeMap<String, String>,
+) -> Result<Vec<(String, String)>, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let name = name.trim();
+            if name.is_empty() || !name.bytes().all(|b| (33..=126).contains(&b) && b != b':') {
+                return Err(format!("Invalid custom email header name '{name}'"));
+            }
+            if RESERVED_EMAIL_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                return Err(format!(
+                    "Custom email header '{name}' would override a standard header"
+                ));
+            }
+            let value = value.trim();
+            if value.len() > 900 || !value.bytes().all(|b| b == b'\t' || (32..=126).contains(&b)) {
+                return Err(format!(
+                    "Custom email header '{name}' must be a single line of printable ASCII"
+                ));
+            }
+            Ok((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Prepend validated custom header lines to a formatted RFC 5322 message.
+fn with_custom_email_headers(formatted: Vec<u8>, headers: &[(String, String)]) -> Vec<u8> {
+    if headers.is_empty() {
+        return formatted;
+    }
+    let mut out = Vec::with_capacity(formatted.len() + headers.len() * 64);
+    for (name, value) in headers {
+        out.extend_from_slice(format!("{name}: {value}\r\n").as_bytes());
+    }
+    out.extend(formatted);
+    out
+}
+
+/// Delay before the first SMTP retry; doubles for each further attempt.
+const SMTP_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// One outbound email as handed to [`SalesEngine::send_email`].
+#[derive(Clone, Copy)]
+struct OutboundEmail<'a> {
+    to: &'a str,
+    subject: &'a str,
+    body: &'a str,
+    html_body: Option<&'a str>,
+    attachments: &'a [SalesEmailAttachment],
+    /// Stable per-message key (the approval id). It becomes the Message-ID,
+    /// so every retry of the same message carries the same id and receiving
+    /// servers can drop duplicates.
+    dedupe_key: Option<&'a str>,
+}
+
+/// `Message-ID` for an outbound email keyed by `dedupe_key`.
+fn outbound_message_id(dedupe_key: &str, sender_domain: &str) -> String {
+    let key = dedupe_key
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'))
+        .collect::<String>();
+    format!("<pulsivo-{key}@{sender_domain}>")
+}
+
+/// Whether an SMTP failure may succeed on retry: 4xx replies, timeouts and
+/// connection errors. Permanent 5xx replies, TLS and client errors are final.
+fn smtp_error_is_retryable(error: &lettre::transport::smtp::Error) -> bool {
+    !(error.is_permanent() || error.is_client() || error.is_tls() || error.is_response())
+}
+
+/// Backoff before retry number `attempt` (1-based).
+fn smtp_retry_delay(attempt: u32) -> Duration {
+    SMTP_RETRY_BASE_DELAY * 2u32.saturating_pow(attempt.saturating_sub(1).min(6))
+}
+
+/// File attached to an outbound email, carried in the approval payload's
+/// `attachments` array. Exactly one of `path` (read at send time, relative to
+/// [`SALES_ATTACHMENTS_DIR`]) or `content_base64` supplies the bytes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SalesEmailAttachment {
+    filename: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_base64: Option<String>,
+}
+
+/// Parse and normalize `payload.attachments`. A missing or null field means no
+/// attachments; anything else that is malformed is an error.
+fn email_attachments_from_payload(
+    payload: &serde_json::Value,
+) -> Result<Vec<SalesEmailAttachment>, String> {
+    let raw = match payload.get("attachments") {
+        None | Some(serde_json::Value::Null) => return Ok(Vec::new()),
+        Some(raw) => raw.clone(),
+    };
+    let attachments = serde_json::from_value::<Vec<SalesEmailAttachment>>(raw)
+        .map_err(|e| format!("Invalid payload.attachments: {e}"))?;
+    attachments
+        .into_iter()
+        .map(|attachment| {
+            let path = attachment
+                .path
+                .map(|path| path.trim().to_string())
+                .filter(|path| !path.is_empty());
+            let content_base64 = attachment
+                .content_base64
+                .map(|content| content.trim().to_string())
+                .filter(|content| !content.is_empty());
+            if path.is_some() == content_base64.is_some() {
+                return Err(
+                    "Each attachment needs exactly one of path or content_base64".to_string(),
+                );
+            }
+            let filename = match attachment.filename.trim() {
+                "" => path
+                    .as_deref()
+                    .and_then(|path| FsPath::new(path).file_name())
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                name => name.to_string(),
+            };
+            if filename.is_empty()
+                || filename.contains(['/', '\\'])
+                || filename.chars().any(char::is_control)
+            {
+                return Err(format!("Invalid attachment filename '{filename}'"));
+            }
+            let content_type = attachment
+                .content_type
+                .map(|content_type| content_type.trim().to_string())
+                .filter(|content_type| !content_type.is_empty());
+            if let Some(content_type) = &content_type {
+                ContentType::parse(content_type).map_err(|_| {
+                    format!("Invalid content type '{content_type}' for attachment '{filename}'")
+                })?;
+            }
+            Ok(SalesEmailAttachment {
+                filename,
+                content_type,
+                path,
+                content_base64,
+            })
+        })
+        .collect()
+}
+
+/// Content type for an attachment without an explicit one, from its extension.
+fn attachment_content_type(filename: &str) -> &'static str {
+    let extension = FsPath::new(filename)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "txt" => "text/plain",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "zip" => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Read an attachment `path` from `attachments_dir`. The resolved file must be
+/// a regular file inside that directory (symlinks included) and no larger than
+/// `max_bytes`, which is checked before anything is read.
+fn read_attachment_file(
+    attachments_dir: &FsPath,
+    path: &str,
+    max_bytes: u64,
+) -> Result<Vec<u8>, String> {
+    let root = attachments_dir.canonicalize().map_err(|e| {
+        format!(
+            "Attachment '{path}' is unavailable: {} cannot be opened: {e}",
+            attachments_dir.display()
+        )
+    })?;
+    let resolved = root
+        .join(path)
+        .canonicalize()
+        .map_err(|e| format!("Failed to read attachment '{path}': {e}"))?;
+    if !resolved.starts_with(&root) {
+        return Err(format!(
+            "Attachment '{path}' is outside {}",
+            attachments_dir.display()
+        ));
+    }
+    let metadata = std::fs::metadata(&resolved)
+        .map_err(|e| format!("Failed to read attachment '{path}': {e}"))?;
+    if !metadata.is_file() {
+        return Err(format!("Attachment '{path}' is not a regular file"));
+    }
+    if metadata.len() > max_bytes {
+        return Err(format!(
+            "Email attachments exceed the {max_bytes} byte limit"
+        ));
+    }
+    std::fs::read(&resolved).map_err(|e| format!("Failed to read attachment '{path}': {e}"))
+}
+
+/// Load attachment bytes and build the MIME parts, rejecting the set when the
+/// decoded total exceeds `max_total_bytes`. `path` attachments are read from
+/// `attachments_dir` only.
+fn build_email_attachment_parts(
+    attachments: &[SalesEmailAttachment],
+    attachments_dir: &FsPath,
+    max_total_bytes: u64,
+) -> Result<Vec<SinglePart>, String> {
+    let mut total_bytes = 0u64;
+    let mut parts = Vec::with_capacity(attachments.len());
+    for attachment in attachments {
+        let remaining = max_total_bytes.saturating_sub(total_bytes);
+        let bytes = match (&attachment.path, &attachment.content_base64) {
+            (Some(path), _) => read_attachment_file(attachments_dir, path, remaining)?,
+            (None, Some(content)) => STANDARD.decode(content).map_err(|e| {
+                format!(
+                    "Attachment '{}' is not valid base64: {e}",
+                    attachment.filename
+                )
+            })?,
+            (None, None) => {
+                return Err(format!(
+                    "Attachment '{}' has no content",
+                    attachment.filename
+                ))
+            }
+        };
+        total_bytes = total_bytes.saturating_add(bytes.len() as u64);
+        if total_bytes > max_total_bytes {
+            return Err(format!(
+                "Email attachments exceed the {max_total_bytes} byte limit"
+            ));
+        }
+        let content_type = attachment
+            .content_type
+            .as_deref()
+            .unwrap_or_else(|| attachment_content_type(&attachment.filename));
+        let content_type = ContentType::parse(content_type)
+            .map_err(|e| format!("Invalid attachment content type '{content_type}': {e}"))?;
+        parts.push(Attachment::new(attachment.filename.clone()).body(bytes, content_type));
+    }
+    Ok(parts)
+}
+
+/// Tags [`sanitize_email_html`] keeps; other tags are dropped but their text
+/// content stays.
+const EMAIL_HTML_ALLOWED_TAGS: &[&str] = &[
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "div",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "hr",
+    "i",
+    "li",
+    "ol",
+    "p",
+    "span",
+    "strong",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// Elements dropped together with their content.
+const EMAIL_HTML_DROPPED_ELEMENTS: &[&str] = &[
+    "head", "iframe", "noscript", "object", "script", "style", "svg", "title",
+];
+
+/// Placeholder in `email_html_template` replaced by the rendered body.
+const EMAIL_HTML_BODY_PLACEHOLDER: &str = "{{body}}";
+
+/// Reduce operator- or LLM-supplied HTML to a small formatting subset: only
+/// allowlisted tags survive, every attribute except an `http(s)`/`mailto`
+/// `href` on links is removed, and scripts, styles and comments are dropped.
+fn sanitize_email_html(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    let mut skip_until: Option<String> = None;
+    while let Some(start) = rest.find('<') {
+        if skip_until.is_none() {
+            out.push_str(&rest[..start]);
+        }
+        let after = &rest[start + 1..];
+        if let Some(comment) = after.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = after.find('>') else {
+            if skip_until.is_none() {
+                out.push_str(&xml_escape(&rest[start..]));
+            }
+            rest = "";
+            break;
+        };
+        let tag = &after[..end];
+        rest = &after[end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if let Some(skipped) = &skip_until {
+            if closing && name == *skipped {
+                skip_until = None;
+            }
+            continue;
+        }
+        if name.is_empty() {
+            if !tag.starts_with(['!', '?']) {
+                out.push_str(&xml_escape(&format!("<{tag}>")));
+            }
+            continue;
+        }
+        if EMAIL_HTML_DROPPED_ELEMENTS.contains(&name.as_str()) {
+            if !closing && !tag.ends_with('/') {
+                skip_until = Some(name);
+            }
+            continue;
+        }
+        if !EMAIL_HTML_ALLOWED_TAGS.contains(&name.as_str()) {
+            continue;
+        }
+        match (closing, name.as_str()) {
+            (true, "br" | "hr") => {}
+            (true, _) => out.push_str(&format!("</{name}>")),
+            (false, "a") => {
+                match html_attribute(tag, "href").filter(|href| is_safe_email_href(href)) {
+                    Some(href) => out.push_str(&format!("<a href=\"{}\">", xml_escape(&href))),
+                    None => out.push_str("<a>"),
+                }
+            }
+            (false, _) => out.push_str(&format!("<{name}>")),
+        }
+    }
+    if skip_until.is_none() {
+        out.push_str(rest);
+    }
+    out
+}
+
+/// Value of attribute `name` in the raw text of an opening tag.
+fn html_attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(pos) = lower[from..].find(name) {
+        let at = from + pos;
+        from = at + name.len();
+        if !lower[..at].ends_with(|c: char| c.is_ascii_whitespace()) {
+            continue;
+        }
+        let Some(value) = tag[from..].trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value
+                .split(|c: char| c.is_ascii_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default(),
+        };
+        return Some(value.trim().to_string());
+    }
+    None
+}
+
+fn is_safe_email_href(href: &str) -> bool {
+    let href = href.to_ascii_lowercase();
+    href.starts_with("https://") || href.starts_with("http://") || href.starts_with("mailto:")
+}
+
+/// Escape a plaintext body into HTML paragraphs, keeping single line breaks.
+fn plain_text_to_html(text: &str) -> String {
+    text.replace("\r\n", "\n")
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| {
+            let lines = paragraph.lines().map(xml_escape).collect::<Vec<_>>();
+            format!("<p>{}</p>", lines.join("<br>"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// HTML part for `body` under the profile's `email_html_template`, or `None`
+/// when no template is configured.
+fn render_email_html(template: &str, body: &str) -> Option<String> {
+    if template.trim().is_empty() {
+        return None;
+    }
+    let html = template.replace(EMAIL_HTML_BODY_PLACEHOLDER, &plain_text_to_html(body));
+    Some(sanitize_email_html(&html))
+}
+
+/// `List-Unsubscribe` value: the optional mailto entry followed by the
+/// one-click suppression URL.
+fn list_unsubscribe_value(unsubscribe_url: &str, mailto: Option<&Address>) -> String {
+    match mailto {
+        Some(address) => format!("<mailto:{address}?subject=unsubscribe>, <{unsubscribe_url}>"),
+        None => format!("<{unsubscribe_url}>"),
+    }
+}
+
+/// Sandbox settings when outbound sandbox mode is on, from
+/// `[channels.sandbox]` or the `PULSIVO_SALESMAN_SALES_SANDBOX` env override.
+fn outbound_sandbox(
+    kernel: &pulsivo_salesman_kernel::PulsivoSalesmanKernel,
+) -> Option<OutboundSandboxConfig> {
+    let mut sandbox = kernel.config_snapshot().channels.sandbox.clone();
+    if let Ok(flag) = std::env::var("PULSIVO_SALESMAN_SALES_SANDBOX") {
+        sandbox.enabled = matches!(
+            flag.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        );
+    }
+    if let Ok(redirect) = std::env::var("PULSIVO_SALESMAN_SALES_SANDBOX_REDIRECT") {
+        sandbox.redirect_to = redirect;
+    }
+    sandbox.redirect_to = sandbox.redirect_to.trim().to_string();
+    sandbox.enabled.then_some(sandbox)
+}
+
+/// Build an authenticated SMTP transport secured according to `tls_mode`.
+pub(crate) fn build_smtp_transport(
+    host: &str,
+    port: u16,
+    tls_mode: SmtpTlsMode,
+    credentials: Credentials,
+) -> Result<AsyncSmtpTransport<Tokio1Executor>, String> {
+    tls_mode.validate_port(port)?;
+    let builder = match tls_mode {
+        SmtpTlsMode::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+            .map_err(|e| format!("Failed to initialize SMTP relay '{host}': {e}"))?,
+        SmtpTlsMode::ImplicitTls => AsyncSmtpTransport::<Tokio1Executor>::relay(host)
+            .map_err(|e| format!("Failed to initialize SMTP relay '{host}': {e}"))?,
+        SmtpTlsMode::Plaintext => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+    };
+    Ok(builder.port(port).credentials(credentials).build())
+}
+
 fn default_mailbox_daily_cap() -> u32 {
     20
 }
@@ -861,6 +2215,14 @@ pub struct SalesLeadQuery {
     pub segment: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SalesLeadSearchQuery {
+    #[serde(default)]
+    pub q: String,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SalesApprovalQuery {
     #[serde(default)]
@@ -869,16 +2231,87 @@ pub struct SalesApprovalQuery {
     pub limit: Option<usize>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesDeliveryFeedQuery {
+    #[serde(default)]
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub campaign_id: Option<String>,
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct SalesSegmentQuery {
     #[serde(default)]
     pub segment: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesApproveQuery {
+    /// Send now even outside the profile's business-hours window.
+    #[serde(default)]
+    pub override_business_hours: bool,
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct SalesApprovalBulkApproveRequest {
     #[serde(default)]
     pub ids: Vec<String>,
+    /// Send now even outside the profile's business-hours window.
+    #[serde(default)]
+    pub override_business_hours: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesLeadRescoreRequest {
+    /// Only rescore leads in this status; all leads when omitted.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Resume after this lead id (the previous page's `next_after`).
+    #[serde(default)]
+    pub after: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// One CRM lead for `POST /api/sales/leads/import`. Re-importing the same
+/// `external_id` refreshes the draft instead of adding a second lead.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SalesLeadImportItem {
+    pub external_id: String,
+    pub company: String,
+    pub company_domain: String,
+    pub contact_name: String,
+    pub contact_title: String,
+    /// Defaults to `https://{company_domain}`.
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub linkedin_url: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub reasons: Vec<String>,
+    #[serde(default)]
+    pub email_subject: String,
+    #[serde(default)]
+    pub email_body: String,
+    #[serde(default)]
+    pub linkedin_message: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesLeadImportRequest {
+    #[serde(default)]
+    pub leads: Vec<SalesLeadImportItem>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesApprovalExpireRequest {
+    /// Expire pending approvals created more than this many days ago.
+    #[serde(default)]
+    pub older_than_days: u32,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -889,6 +2322,39 @@ pub struct SalesPolicyProposalQuery {
     pub limit: Option<usize>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesLeadStatusRequest {
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesLeadBlockRequest {
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// Operator corrections to a lead; omitted fields are left unchanged.
+#[derive(Debug, Default, Deserialize)]
+pub struct SalesLeadEditRequest {
+    #[serde(default)]
+    pub contact_name: Option<String>,
+    #[serde(default)]
+    pub contact_title: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub linkedin_url: Option<String>,
+    #[serde(default)]
+    pub email_subject: Option<String>,
+    #[serde(default)]
+    pub email_body: Option<String>,
+    #[serde(default)]
+    pub linkedin_message: Option<String>,
+}
+
 #[derive(Debug, Default, Deserialize)]
 pub struct SalesApprovalEditRequest {
     #[serde(default)]
@@ -556,6 +556,12 @@ fn truncate_text_for_reason(text: &str, max_len: usize) -> String {
     truncate_cleaned_text(text, max_len)
 }
 
+/// Fit a LinkedIn message into a connection-request note, leaving room for
+/// the ellipsis `truncate_cleaned_text` appends.
+fn linkedin_connect_note(message: &str) -> String {
+    truncate_cleaned_text(message, LINKEDIN_CONNECT_NOTE_MAX_CHARS - 3)
+}
+
 fn domain_to_company(domain: &str) -> String {
     let left = domain.split('.').next().unwrap_or(domain);
     left.replace('-', " ")
@@ -571,18 +577,45 @@ fn domain_to_company(domain: &str) -> String {
         .join(" ")
 }
 
-fn outreach_recipient_name(contact_name: Option<&str>, company: &str, target_geo: &str) -> String {
+/// Keep at most `cap` candidates per company name (see `domain_to_company`),
+/// preferring the highest scores. Survivors keep their order; returns how
+/// many candidates were dropped.
+fn cap_candidates_per_company(candidates: &mut Vec<DomainCandidate>, cap: usize) -> usize {
+    let mut ranked = (0..candidates.len()).collect::<Vec<_>>();
+    ranked.sort_by(|&a, &b| {
+        candidates[b]
+            .score
+            .cmp(&candidates[a].score)
+            .then_with(|| a.cmp(&b))
+    });
+    let mut per_company = HashMap::<String, usize>::new();
+    let mut keep = vec![false; candidates.len()];
+    for idx in ranked {
+        let company = domain_to_company(&candidates[idx].domain).to_lowercase();
+        if company.is_empty() {
+            keep[idx] = true;
+            continue;
+        }
+        let kept = per_company.entry(company).or_default();
+        if *kept < cap {
+            *kept += 1;
+            keep[idx] = true;
+        }
+    }
+    let before = candidates.len();
+    let mut keep = keep.into_iter();
+    candidates.retain(|_| keep.next().unwrap_or(false));
+    before - candidates.len()
+}
+
+fn outreach_recipient_name(contact_name: Option<&str>, company: &str, locale: &str) -> String {
     if let Some(first_name) = contact_name
         .and_then(normalize_person_name)
         .and_then(|value| value.split_whitespace().next().map(|part| part.to_string()))
     {
         return first_name;
     }
-    if geo_is_turkey(target_geo) {
-        format!("{} ekibi", company)
-    } else {
-        format!("{} team", company)
-    }
+    sales_message(locale, "outreach.recipient_team", &[("company", company)])
 }
 
 fn build_sales_lead_reasons(
@@ -590,14 +623,17 @@ fn build_sales_lead_reasons(
     company: &str,
     matched: &str,
     evidence: &str,
+    evidence_url: Option<&str>,
     contact_title: Option<&str>,
 ) -> Vec<String> {
+    let limits = SalesReasonLimits::from_profile(profile);
+    let evidence = truncate_text_for_reason(evidence, limits.evidence_chars);
     let mut reasons = vec![
         format!("ICP fit: {}", truncate_text_for_reason(matched, 140)),
-        format!(
-            "Public evidence: {}",
-            truncate_text_for_reason(evidence, 220)
-        ),
+        match evidence_url.map(str::trim).filter(|url| !url.is_empty()) {
+            Some(url) => format!("Public evidence: {evidence} (source: {url})"),
+            None => format!("Public evidence: {evidence}"),
+        },
     ];
     if let Some(title) = contact_title
         .map(str::trim)
@@ -611,15 +647,17 @@ fn build_sales_lead_reasons(
         company,
         truncate_text_for_reason(&profile.product_description, 140)
     ));
-    dedupe_strings(reasons)
+    let mut reasons = dedupe_strings(reasons);
+    reasons.truncate(limits.max_reasons);
+    reasons
 }
 
 fn build_sales_email_subject(profile: &SalesProfile, company: &str) -> String {
-    if geo_is_turkey(&profile.target_geo) {
-        format!("{company} icin saha operasyon koordinasyonu")
-    } else {
-        format!("{company}: field ops coordination")
-    }
+    sales_message(
+        &sales_locale(profile),
+        "outreach.email_subject",
+        &[("company", company)],
+    )
 }
 
 fn build_sales_email_body(
@@ -629,37 +667,56 @@ fn build_sales_email_body(
     matched: &str,
     evidence: &str,
 ) -> String {
-    let recipient = outreach_recipient_name(contact_name, company, &profile.target_geo);
+    let locale = sales_locale(profile);
+    let recipient = outreach_recipient_name(contact_name, company, &locale);
     let evidence_short = truncate_text_for_reason(evidence, 160);
     let matched_short = truncate_text_for_reason(matched, 90);
     let value_short = truncate_text_for_reason(&profile.product_description, 150);
 
-    if geo_is_turkey(&profile.target_geo) {
-        format!(
-            "Merhaba {},\n\n{} ile ilgili su sinyali gordum: {}.\n\n{} tarafinda {} operasyonlarinda is atama, takip ve gecikme yonetimi kolayca daginik hale gelebiliyor. {} burada su ise yarayabilir: {}.\n\nUygunsa {} icin 3 maddelik kisa bir operasyon akisi onerisi paylasabilirim.\n\nSelamlar,\n{}",
-            recipient,
-            company,
-            evidence_short,
-            company,
-            matched_short,
-            profile.product_name,
-            value_short,
-            company,
-            profile.sender_name
-        )
-    } else {
-        format!(
-            "Hi {},\n\nI came across {} through this public signal: {}.\n\nFor teams running {}, the friction is usually around task ownership, follow-up, and delay recovery across email, phone, and chat. {} could help here: {}.\n\nIf useful, I can send a short 3-point workflow teardown for {}.\n\nBest,\n{}",
-            recipient,
-            company,
-            evidence_short,
-            matched_short,
-            profile.product_name,
-            value_short,
-            company,
-            profile.sender_name
-        )
-    }
+    sales_message(
+        &locale,
+        "outreach.email_body",
+        &[
+            ("recipient", &recipient),
+            ("company", company),
+            ("evidence", &evidence_short),
+            ("matched", &matched_short),
+            ("product", &profile.product_name),
+            ("value", &value_short),
+            ("sender", &profile.sender_name),
+        ],
+    )
+}
+
+/// Append a localized line citing where the evidence in `body` came from.
+fn cite_evidence_source(profile: &SalesProfile, body: &str, url: &str) -> String {
+    let citation = sales_message(
+        &sales_locale(profile),
+        "outreach.evidence_source",
+        &[("url", url)],
+    );
+    format!("{}\n\n{citation}", body.trim_end())
+}
+
+const TEST_SEND_SUBJECT_PREFIX: &str = "[TEST]";
+
+/// Build the subject/body for an operator test send. Uses the stored lead copy
+/// when available, otherwise renders a synthetic sample through the same
+/// templates (including UTF-8 truncation) that real leads go through.
+fn build_test_send_email(profile: &SalesProfile, lead: Option<&SalesLead>) -> (String, String) {
+    let (subject, body) = match lead {
+        Some(lead) => (lead.email_subject.clone(), lead.email_body.clone()),
+        None => {
+            let company = "Örnek Yapı A.Ş.";
+            let matched = "field service dispatch, saha operasyonu, bakım ekipleri";
+            let evidence = "Şantiye ve saha ekiplerinde günlük iş emri takibi, gecikme yönetimi ve çok kanallı koordinasyon için yeni operasyon müdürü ilanı yayınlandı; ekip 40+ teknisyene büyüyor.";
+            (
+                build_sales_email_subject(profile, company),
+                build_sales_email_body(profile, company, Some("Ayşe Yılmaz"), matched, evidence),
+            )
+        }
+    };
+    (format!("{TEST_SEND_SUBJECT_PREFIX} {subject}"), body)
 }
 
 fn build_sales_linkedin_message(
@@ -668,25 +725,22 @@ fn build_sales_linkedin_message(
     contact_name: Option<&str>,
     evidence: &str,
 ) -> String {
-    let recipient = outreach_recipient_name(contact_name, company, &profile.target_geo);
+    let locale = sales_locale(profile);
+    let recipient = outreach_recipient_name(contact_name, company, &locale);
     let evidence_short = truncate_text_for_reason(evidence, 110);
-    if geo_is_turkey(&profile.target_geo) {
-        truncate_cleaned_text(
-            &format!(
-                "Merhaba {}, {} ile ilgili su sinyali gordum: {}. {} saha ekiplerinde takip ve koordinasyonu toparlamaya yardimci oluyor. Uygunsa kisa bir akis onerisi paylasabilirim.",
-                recipient, company, evidence_short, profile.product_name
-            ),
-            300,
-        )
-    } else {
-        truncate_cleaned_text(
-            &format!(
-                "Hi {}, noticed {} through this signal: {}. {} helps field teams tighten follow-up and coordination. Happy to share a short workflow teardown if relevant.",
-                recipient, company, evidence_short, profile.product_name
-            ),
-            300,
-        )
-    }
+    truncate_cleaned_text(
+        &sales_message(
+            &locale,
+            "outreach.linkedin",
+            &[
+                ("recipient", &recipient),
+                ("company", company),
+                ("evidence", &evidence_short),
+                ("product", &profile.product_name),
+            ],
+        ),
+        300,
+    )
 }
 
 /// Stage 1: Determine message strategy from thesis + persona context.
@@ -707,22 +761,14 @@ fn generate_message_strategy(
     evidence: &str,
     matched: &str,
 ) -> MessageStrategy {
-    let language = if geo_is_turkey(&profile.target_geo) {
-        "tr"
-    } else {
-        "en"
-    };
-    let cta = if language == "tr" {
-        "Uygunsa size 2 sayfalik kisa bir operasyon analizi paylasabilirim."
-    } else {
-        "Happy to share a brief 2-page operational analysis if helpful."
-    };
+    let language = sales_locale(profile);
+    let cta = sales_message(&language, "outreach.cta", &[]);
     MessageStrategy {
         pain_angle: matched.to_string(),
         trigger_evidence: evidence.to_string(),
-        cta_type: cta.to_string(),
+        cta_type: cta,
         tone: "professional_warm".to_string(),
-        language: language.to_string(),
+        language,
     }
 }
 
@@ -768,9 +814,178 @@ fn generate_message_copy(
     })
 }
 
+/// Email and LinkedIn copy for one lead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SalesOutreachCopy {
+    pub email_subject: String,
+    pub email_body: String,
+    pub linkedin_message: String,
+}
+
+/// Render a lead's outreach copy the way `run_generation` does: the
+/// evidence-bound strategy first, direct templates if it refuses, then the
+/// optional source citation.
+fn render_outreach_copy(
+    profile: &SalesProfile,
+    company: &str,
+    contact_name: Option<&str>,
+    matched: &str,
+    evidence: &str,
+    evidence_url: Option<&str>,
+) -> SalesOutreachCopy {
+    let strategy = generate_message_strategy(profile, company, contact_name, evidence, matched);
+    let (email_subject, mut email_body, linkedin_message) =
+        match generate_message_copy(&strategy, profile, company, contact_name) {
+            Ok(copy) => (copy.subject, copy.body, copy.linkedin_copy),
+            Err(_) => (
+                build_sales_email_subject(profile, company),
+                build_sales_email_body(profile, company, contact_name, matched, evidence),
+                build_sales_linkedin_message(profile, company, contact_name, evidence),
+            ),
+        };
+    if profile.cite_evidence_source {
+        if let Some(url) = evidence_url {
+            email_body = cite_evidence_source(profile, &email_body, url);
+        }
+    }
+    SalesOutreachCopy {
+        email_subject,
+        email_body,
+        linkedin_message,
+    }
+}
+
+/// Copy and lead reasons for a hypothetical company and contact.
+#[derive(Debug, Clone, Serialize)]
+pub struct SalesCopyPreview {
+    pub company: String,
+    pub domain: String,
+    pub reasons: Vec<String>,
+    #[serde(flatten)]
+    pub copy: SalesOutreachCopy,
+}
+
+/// Render outreach copy without discovery or persistence. Missing evidence
+/// falls back to the same generic line a run uses for search-only matches.
+fn preview_outreach_copy(
+    profile: &SalesProfile,
+    request: &SalesCopyPreviewRequest,
+) -> Result<SalesCopyPreview, String> {
+    let domain = Some(request.domain.trim())
+        .filter(|raw| !raw.is_empty())
+        .map(normalize_domain)
+        .unwrap_or_default();
+    let company = match request.company.trim() {
+        "" if domain.is_empty() => return Err("Provide a company or domain".to_string()),
+        "" => domain_to_company(&domain),
+        company => company.to_string(),
+    };
+    let trimmed = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let contact_name = trimmed(&request.contact_name);
+    let contact_title = trimmed(&request.contact_title);
+    let evidence_url = trimmed(&request.evidence_url);
+    let evidence = trimmed(&request.evidence).unwrap_or_else(|| {
+        format!(
+            "{} appears in search results for {}",
+            company, profile.target_industry
+        )
+    });
+    let matched = profile.target_industry.clone();
+
+    let reasons = build_sales_lead_reasons(
+        profile,
+        &company,
+        &matched,
+        &evidence,
+        evidence_url.as_deref(),
+        contact_title.as_deref(),
+    );
+    let copy = render_outreach_copy(
+        profile,
+        &company,
+        contact_name.as_deref(),
+        &matched,
+        &evidence,
+        evidence_url.as_deref(),
+    );
+    Ok(SalesCopyPreview {
+        company,
+        domain,
+        reasons,
+        copy,
+    })
+}
+
+const CONTACT_NAME_PATTERN: &str = r"[A-ZÀ-ÖØ-ÞİĞŞÇÜÖÂÊÎÔÛ][A-Za-zÀ-ÖØ-öø-ÿİıĞğŞşÇçÜüÖöÂâÊêÎîÔôÛû\.'\-]*(?:\s+[A-ZÀ-ÖØ-ÞİĞŞÇÜÖÂÊÎÔÛ][A-Za-zÀ-ÖØ-öø-ÿİıĞğŞşÇçÜüÖöÂâÊêÎîÔôÛû\.'\-]*){1,4}";
+
+/// Build a case-insensitive alternation from planner/profile contact titles,
+/// longest first so "Saha Operasyon Müdürü" wins over "Operasyon Müdürü".
+fn contact_title_alternation(contact_titles: &[String]) -> Option<String> {
+    let mut seen = HashSet::new();
+    let mut titles = contact_titles
+        .iter()
+        .map(|title| title.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|title| title.chars().count() >= 2 && seen.insert(title.to_lowercase()))
+        .collect::<Vec<_>>();
+    if titles.is_empty() {
+        return None;
+    }
+    titles.sort_by_key(|title| std::cmp::Reverse(title.chars().count()));
+    Some(
+        titles
+            .iter()
+            .map(|title| regex_lite::escape(title).replace(' ', r"\s+"))
+            .collect::<Vec<_>>()
+            .join("|"),
+    )
+}
+
+/// Match `contact_titles` in raw (non-canonicalized) search text so localized
+/// titles are found before canonicalization rewrites them.
+fn extract_contact_with_dynamic_titles(
+    text: &str,
+    title_policy: &str,
+    contact_titles: &[String],
+) -> Option<(Option<String>, Option<String>)> {
+    let titles = contact_title_alternation(contact_titles)?;
+    let name_then_title =
+        format!(r"(?m)\b({CONTACT_NAME_PATTERN})\b\s*[-|,–]\s*(?i:({titles}))(?:\W|$)");
+    let title_then_name =
+        format!(r"(?m)(?:^|\W)(?i:({titles}))\s*[:\-–]?\s*({CONTACT_NAME_PATTERN})\b");
+    for (pattern, name_group, title_group) in [(name_then_title, 1, 2), (title_then_name, 2, 1)] {
+        let Ok(re) = regex_lite::Regex::new(&pattern) else {
+            continue;
+        };
+        for cap in re.captures_iter(text) {
+            let name = cap
+                .get(name_group)
+                .and_then(|m| normalize_person_name(m.as_str().trim()));
+            let title = cap
+                .get(title_group)
+                .map(|m| normalize_contact_title(m.as_str()));
+            if name.is_some()
+                && title
+                    .as_deref()
+                    .map(|t| title_allowed_for_policy(title_policy, t))
+                    .unwrap_or(false)
+            {
+                return Some((name, title));
+            }
+        }
+    }
+    None
+}
+
 fn extract_contact_from_search(
     search_output: &str,
     title_policy: &str,
+    contact_titles: &[String],
 ) -> (Option<String>, Option<String>, Option<String>) {
     let filtered_output = search_output
         .lines()
@@ -792,6 +1007,12 @@ fn extract_contact_from_search(
         .clone()
         .or_else(|| company_linkedin_url.clone());
 
+    if let Some((name, title)) =
+        extract_contact_with_dynamic_titles(&filtered_output, title_policy, contact_titles)
+    {
+        return (name, title, linkedin_url);
+    }
+
     let ranked_re = regex_lite::Regex::new(
         r"(?im)^\s*\d+\.\s*([A-ZÀ-ÖØ-ÞİĞŞÇÜÖÂÊÎÔÛ][A-Za-zÀ-ÖØ-öø-ÿİıĞğŞşÇçÜüÖöÂâÊêÎîÔôÛû\.'\-]*(?:\s+[A-ZÀ-ÖØ-ÞİĞŞÇÜÖÂÊÎÔÛ][A-Za-zÀ-ÖØ-öø-ÿİıĞğŞşÇçÜüÖöÂâÊêÎîÔôÛû\.'\-]*){1,4})\s*[-|]\s*(CEO|Chief Executive Officer|Founder|Co[- ]Founder|Managing Director|COO|Chief Operating Officer|Head of Operations|Operations Director)",
     )
@@ -918,6 +1139,7 @@ fn extract_contact_from_search(
 fn extract_contact_from_search_for_company(
     search_output: &str,
     title_policy: &str,
+    contact_titles: &[String],
     company: &str,
     domain: &str,
 ) -> (
@@ -972,7 +1194,7 @@ fn extract_contact_from_search_for_company(
 
         let single_result = format!("{}\n{}\n{}", entry.title, entry.snippet, entry.url);
         let (mut name, mut title, mut linkedin) =
-            extract_contact_from_search(&single_result, title_policy);
+            extract_contact_from_search(&single_result, title_policy, contact_titles);
         if name.as_deref().map(contains_company_token).unwrap_or(false) {
             name = None;
         }
@@ -1152,14 +1374,21 @@ fn best_search_contact_evidence(
 fn best_search_contact_enrichment(
     search_outputs: &[String],
     title_policy: &str,
+    contact_titles: &[String],
     company: &str,
     domain: &str,
 ) -> SiteContactEnrichment {
     let combined = search_outputs.join("\n");
     let (search_name, search_title, search_linkedin, _search_email) =
-        extract_contact_from_search_for_company(&combined, title_policy, company, domain);
+        extract_contact_from_search_for_company(
+            &combined,
+            title_policy,
+            contact_titles,
+            company,
+            domain,
+        );
     let (fallback_name, fallback_title, fallback_linkedin) =
-        extract_contact_from_search(&combined, title_policy);
+        extract_contact_from_search(&combined, title_policy, contact_titles);
     let linkedin_url = search_linkedin
         .or(fallback_linkedin)
         .and_then(|value| normalize_outreach_linkedin_url(&value));
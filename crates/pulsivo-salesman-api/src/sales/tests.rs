@@ -2,6 +2,39 @@
 mod tests {
     use super::*;
 
+    /// Fresh engine over a temp home dir; keep the `TempDir` alive for the test.
+    fn test_engine() -> (tempfile::TempDir, SalesEngine) {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let engine = SalesEngine::new(temp.path());
+        engine.init().expect("init");
+        (temp, engine)
+    }
+
+    /// A draft lead for Machinity; override fields with struct update syntax.
+    fn sample_lead() -> SalesLead {
+        SalesLead {
+            id: "lead-1".to_string(),
+            run_id: "run-1".to_string(),
+            company: "Machinity".to_string(),
+            website: "https://machinity.ai".to_string(),
+            company_domain: "machinity.ai".to_string(),
+            contact_name: "Aylin Demir".to_string(),
+            contact_title: "CEO".to_string(),
+            linkedin_url: None,
+            email: Some("aylin@machinity.ai".to_string()),
+            phone: None,
+            reasons: vec!["Field operations expansion".to_string()],
+            email_subject: "Subject".to_string(),
+            email_body: "Body".to_string(),
+            linkedin_message: "LinkedIn".to_string(),
+            score: 50,
+            status: "draft_ready".to_string(),
+            created_at: "2026-03-26T11:00:00Z".to_string(),
+            external_id: None,
+            contact_confidence: None,
+        }
+    }
+
     #[test]
     fn sales_profile_draft_accepts_empty_numeric_strings() {
         let payload = r#"{
@@ -40,6 +73,7 @@ mod tests {
             matched_keywords: vec!["Field Operations".to_string()],
             source_links: Vec::new(),
             phone: None,
+            evidence_urls: HashMap::new(),
         };
         assert!(!candidate_has_field_ops_signal(&only_generic));
     }
@@ -53,6 +87,7 @@ mod tests {
             matched_keywords: vec!["equipment rental".to_string()],
             source_links: Vec::new(),
             phone: None,
+            evidence_urls: HashMap::new(),
         };
         assert!(candidate_has_field_ops_signal(&platform_company));
     }
@@ -72,6 +107,7 @@ mod tests {
             daily_send_cap: 5,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
         assert!(profile_targets_field_ops(&profile));
         assert!(!profile_targets_energy(&profile));
@@ -102,6 +138,7 @@ mod tests {
             daily_send_cap: 20,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
 
         assert!(candidate_should_skip_for_profile(
@@ -112,6 +149,7 @@ mod tests {
                 matched_keywords: vec![],
                 source_links: Vec::new(),
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             &profile,
         ));
@@ -123,6 +161,7 @@ mod tests {
                 matched_keywords: vec![],
                 source_links: Vec::new(),
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             &profile,
         ));
@@ -186,7 +225,7 @@ mod tests {
    URL: https://www.enerjisa.com.tr/leadership
    CEO: Emre Erdoğan
 "#;
-        let (name, title, _) = extract_contact_from_search(sample, "ceo_only");
+        let (name, title, _) = extract_contact_from_search(sample, "ceo_only", &[]);
         assert_eq!(name.as_deref(), Some("Emre Erdoğan"));
         assert_eq!(title.as_deref(), Some("CEO"));
     }
@@ -198,7 +237,7 @@ mod tests {
    URL: https://www.yapimerkezi.com.tr/yonetim
    Genel Müdür Aslan Uzun
 "#;
-        let (name, title, _) = extract_contact_from_search(sample, "ceo_then_founder");
+        let (name, title, _) = extract_contact_from_search(sample, "ceo_then_founder", &[]);
         assert_eq!(name.as_deref(), Some("Aslan Uzun"));
         assert_eq!(title.as_deref(), Some("CEO"));
     }
@@ -227,6 +266,8 @@ mod tests {
                 score: 61,
                 status: "new".to_string(),
                 created_at: "2026-03-20T09:00:00Z".to_string(),
+                external_id: None,
+                contact_confidence: None,
             },
             SalesLead {
                 id: "lead-2".to_string(),
@@ -249,6 +290,8 @@ mod tests {
                 score: 78,
                 status: "new".to_string(),
                 created_at: "2026-03-21T10:30:00Z".to_string(),
+                external_id: None,
+                contact_confidence: None,
             },
             SalesLead {
                 id: "lead-3".to_string(),
@@ -268,6 +311,8 @@ mod tests {
                 score: 72,
                 status: "new".to_string(),
                 created_at: "2026-03-21T11:00:00Z".to_string(),
+                external_id: None,
+                contact_confidence: None,
             },
         ];
 
@@ -286,6 +331,7 @@ mod tests {
             daily_send_cap: 20,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
 
         let profiles = build_prospect_profiles(leads, 10, Some(&sales_profile));
@@ -341,6 +387,7 @@ mod tests {
             ],
             source_links: vec!["https://www.tmb.org.tr/en/members".to_string()],
             phone: None,
+            evidence_urls: HashMap::new(),
         }];
         let sales_profile = SalesProfile {
             product_name: "Machinity".to_string(),
@@ -357,6 +404,7 @@ mod tests {
             daily_send_cap: 20,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
 
         let profiles = build_candidate_prospect_profiles(
@@ -395,6 +443,7 @@ mod tests {
                 matched_keywords: vec!["field service".to_string()],
                 source_links: Vec::new(),
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
             DomainCandidate {
                 domain: "ornekbakim.com.tr".to_string(),
@@ -403,6 +452,7 @@ mod tests {
                 matched_keywords: vec!["field service".to_string(), "maintenance".to_string()],
                 source_links: vec!["https://www.asmud.org.tr/Uyeler.asp".to_string()],
                 phone: None,
+                evidence_urls: HashMap::new(),
             },
         ];
         let mut hints = HashMap::new();
@@ -430,6 +480,7 @@ mod tests {
             daily_send_cap: 20,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
 
         let profiles = build_candidate_prospect_profiles(
@@ -529,9 +580,7 @@ mod tests {
 
     #[test]
     fn latest_successful_run_id_since_accepts_profile_only_runs() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
         engine
@@ -578,9 +627,7 @@ mod tests {
 
     #[test]
     fn recover_latest_timed_out_run_completes_partial_marketing_progress() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
         let lead = SalesLead {
@@ -601,10 +648,15 @@ mod tests {
             score: 92,
             status: "draft_ready".to_string(),
             created_at: "2026-03-25T10:00:00Z".to_string(),
+            external_id: None,
+            contact_confidence: None,
         };
-        assert!(engine.insert_lead(&lead).expect("insert lead"));
+        assert_eq!(
+            engine.insert_lead(&lead, 0).expect("insert lead"),
+            LeadWriteOutcome::Inserted
+        );
         let queued = engine
-            .queue_approvals_for_lead(&lead)
+            .queue_approvals_for_lead(&lead, &SalesProfile::default())
             .expect("queue approvals");
         assert_eq!(queued, 1);
         engine
@@ -670,9 +722,7 @@ mod tests {
 
     #[test]
     fn recover_latest_timed_out_run_fails_when_nothing_was_persisted() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
 
@@ -695,9 +745,7 @@ mod tests {
 
     #[test]
     fn recover_latest_timed_out_run_if_stale_honors_age_threshold() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
         engine
@@ -764,9 +812,7 @@ mod tests {
 
     #[test]
     fn list_runs_surfaces_live_progress_for_running_run() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
         let lead = SalesLead {
@@ -787,11 +833,16 @@ mod tests {
             score: 92,
             status: "draft_ready".to_string(),
             created_at: "2026-03-25T10:00:00Z".to_string(),
+            external_id: None,
+            contact_confidence: None,
         };
-        assert!(engine.insert_lead(&lead).expect("insert lead"));
+        assert_eq!(
+            engine.insert_lead(&lead, 0).expect("insert lead"),
+            LeadWriteOutcome::Inserted
+        );
         assert_eq!(
             engine
-                .queue_approvals_for_lead(&lead)
+                .queue_approvals_for_lead(&lead, &SalesProfile::default())
                 .expect("queue approvals"),
             1
         );
@@ -838,9 +889,7 @@ mod tests {
 
     #[test]
     fn job_progress_surfaces_checkpoints_and_active_lookup() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let job_id = engine
             .create_job_run("discovery", SalesSegment::B2B)
@@ -901,9 +950,7 @@ mod tests {
 
     #[test]
     fn list_approvals_skips_non_actionable_email_payloads() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let conn = engine.open().expect("open db");
         let created_at = "2026-03-26T10:00:00Z";
@@ -969,9 +1016,7 @@ mod tests {
 
     #[test]
     fn edit_approval_updates_touch_payload_and_returns_sanitized_payload() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let lead = SalesLead {
             id: uuid::Uuid::new_v4().to_string(),
@@ -991,11 +1036,16 @@ mod tests {
             score: 91,
             status: "draft_ready".to_string(),
             created_at: "2026-03-26T11:00:00Z".to_string(),
+            external_id: None,
+            contact_confidence: None,
         };
-        assert!(engine.insert_lead(&lead).expect("insert lead"));
+        assert_eq!(
+            engine.insert_lead(&lead, 0).expect("insert lead"),
+            LeadWriteOutcome::Inserted
+        );
         assert_eq!(
             engine
-                .queue_approvals_for_lead(&lead)
+                .queue_approvals_for_lead(&lead, &SalesProfile::default())
                 .expect("queue approvals"),
             2
         );
@@ -1050,9 +1100,7 @@ mod tests {
 
     #[test]
     fn ensure_default_sequence_template_uses_five_step_playbook() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         let conn = engine.open().expect("open");
         let template_id = engine
@@ -1081,9 +1129,7 @@ mod tests {
 
     #[test]
     fn select_accounts_for_activation_logs_mid_score_exploration() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
         let conn = engine.open().expect("open");
         let now = Utc::now().to_rfc3339();
 
@@ -1123,9 +1169,7 @@ mod tests {
 
     #[test]
     fn missed_signal_review_creates_policy_proposal_and_supports_lifecycle() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
         let conn = engine.open().expect("open");
         let now = Utc::now().to_rfc3339();
 
@@ -1202,9 +1246,7 @@ mod tests {
 
     #[test]
     fn get_account_dossier_falls_back_to_prospect_profile_when_canonical_core_missing() {
-        let temp = tempfile::tempdir().expect("tempdir");
-        let engine = SalesEngine::new(temp.path());
-        engine.init().expect("init");
+        let (_temp, engine) = test_engine();
 
         engine
             .upsert_prospect_profiles(SalesSegment::B2B, &[SalesProspectProfile {
@@ -1274,6 +1316,7 @@ mod tests {
         let (name, title, _linkedin, _email) = extract_contact_from_search_for_company(
             sample,
             "ceo_then_founder",
+            &[],
             "Ronesans",
             "ronesans.com",
         );
@@ -2448,6 +2491,7 @@ mod tests {
                     matched_keywords: vec!["construction".to_string()],
                     source_links: vec!["https://www.tmb.org.tr/en/members".to_string()],
                     phone: None,
+                    evidence_urls: HashMap::new(),
                 },
                 contact_hint: SourceContactHint {
                     contact_name: Some("Ahmet Yılmaz".to_string()),
@@ -2704,6 +2748,8 @@ mod tests {
             score: 84,
             status: "draft_ready".to_string(),
             created_at: "2026-03-25T10:00:00Z".to_string(),
+            external_id: None,
+            contact_confidence: None,
         }];
 
         let profiles = build_prospect_profiles(leads, 10, None);
@@ -2774,6 +2820,7 @@ mod tests {
             daily_send_cap: 20,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
 
         let draft = heuristic_lead_query_plan(&profile);
@@ -2828,6 +2875,7 @@ mod tests {
             daily_send_cap: 20,
             schedule_hour_local: 9,
             timezone_mode: "local".to_string(),
+            ..SalesProfile::default()
         };
 
         let lead_plan = heuristic_lead_query_plan(&profile);
@@ -3087,6 +3135,7 @@ Bergiz Holding altyapi ve insaat projeleri yurutur.
         let enrichment = best_search_contact_enrichment(
             &outputs,
             "ceo_then_founder",
+            &[],
             "Bergiz Holding",
             "bergiz.com.tr",
         );
@@ -3145,6 +3194,7 @@ Bergiz Holding altyapi ve insaat projeleri yurutur.
             daily_send_cap: 0,
             schedule_hour_local: 44,
             timezone_mode: "UTC".to_string(),
+            ..SalesProfile::default()
         };
 
         let normalized = normalize_sales_profile(profile).expect("profile normalizes");
@@ -3612,9 +3662,7 @@ Bergiz Holding altyapi ve insaat projeleri yurutur.
 
     #[test]
     fn spec_sequence_advancement_completes_on_positive_outcome() {
-        let temp = tempfile::tempdir().unwrap();
-        let engine = SalesEngine::new(temp.path());
-        engine.init().unwrap();
+        let (_temp, engine) = test_engine();
         let conn = engine.open().unwrap();
         let now = Utc::now().to_rfc3339();
         engine.ensure_default_sequence_template(&conn).unwrap();
@@ -3657,9 +3705,7 @@ Bergiz Holding altyapi ve insaat projeleri yurutur.
 
     #[test]
     fn spec_sequence_advancement_cancels_on_bounce() {
-        let temp = tempfile::tempdir().unwrap();
-        let engine = SalesEngine::new(temp.path());
-        engine.init().unwrap();
+        let (_temp, engine) = test_engine();
         let conn = engine.open().unwrap();
         let now = Utc::now().to_rfc3339();
         engine.ensure_default_sequence_template(&conn).unwrap();
@@ -3815,9 +3861,7 @@ Bergiz Holding altyapi ve insaat projeleri yurutur.
 
     #[test]
     fn spec_record_mailbox_send_persists_daily_counter() {
-        let dir = tempfile::tempdir().unwrap();
-        let engine = SalesEngine::new(dir.path());
-        engine.init().unwrap();
+        let (_dir, engine) = test_engine();
         let sender_cfg = SenderConfig {
             mailboxes: vec![MailboxConfig {
                 email: "warm@send.example.com".into(),
@@ -4014,4 +4058,2661 @@ Bergiz Holding altyapi ve insaat projeleri yurutur.
             Box<dyn std::future::Future<Output = bool> + Send + '_>,
         > = |domain| Box::pin(verify_domain_exists(domain));
     }
+
+    #[test]
+    fn build_test_send_email_marks_subject_and_renders_sample_utf8() {
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Saha operasyonları için yapay zekâ destekli koordinasyon"
+                .to_string(),
+            target_industry: "Field operations".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Machinity".to_string(),
+            sender_email: "info@machinity.ai".to_string(),
+            ..SalesProfile::default()
+        };
+
+        let (subject, body) = build_test_send_email(&profile, None);
+        assert!(subject.starts_with("[TEST] "));
+        assert!(body.contains("Merhaba Ayşe"));
+        assert!(body.contains("..."));
+
+        let lead = SalesLead {
+            company: "Acme".to_string(),
+            website: "https://acme.com".to_string(),
+            company_domain: "acme.com".to_string(),
+            contact_name: "Jane Doe".to_string(),
+            email: Some("jane@acme.com".to_string()),
+            reasons: Vec::new(),
+            email_subject: "Acme: field ops coordination".to_string(),
+            email_body: "Hi Jane".to_string(),
+            linkedin_message: String::new(),
+            score: 80,
+            status: "activation_candidate".to_string(),
+            created_at: "2026-03-25T10:00:00Z".to_string(),
+            ..sample_lead()
+        };
+        let (subject, body) = build_test_send_email(&profile, Some(&lead));
+        assert_eq!(subject, "[TEST] Acme: field ops coordination");
+        assert_eq!(body, "Hi Jane");
+    }
+
+    #[test]
+    fn custom_blocked_company_domains_exclude_domain_and_subdomains_from_candidates() {
+        let blocklist = vec![
+            "https://www.Sektorrehberi-Ornek.com/".to_string(),
+            "firmalistesi-ornek.*".to_string(),
+        ];
+        let sample = r#"
+1. Sektor Rehberi - Saha servis firmalari
+   URL: https://sektorrehberi-ornek.com/saha-servis
+   Saha servis ve bakim firmalari listesi
+2. Sektor Rehberi Istanbul
+   URL: https://istanbul.sektorrehberi-ornek.com/bakim
+   Bakim ve saha operasyon firmalari
+3. Firma Listesi
+   URL: https://www.firmalistesi-ornek.com.tr/saha
+   Saha operasyon firmalari
+4. Ornek Bakim Hizmetleri
+   URL: https://ornekbakimhizmetleri.com.tr/
+   Saha servis ve bakim ekipleri icin dispatch
+"#;
+        let mut candidates = HashMap::new();
+        collect_domain_candidates_from_search(
+            sample,
+            &mut candidates,
+            &["saha servis".to_string()],
+            &[],
+            "TR",
+            true,
+            true,
+            SalesReasonLimits::default(),
+        );
+        let web_candidates = candidates.into_values().collect::<Vec<_>>();
+
+        let unfiltered = merge_all_discovery_sources(
+            Vec::new(),
+            web_candidates.clone(),
+            Vec::new(),
+            &mut HashMap::new(),
+            &[],
+        );
+        assert!(unfiltered
+            .iter()
+            .any(|c| c.domain == "firmalistesi-ornek.com.tr"));
+
+        let merged = merge_all_discovery_sources(
+            Vec::new(),
+            web_candidates,
+            Vec::new(),
+            &mut HashMap::new(),
+            &blocklist,
+        );
+        assert!(merged
+            .iter()
+            .any(|c| c.domain == "ornekbakimhizmetleri.com.tr"));
+        assert!(merged
+            .iter()
+            .all(|c| !c.domain.contains("sektorrehberi-ornek")
+                && !c.domain.contains("firmalistesi-ornek")));
+
+        assert!(blocked_domain_entry_matches("acme.com.tr", "acme.*"));
+        assert!(blocked_domain_entry_matches("shop.acme.co.uk", "acme.*"));
+        assert!(!blocked_domain_entry_matches("acme.example.com", "acme.*"));
+        assert!(!blocked_domain_entry_matches("notacme.com", "acme.com"));
+    }
+
+    #[test]
+    fn registrable_domain_handles_multi_label_suffixes() {
+        assert_eq!(registrable_domain("shop.acme.co.uk"), "acme.co.uk");
+        assert_eq!(registrable_domain("acme.co.uk"), "acme.co.uk");
+        assert_eq!(registrable_domain("a.b.ornek.com.tr"), "ornek.com.tr");
+        assert_eq!(registrable_domain("shop.acme.com"), "acme.com");
+        assert_eq!(registrable_domain("acme.com"), "acme.com");
+        assert_eq!(registrable_domain("acme.github.io"), "acme.github.io");
+        assert_eq!(registrable_domain("shop.acme.com.tw"), "acme.com.tw");
+        assert_eq!(registrable_domain("www.ornek.co.th"), "ornek.co.th");
+        assert_eq!(registrable_domain("a.b.acme.org.sg"), "acme.org.sg");
+        assert_ne!(
+            registrable_domain("alpha.com.tw"),
+            registrable_domain("beta.com.tw")
+        );
+        assert_eq!(registrable_domain("shop.acme.io"), "acme.io");
+        assert_eq!(registrable_domain("portal.acme.gob.mx"), "acme.gob.mx");
+        assert_eq!(registrable_domain("10.0.0.1"), "10.0.0.1");
+        assert_eq!(
+            candidate_domain_key("shop.acme.co.uk", true),
+            "shop.acme.co.uk"
+        );
+    }
+
+    #[test]
+    fn collect_domain_candidates_collapses_subdomains_unless_opted_out() {
+        let sample = r#"
+1. Acme Field Service UK
+   URL: https://acme.co.uk/
+   Field service dispatch and maintenance crews
+2. Acme Shop
+   URL: https://shop.acme.co.uk/parts
+   Field service spare parts for maintenance crews
+"#;
+        let keywords = vec!["field service".to_string()];
+        let mut collapsed = HashMap::new();
+        collect_domain_candidates_from_search(
+            sample,
+            &mut collapsed,
+            &keywords,
+            &[],
+            "UK",
+            true,
+            false,
+            SalesReasonLimits::default(),
+        );
+        assert_eq!(collapsed.len(), 1);
+        assert!(collapsed.contains_key("acme.co.uk"));
+
+        let mut separate = HashMap::new();
+        collect_domain_candidates_from_search(
+            sample,
+            &mut separate,
+            &keywords,
+            &[],
+            "UK",
+            true,
+            true,
+            SalesReasonLimits::default(),
+        );
+        assert!(separate.contains_key("acme.co.uk"));
+        assert!(separate.contains_key("shop.acme.co.uk"));
+    }
+
+    #[test]
+    fn reopen_approval_moves_rejected_back_to_pending_and_records_event() {
+        let (_temp, engine) = test_engine();
+
+        let conn = engine.open().expect("open db");
+        for (id, status, to) in [
+            ("approval-rejected", "pending", "eray@artiplatform.com.tr"),
+            ("approval-sent", "approved", "ops@acme.com.tr"),
+        ] {
+            conn.execute(
+                "INSERT INTO approvals (id, lead_id, channel, payload_json, status, created_at) VALUES (?, 'lead-1', 'email', ?, ?, '2026-03-26T10:00:00Z')",
+                params![
+                    id,
+                    serde_json::json!({"to": to, "subject": "Subject", "body": "Body"}).to_string(),
+                    status
+                ],
+            )
+            .expect("insert approval");
+        }
+
+        engine
+            .reject_approval("approval-rejected")
+            .expect("reject approval");
+        engine
+            .reopen_approval("approval-rejected")
+            .expect("reopen approval");
+
+        let (status, decided_at) = conn
+            .query_row(
+                "SELECT status, decided_at FROM approvals WHERE id = 'approval-rejected'",
+                [],
+                |r| Ok((r.get::<_, String>(0)?, r.get::<_, Option<String>>(1)?)),
+            )
+            .expect("approval row");
+        assert_eq!(status, "pending");
+        assert!(decided_at.is_none());
+        let (from_status, to_status) = conn
+            .query_row(
+                "SELECT from_status, to_status FROM approval_events WHERE approval_id = 'approval-rejected'",
+                [],
+                |r| Ok((r.get::<_, String>(0)?, r.get::<_, String>(1)?)),
+            )
+            .expect("approval event");
+        assert_eq!(from_status, "rejected");
+        assert_eq!(to_status, "pending");
+
+        assert!(engine.reopen_approval("approval-sent").is_err());
+        assert!(engine.reopen_approval("approval-rejected").is_err());
+        assert_eq!(
+            engine.reopen_approval("missing").unwrap_err(),
+            "Approval not found"
+        );
+        let events: i64 = conn
+            .query_row("SELECT COUNT(*) FROM approval_events", [], |r| r.get(0))
+            .expect("event count");
+        assert_eq!(events, 1);
+    }
+
+    #[test]
+    fn extract_contact_from_search_matches_plan_contact_titles() {
+        let sample = r#"
+1. Anadolu Asansör Ekibimiz
+   URL: https://www.anadoluasansor.com.tr/ekibimiz
+   Saha Şefi: Mehmet Kaya, 15 yıllık deneyim
+"#;
+        let contact_titles = vec!["Saha Şefi".to_string(), "Teknik Müdür".to_string()];
+        let (name, title, _) =
+            extract_contact_from_search(sample, "ceo_then_founder", &contact_titles);
+        assert_eq!(name.as_deref(), Some("Mehmet Kaya"));
+        assert_eq!(title.as_deref(), Some("Saha Şefi"));
+
+        let (_, static_title, _) = extract_contact_from_search(sample, "ceo_then_founder", &[]);
+        assert_ne!(static_title.as_deref(), Some("Saha Şefi"));
+    }
+
+    async fn spawn_fake_smtp_server(rcpt_reply: &'static str) -> u16 {
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind fake smtp");
+        let port = listener.local_addr().expect("local addr").port();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            let (read_half, mut write_half) = stream.into_split();
+            let mut lines = tokio::io::BufReader::new(read_half).lines();
+            write_half
+                .write_all(b"220 mx.example ESMTP\r\n")
+                .await
+                .expect("banner");
+            while let Ok(Some(line)) = lines.next_line().await {
+                let reply = if line.starts_with("EHLO") {
+                    "250-mx.example\r\n250 SIZE 1000000\r\n"
+                } else if line.starts_with("MAIL FROM") {
+                    "250 OK\r\n"
+                } else if line.starts_with("RCPT TO") {
+                    rcpt_reply
+                } else {
+                    "221 Bye\r\n"
+                };
+                if write_half.write_all(reply.as_bytes()).await.is_err() || line == "QUIT" {
+                    break;
+                }
+            }
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn smtp_rcpt_probe_classifies_mailbox_replies() {
+        for (rcpt_reply, expected) in [
+            ("250 Accepted\r\n", EmailVerification::Deliverable),
+            (
+                "550 5.1.1 No such user\r\n",
+                EmailVerification::Undeliverable,
+            ),
+            ("451 4.7.1 Try again later\r\n", EmailVerification::Unknown),
+        ] {
+            let port = spawn_fake_smtp_server(rcpt_reply).await;
+            let verdict =
+                smtp_rcpt_probe("127.0.0.1", port, "pulsivo.test", "ayse.kaya@acme.com.tr").await;
+            assert_eq!(verdict, expected, "reply: {rcpt_reply}");
+        }
+    }
+
+    #[test]
+    fn list_runs_surfaces_recorded_run_telemetry() {
+        let (_temp, engine) = test_engine();
+
+        let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
+        let telemetry = SalesRunTelemetry {
+            llm_plan_ms: 120,
+            llm_candidate_ms: 900,
+            discovery_search_ms: 2_400,
+            contact_lookup_ms: 7_800,
+            queries_issued: 14,
+            search_errors: 2,
+            max_candidates: 90,
+            approvals_capped: 3,
+            diagnostics: Vec::new(),
+        };
+        engine
+            .record_run_telemetry(&run_id, &telemetry)
+            .expect("record telemetry");
+        engine
+            .finish_run(&run_id, "completed", 5, 2, 2, None)
+            .expect("finish run");
+        let untracked = engine.begin_run(SalesSegment::B2B).expect("begin run");
+
+        let runs = engine.list_runs(SalesSegment::B2B, 10).expect("list runs");
+        let tracked = runs.iter().find(|run| run.id == run_id).expect("run");
+        let recorded = tracked.telemetry.as_ref().expect("telemetry");
+        assert_eq!(recorded.contact_lookup_ms, 7_800);
+        assert_eq!(recorded.queries_issued, 14);
+        assert_eq!(recorded.search_errors, 2);
+        assert_eq!(recorded.approvals_capped, 3);
+        assert!(runs
+            .iter()
+            .find(|run| run.id == untracked)
+            .expect("untracked run")
+            .telemetry
+            .is_none());
+    }
+
+    #[test]
+    fn init_migrates_unversioned_sales_db() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        {
+            let conn = Connection::open(temp.path().join("sales.db")).expect("open");
+            conn.execute_batch(
+                "CREATE TABLE sales_runs (
+                    id TEXT PRIMARY KEY,
+                    status TEXT NOT NULL,
+                    started_at TEXT NOT NULL,
+                    completed_at TEXT,
+                    discovered INTEGER NOT NULL DEFAULT 0,
+                    inserted INTEGER NOT NULL DEFAULT 0,
+                    approvals_queued INTEGER NOT NULL DEFAULT 0,
+                    error TEXT
+                );",
+            )
+            .expect("legacy schema");
+        }
+
+        let engine = SalesEngine::new(temp.path());
+        engine.init().expect("init");
+        let latest = SALES_DB_MIGRATIONS.last().map(|m| m.version).unwrap();
+        assert_eq!(engine.schema_version().expect("version"), latest);
+
+        let run_id = engine.begin_run(SalesSegment::B2C).expect("begin run");
+        engine
+            .record_run_telemetry(&run_id, &SalesRunTelemetry::default())
+            .expect("telemetry column exists");
+
+        engine.init().expect("re-init is a no-op");
+        assert_eq!(engine.schema_version().expect("version"), latest);
+    }
+
+    #[test]
+    fn sales_db_connections_use_wal_and_return_to_pool() {
+        let (temp, engine) = test_engine();
+
+        let conn = engine.open().expect("open");
+        let mode: String = conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .expect("journal mode");
+        assert_eq!(mode.to_ascii_lowercase(), "wal");
+        drop(conn);
+
+        let idle = SALES_DB_POOL
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(path, _)| *path == temp.path().join("sales.db"))
+            .map(|(_, idle)| idle.len())
+            .unwrap_or(0);
+        assert!(idle >= 1);
+    }
+
+    #[test]
+    fn sales_db_pool_evicts_least_recently_used_paths() {
+        let (first, engine) = test_engine();
+        drop(engine.open().expect("open"));
+
+        let others: Vec<_> = (0..SALES_DB_POOL_MAX_PATHS)
+            .map(|_| test_engine())
+            .collect();
+        for (_, other) in &others {
+            drop(other.open().expect("open"));
+        }
+
+        let pool = SALES_DB_POOL.lock().unwrap();
+        assert!(pool.len() <= SALES_DB_POOL_MAX_PATHS);
+        assert!(!pool
+            .iter()
+            .any(|(path, _)| *path == first.path().join("sales.db")));
+    }
+
+    #[test]
+    fn search_leads_ranks_keyword_matches() {
+        let (_temp, engine) = test_engine();
+
+        let lead = |id: &str, company: &str, reason: &str| SalesLead {
+            id: id.to_string(),
+            company: company.to_string(),
+            website: format!("https://{id}.example"),
+            company_domain: format!("{id}.example"),
+            contact_name: "Ayse Kaya".to_string(),
+            contact_title: "COO".to_string(),
+            email: None,
+            reasons: vec![reason.to_string()],
+            email_subject: "Intro".to_string(),
+            email_body: "Hello".to_string(),
+            linkedin_message: String::new(),
+            created_at: "2026-03-20T09:00:00Z".to_string(),
+            ..sample_lead()
+        };
+        assert_eq!(
+            engine
+                .insert_lead(
+                    &lead("acme", "Acme Elevators", "Elevator maintenance crews"),
+                    0
+                )
+                .expect("insert acme"),
+            LeadWriteOutcome::Inserted
+        );
+        assert_eq!(
+            engine
+                .insert_lead(&lead("globex", "Globex Foods", "Cold chain logistics"), 0)
+                .expect("insert globex"),
+            LeadWriteOutcome::Inserted
+        );
+
+        let (hits, mode) = engine.search_leads("elevat", 10).expect("fts search");
+        assert_eq!(mode, "fts");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].company, "Acme Elevators");
+
+        engine
+            .open()
+            .expect("open")
+            .execute_batch(
+                "DROP TRIGGER leads_fts_insert;
+                 DROP TRIGGER leads_fts_delete;
+                 DROP TRIGGER leads_fts_update;
+                 DROP TABLE leads_fts;",
+            )
+            .expect("drop fts");
+
+        // A missing index is rebuilt on the next search, including leads
+        // written while it was gone.
+        assert_eq!(
+            engine
+                .insert_lead(&lead("initech", "Initech Boilers", "Boiler servicing"), 0)
+                .expect("insert initech"),
+            LeadWriteOutcome::Inserted
+        );
+        let (hits, mode) = engine.search_leads("boiler", 10).expect("fts search");
+        assert_eq!(mode, "fts");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].company, "Initech Boilers");
+        let (hits, _) = engine.search_leads("cold chain", 10).expect("fts search");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].company, "Globex Foods");
+    }
+
+    #[test]
+    fn lead_status_transitions_are_validated_and_recorded() {
+        let (_temp, engine) = test_engine();
+        assert_eq!(
+            engine
+                .insert_lead(
+                    &SalesLead {
+                        company: "Acme".to_string(),
+                        website: "https://acme.example".to_string(),
+                        company_domain: "acme.example".to_string(),
+                        contact_name: "Ayse Kaya".to_string(),
+                        contact_title: "COO".to_string(),
+                        email: None,
+                        reasons: Vec::new(),
+                        email_subject: String::new(),
+                        email_body: String::new(),
+                        linkedin_message: String::new(),
+                        created_at: "2026-03-20T09:00:00Z".to_string(),
+                        ..sample_lead()
+                    },
+                    0
+                )
+                .expect("insert"),
+            LeadWriteOutcome::Inserted
+        );
+
+        let err = engine
+            .transition_lead_status("lead-1", "replied", None)
+            .unwrap_err();
+        assert!(err.contains("draft_ready -> replied"));
+        assert!(engine
+            .transition_lead_status("lead-1", "won", None)
+            .unwrap_err()
+            .contains("Unknown lead status"));
+        assert_eq!(
+            engine
+                .transition_lead_status("missing", "queued", None)
+                .unwrap_err(),
+            "Lead not found"
+        );
+
+        engine
+            .transition_lead_status("lead-1", "queued", None)
+            .expect("queue");
+        let change = engine
+            .transition_lead_status("lead-1", "contacted", Some("sent from inbox"))
+            .expect("contact");
+        assert_eq!(change.from_status, "queued");
+        assert_eq!(change.note.as_deref(), Some("sent from inbox"));
+
+        let history = engine.lead_status_history("lead-1").expect("history");
+        let steps = history
+            .iter()
+            .map(|change| change.to_status.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(steps, vec!["queued", "contacted"]);
+    }
+
+    #[test]
+    fn apply_llm_rerank_scores_merges_and_drops() {
+        let candidate = |domain: &str, score: i32| DomainCandidate {
+            domain: domain.to_string(),
+            score,
+            ..DomainCandidate::default()
+        };
+        let mut candidates = vec![
+            candidate("fit.example", 20),
+            candidate("vendor.example", 30),
+            candidate("unranked.example", 12),
+        ];
+        let rankings = HashMap::from([
+            ("fit.example".to_string(), (90, true)),
+            ("vendor.example".to_string(), (10, false)),
+        ]);
+
+        apply_llm_rerank_scores(&mut candidates, &rankings);
+
+        let scores = candidates
+            .iter()
+            .map(|c| (c.domain.as_str(), c.score))
+            .collect::<Vec<_>>();
+        assert_eq!(scores, vec![("fit.example", 28), ("unranked.example", 12)]);
+    }
+
+    #[test]
+    fn sales_message_uses_locale_catalogs_with_english_fallback() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let locales = temp.path().join("locales");
+        std::fs::create_dir_all(&locales).unwrap();
+        std::fs::write(
+            locales.join("xx-test.toml"),
+            "[outreach]\nemail_subject = \"{company} / {missing}\"\n",
+        )
+        .unwrap();
+        load_sales_message_catalogs(temp.path());
+
+        let profile = SalesProfile {
+            locale: "XX_test".to_string(),
+            target_geo: "TR".to_string(),
+            ..SalesProfile::default()
+        };
+        assert_eq!(sales_locale(&profile), "xx-test");
+        assert_eq!(
+            build_sales_email_subject(&profile, "Acme {x}"),
+            "Acme {x} / {missing}"
+        );
+        assert_eq!(
+            sales_message("xx-test", "outreach.recipient_team", &[("company", "Acme")]),
+            "Acme team"
+        );
+        assert_eq!(
+            sales_message("tr-tr", "outreach.recipient_team", &[("company", "Acme")]),
+            "Acme ekibi"
+        );
+        assert_eq!(
+            sales_message("en", "outreach.unknown", &[]),
+            "outreach.unknown"
+        );
+
+        load_sales_message_catalogs(&temp.path().join("missing"));
+        assert_eq!(
+            build_sales_email_subject(&profile, "Acme"),
+            "Acme: field ops coordination"
+        );
+    }
+
+    #[test]
+    fn sales_stats_aggregates_counts_and_daily_cap() {
+        let (_temp, engine) = test_engine();
+        for (id, domain, status) in [
+            ("lead-1", "acme.example", "draft_ready"),
+            ("lead-2", "globex.example", "contacted"),
+            ("lead-3", "initech.example", "contacted"),
+        ] {
+            assert_eq!(
+                engine
+                    .insert_lead(
+                        &SalesLead {
+                            id: id.to_string(),
+                            company: domain.to_string(),
+                            website: format!("https://{domain}"),
+                            company_domain: domain.to_string(),
+                            contact_name: "Ayse Kaya".to_string(),
+                            contact_title: "COO".to_string(),
+                            email: None,
+                            reasons: Vec::new(),
+                            email_subject: String::new(),
+                            email_body: String::new(),
+                            linkedin_message: String::new(),
+                            status: status.to_string(),
+                            created_at: "2026-03-20T09:00:00Z".to_string(),
+                            ..sample_lead()
+                        },
+                        0
+                    )
+                    .expect("insert"),
+                LeadWriteOutcome::Inserted
+            );
+        }
+        {
+            let conn = engine.open().expect("open");
+            for (id, status) in [
+                ("ap-1", "pending"),
+                ("ap-2", "pending"),
+                ("ap-3", "rejected"),
+            ] {
+                conn.execute(
+                    "INSERT INTO approvals (id, lead_id, channel, payload_json, status, created_at)
+                     VALUES (?1, 'lead-1', 'email', '{}', ?2, '2026-03-20T09:00:00Z')",
+                    params![id, status],
+                )
+                .expect("approval");
+            }
+            conn.execute(
+                "INSERT INTO deliveries (id, approval_id, channel, recipient, status, error, sent_at)
+                 VALUES ('old', 'ap-0', 'email', 'a@acme.example', 'sent', NULL, '2020-01-01T09:00:00+00:00')",
+                [],
+            )
+            .expect("old delivery");
+        }
+        engine
+            .record_delivery("ap-1", "email", "b@acme.example", "sent", None)
+            .expect("sent");
+        engine
+            .record_delivery("ap-2", "email", "c@acme.example", "failed", Some("550"))
+            .expect("failed");
+        engine
+            .record_delivery(
+                "ap-3",
+                "email",
+                "d@acme.example",
+                "sandboxed",
+                Some("Sandbox: send skipped"),
+            )
+            .expect("sandboxed");
+
+        let stats = engine.stats(SalesSegment::B2B).expect("stats");
+        assert_eq!(stats.total_leads, 3);
+        assert_eq!(stats.leads_by_status.get("contacted"), Some(&2));
+        assert_eq!(stats.approvals_pending, 2);
+        assert_eq!(stats.approvals_rejected, 1);
+        assert_eq!(stats.approvals_approved, 0);
+        assert_eq!(stats.deliveries_sent_today, 1);
+        assert_eq!(stats.deliveries_failed_today, 1);
+        assert_eq!(stats.deliveries_sent_total, 2);
+        assert_eq!(stats.deliveries_failed_total, 1);
+        assert_eq!(stats.deliveries_sandboxed_total, 1);
+        assert!(!stats.sandbox_mode);
+        assert_eq!(stats.daily_send_cap, 20);
+        assert_eq!(stats.sends_remaining_today, 19);
+        assert_eq!(engine.deliveries_today("local").expect("today"), 1);
+        assert!(stats.last_run.is_none());
+    }
+
+    #[test]
+    fn validate_sales_llm_providers_rejects_unknown_fallbacks() {
+        let mut config = pulsivo_salesman_types::config::KernelConfig::default();
+        assert!(validate_sales_llm_providers(&config).is_ok());
+
+        // The sales chain never builds the default model's provider.
+        config.default_model.provider = "not-a-provider".to_string();
+        assert!(validate_sales_llm_providers(&config).is_ok());
+
+        config
+            .default_model
+            .fallback_models
+            .push(pulsivo_salesman_types::agent::FallbackModel {
+                provider: "gemnii".to_string(),
+                model: "gemini-2.5-flash".to_string(),
+                api_key_env: None,
+                base_url: None,
+                connect_timeout_secs: None,
+                request_timeout_secs: None,
+                stream_idle_timeout_secs: None,
+            });
+        let err = validate_sales_llm_providers(&config).unwrap_err();
+        assert!(err.contains("default_model.fallback_models"));
+        assert!(err.contains("Unknown provider 'gemnii'"));
+        assert!(err.contains("gemini"));
+
+        // Offline runs only chain local fallbacks.
+        config.offline = true;
+        assert!(validate_sales_llm_providers(&config).is_ok());
+        config.offline = false;
+
+        config.default_model.fallback_models[0].base_url =
+            Some("http://localhost:8080/v1".to_string());
+        assert!(validate_sales_llm_providers(&config).is_ok());
+    }
+
+    #[test]
+    fn sales_search_limits_default_and_clamp() {
+        let limits = SalesSearchLimits::from_profile(&SalesProfile::default());
+        assert_eq!(limits.discovery(24), 24);
+        assert_eq!(limits.contact(6), 6);
+        assert_eq!(limits.fallback(20), 20);
+        assert_eq!(
+            limits.candidates(DISCOVERY_RESERVOIR_CANDIDATES),
+            DISCOVERY_RESERVOIR_CANDIDATES
+        );
+
+        let limits = SalesSearchLimits::from_profile(&SalesProfile {
+            discovery_results: 5,
+            contact_results: 500,
+            fallback_results: 2,
+            max_candidates: 25,
+            ..SalesProfile::default()
+        });
+        assert_eq!(limits.discovery(24), 5);
+        assert_eq!(limits.contact(6), SALES_SEARCH_RESULTS_MAX as usize);
+        assert_eq!(limits.fallback(20), 2);
+        assert_eq!(limits.candidates(DISCOVERY_RESERVOIR_CANDIDATES), 25);
+
+        let limits = SalesSearchLimits::from_profile(&SalesProfile {
+            max_candidates: 100_000,
+            ..SalesProfile::default()
+        });
+        assert_eq!(
+            limits.candidates(DISCOVERY_RESERVOIR_CANDIDATES),
+            SALES_MAX_CANDIDATES_MAX as usize
+        );
+    }
+
+    #[test]
+    fn sales_run_guard_rejects_concurrent_triggers() {
+        let slot = SalesRunSlot::default();
+        let mut first = SalesRunGuard::try_acquire(&slot, "pending").expect("slot should be free");
+        first.rebind("job-1");
+        assert_eq!(
+            SalesRunGuard::try_acquire(&slot, "pending")
+                .err()
+                .as_deref(),
+            Some("job-1")
+        );
+        // Separate app states do not share a slot.
+        let other = SalesRunGuard::try_acquire(&SalesRunSlot::default(), "pending");
+        assert!(other.is_ok());
+        drop(first);
+        let second = SalesRunGuard::try_acquire(&slot, "job-2").expect("slot should be released");
+        drop(second);
+    }
+
+    #[tokio::test]
+    async fn concurrent_run_triggers_start_one_job() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let kernel =
+            pulsivo_salesman_kernel::PulsivoSalesmanKernel::boot_with_config(KernelConfig {
+                home_dir: temp.path().to_path_buf(),
+                data_dir: temp.path().join("data"),
+                ..KernelConfig::default()
+            })
+            .expect("boot kernel");
+        let state = Arc::new(AppState {
+            kernel: Arc::new(kernel),
+            started_at: std::time::Instant::now(),
+            shutdown_notify: Arc::new(tokio::sync::Notify::new()),
+            sales_runs: SalesRunSlot::default(),
+        });
+        engine_from_state(&state)
+            .expect("engine")
+            .upsert_profile(
+                SalesSegment::B2B,
+                &SalesProfile {
+                    product_name: "Machinity".to_string(),
+                    product_description: "Field operations coordination".to_string(),
+                    target_industry: "Construction".to_string(),
+                    target_geo: "TR".to_string(),
+                    sender_name: "Machinity".to_string(),
+                    sender_email: "info@machinity.ai".to_string(),
+                    ..SalesProfile::default()
+                },
+            )
+            .expect("save profile");
+
+        let trigger = || {
+            run_sales_now(
+                State(state.clone()),
+                Query(SalesSegmentQuery { segment: None }),
+                None,
+            )
+        };
+        let (first, second) = tokio::join!(trigger(), trigger());
+        let mut statuses = [
+            first.into_response().status(),
+            second.into_response().status(),
+        ];
+        statuses.sort();
+        assert_eq!(statuses, [StatusCode::ACCEPTED, StatusCode::CONFLICT]);
+    }
+
+    #[test]
+    fn insert_lead_upserts_on_external_id() {
+        let (_temp, engine) = test_engine();
+        let lead = |id: &str, subject: &str, external_id: Option<&str>| SalesLead {
+            id: id.to_string(),
+            run_id: "import-1".to_string(),
+            company: "Acme".to_string(),
+            website: "https://acme.example".to_string(),
+            company_domain: "acme.example".to_string(),
+            contact_name: "Ayse Kaya".to_string(),
+            contact_title: "COO".to_string(),
+            email: None,
+            reasons: Vec::new(),
+            email_subject: subject.to_string(),
+            email_body: String::new(),
+            linkedin_message: String::new(),
+            created_at: "2026-03-20T09:00:00Z".to_string(),
+            external_id: external_id.map(str::to_string),
+            ..sample_lead()
+        };
+
+        assert_eq!(
+            engine
+                .insert_lead(&lead("lead-1", "First draft", Some("crm-42")), 0)
+                .expect("insert"),
+            LeadWriteOutcome::Inserted
+        );
+        assert_eq!(
+            engine
+                .insert_lead(&lead("lead-2", "Second draft", Some("crm-42")), 0)
+                .expect("upsert"),
+            LeadWriteOutcome::Updated
+        );
+        let stored = engine
+            .get_lead_by_id("lead-1")
+            .expect("load")
+            .expect("lead exists");
+        assert_eq!(stored.email_subject, "Second draft");
+        assert_eq!(stored.external_id.as_deref(), Some("crm-42"));
+        assert!(engine.get_lead_by_id("lead-2").expect("load").is_none());
+
+        assert_eq!(
+            engine
+                .insert_lead(&lead("lead-3", "Same contact", None), 0)
+                .expect("dedup"),
+            LeadWriteOutcome::Duplicate
+        );
+
+        // Once the lead leaves the draft stage a re-import leaves it alone.
+        engine
+            .open()
+            .expect("open")
+            .execute(
+                "UPDATE leads SET status = 'contacted' WHERE id = 'lead-1'",
+                [],
+            )
+            .expect("mark contacted");
+        assert_eq!(
+            engine
+                .insert_lead(&lead("lead-4", "Third draft", Some("crm-42")), 0)
+                .expect("re-import"),
+            LeadWriteOutcome::Duplicate
+        );
+        let stored = engine
+            .get_lead_by_id("lead-1")
+            .expect("load")
+            .expect("lead exists");
+        assert_eq!(stored.email_subject, "Second draft");
+    }
+
+    #[test]
+    fn lead_import_is_idempotent_by_external_id() {
+        let (_temp, engine) = test_engine();
+        let item = |subject: &str| SalesLeadImportItem {
+            external_id: "crm-7".to_string(),
+            company: "Acme".to_string(),
+            company_domain: "Acme.example".to_string(),
+            contact_name: "Ayse Kaya".to_string(),
+            contact_title: "COO".to_string(),
+            email: Some("ayse@acme.example".to_string()),
+            email_subject: subject.to_string(),
+            ..SalesLeadImportItem::default()
+        };
+
+        let first = engine
+            .import_leads(&[item("First"), SalesLeadImportItem::default()])
+            .expect("import");
+        assert_eq!(first.inserted, 1);
+        assert_eq!(first.errors.len(), 1);
+        assert!(first.errors[0].starts_with("leads[1]: "));
+
+        let second = engine.import_leads(&[item("Second")]).expect("re-import");
+        assert_eq!((second.inserted, second.updated), (0, 1));
+        let leads = engine.list_leads(10, None).expect("list");
+        assert_eq!(leads.len(), 1);
+        assert_eq!(leads[0].email_subject, "Second");
+        assert_eq!(leads[0].company_domain, "acme.example");
+        assert_eq!(leads[0].website, "https://acme.example");
+    }
+
+    #[test]
+    fn reason_limits_apply_configured_lengths_to_multibyte_evidence() {
+        let profile = SalesProfile {
+            product_name: "Pulsivo".to_string(),
+            product_description: "saha ekipleri için iş emri takibi".to_string(),
+            max_reasons: 2,
+            evidence_items: 1,
+            evidence_chars: 17,
+            ..SalesProfile::default()
+        };
+        let limits = SalesReasonLimits::from_profile(&profile);
+        assert_eq!(limits.max_reasons, 2);
+        assert_eq!(limits.evidence_items, 1);
+        assert_eq!(limits.evidence_chars, 17);
+
+        let evidence = "Şantiye ve saha ekiplerinde günlük iş emri takibi ölçülüyor";
+        let reasons = build_sales_lead_reasons(
+            &profile,
+            "Örnek Yapı",
+            "saha operasyonu",
+            evidence,
+            None,
+            Some("Operasyon Müdürü"),
+        );
+        assert_eq!(reasons.len(), 2);
+        let truncated = reasons[1]
+            .strip_prefix("Public evidence: ")
+            .expect("evidence reason");
+        assert!(truncated.ends_with("..."));
+        assert!(truncated.trim_end_matches("...").chars().count() <= 17);
+
+        let sample = r#"
+1. Örnek Yapı
+   URL: https://ornekyapi.com.tr/
+   Şantiye ve saha ekiplerinde günlük iş emri takibi ölçülüyor
+2. Örnek Yapı Kariyer
+   URL: https://ornekyapi.com.tr/kariyer
+   Saha servis teknisyeni ilanı: bakım ekipleri büyüyor
+"#;
+        let mut candidates = HashMap::new();
+        collect_domain_candidates_from_search(
+            sample,
+            &mut candidates,
+            &["saha".to_string()],
+            &[],
+            "TR",
+            true,
+            false,
+            limits,
+        );
+        let candidate = candidates.get("ornekyapi.com.tr").expect("candidate");
+        assert_eq!(candidate.evidence.len(), 1);
+        let kept = candidate.evidence[0].trim_end_matches("...");
+        assert!(kept.chars().count() <= 17);
+
+        let defaults = SalesReasonLimits::from_profile(&SalesProfile {
+            evidence_chars: 100_000,
+            ..SalesProfile::default()
+        });
+        assert_eq!(defaults.max_reasons, 4);
+        assert_eq!(defaults.evidence_chars, SALES_EVIDENCE_CHARS_MAX as usize);
+    }
+
+    #[test]
+    fn queue_approvals_uses_linkedin_connect_when_profile_prefers_it() {
+        let (_temp, engine) = test_engine();
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Field operations copilot".to_string(),
+            target_industry: "Field services".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Deniz".to_string(),
+            sender_email: "deniz@machinity.ai".to_string(),
+            linkedin_connect_with_note: true,
+            ..SalesProfile::default()
+        };
+        // The stored B2B profile must not override the run's (campaign) profile.
+        engine
+            .upsert_profile(
+                SalesSegment::B2B,
+                &SalesProfile {
+                    linkedin_connect_with_note: false,
+                    ..profile.clone()
+                },
+            )
+            .expect("save profile");
+
+        let lead = SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            linkedin_url: Some("https://www.linkedin.com/in/aylin-demir/".to_string()),
+            email: None,
+            email_subject: String::new(),
+            email_body: String::new(),
+            linkedin_message: "Merhaba Aylin, saha ekiplerinin iş emri takibi üzerine ".repeat(10),
+            score: 88,
+            ..sample_lead()
+        };
+        engine.insert_lead(&lead, 0).expect("insert lead");
+        assert_eq!(
+            engine
+                .queue_approvals_for_lead(&lead, &profile)
+                .expect("queue approvals"),
+            1
+        );
+
+        let approvals = engine
+            .list_approvals(Some("pending"), 10)
+            .expect("list approvals");
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].channel, "linkedin_connect");
+        assert_eq!(approvals[0].payload["action"], "connect_with_note");
+        let note = approvals[0].payload["message"].as_str().expect("note");
+        assert!(note.chars().count() <= LINKEDIN_CONNECT_NOTE_MAX_CHARS);
+    }
+
+    #[test]
+    fn sender_display_name_rejects_header_injection() {
+        assert!(validate_sender_display_name("Jane Doe").is_ok());
+        assert!(validate_sender_display_name("Ayşe Yılmaz").is_ok());
+        assert!(validate_sender_display_name("Jane\r\nBcc: victim@example.com").is_err());
+
+        let address: Mailbox = "jane@acme.com".parse().expect("address");
+        let from = Mailbox::new(Some("Jane Doe".to_string()), address.email);
+        assert_eq!(from.to_string(), "Jane Doe <jane@acme.com>");
+
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Field operations copilot".to_string(),
+            target_industry: "Field services".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Jane\nDoe".to_string(),
+            sender_email: "jane@acme.com".to_string(),
+            ..SalesProfile::default()
+        };
+        assert!(normalize_sales_profile(profile).is_err());
+    }
+
+    #[test]
+    fn custom_email_headers_are_sanitized_and_prepended() {
+        let mut headers = BTreeMap::new();
+        headers.insert("X-Campaign".to_string(), " spring-2026 ".to_string());
+        let sanitized = sanitize_custom_email_headers(&headers).expect("valid headers");
+        assert_eq!(
+            sanitized,
+            vec![("X-Campaign".to_string(), "spring-2026".to_string())]
+        );
+        let raw = with_custom_email_headers(b"Subject: hi\r\n\r\nbody".to_vec(), &sanitized);
+        assert_eq!(
+            raw,
+            b"X-Campaign: spring-2026\r\nSubject: hi\r\n\r\nbody".to_vec()
+        );
+
+        for (name, value) in [
+            ("X-Campaign", "a\r\nBcc: victim@example.com"),
+            ("X Bad", "ok"),
+            ("Bcc", "victim@example.com"),
+            ("X-Name", "Ayşe"),
+        ] {
+            let headers = BTreeMap::from([(name.to_string(), value.to_string())]);
+            assert!(sanitize_custom_email_headers(&headers).is_err(), "{name}");
+        }
+
+        let mailto: Address = "unsubscribe@acme.com".parse().expect("address");
+        assert_eq!(
+            list_unsubscribe_value("https://app.example/u?token=t", Some(&mailto)),
+            "<mailto:unsubscribe@acme.com?subject=unsubscribe>, <https://app.example/u?token=t>"
+        );
+        assert_eq!(
+            list_unsubscribe_value("https://app.example/u?token=t", None),
+            "<https://app.example/u?token=t>"
+        );
+    }
+
+    #[test]
+    fn edit_lead_validates_contacts_and_rewrites_pending_approvals() {
+        let (_temp, engine) = test_engine();
+
+        let lead = SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            linkedin_url: Some("https://www.linkedin.com/in/aylin-demir/".to_string()),
+            email_subject: "Original subject".to_string(),
+            email_body: "Original body".to_string(),
+            linkedin_message: "Original LinkedIn".to_string(),
+            score: 91,
+            ..sample_lead()
+        };
+        engine.insert_lead(&lead, 0).expect("insert lead");
+        engine
+            .queue_approvals_for_lead(&lead, &SalesProfile::default())
+            .expect("queue approvals");
+
+        let invalid_email = SalesLeadEditRequest {
+            email: Some("not-an-email".to_string()),
+            ..SalesLeadEditRequest::default()
+        };
+        assert!(engine.edit_lead(&lead.id, &invalid_email).is_err());
+        let invalid_linkedin = SalesLeadEditRequest {
+            linkedin_url: Some("https://www.linkedin.com/company/machinity/".to_string()),
+            ..SalesLeadEditRequest::default()
+        };
+        assert!(engine.edit_lead(&lead.id, &invalid_linkedin).is_err());
+        assert_eq!(
+            engine
+                .edit_lead("missing", &SalesLeadEditRequest::default())
+                .unwrap_err(),
+            "Lead not found"
+        );
+
+        let edit = SalesLeadEditRequest {
+            contact_title: Some("Founder & CEO".to_string()),
+            email: Some(" Aylin.Demir@machinity.ai ".to_string()),
+            email_subject: Some("Corrected subject".to_string()),
+            ..SalesLeadEditRequest::default()
+        };
+        let (edited, approvals_updated) = engine.edit_lead(&lead.id, &edit).expect("edit lead");
+        assert_eq!(approvals_updated, 1);
+        assert_eq!(edited.contact_title, "Founder & CEO");
+        assert_eq!(edited.email.as_deref(), Some("aylin.demir@machinity.ai"));
+
+        let approvals = engine
+            .list_approvals(Some("pending"), 10)
+            .expect("list approvals");
+        let email = approvals
+            .iter()
+            .find(|item| item.channel == "email")
+            .expect("email approval");
+        assert_eq!(email.payload["to"], "aylin.demir@machinity.ai");
+        assert_eq!(email.payload["subject"], "Corrected subject");
+        assert_eq!(email.payload["body"], "Original body");
+        let linkedin = approvals
+            .iter()
+            .find(|item| item.channel != "email")
+            .expect("linkedin approval");
+        assert_eq!(linkedin.payload["message"], "Original LinkedIn");
+    }
+
+    #[test]
+    fn run_funnel_explains_empty_runs_upstream_first() {
+        let empty = SalesRunFunnel {
+            llm_discovery_error: Some("timed out".to_string()),
+            search_unavailable: true,
+            ..SalesRunFunnel::default()
+        };
+        let codes = empty
+            .diagnostics()
+            .into_iter()
+            .map(|diagnostic| diagnostic.code)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                "llm_discovery_failed",
+                "search_unavailable",
+                "no_candidates_discovered"
+            ]
+        );
+
+        let filtered = SalesRunFunnel {
+            discovered_by_source: 12,
+            filtered_input: 9,
+            min_score: 18,
+            below_min_score: 7,
+            profile_filtered: 2,
+            ..SalesRunFunnel::default()
+        };
+        let diagnostics = filtered.diagnostics();
+        assert_eq!(diagnostics[0].code, "below_min_score");
+        assert!(diagnostics[0].message.contains("7 of 9"));
+        assert_eq!(diagnostics[1].code, "profile_filter");
+        let summary = sales_run_diagnostics_summary(&diagnostics).expect("summary");
+        assert!(summary.starts_with("Likely causes: 7 of 9 companies"));
+        assert!(sales_run_diagnostics_summary(&[]).is_none());
+
+        let telemetry = SalesRunTelemetry {
+            diagnostics,
+            ..SalesRunTelemetry::default()
+        };
+        let json = serde_json::to_value(&telemetry).expect("serialize");
+        assert_eq!(json["diagnostics"][1]["code"], "profile_filter");
+        let quiet = serde_json::to_value(SalesRunTelemetry::default()).expect("serialize");
+        assert!(quiet.get("diagnostics").is_none());
+    }
+
+    #[test]
+    fn campaigns_scope_profiles_runs_and_outreach() {
+        let (_temp, engine) = test_engine();
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Field operations copilot".to_string(),
+            target_industry: "Field services".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Deniz".to_string(),
+            sender_email: "deniz@machinity.ai".to_string(),
+            ..SalesProfile::default()
+        };
+
+        let campaigns = engine.list_campaigns().expect("list campaigns");
+        assert_eq!(campaigns.len(), 1);
+        assert_eq!(campaigns[0].id, DEFAULT_SALES_CAMPAIGN_ID);
+        engine
+            .upsert_profile(SalesSegment::B2B, &profile)
+            .expect("save legacy profile");
+        assert_eq!(
+            engine
+                .get_campaign_profile(DEFAULT_SALES_CAMPAIGN_ID)
+                .expect("default profile")
+                .map(|p| p.product_name),
+            Some("Machinity".to_string())
+        );
+
+        assert!(engine
+            .create_campaign(&SalesCampaignRequest {
+                name: Some("  ".to_string()),
+                ..SalesCampaignRequest::default()
+            })
+            .is_err());
+        let campaign = engine
+            .create_campaign(&SalesCampaignRequest {
+                name: Some(" Germany logistics ".to_string()),
+                profile: Some(SalesProfile {
+                    target_industry: "Logistics".to_string(),
+                    target_geo: "de".to_string(),
+                    ..profile.clone()
+                }),
+                ..SalesCampaignRequest::default()
+            })
+            .expect("create campaign");
+        assert_eq!(campaign.name, "Germany logistics");
+        assert!(campaign.active);
+        assert_eq!(campaign.profile.as_ref().unwrap().target_geo, "DE");
+        assert_eq!(
+            engine
+                .get_profile(SalesSegment::B2B)
+                .unwrap()
+                .unwrap()
+                .target_geo,
+            "TR"
+        );
+
+        let run_id = engine
+            .begin_campaign_run(SalesSegment::B2B, &campaign.id)
+            .expect("campaign run");
+        let lead = SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            run_id: run_id.clone(),
+            company: "Kargo Lojistik".to_string(),
+            website: "https://kargo.example".to_string(),
+            company_domain: "kargo.example".to_string(),
+            contact_name: "Jonas Weber".to_string(),
+            email: Some("jonas@kargo.example".to_string()),
+            reasons: vec!["Fleet expansion".to_string()],
+            score: 80,
+            ..sample_lead()
+        };
+        engine.insert_lead(&lead, 0).expect("insert lead");
+        engine
+            .queue_approvals_for_lead(&lead, &SalesProfile::default())
+            .expect("queue approvals");
+        let approval = engine
+            .list_campaign_approvals(&campaign.id, Some("pending"), 10)
+            .expect("campaign approvals")
+            .pop()
+            .expect("approval tagged with campaign");
+        engine
+            .record_delivery(&approval.id, "email", "jonas@kargo.example", "sent", None)
+            .expect("delivery");
+        engine
+            .finish_run(&run_id, "completed", 1, 1, 1, None)
+            .expect("finish run");
+
+        assert_eq!(
+            engine.list_campaign_runs(&campaign.id, 10).unwrap().len(),
+            1
+        );
+        assert_eq!(
+            engine
+                .list_campaign_leads(&campaign.id, None, 10)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            engine
+                .list_campaign_deliveries(&campaign.id, 10)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(engine
+            .list_campaign_leads(DEFAULT_SALES_CAMPAIGN_ID, None, 10)
+            .unwrap()
+            .is_empty());
+        assert!(engine
+            .list_campaign_runs(DEFAULT_SALES_CAMPAIGN_ID, 10)
+            .unwrap()
+            .is_empty());
+        assert_eq!(engine.list_leads(10, None).unwrap().len(), 1);
+        assert!(engine
+            .campaign_already_ran_today(&campaign.id, "utc")
+            .unwrap());
+        assert!(!engine
+            .campaign_already_ran_today(DEFAULT_SALES_CAMPAIGN_ID, "utc")
+            .unwrap());
+
+        let paused = engine
+            .update_campaign(
+                &campaign.id,
+                &SalesCampaignRequest {
+                    active: Some(false),
+                    ..SalesCampaignRequest::default()
+                },
+            )
+            .expect("pause campaign");
+        assert!(!paused.active);
+        assert_eq!(paused.name, "Germany logistics");
+        assert_eq!(
+            engine
+                .update_campaign("missing", &SalesCampaignRequest::default())
+                .unwrap_err(),
+            "Campaign not found"
+        );
+    }
+
+    #[test]
+    fn deliveries_atom_feed_escapes_and_lists_entries() {
+        let deliveries = vec![
+            SalesDelivery {
+                id: "d-2".to_string(),
+                approval_id: "ap-2".to_string(),
+                channel: "email".to_string(),
+                recipient: "Ops <ops@acme.example>".to_string(),
+                status: "failed".to_string(),
+                error: Some("550 mailbox & domain unknown".to_string()),
+                sent_at: "2026-03-27T09:00:00+00:00".to_string(),
+                attachment_count: 0,
+            },
+            SalesDelivery {
+                id: "d-1".to_string(),
+                approval_id: "ap-1".to_string(),
+                channel: "linkedin".to_string(),
+                recipient: "https://www.linkedin.com/in/aylin-demir/".to_string(),
+                status: "sent".to_string(),
+                error: None,
+                sent_at: "2026-03-26T09:00:00+00:00".to_string(),
+                attachment_count: 0,
+            },
+        ];
+
+        let feed = render_deliveries_atom_feed(&deliveries, "/api/sales/deliveries/feed?limit=2");
+        assert!(feed.starts_with("<?xml version=\"1.0\""));
+        assert!(feed.contains("<updated>2026-03-27T09:00:00+00:00</updated>"));
+        assert_eq!(feed.matches("<entry>").count(), 2);
+        assert!(feed.contains("<title>email to Ops &lt;ops@acme.example&gt;: failed</title>"));
+        assert!(feed.contains("Error: 550 mailbox &amp; domain unknown"));
+        assert!(feed.contains("<id>urn:pulsivo-salesman:delivery:d-1</id>"));
+        assert!(!feed.contains("<ops@"));
+
+        let empty = render_deliveries_atom_feed(&[], "/api/sales/deliveries/feed");
+        assert!(!empty.contains("<entry>"));
+        assert!(empty.trim_end().ends_with("</feed>"));
+    }
+
+    #[test]
+    fn send_window_uses_target_geo_time_and_reports_next_opening() {
+        let at = |value: &str| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        assert_eq!(geo_utc_offset_minutes("TR"), Some(180));
+        assert_eq!(geo_utc_offset_minutes("Munich, Germany"), Some(60));
+        assert_eq!(geo_utc_offset_minutes("Atlantis"), None);
+
+        let mut profile = SalesProfile {
+            target_geo: "TR".to_string(),
+            ..SalesProfile::default()
+        };
+        assert!(SendWindow::from_profile(&profile).is_none());
+
+        profile.business_hours_start = 9;
+        profile.business_hours_end = 18;
+        profile.business_days = vec![1, 2, 3, 4, 5];
+        let window = SendWindow::from_profile(&profile).expect("window");
+        // Wednesday 07:00 UTC is 10:00 in Istanbul.
+        assert!(window.allows(at("2026-03-25T07:00:00Z")));
+        // Wednesday 16:30 UTC is 19:30 in Istanbul: next opening is Thursday 09:00.
+        assert!(!window.allows(at("2026-03-25T16:30:00Z")));
+        assert_eq!(
+            window.next_open(at("2026-03-25T16:30:00Z")),
+            Some(at("2026-03-26T06:00:00Z"))
+        );
+        // Friday evening rolls over the weekend to Monday 09:00.
+        assert_eq!(
+            window.next_open(at("2026-03-27T20:00:00Z")),
+            Some(at("2026-03-30T06:00:00Z"))
+        );
+
+        profile.business_hours_start = 22;
+        profile.business_hours_end = 2;
+        profile.business_days.clear();
+        let overnight = SendWindow::from_profile(&profile).expect("overnight window");
+        assert!(overnight.allows(at("2026-03-25T20:30:00Z")));
+        assert!(!overnight.allows(at("2026-03-25T12:00:00Z")));
+    }
+
+    #[test]
+    fn per_company_cap_keeps_highest_scoring_sibling_domains() {
+        let candidate = |domain: &str, score: i32| DomainCandidate {
+            domain: domain.to_string(),
+            score,
+            evidence: Vec::new(),
+            matched_keywords: Vec::new(),
+            source_links: Vec::new(),
+            phone: None,
+            evidence_urls: HashMap::new(),
+        };
+        let original = vec![
+            candidate("acme.io", 40),
+            candidate("globex.com", 35),
+            candidate("acme.com", 70),
+            candidate("acme.co", 55),
+            candidate("initech.com.tr", 20),
+        ];
+
+        let mut one_each = original.clone();
+        assert_eq!(cap_candidates_per_company(&mut one_each, 1), 2);
+        let domains = one_each
+            .iter()
+            .map(|c| c.domain.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(domains, vec!["globex.com", "acme.com", "initech.com.tr"]);
+
+        let mut two_each = original.clone();
+        assert_eq!(cap_candidates_per_company(&mut two_each, 2), 1);
+        assert!(two_each.iter().all(|c| c.domain != "acme.io"));
+
+        let unset = SalesSearchLimits::from_profile(&SalesProfile::default());
+        assert_eq!(unset.per_company(), 1);
+        let widened = SalesSearchLimits::from_profile(&SalesProfile {
+            max_candidates_per_company: 3,
+            ..SalesProfile::default()
+        });
+        assert_eq!(widened.per_company(), 3);
+    }
+
+    /// Driver that replays a fixed script of replies, one per call.
+    struct ScriptedAutofillDriver {
+        replies: std::sync::Mutex<std::collections::VecDeque<Result<String, LlmError>>>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl pulsivo_salesman_runtime::llm_driver::LlmDriver for ScriptedAutofillDriver {
+        async fn complete(&self, _req: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let reply = self
+                .replies
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| Err(LlmError::Http("script exhausted".to_string())));
+            reply.map(|text| CompletionResponse {
+                content: vec![pulsivo_salesman_types::message::ContentBlock::Text { text }],
+                stop_reason: pulsivo_salesman_types::message::StopReason::EndTurn,
+                tool_calls: Vec::new(),
+                usage: Default::default(),
+            })
+        }
+    }
+
+    fn scripted_autofill_chain(
+        replies: Vec<Result<String, LlmError>>,
+    ) -> (ModelFallbackChain, Arc<ScriptedAutofillDriver>) {
+        let driver = Arc::new(ScriptedAutofillDriver {
+            replies: std::sync::Mutex::new(replies.into()),
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let factory_driver = driver.clone();
+        let config = DriverConfig {
+            provider: "scripted".to_string(),
+            api_key: None,
+            base_url: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        };
+        let chain = ModelFallbackChain::new(vec![ModelCandidate::new("scripted-model", config)])
+            .with_driver_factory(Arc::new(move |_| {
+                Ok(factory_driver.clone()
+                    as Arc<dyn pulsivo_salesman_runtime::llm_driver::LlmDriver>)
+            }));
+        (chain, driver)
+    }
+
+    #[tokio::test]
+    async fn autofill_repairs_within_bound_and_classifies_failures() {
+        let brief = "We sell field service scheduling software to HVAC contractors in Turkey.";
+        let valid = r#"{"product_name":"Dispatchly","target_industry":"HVAC Contractors"}"#;
+
+        let (chain, driver) = scripted_autofill_chain(vec![
+            Ok("not json".to_string()),
+            Ok("still not json".to_string()),
+            Ok(valid.to_string()),
+        ]);
+        let draft = autofill_profile_with_driver(
+            &chain,
+            brief,
+            SalesSegment::B2B,
+            PROFILE_AUTOFILL_SAMPLING,
+            2,
+        )
+        .await
+        .expect("second repair parses");
+        assert_eq!(draft.product_name.as_deref(), Some("Dispatchly"));
+        assert_eq!(driver.calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        let (chain, driver) = scripted_autofill_chain(vec![
+            Ok("not json".to_string()),
+            Ok("still not json".to_string()),
+            Ok(valid.to_string()),
+        ]);
+        let err = autofill_profile_with_driver(
+            &chain,
+            brief,
+            SalesSegment::B2B,
+            PROFILE_AUTOFILL_SAMPLING,
+            1,
+        )
+        .await
+        .expect_err("repair budget exhausted");
+        assert!(matches!(err, SalesAutofillError::Unparseable(_)));
+        assert!(err.to_string().starts_with("LLM output unparseable"));
+        assert_eq!(driver.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        let (chain, _) =
+            scripted_autofill_chain(vec![Err(LlmError::Http("connection refused".to_string()))]);
+        let err = autofill_profile_with_driver(
+            &chain,
+            brief,
+            SalesSegment::B2B,
+            PROFILE_AUTOFILL_SAMPLING,
+            3,
+        )
+        .await
+        .expect_err("provider down");
+        assert!(matches!(err, SalesAutofillError::Unreachable(_)));
+        assert!(err.to_string().contains("connection refused"));
+
+        let (chain, _) = scripted_autofill_chain(vec![
+            Ok("not json".to_string()),
+            Err(LlmError::Http("reset by peer".to_string())),
+        ]);
+        let err = autofill_profile_with_driver(
+            &chain,
+            brief,
+            SalesSegment::B2B,
+            PROFILE_AUTOFILL_SAMPLING,
+            3,
+        )
+        .await
+        .expect_err("repair call fails");
+        assert!(matches!(err, SalesAutofillError::Unreachable(_)));
+    }
+
+    #[test]
+    fn csv_export_negotiates_and_escapes_rows() {
+        let accept = |value: &str| {
+            let mut headers = axum::http::HeaderMap::new();
+            headers.insert(
+                axum::http::header::ACCEPT,
+                axum::http::HeaderValue::from_str(value).unwrap(),
+            );
+            headers
+        };
+        assert!(!accepts_csv(&axum::http::HeaderMap::new()));
+        assert!(!accepts_csv(&accept("*/*")));
+        assert!(accepts_csv(&accept("text/csv")));
+        assert!(accepts_csv(&accept("text/csv, application/json;q=0.5")));
+        assert!(!accepts_csv(&accept("application/json, text/csv;q=0.5")));
+        assert!(!accepts_csv(&accept("text/csv;q=0")));
+
+        let delivery = SalesDelivery {
+            id: "d1".to_string(),
+            approval_id: "a1".to_string(),
+            channel: "email".to_string(),
+            recipient: "Şirket \"Ana\", Ltd <ceo@ornek.com.tr>".to_string(),
+            status: "failed".to_string(),
+            error: Some("=HYPERLINK(\"x\")\nretry".to_string()),
+            sent_at: "2026-03-25T09:00:00Z".to_string(),
+            attachment_count: 0,
+        };
+        let csv = render_sales_csv(&[delivery]);
+        assert!(csv.starts_with(
+            "\u{FEFF}id,approval_id,channel,recipient,status,error,sent_at,attachment_count\r\n"
+        ));
+        assert!(csv.contains("\"Şirket \"\"Ana\"\", Ltd <ceo@ornek.com.tr>\""));
+        assert!(csv.contains("\"'=HYPERLINK(\"\"x\"\")\nretry\""));
+        assert!(csv.ends_with("2026-03-25T09:00:00Z,0\r\n"));
+    }
+
+    #[test]
+    fn dedupe_window_reengages_stale_leads_and_suppresses_recent_contact() {
+        let (_temp, engine) = test_engine();
+        let days_ago = |days: i64| (Utc::now() - chrono::Duration::days(days)).to_rfc3339();
+        let lead = |id: &str, domain: &str, run_id: &str, created_at: String| SalesLead {
+            id: id.to_string(),
+            run_id: run_id.to_string(),
+            company: "Acme".to_string(),
+            website: format!("https://{domain}"),
+            company_domain: domain.to_string(),
+            contact_name: "Ayse Kaya".to_string(),
+            contact_title: "COO".to_string(),
+            linkedin_url: None,
+            email: None,
+            phone: None,
+            reasons: Vec::new(),
+            email_subject: format!("Draft from {run_id}"),
+            email_body: String::new(),
+            linkedin_message: String::new(),
+            score: 50,
+            status: "activation_candidate".to_string(),
+            created_at,
+            external_id: None,
+            contact_confidence: None,
+        };
+
+        for (id, domain) in [("stale", "acme.example"), ("contacted", "globex.example")] {
+            assert_eq!(
+                engine
+                    .insert_lead(&lead(id, domain, "run-old", days_ago(60)), 0)
+                    .expect("seed"),
+                LeadWriteOutcome::Inserted
+            );
+        }
+        let conn = engine.open().expect("open");
+        conn.execute(
+            "INSERT INTO approvals (id, lead_id, channel, payload_json, status, created_at) VALUES ('appr-1', 'contacted', 'email', '{}', 'approved', ?1)",
+            params![days_ago(6)],
+        )
+        .expect("approval");
+        conn.execute(
+            "INSERT INTO deliveries (id, approval_id, channel, recipient, status, error, sent_at) VALUES ('del-1', 'appr-1', 'email', 'ayse@globex.example', 'sent', NULL, ?1)",
+            params![days_ago(5)],
+        )
+        .expect("delivery");
+
+        // All-time dedup stays the default.
+        assert_eq!(
+            engine
+                .insert_lead(&lead("again", "acme.example", "run-new", days_ago(0)), 0)
+                .expect("all-time"),
+            LeadWriteOutcome::Duplicate
+        );
+        // A delivery inside the window suppresses the domain.
+        assert_eq!(
+            engine
+                .insert_lead(&lead("again", "globex.example", "run-new", days_ago(0)), 30)
+                .expect("recent delivery"),
+            LeadWriteOutcome::Duplicate
+        );
+        // Nothing inside the window: the old row is refreshed in place.
+        assert_eq!(
+            engine
+                .insert_lead(&lead("again", "acme.example", "run-new", days_ago(0)), 30)
+                .expect("stale"),
+            LeadWriteOutcome::Reengaged {
+                id: "stale".to_string()
+            }
+        );
+        let stored = engine
+            .get_lead_by_id("stale")
+            .expect("load")
+            .expect("lead exists");
+        assert_eq!(stored.run_id, "run-new");
+        assert_eq!(stored.email_subject, "Draft from run-new");
+        assert!(engine.get_lead_by_id("again").expect("load").is_none());
+        // The refreshed lead now counts as recent.
+        assert_eq!(
+            engine
+                .insert_lead(&lead("third", "acme.example", "run-3", days_ago(0)), 30)
+                .expect("recent lead"),
+            LeadWriteOutcome::Duplicate
+        );
+    }
+
+    #[test]
+    fn evidence_snippets_keep_their_source_urls_through_reasons_and_copy() {
+        let sample = r#"
+1. Acme Field Services
+   URL: https://acme-field.com/about
+   Acme dispatches 40 technicians across Istanbul
+2. Acme Careers
+   URL: https://acme-field.com/careers
+   Hiring a field service coordinator
+"#;
+        let limits = SalesReasonLimits::from_profile(&SalesProfile::default());
+        let mut candidates = HashMap::new();
+        collect_domain_candidates_from_search(
+            sample,
+            &mut candidates,
+            &[],
+            &[],
+            "TR",
+            true,
+            false,
+            limits,
+        );
+        let candidate = candidates.get("acme-field.com").expect("candidate").clone();
+        assert_eq!(candidate.evidence.len(), 2);
+        assert_eq!(
+            candidate.evidence_url(&candidate.evidence[0]),
+            Some("https://acme-field.com/about")
+        );
+        assert_eq!(
+            candidate.evidence_url(&candidate.evidence[1]),
+            Some("https://acme-field.com/careers")
+        );
+
+        // A single-source directory candidate lends its listing URL to its
+        // snippet when merged with search results.
+        let directory = DomainCandidate {
+            domain: "acme-field.com".to_string(),
+            evidence: vec!["Listed as a member of the field services association".to_string()],
+            source_links: vec!["https://association.example/members".to_string()],
+            ..DomainCandidate::default()
+        };
+        let merged = dedupe_domain_candidates(vec![candidate, directory]);
+        let merged = &merged[0];
+        assert_eq!(
+            merged.evidence_url("Listed as a member of the field services association"),
+            Some("https://association.example/members")
+        );
+        assert_eq!(
+            merged.evidence_url(&merged.evidence[1]),
+            Some("https://acme-field.com/careers")
+        );
+
+        let profile = SalesProfile {
+            product_name: "Pulsivo".to_string(),
+            product_description: "dispatch coordination".to_string(),
+            cite_evidence_source: true,
+            ..SalesProfile::default()
+        };
+        let reasons = build_sales_lead_reasons(
+            &profile,
+            "Acme",
+            "field service",
+            &merged.evidence[0],
+            merged.evidence_url(&merged.evidence[0]),
+            None,
+        );
+        assert_eq!(
+            reasons[1],
+            "Public evidence: Acme dispatches 40 technicians across Istanbul \
+             (source: https://acme-field.com/about)"
+        );
+        let body = cite_evidence_source(
+            &profile,
+            "Hi Ayse,\n\nBest,\nDeniz\n",
+            "https://acme-field.com/about",
+        );
+        assert!(body.ends_with("Deniz\n\nSource: https://acme-field.com/about"));
+    }
+
+    #[test]
+    fn min_contact_confidence_keeps_guessed_emails_out_of_the_queue() {
+        let (_temp, engine) = test_engine();
+        let profile = SalesProfile {
+            min_contact_confidence: Some(ContactConfidence::Discovered),
+            ..SalesProfile::default()
+        };
+        let lead = |domain: &str, confidence: Option<ContactConfidence>| SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            company: domain.to_string(),
+            website: format!("https://{domain}"),
+            company_domain: domain.to_string(),
+            linkedin_url: Some(format!("https://www.linkedin.com/in/aylin-{domain}/")),
+            email: Some(format!("aylin.demir@{domain}")),
+            reasons: Vec::new(),
+            email_subject: "Hello".to_string(),
+            linkedin_message: "Merhaba Aylin".to_string(),
+            score: 70,
+            contact_confidence: confidence,
+            ..sample_lead()
+        };
+
+        let guessed = lead("guessed.example", Some(ContactConfidence::Guessed));
+        engine.insert_lead(&guessed, 0).expect("insert guessed");
+        let stored = engine
+            .get_lead_by_id(&guessed.id)
+            .expect("load")
+            .expect("lead exists");
+        assert_eq!(stored.contact_confidence, Some(ContactConfidence::Guessed));
+        assert_eq!(
+            engine
+                .queue_approvals_for_lead(&stored, &profile)
+                .expect("queue"),
+            1
+        );
+
+        for confidence in [Some(ContactConfidence::Verified), None] {
+            let lead = lead(
+                &format!("{}.example", confidence.map_or("imported", |c| c.as_str())),
+                confidence,
+            );
+            engine.insert_lead(&lead, 0).expect("insert");
+            assert_eq!(
+                engine
+                    .queue_approvals_for_lead(&lead, &profile)
+                    .expect("queue"),
+                2
+            );
+        }
+
+        let approvals = engine
+            .list_approvals(Some("pending"), 10)
+            .expect("list approvals");
+        assert!(!approvals.iter().any(|approval| approval.channel == "email"
+            && approval.payload["to"] == "aylin.demir@guessed.example"));
+        assert_eq!(
+            approvals
+                .iter()
+                .filter(|approval| approval.channel == "email")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn run_records_keep_evaluated_qualified_and_inserted_apart() {
+        let (_temp, engine) = test_engine();
+
+        let run_id = engine.begin_run(SalesSegment::B2B).expect("begin run");
+        engine
+            .record_run_funnel(&run_id, 12, 5)
+            .expect("record funnel");
+        engine
+            .finish_run(&run_id, "completed", 5, 2, 1, None)
+            .expect("finish run");
+
+        let runs = engine.list_runs(SalesSegment::B2B, 5).expect("list runs");
+        assert_eq!(runs[0].candidates_evaluated, 12);
+        assert_eq!(runs[0].candidates_qualified, 5);
+        assert_eq!(runs[0].discovered, 5);
+        assert_eq!(runs[0].inserted, 2);
+        assert_eq!(runs[0].approvals_queued, 1);
+    }
+
+    #[test]
+    fn blank_industry_falls_through_to_last_resort_product_keyword_plan() {
+        let profile = SalesProfile {
+            product_name: "Ledgerly".to_string(),
+            product_description: "Invoice reconciliation and cash forecasting for distributors"
+                .to_string(),
+            target_industry: "  ".to_string(),
+            target_geo: "DE".to_string(),
+            ..SalesProfile::default()
+        };
+        assert!(heuristic_lead_query_plan(&profile)
+            .discovery_queries
+            .is_empty());
+
+        let resolved =
+            heuristic_or_last_resort_plan(&profile, "Lead query planner timed out".to_string());
+        assert_eq!(resolved.source, "last_resort");
+        assert!(resolved
+            .fallback_reason
+            .as_deref()
+            .is_some_and(|reason| reason.starts_with("Lead query planner timed out")));
+        assert!(resolved
+            .plan
+            .discovery_queries
+            .iter()
+            .any(|q| q.contains("reconciliation") && q.ends_with("DE")));
+        assert!(resolved
+            .plan
+            .must_include_keywords
+            .iter()
+            .any(|kw| kw == "invoice"));
+        assert!(!resolved.plan.exclude_keywords.is_empty());
+
+        let empty = last_resort_lead_query_plan(&SalesProfile::default());
+        assert!(!empty.discovery_queries.is_empty());
+    }
+
+    #[test]
+    fn lead_detail_joins_approvals_and_their_deliveries() {
+        let (_temp, engine) = test_engine();
+
+        let lead = SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            linkedin_url: Some("https://www.linkedin.com/in/aylin-demir/".to_string()),
+            score: 91,
+            ..sample_lead()
+        };
+        engine.insert_lead(&lead, 0).expect("insert lead");
+        let queued = engine
+            .queue_approvals_for_lead(&lead, &SalesProfile::default())
+            .expect("queue approvals");
+        assert!(queued >= 1);
+
+        let detail = engine
+            .lead_detail(&lead.id)
+            .expect("lead detail")
+            .expect("lead exists");
+        assert_eq!(detail.lead.id, lead.id);
+        assert_eq!(detail.approvals.len(), queued as usize);
+        assert!(detail.deliveries.is_empty());
+
+        let approval = &detail.approvals[0];
+        engine
+            .record_delivery(
+                &approval.id,
+                &approval.channel,
+                "aylin@machinity.ai",
+                "sent",
+                None,
+            )
+            .expect("record delivery");
+        engine
+            .record_delivery("unrelated-approval", "email", "x@example.com", "sent", None)
+            .expect("record unrelated delivery");
+
+        let detail = engine
+            .lead_detail(&lead.id)
+            .expect("lead detail")
+            .expect("lead exists");
+        assert_eq!(detail.deliveries.len(), 1);
+        assert_eq!(detail.deliveries[0].approval_id, approval.id);
+
+        assert!(engine.lead_detail("missing").expect("lookup").is_none());
+    }
+
+    #[test]
+    fn sales_llm_sampling_applies_overrides_within_model_limits() {
+        let defaults = COMPANY_CANDIDATE_SAMPLING;
+        assert_eq!(
+            defaults.with_overrides(&SalesLlmStepConfig::default(), 0),
+            defaults
+        );
+
+        let step = SalesLlmStepConfig {
+            temperature: Some(0.6),
+            max_tokens: Some(16_000),
+        };
+        let tuned = defaults.with_overrides(&step, 8_192);
+        assert_eq!(tuned.temperature, 0.6);
+        assert_eq!(tuned.max_tokens, 8_192);
+        assert_eq!(defaults.with_overrides(&step, 0).max_tokens, 16_000);
+        assert_eq!(
+            defaults
+                .with_overrides(&SalesLlmStepConfig::default(), 1_000)
+                .max_tokens,
+            1_000
+        );
+    }
+
+    #[test]
+    fn preview_outreach_copy_matches_run_rendering_without_persistence() {
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Saha ekipleri için iş emri ve dispatch koordinasyonu".to_string(),
+            target_industry: "Field service operations".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Deniz".to_string(),
+            cite_evidence_source: true,
+            ..SalesProfile::default()
+        };
+        let request = SalesCopyPreviewRequest {
+            domain: "https://www.ornek-yapi.com.tr/".to_string(),
+            contact_name: Some("Ayşe Yılmaz".to_string()),
+            contact_title: Some("Operasyon Direktörü".to_string()),
+            evidence: Some("Şantiye ekipleri 40+ teknisyene büyüyor; ".repeat(20)),
+            evidence_url: Some("https://ornek-yapi.com.tr/kariyer".to_string()),
+            ..SalesCopyPreviewRequest::default()
+        };
+
+        let preview = preview_outreach_copy(&profile, &request).expect("preview");
+        assert_eq!(preview.domain, "ornek-yapi.com.tr");
+        assert_eq!(preview.company, "Ornek Yapi");
+        let evidence = request.evidence.as_deref().unwrap();
+        assert_eq!(
+            preview.copy,
+            render_outreach_copy(
+                &profile,
+                "Ornek Yapi",
+                Some("Ayşe Yılmaz"),
+                &profile.target_industry,
+                evidence,
+                request.evidence_url.as_deref(),
+            )
+        );
+        assert!(preview
+            .copy
+            .email_body
+            .ends_with("https://ornek-yapi.com.tr/kariyer"));
+        assert!(preview.copy.linkedin_message.chars().count() <= 300);
+        assert!(preview
+            .reasons
+            .iter()
+            .any(|reason| reason.contains("Operasyon Direktörü")));
+
+        let err = preview_outreach_copy(&profile, &SalesCopyPreviewRequest::default())
+            .expect_err("company or domain required");
+        assert!(err.contains("company or domain"));
+    }
+
+    #[test]
+    fn block_lead_suppresses_rejects_pending_and_archives() {
+        let (_temp, engine) = test_engine();
+
+        let lead = SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            email: Some("Aylin@Machinity.ai".to_string()),
+            score: 91,
+            ..sample_lead()
+        };
+        engine.insert_lead(&lead, 0).expect("insert lead");
+        let queued = engine
+            .queue_approvals_for_lead(&lead, &SalesProfile::default())
+            .expect("queue approvals");
+        assert!(queued >= 1);
+
+        let block = engine
+            .block_lead(&lead.id, Some("Replied: not interested"))
+            .expect("block lead");
+        assert_eq!(block.suppression.contact_method_value, "aylin@machinity.ai");
+        assert_eq!(block.suppression.reason, "do_not_contact");
+        assert!(block.suppression.permanent);
+        assert_eq!(block.approvals_rejected, queued as usize);
+        let change = block.status_change.expect("status change");
+        assert_eq!(change.from_status, "draft_ready");
+        assert_eq!(change.to_status, "archived");
+        assert_eq!(change.note.as_deref(), Some("Replied: not interested"));
+
+        let detail = engine
+            .lead_detail(&lead.id)
+            .expect("lead detail")
+            .expect("lead exists");
+        assert_eq!(detail.lead.status, "archived");
+        assert!(detail.approvals.iter().all(|a| a.status == "rejected"));
+        let conn = engine.open().expect("open");
+        assert!(engine
+            .is_suppressed(&conn, "aylin@machinity.ai")
+            .expect("lookup"));
+
+        let again = engine.block_lead(&lead.id, None).expect("block again");
+        assert_eq!(again.suppression.id, block.suppression.id);
+        assert_eq!(again.approvals_rejected, 0);
+        assert!(again.status_change.is_none());
+
+        assert_eq!(
+            engine.block_lead("missing", None).unwrap_err(),
+            "Lead not found"
+        );
+    }
+
+    #[test]
+    fn domain_suppression_blocks_every_address_at_that_domain() {
+        let (_temp, engine) = test_engine();
+        let conn = engine.open().expect("open");
+        engine
+            .suppress_contact(&conn, "acme.com", "do_not_contact", true, None)
+            .expect("suppress domain");
+        assert!(engine.is_suppressed(&conn, "ops@acme.com").expect("lookup"));
+        assert!(engine.is_suppressed(&conn, "acme.com").expect("lookup"));
+        assert!(!engine
+            .is_suppressed(&conn, "ops@acme.com.tr")
+            .expect("lookup"));
+    }
+
+    #[test]
+    fn expire_stale_approvals_only_touches_old_pending_rows() {
+        let (_temp, engine) = test_engine();
+
+        let make_lead = |company: &str, domain: &str, email: &str| SalesLead {
+            id: uuid::Uuid::new_v4().to_string(),
+            company: company.to_string(),
+            website: format!("https://{domain}"),
+            company_domain: domain.to_string(),
+            email: Some(email.to_string()),
+            score: 91,
+            ..sample_lead()
+        };
+        let stale = make_lead("Machinity", "machinity.ai", "aylin@machinity.ai");
+        let fresh = make_lead("Fieldly", "fieldly.io", "aylin@fieldly.io");
+        for lead in [&stale, &fresh] {
+            engine.insert_lead(lead, 0).expect("insert lead");
+            assert_eq!(
+                engine
+                    .queue_approvals_for_lead(lead, &SalesProfile::default())
+                    .expect("queue"),
+                1
+            );
+        }
+        let conn = engine.open().expect("open");
+        conn.execute(
+            "UPDATE approvals SET created_at = ?2 WHERE lead_id = ?1",
+            params![
+                stale.id,
+                (Utc::now() - chrono::Duration::days(10)).to_rfc3339()
+            ],
+        )
+        .expect("backdate approval");
+        assert_eq!(engine.count_pending_approvals().expect("count"), 2);
+
+        assert!(engine.expire_stale_approvals(0).is_err());
+        assert_eq!(engine.expire_stale_approvals(7).expect("expire"), 1);
+        assert_eq!(engine.count_pending_approvals().expect("count"), 1);
+        let expired = engine
+            .query_approvals(None, Some(&stale.id), None, 10)
+            .expect("approvals");
+        assert_eq!(expired[0].status, "expired");
+        assert!(expired[0].decided_at.is_some());
+        assert_eq!(engine.expire_stale_approvals(7).expect("expire again"), 0);
+    }
+
+    #[test]
+    fn validate_sales_profile_fields_reports_each_invalid_field() {
+        let profile = SalesProfile {
+            sender_email: "not-an-email".to_string(),
+            target_title_policy: "vp_only".to_string(),
+            schedule_hour_local: 24,
+            timezone_mode: "Europe/Istanbul".to_string(),
+            ..SalesProfile::default()
+        };
+        let errors = validate_sales_profile_fields(&profile);
+        assert_eq!(
+            errors.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec![
+                "schedule_hour_local",
+                "sender_email",
+                "target_title_policy",
+                "timezone_mode"
+            ]
+        );
+        assert!(errors["target_title_policy"].contains("ceo_then_founder, ceo_only"));
+
+        let valid = SalesProfile {
+            sender_email: " founder@machinity.ai ".to_string(),
+            target_title_policy: "ceo_only".to_string(),
+            schedule_hour_local: 23,
+            timezone_mode: " UTC ".to_string(),
+            daily_target: 999,
+            ..SalesProfile::default()
+        };
+        assert!(validate_sales_profile_fields(&valid).is_empty());
+    }
+
+    #[test]
+    fn scheduled_trigger_minute_is_stable_per_day_and_bounded() {
+        let day = chrono::NaiveDate::from_ymd_opt(2026, 3, 26).expect("date");
+        assert_eq!(scheduled_trigger_minute("c1", day, 9, 0), 9 * 60);
+
+        let first = scheduled_trigger_minute("c1", day, 9, 45);
+        assert_eq!(first, scheduled_trigger_minute("c1", day, 9, 45));
+        assert!((9 * 60..=9 * 60 + 45).contains(&first));
+
+        let minutes = (0..30)
+            .map(|offset| {
+                let day = day + chrono::Duration::days(offset);
+                scheduled_trigger_minute("c1", day, 9, 45)
+            })
+            .collect::<HashSet<_>>();
+        assert!(minutes.len() > 1, "jitter should vary across days");
+        assert!(minutes.iter().all(|m| (9 * 60..=9 * 60 + 45).contains(m)));
+
+        // Oversized jitter is capped and late hours never spill into the next day.
+        let late = scheduled_trigger_minute("c1", day, 23, 10_000);
+        assert!((23 * 60..24 * 60 - SCHEDULE_TRIGGER_WINDOW_MINUTES).contains(&late));
+    }
+
+    #[test]
+    fn email_attachments_are_validated_and_capped() {
+        let payload = serde_json::json!({
+            "to": "aylin@machinity.ai",
+            "subject": "Product brief",
+            "body": "One-pager attached.",
+            "attachments": [
+                {"filename": " brief.pdf ", "content_base64": STANDARD.encode(b"%PDF-1.4 brief")},
+                {"filename": "", "path": "/srv/briefs/pricing.xlsx", "content_type": "application/vnd.ms-excel"}
+            ],
+        });
+        let attachments = email_attachments_from_payload(&payload).expect("attachments");
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].filename, "brief.pdf");
+        assert_eq!(attachments[1].filename, "pricing.xlsx");
+        assert_eq!(attachment_content_type("brief.PDF"), "application/pdf");
+        assert_eq!(attachment_content_type("notes"), "application/octet-stream");
+
+        let sanitized = sanitize_approval_payload("email", payload).expect("sanitized");
+        assert_eq!(sanitized["attachments"].as_array().map(Vec::len), Some(2));
+        let plain = sanitize_approval_payload(
+            "email",
+            serde_json::json!({"to": "aylin@machinity.ai", "subject": "Hi", "body": "Hello"}),
+        )
+        .expect("plain payload");
+        assert!(plain.get("attachments").is_none());
+
+        let both_sources = serde_json::json!({"attachments": [
+            {"filename": "a.pdf", "path": "/tmp/a.pdf", "content_base64": "YQ=="}
+        ]});
+        assert!(email_attachments_from_payload(&both_sources).is_err());
+        let bad_name = serde_json::json!({"attachments": [
+            {"filename": "../a.pdf", "content_base64": "YQ=="}
+        ]});
+        assert!(email_attachments_from_payload(&bad_name).is_err());
+
+        let inline = vec![attachments[0].clone()];
+        let no_dir = FsPath::new("/nonexistent");
+        assert_eq!(
+            build_email_attachment_parts(&inline, no_dir, 1024)
+                .expect("within cap")
+                .len(),
+            1
+        );
+        let err = build_email_attachment_parts(&inline, no_dir, 4).expect_err("over cap");
+        assert!(err.contains("4 byte limit"));
+    }
+
+    #[test]
+    fn email_attachment_paths_stay_inside_the_attachments_dir() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let dir = temp.path().join(SALES_ATTACHMENTS_DIR);
+        std::fs::create_dir_all(dir.join("briefs")).expect("mkdir");
+        std::fs::write(dir.join("briefs/brief.pdf"), b"%PDF-1.4 brief").expect("write");
+        std::fs::write(temp.path().join("config.toml"), b"api_key = \"secret\"").expect("write");
+        let by_path = |path: &str| {
+            vec![SalesEmailAttachment {
+                filename: "brief.pdf".to_string(),
+                content_type: None,
+                path: Some(path.to_string()),
+                content_base64: None,
+            }]
+        };
+
+        assert_eq!(
+            build_email_attachment_parts(&by_path("briefs/brief.pdf"), &dir, 1024)
+                .expect("inside dir")
+                .len(),
+            1
+        );
+        let outside = temp.path().join("config.toml");
+        for path in ["../config.toml", outside.to_str().unwrap()] {
+            let err =
+                build_email_attachment_parts(&by_path(path), &dir, 1024).expect_err("outside dir");
+            assert!(err.contains("is outside"), "{err}");
+        }
+        let err =
+            build_email_attachment_parts(&by_path("briefs"), &dir, 1024).expect_err("directory");
+        assert!(err.contains("not a regular file"));
+        let err = build_email_attachment_parts(&by_path("briefs/brief.pdf"), &dir, 4)
+            .expect_err("over cap");
+        assert!(err.contains("4 byte limit"));
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&outside, dir.join("link.pdf")).expect("symlink");
+            assert!(build_email_attachment_parts(&by_path("link.pdf"), &dir, 1024).is_err());
+        }
+    }
+
+    #[test]
+    fn rescore_leads_pages_through_backlog_and_only_writes_changed_scores() {
+        let (_temp, engine) = test_engine();
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Dispatch ve saha operasyon koordinasyonu".to_string(),
+            target_industry: "Construction and field service".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Machinity".to_string(),
+            sender_email: "info@machinity.ai".to_string(),
+            target_title_policy: "ceo_then_founder".to_string(),
+            ..SalesProfile::default()
+        };
+
+        let make_lead = |id: &str, company: &str, domain: &str, reason: &str| SalesLead {
+            id: id.to_string(),
+            company: company.to_string(),
+            website: format!("https://{domain}"),
+            company_domain: domain.to_string(),
+            email: Some(format!("aylin@{domain}")),
+            reasons: vec![reason.to_string()],
+            score: 0,
+            ..sample_lead()
+        };
+        for lead in [
+            make_lead(
+                "lead-a",
+                "Kargo Tesisat",
+                "kargotesisat.com.tr",
+                "Field service and maintenance crews",
+            ),
+            make_lead(
+                "lead-b",
+                "Ornek Market",
+                "ornekmarket.example",
+                "Regional grocery chain",
+            ),
+            make_lead("lead-c", "Daily News", "dailynews.example", "News portal"),
+        ] {
+            engine.insert_lead(&lead, 0).expect("insert lead");
+        }
+        let missing = engine.rescore_leads(None, None, 10).unwrap_err();
+        assert_eq!(missing, RESCORE_PROFILE_MISSING);
+        engine
+            .upsert_profile(SalesSegment::B2B, &profile)
+            .expect("profile");
+
+        let first = engine.rescore_leads(None, None, 2).expect("first page");
+        assert_eq!(first.scanned, 2);
+        assert_eq!(first.changed, 2);
+        assert_eq!(first.next_after.as_deref(), Some("lead-b"));
+        let second = engine
+            .rescore_leads(None, first.next_after.as_deref(), 2)
+            .expect("second page");
+        assert_eq!(second.scanned, 1);
+        assert_eq!(second.next_after, None);
+
+        let score = |id: &str| {
+            engine
+                .get_lead_by_id(id)
+                .expect("lead")
+                .expect("exists")
+                .score
+        };
+        assert!(score("lead-a") > score("lead-b"));
+        assert!(score("lead-b") > score("lead-c"));
+
+        let again = engine
+            .rescore_leads(Some("draft_ready"), None, 10)
+            .expect("rerun");
+        assert_eq!(again.scanned, 3);
+        assert_eq!(again.changed, 0);
+        assert_eq!(
+            engine
+                .rescore_leads(Some("archived"), None, 10)
+                .expect("archived")
+                .scanned,
+            0
+        );
+    }
+
+    #[test]
+    fn email_html_is_sanitized_and_rendered_from_profile_template() {
+        let dirty = r#"<p onclick="x()">Hi <b>there</b></p><script>alert(1)</script><!-- note -->
+<a href="javascript:alert(1)">bad</a> <a class="x" HREF='https://machinity.ai/demo'>demo</a>
+<img src="https://track.example/p.gif"><style>p{}</style>1 < 2"#;
+        let clean = sanitize_email_html(dirty);
+        assert_eq!(
+            clean,
+            "<p>Hi <b>there</b></p>\n<a>bad</a> <a href=\"https://machinity.ai/demo\">demo</a>\n1 &lt; 2"
+        );
+
+        assert_eq!(
+            plain_text_to_html("Hi Aylin,\nquick note <3\n\nBest"),
+            "<p>Hi Aylin,<br>quick note &lt;3</p>\n<p>Best</p>"
+        );
+        assert_eq!(render_email_html("  ", "Hi"), None);
+        assert_eq!(
+            render_email_html(
+                "{{body}}<p>-- <a href=\"https://machinity.ai\">Machinity</a></p>",
+                "Hi & welcome"
+            )
+            .as_deref(),
+            Some("<p>Hi &amp; welcome</p><p>-- <a href=\"https://machinity.ai\">Machinity</a></p>")
+        );
+
+        let payload = sanitize_approval_payload(
+            "email",
+            serde_json::json!({
+                "to": "aylin@machinity.ai",
+                "subject": "Hi",
+                "body": "Hello",
+                "html_body": "<p>Hello</p><script>x</script>",
+            }),
+        )
+        .expect("payload");
+        assert_eq!(payload["html_body"], "<p>Hello</p>");
+
+        let profile = SalesProfile {
+            email_html_template: "<p>No placeholder</p>".to_string(),
+            ..SalesProfile::default()
+        };
+        assert!(validate_sales_profile_fields(&profile).contains_key("email_html_template"));
+    }
+
+    #[test]
+    fn outbound_email_retry_helpers_and_delivery_dedupe() {
+        assert_eq!(
+            outbound_message_id("3f2b-9c<x>", "mail.machinity.ai"),
+            "<pulsivo-3f2b-9cx@mail.machinity.ai>"
+        );
+        assert_eq!(smtp_retry_delay(1), Duration::from_secs(1));
+        assert_eq!(smtp_retry_delay(3), Duration::from_secs(4));
+        assert_eq!(
+            pulsivo_salesman_types::config::EmailConfig::default().send_retries,
+            2
+        );
+
+        let (_temp, engine) = test_engine();
+        engine
+            .record_delivery("ap-1", "email", "b@acme.example", "failed", Some("421"))
+            .expect("failed delivery");
+        assert!(!engine.approval_already_delivered("ap-1").expect("lookup"));
+        engine
+            .record_delivery("ap-1", "email", "b@acme.example", "sent", None)
+            .expect("sent delivery");
+        assert!(engine.approval_already_delivered("ap-1").expect("lookup"));
+        assert!(!engine.approval_already_delivered("ap-2").expect("lookup"));
+    }
+
+    #[test]
+    fn email_verification_verdicts_update_stored_leads() {
+        let (_temp, engine) = test_engine();
+        let guessed = |id: &str, domain: &str| SalesLead {
+            id: id.to_string(),
+            company_domain: domain.to_string(),
+            website: format!("https://{domain}"),
+            email: Some(format!("aylin@{domain}")),
+            contact_confidence: Some(ContactConfidence::Guessed),
+            ..sample_lead()
+        };
+        for lead in [
+            guessed("lead-bounce", "bounce.example"),
+            guessed("lead-ok", "ok.example"),
+        ] {
+            engine.insert_lead(&lead, 0).expect("insert lead");
+        }
+
+        engine
+            .apply_email_verification("lead-bounce", "aylin@bounce.example", None)
+            .expect("drop email");
+        engine
+            .apply_email_verification(
+                "lead-ok",
+                "aylin@ok.example",
+                Some(ContactConfidence::Verified),
+            )
+            .expect("verify email");
+
+        let conn = engine.open().expect("open db");
+        let row = |id: &str| {
+            conn.query_row(
+                "SELECT email, contact_confidence FROM leads WHERE id = ?",
+                params![id],
+                |r| {
+                    Ok((
+                        r.get::<_, Option<String>>(0)?,
+                        r.get::<_, Option<String>>(1)?,
+                    ))
+                },
+            )
+            .expect("lead row")
+        };
+        assert_eq!(row("lead-bounce"), (None, None));
+        assert_eq!(
+            row("lead-ok"),
+            (
+                Some("aylin@ok.example".to_string()),
+                Some("verified".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn rescore_right_after_a_campaign_run_changes_nothing() {
+        let (_temp, engine) = test_engine();
+        let b2b = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Dispatch ve saha operasyon koordinasyonu".to_string(),
+            target_industry: "Construction and field service".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Machinity".to_string(),
+            sender_email: "info@machinity.ai".to_string(),
+            target_title_policy: "ceo_then_founder".to_string(),
+            ..SalesProfile::default()
+        };
+        engine
+            .upsert_profile(SalesSegment::B2B, &b2b)
+            .expect("profile");
+        let campaign = engine
+            .create_campaign(&SalesCampaignRequest {
+                name: Some("Germany logistics".to_string()),
+                profile: Some(SalesProfile {
+                    product_description: "Freight visibility for shippers".to_string(),
+                    target_industry: "Logistics".to_string(),
+                    target_geo: "DE".to_string(),
+                    ..b2b.clone()
+                }),
+                ..SalesCampaignRequest::default()
+            })
+            .expect("create campaign");
+        let campaign_profile = campaign.profile.clone().expect("campaign profile");
+        let run_id = engine
+            .begin_campaign_run(SalesSegment::B2B, &campaign.id)
+            .expect("run");
+
+        // Store leads the way a run does: the discovery score (here the
+        // campaign-profile fit, or an LLM re-ranked 85) and the activation
+        // priority are kept as floors, and the stored score is their max.
+        let fit = |lead: &SalesLead, profile: &SalesProfile| {
+            let plan = heuristic_lead_query_plan(profile);
+            lead_fit_score(lead, profile, &plan, profile_targets_field_ops(profile))
+        };
+        for (id, domain, reason, discovery, activation) in [
+            (
+                "lead-a",
+                "kargotesisat.com.tr",
+                "Field service and maintenance crews",
+                None,
+                0,
+            ),
+            (
+                "lead-b",
+                "spedition.de",
+                "Logistics and freight forwarding in Germany",
+                Some(85),
+                0,
+            ),
+            ("lead-c", "dailynews.example", "News portal", None, 90),
+        ] {
+            let mut lead = SalesLead {
+                id: id.to_string(),
+                run_id: run_id.clone(),
+                company: domain.split('.').next().unwrap_or_default().to_string(),
+                website: format!("https://{domain}"),
+                company_domain: domain.to_string(),
+                email: Some(format!("info@{domain}")),
+                reasons: vec![reason.to_string()],
+                ..sample_lead()
+            };
+            let floors = LeadScoreFloors {
+                discovery: discovery.unwrap_or_else(|| fit(&lead, &campaign_profile)),
+                activation,
+            };
+            lead.score = floors.discovery.max(floors.activation);
+            engine
+                .insert_scored_lead(&lead, 0, floors)
+                .expect("insert lead");
+        }
+
+        let lead_a = engine.get_lead_by_id("lead-a").unwrap().unwrap();
+        assert_ne!(
+            fit(&lead_a, &b2b),
+            lead_a.score,
+            "the stored B2B profile would score this lead differently"
+        );
+        let score = |id: &str| engine.get_lead_by_id(id).unwrap().unwrap().score;
+        assert_eq!(score("lead-b"), 85);
+        assert_eq!(score("lead-c"), 90);
+
+        let rescore = engine.rescore_leads(None, None, 10).expect("rescore");
+        assert_eq!(rescore.scanned, 3);
+        assert_eq!(rescore.changed, 0);
+    }
+
+    #[test]
+    fn email_html_is_rendered_from_the_edited_body_at_send_time() {
+        let (_temp, engine) = test_engine();
+        let profile = SalesProfile {
+            product_name: "Machinity".to_string(),
+            product_description: "Dispatch ve saha operasyon koordinasyonu".to_string(),
+            target_industry: "Construction and field service".to_string(),
+            target_geo: "TR".to_string(),
+            sender_name: "Machinity".to_string(),
+            sender_email: "info@machinity.ai".to_string(),
+            email_html_template: "{{body}}<p>-- Machinity</p>".to_string(),
+            ..SalesProfile::default()
+        };
+        engine
+            .upsert_profile(SalesSegment::B2B, &profile)
+            .expect("profile");
+        let lead = SalesLead {
+            email: Some("aylin@machinity.ai".to_string()),
+            email_body: "Original body".to_string(),
+            ..sample_lead()
+        };
+        engine.insert_lead(&lead, 0).expect("insert lead");
+        engine
+            .queue_approvals_for_lead(&lead, &profile)
+            .expect("queue approvals");
+        let approval = engine
+            .list_approvals(Some("pending"), 10)
+            .expect("list approvals")
+            .into_iter()
+            .find(|item| item.channel == "email")
+            .expect("email approval");
+        assert!(approval.payload.get("html_body").is_none());
+
+        let html_for = |payload: &serde_json::Value| {
+            engine
+                .approval_email_html(
+                    DEFAULT_SALES_CAMPAIGN_ID,
+                    payload,
+                    payload["body"].as_str().expect("body"),
+                )
+                .expect("render")
+        };
+        let edited = engine
+            .edit_approval(
+                &approval.id,
+                serde_json::json!({
+                    "to": "aylin@machinity.ai",
+                    "subject": "Updated subject",
+                    "body": "Updated body",
+                }),
+            )
+            .expect("edit approval");
+        assert!(edited.payload.get("html_body").is_none());
+        assert_eq!(
+            html_for(&edited.payload).as_deref(),
+            Some("<p>Updated body</p><p>-- Machinity</p>")
+        );
+
+        // An explicit html_body is sanitized and takes precedence.
+        let edited = engine
+            .edit_approval(
+                &approval.id,
+                serde_json::json!({
+                    "to": "aylin@machinity.ai",
+                    "subject": "Updated subject",
+                    "body": "Updated body",
+                    "html_body": "<p>Custom <b>layout</b></p><script>x</script>",
+                }),
+            )
+            .expect("edit approval");
+        assert_eq!(
+            html_for(&edited.payload).as_deref(),
+            Some("<p>Custom <b>layout</b></p>")
+        );
+    }
 }
@@ -2,19 +2,24 @@
 
 use crate::codex_oauth;
 use crate::middleware;
+use crate::offline;
 use crate::rate_limiter;
 use crate::routes::{self, AppState};
+use crate::run_limiter::{self, RunLimiter};
 use crate::sales;
 use crate::webchat;
+use axum::extract::DefaultBodyLimit;
+use axum::http::{HeaderName, HeaderValue, Method};
 use axum::routing::{get, patch, post};
 use axum::Router;
 use pulsivo_salesman_kernel::PulsivoSalesmanKernel;
+use pulsivo_salesman_types::config::{CorsConfig, KernelConfig};
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Instant;
 use tower_http::compression::CompressionLayer;
-use tower_http::cors::CorsLayer;
+use tower_http::cors::{self, AllowHeaders, AllowMethods, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing::info;
 
@@ -29,6 +34,11 @@ pub struct DaemonInfo {
 }
 
 /// Build the sales API router and shared state.
+///
+/// Request bodies are capped at `api_limits.max_body_bytes` (1 MiB by default).
+/// Routes that legitimately need more (e.g. bulk imports) can override it with
+/// `post(handler).layer(DefaultBodyLimit::max(n))`; oversized bodies always get
+/// a JSON 413.
 pub async fn build_router(
     kernel: Arc<PulsivoSalesmanKernel>,
     listen_addr: SocketAddr,
@@ -37,62 +47,36 @@ pub async fn build_router(
         kernel: kernel.clone(),
         started_at: Instant::now(),
         shutdown_notify: Arc::new(tokio::sync::Notify::new()),
+        sales_runs: sales::SalesRunSlot::default(),
     });
 
     codex_oauth::initialize_codex_auth(&state).await;
-    let api_key = state.kernel.api_key();
+    let config = state.kernel.config_snapshot();
+    let api_auth = middleware::ApiAuth::from_config(&config);
+    let approval_edit_limit = approval_edit_body_limit(&config);
+    let api_limits = config.api_limits;
+    let run_limiter = Arc::new(RunLimiter::from_config(&api_limits));
 
-    let cors = if api_key.is_empty() {
-        let port = listen_addr.port();
-        let mut origins: Vec<axum::http::HeaderValue> = vec![
-            format!("http://{listen_addr}").parse().unwrap(),
-            format!("http://localhost:{port}").parse().unwrap(),
-        ];
-        for dev_port in [3000u16, 8080] {
-            if dev_port != port {
-                if let Ok(origin) = format!("http://127.0.0.1:{dev_port}").parse() {
-                    origins.push(origin);
-                }
-                if let Ok(origin) = format!("http://localhost:{dev_port}").parse() {
-                    origins.push(origin);
-                }
-            }
-        }
-        CorsLayer::new()
-            .allow_origin(origins)
-            .allow_methods(tower_http::cors::Any)
-            .allow_headers(tower_http::cors::Any)
-    } else {
-        let mut origins: Vec<axum::http::HeaderValue> = vec![
-            format!("http://{listen_addr}").parse().unwrap(),
-            "http://localhost:4200".parse().unwrap(),
-            "http://127.0.0.1:4200".parse().unwrap(),
-            "http://localhost:8080".parse().unwrap(),
-            "http://127.0.0.1:8080".parse().unwrap(),
-        ];
-        if listen_addr.port() != 4200 && listen_addr.port() != 8080 {
-            if let Ok(origin) = format!("http://localhost:{}", listen_addr.port()).parse() {
-                origins.push(origin);
-            }
-            if let Ok(origin) = format!("http://127.0.0.1:{}", listen_addr.port()).parse() {
-                origins.push(origin);
-            }
-        }
-        CorsLayer::new()
-            .allow_origin(origins)
-            .allow_methods(tower_http::cors::Any)
-            .allow_headers(tower_http::cors::Any)
-    };
+    let cors = build_cors_layer(&config.cors, listen_addr, api_auth.is_open());
 
     let app = Router::new()
         .route("/", get(webchat::webchat_page))
         .route("/logo.png", get(webchat::logo_png))
         .route("/favicon.ico", get(webchat::favicon_ico))
+        .route("/metrics", get(routes::prometheus_metrics))
         .route("/api/metrics", get(routes::prometheus_metrics))
         .route("/api/health", get(routes::health))
         .route("/api/health/detail", get(routes::health_detail))
         .route("/api/status", get(routes::status))
         .route("/api/version", get(routes::version))
+        .route("/api/admin/reload", post(routes::reload_config))
+        .route("/api/admin/log-level", post(routes::set_log_level))
+        .route("/api/channels/status", get(routes::channels_status))
+        .route(
+            "/api/admin/channels/validate",
+            post(routes::validate_channels),
+        )
+        .route("/api/admin/channels/apply", post(routes::apply_channels))
         .route(
             "/api/auth/codex/start",
             post(codex_oauth::codex_oauth_start),
@@ -114,6 +98,7 @@ pub async fn build_router(
             "/api/auth/codex/status",
             get(codex_oauth::codex_oauth_status),
         )
+        .route("/api/auth/codex/debug", get(codex_oauth::codex_oauth_debug))
         .route(
             "/api/auth/codex/logout",
             post(codex_oauth::codex_oauth_logout),
@@ -126,6 +111,11 @@ pub async fn build_router(
             "/api/sales/profile/autofill",
             post(sales::autofill_sales_profile),
         )
+        .route(
+            "/api/sales/profile/preview",
+            post(sales::preview_sales_profile),
+        )
+        .route("/api/sales/preview-copy", post(sales::preview_sales_copy))
         .route(
             "/api/sales/onboarding/status",
             get(sales::get_sales_onboarding_status),
@@ -134,6 +124,7 @@ pub async fn build_router(
             "/api/sales/onboarding/brief",
             post(sales::put_sales_onboarding_brief),
         )
+        .route("/api/sales/plan", get(sales::get_sales_query_plan))
         .route("/api/sales/run", post(sales::run_sales_now))
         .route(
             "/api/sales/jobs/active",
@@ -151,8 +142,25 @@ pub async fn build_router(
             "/api/sales/source-health",
             get(sales::list_sales_source_health),
         )
+        .route("/api/sales/stats", get(sales::get_sales_stats))
         .route("/api/sales/runs", get(sales::list_sales_runs))
         .route("/api/sales/leads", get(sales::list_sales_leads))
+        .route("/api/sales/leads/search", get(sales::search_sales_leads))
+        .route("/api/sales/leads/rescore", post(sales::rescore_sales_leads))
+        .route("/api/sales/leads/import", post(sales::import_sales_leads))
+        .route(
+            "/api/sales/leads/{id}",
+            get(sales::get_sales_lead).patch(sales::edit_sales_lead),
+        )
+        .route(
+            "/api/sales/leads/{id}/status",
+            patch(sales::update_sales_lead_status),
+        )
+        .route("/api/sales/leads/{id}/block", post(sales::block_sales_lead))
+        .route(
+            "/api/sales/leads/{id}/status-history",
+            get(sales::get_sales_lead_status_history),
+        )
         .route("/api/sales/prospects", get(sales::list_sales_prospects))
         .route(
             "/api/sales/accounts/{id}/dossier",
@@ -163,9 +171,13 @@ pub async fn build_router(
             "/api/sales/approvals/bulk-approve",
             post(sales::bulk_approve_sales_approvals),
         )
+        .route(
+            "/api/sales/approvals/expire",
+            post(sales::expire_sales_approvals),
+        )
         .route(
             "/api/sales/approvals/{id}/edit",
-            patch(sales::edit_sales_approval),
+            patch(sales::edit_sales_approval).layer(DefaultBodyLimit::max(approval_edit_limit)),
         )
         .route(
             "/api/sales/approvals/{id}/approve",
@@ -175,13 +187,60 @@ pub async fn build_router(
             "/api/sales/approvals/{id}/reject",
             post(sales::reject_sales_approval),
         )
+        .route(
+            "/api/sales/approvals/{id}/reopen",
+            post(sales::reopen_sales_approval),
+        )
+        .route("/api/sales/test-send", post(sales::test_send_sales_email))
         .route("/api/sales/deliveries", get(sales::list_sales_deliveries))
-        .layer(axum::middleware::from_fn_with_state(
-            api_key,
-            middleware::auth,
-        ));
+        .route(
+            "/api/sales/deliveries/feed",
+            get(sales::get_sales_deliveries_feed),
+        )
+        .route(
+            "/api/sales/campaigns",
+            get(sales::list_sales_campaigns).post(sales::create_sales_campaign),
+        )
+        .route(
+            "/api/sales/campaigns/{id}",
+            get(sales::get_sales_campaign).put(sales::update_sales_campaign),
+        )
+        .route(
+            "/api/sales/campaigns/{id}/profile",
+            get(sales::get_sales_campaign_profile).put(sales::put_sales_campaign_profile),
+        )
+        .route(
+            "/api/sales/campaigns/{id}/run",
+            post(sales::run_sales_campaign),
+        )
+        .route(
+            "/api/sales/campaigns/{id}/runs",
+            get(sales::list_sales_campaign_runs),
+        )
+        .route(
+            "/api/sales/campaigns/{id}/leads",
+            get(sales::list_sales_campaign_leads),
+        )
+        .route(
+            "/api/sales/campaigns/{id}/approvals",
+            get(sales::list_sales_campaign_approvals),
+        )
+        .route(
+            "/api/sales/campaigns/{id}/deliveries",
+            get(sales::list_sales_campaign_deliveries),
+        );
+    let app = guard_api_routes(app, api_auth, run_limiter);
 
     let app = app
+        .layer(DefaultBodyLimit::max(api_limits.max_body_bytes))
+        .layer(axum::middleware::from_fn_with_state(
+            api_limits.max_body_bytes,
+            middleware::payload_too_large_json,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            offline::offline_guard,
+        ))
         .layer(axum::middleware::from_fn_with_state(
             rate_limiter::create_rate_limiter(),
             rate_limiter::gcra_rate_limit,
@@ -196,6 +255,135 @@ pub async fn build_router(
     (app, state)
 }
 
+/// Wrap the API routes in auth and, inside it, the run limit, so
+/// unauthenticated calls are rejected before they take a run slot.
+/// Body limit for approval edits: the router-wide limit plus room for
+/// `max_attachment_bytes` of base64-encoded `content_base64` attachments.
+fn approval_edit_body_limit(config: &KernelConfig) -> usize {
+    let attachments = config
+        .channels
+        .email
+        .as_ref()
+        .map(|email| email.max_attachment_bytes)
+        .unwrap_or_default();
+    let encoded = usize::try_from(attachments.div_ceil(3).saturating_mul(4)).unwrap_or(usize::MAX);
+    config.api_limits.max_body_bytes.saturating_add(encoded)
+}
+
+fn guard_api_routes<S>(
+    routes: Router<S>,
+    api_auth: middleware::ApiAuth,
+    run_limiter: Arc<RunLimiter>,
+) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    routes
+        .layer(axum::middleware::from_fn_with_state(
+            run_limiter,
+            run_limiter::run_limit,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            api_auth,
+            middleware::auth,
+        ))
+}
+
+/// Built-in origins used when `cors.allowed_origins` is empty.
+fn default_cors_origins(listen_addr: SocketAddr, open: bool) -> Vec<HeaderValue> {
+    let port = listen_addr.port();
+    if open {
+        let mut origins: Vec<HeaderValue> = vec![
+            format!("http://{listen_addr}").parse().unwrap(),
+            format!("http://localhost:{port}").parse().unwrap(),
+        ];
+        for dev_port in [3000u16, 8080] {
+            if dev_port != port {
+                if let Ok(origin) = format!("http://127.0.0.1:{dev_port}").parse() {
+                    origins.push(origin);
+                }
+                if let Ok(origin) = format!("http://localhost:{dev_port}").parse() {
+                    origins.push(origin);
+                }
+            }
+        }
+        origins
+    } else {
+        let mut origins: Vec<HeaderValue> = vec![
+            format!("http://{listen_addr}").parse().unwrap(),
+            "http://localhost:4200".parse().unwrap(),
+            "http://127.0.0.1:4200".parse().unwrap(),
+            "http://localhost:8080".parse().unwrap(),
+            "http://127.0.0.1:8080".parse().unwrap(),
+        ];
+        if port != 4200 && port != 8080 {
+            if let Ok(origin) = format!("http://localhost:{port}").parse() {
+                origins.push(origin);
+            }
+            if let Ok(origin) = format!("http://127.0.0.1:{port}").parse() {
+                origins.push(origin);
+            }
+        }
+        origins
+    }
+}
+
+/// Build the CORS layer from config, falling back to localhost-only origins.
+///
+/// Empty method/header lists allow any value; with credentials enabled the
+/// request's values are mirrored instead, since browsers reject `*` there.
+fn build_cors_layer(config: &CorsConfig, listen_addr: SocketAddr, open: bool) -> CorsLayer {
+    let any_origin = config.allowed_origins.iter().any(|origin| origin == "*");
+    let credentials = config.allow_credentials && !any_origin;
+    if config.allow_credentials && any_origin {
+        tracing::warn!("cors.allow_credentials ignored: not allowed with a '*' origin");
+    }
+
+    let mut layer = CorsLayer::new();
+    layer = if any_origin {
+        layer.allow_origin(cors::Any)
+    } else if config.allowed_origins.is_empty() {
+        layer.allow_origin(default_cors_origins(listen_addr, open))
+    } else {
+        let origins: Vec<HeaderValue> = config
+            .allowed_origins
+            .iter()
+            .filter_map(|origin| match origin.trim().parse() {
+                Ok(value) => Some(value),
+                Err(_) => {
+                    tracing::warn!(origin = %origin, "Ignoring invalid CORS origin");
+                    None
+                }
+            })
+            .collect();
+        layer.allow_origin(origins)
+    };
+
+    let methods: Vec<Method> = config
+        .allowed_methods
+        .iter()
+        .filter_map(|method| method.trim().to_ascii_uppercase().parse().ok())
+        .collect();
+    layer = match (methods.is_empty(), credentials) {
+        (false, _) => layer.allow_methods(methods),
+        (true, true) => layer.allow_methods(AllowMethods::mirror_request()),
+        (true, false) => layer.allow_methods(cors::Any),
+    };
+
+    let headers: Vec<HeaderName> = config
+        .allowed_headers
+        .iter()
+        .filter_map(|header| header.trim().parse().ok())
+        .collect();
+    layer = match (headers.is_empty(), credentials) {
+        (false, _) => layer.allow_headers(headers),
+        (true, true) => layer.allow_headers(AllowHeaders::mirror_request()),
+        (true, false) => layer.allow_headers(cors::Any),
+    };
+
+    layer.allow_credentials(credentials)
+}
+
 /// Start the PulsivoSalesman sales daemon: boot kernel + HTTP API server.
 pub async fn run_daemon(
     kernel: PulsivoSalesmanKernel,
@@ -207,7 +395,6 @@ pub async fn run_daemon(
     let kernel = Arc::new(kernel);
     kernel.set_self_handle();
     kernel.start_background_agents();
-    sales::spawn_sales_scheduler(kernel.clone());
 
     {
         let hot_reload_kernel = kernel.clone();
@@ -226,6 +413,9 @@ pub async fn run_daemon(
                     tracing::info!("Config file changed, reloading...");
                     match hot_reload_kernel.reload_config() {
                         Ok(plan) => {
+                            if plan.is_hot_reloadable() {
+                                sales::warm_sales_llm_drivers(&hot_reload_kernel).await;
+                            }
                             if plan.has_changes() {
                                 tracing::info!("Config hot-reload applied: {:?}", plan.hot_actions);
                             } else {
@@ -240,6 +430,7 @@ pub async fn run_daemon(
     }
 
     let (app, state) = build_router(kernel.clone(), addr).await;
+    sales::spawn_sales_scheduler(kernel.clone(), state.sales_runs.clone());
 
     if let Some(info_path) = daemon_info_path {
         if info_path.exists() {
@@ -365,3 +556,141 @@ fn is_process_alive(pid: u32) -> bool {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    fn preflight(origin: &str) -> Request<Body> {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/api/sales/run")
+            .header("origin", origin)
+            .header("access-control-request-method", "POST")
+            .header("access-control-request-headers", "authorization")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_approval_edit_body_limit_fits_encoded_attachments() {
+        let mut config = KernelConfig::default();
+        assert_eq!(
+            approval_edit_body_limit(&config),
+            config.api_limits.max_body_bytes
+        );
+        config.channels.email = Some(pulsivo_salesman_types::config::EmailConfig {
+            max_attachment_bytes: 3 * 1024 * 1024,
+            ..Default::default()
+        });
+        assert_eq!(
+            approval_edit_body_limit(&config),
+            config.api_limits.max_body_bytes + 4 * 1024 * 1024
+        );
+    }
+
+    #[tokio::test]
+    async fn test_configured_cors_answers_preflight() {
+        let config = CorsConfig {
+            allowed_origins: vec!["https://app.example.com".to_string()],
+            allow_credentials: true,
+            ..CorsConfig::default()
+        };
+        let app = Router::new()
+            .route("/api/sales/run", post(|| async { "ok" }))
+            .layer(build_cors_layer(
+                &config,
+                "127.0.0.1:50051".parse().unwrap(),
+                false,
+            ));
+
+        let allowed = app
+            .clone()
+            .oneshot(preflight("https://app.example.com"))
+            .await
+            .unwrap();
+        assert_eq!(allowed.status(), StatusCode::OK);
+        let headers = allowed.headers();
+        assert_eq!(
+            headers["access-control-allow-origin"],
+            "https://app.example.com"
+        );
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert_eq!(headers["access-control-allow-headers"], "authorization");
+
+        let denied = app
+            .oneshot(preflight("https://evil.example"))
+            .await
+            .unwrap();
+        assert!(denied
+            .headers()
+            .get("access-control-allow-origin")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_default_cors_allows_localhost_only() {
+        let app = Router::new()
+            .route("/api/sales/run", post(|| async { "ok" }))
+            .layer(build_cors_layer(
+                &CorsConfig::default(),
+                "127.0.0.1:50051".parse().unwrap(),
+                true,
+            ));
+
+        let local = app
+            .clone()
+            .oneshot(preflight("http://localhost:3000"))
+            .await
+            .unwrap();
+        assert_eq!(
+            local.headers()["access-control-allow-origin"],
+            "http://localhost:3000"
+        );
+        let remote = app
+            .oneshot(preflight("https://app.example.com"))
+            .await
+            .unwrap();
+        assert!(remote
+            .headers()
+            .get("access-control-allow-origin")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_run_does_not_take_a_run_slot() {
+        let limiter = Arc::new(RunLimiter::new(1, 0, 5));
+        let api_auth = middleware::ApiAuth {
+            api_key: "secret".to_string(),
+            tokens: Vec::new(),
+            metrics_loopback_public: false,
+        };
+        let app = guard_api_routes(
+            Router::new().route("/api/sales/run", post(|| async { "ok" })),
+            api_auth,
+            limiter.clone(),
+        );
+        let run = |token: Option<&str>| {
+            let mut request = Request::builder()
+                .method(Method::POST)
+                .uri("/api/sales/run");
+            if let Some(token) = token {
+                request = request.header("authorization", format!("Bearer {token}"));
+            }
+            request.body(Body::empty()).unwrap()
+        };
+
+        let held = limiter.acquire().await.expect("slot");
+        let anonymous = app.clone().oneshot(run(None)).await.unwrap();
+        assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+        let authed = app.clone().oneshot(run(Some("secret"))).await.unwrap();
+        assert_eq!(authed.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        drop(held);
+        let authed = app.oneshot(run(Some("secret"))).await.unwrap();
+        assert_eq!(authed.status(), StatusCode::OK);
+    }
+}
@@ -208,12 +208,16 @@ export const salesApprovalMixins = {
       try {
         var data = await PulsivoSalesmanAPI.post('/api/sales/approvals/bulk-approve', { ids: ids });
         var approved = data && Array.isArray(data.approved) ? data.approved.length : 0;
+        var deferred = data && Array.isArray(data.deferred) ? data.deferred.length : 0;
         var failed = data && Array.isArray(data.failed) ? data.failed.length : 0;
         this.approvalSelections = {};
         await Promise.all([this.loadApprovals(), this.loadDeliveries()]);
         if (approved > 0) {
           PulsivoSalesmanToast.success(String(approved) + ' taslak onaylandi');
         }
+        if (deferred > 0) {
+          PulsivoSalesmanToast.warn(String(deferred) + ' taslak mesai saatleri disinda oldugu icin bekletildi', 8000);
+        }
         if (failed > 0) {
           PulsivoSalesmanToast.warn(String(failed) + ' taslak gonderilemedi', 8000);
         }
@@ -254,13 +258,14 @@ export const salesApprovalMixins = {
     approvalRecipient(a) {
       if (!a || !a.payload) return '-';
       if (a.channel === 'email') return a.payload.to || '-';
-      if (a.channel === 'linkedin' || a.channel === 'linkedin_assist') return a.payload.profile_url || '-';
+      if (a.channel === 'linkedin' || a.channel === 'linkedin_assist' || a.channel === 'linkedin_connect') return a.payload.profile_url || '-';
       return '-';
     },
 
     approvalTitle(a) {
       if (!a || !a.payload) return 'Taslak';
       if (a.channel === 'email') return a.payload.subject || 'E-posta taslagi';
+      if (a.channel === 'linkedin_connect') return 'LinkedIn baglanti istegi (not ile)';
       if (a.channel === 'linkedin' || a.channel === 'linkedin_assist') return 'LinkedIn operator assist';
       return 'Taslak';
     },
@@ -268,13 +273,19 @@ export const salesApprovalMixins = {
     approvalBody(a) {
       if (!a || !a.payload) return '';
       if (a.channel === 'email') return a.payload.body || '';
-      if (a.channel === 'linkedin' || a.channel === 'linkedin_assist') return a.payload.message || '';
+      if (a.channel === 'linkedin' || a.channel === 'linkedin_assist' || a.channel === 'linkedin_connect') return a.payload.message || '';
       return '';
     },
     async approve(id) {
       try {
-        await PulsivoSalesmanAPI.post('/api/sales/approvals/' + encodeURIComponent(id) + '/approve', {});
-        PulsivoSalesmanToast.success('Onaylandi');
+        var data = await PulsivoSalesmanAPI.post('/api/sales/approvals/' + encodeURIComponent(id) + '/approve', {});
+        var result = data && data.result ? data.result : null;
+        if (result && result.status === 'deferred') {
+          var next = result.next_eligible_at ? new Date(result.next_eligible_at).toLocaleString() : '-';
+          PulsivoSalesmanToast.warn('Mesai saatleri disinda; taslak bekletildi. Sonraki uygun zaman: ' + next, 8000);
+        } else {
+          PulsivoSalesmanToast.success('Onaylandi');
+        }
         await Promise.all([this.loadApprovals(), this.loadDeliveries()]);
       } catch (e) {
         PulsivoSalesmanToast.error(e && e.message ? e.message : 'Onay islemi basarisiz');
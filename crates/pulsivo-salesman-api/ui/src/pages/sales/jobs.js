@@ -45,7 +45,13 @@ export const salesJobMixins = {
                 ]);
               }
             }
-            if (data && data.status === 'completed') {
+            var diagnostics = latestRun && latestRun.telemetry && Array.isArray(latestRun.telemetry.diagnostics)
+              ? latestRun.telemetry.diagnostics
+              : [];
+            if (data && data.status === 'completed' && diagnostics.length) {
+              var first = diagnostics[0];
+              PulsivoSalesmanToast.warn(first.message + ' ' + first.suggestion, 8000);
+            } else if (data && data.status === 'completed') {
               PulsivoSalesmanToast.success(self.isB2C ? 'Social discovery job tamamlandi' : 'Prospecting job tamamlandi');
             } else if (data && data.error_message) {
               PulsivoSalesmanToast.error(data.error_message);
@@ -323,7 +323,33 @@ export const salesStateMixins = {
         daily_target: Number(src.daily_target || defaults.daily_target),
         daily_send_cap: Number(src.daily_send_cap || defaults.daily_send_cap),
         schedule_hour_local: Number(src.schedule_hour_local || defaults.schedule_hour_local),
-        timezone_mode: src.timezone_mode || defaults.timezone_mode
+        timezone_mode: src.timezone_mode || defaults.timezone_mode,
+        business_hours_start: Number(src.business_hours_start || 0),
+        business_hours_end: Number(src.business_hours_end || 0),
+        business_days: Array.isArray(src.business_days) ? src.business_days : [],
+        blocked_company_domains: Array.isArray(src.blocked_company_domains)
+          ? src.blocked_company_domains
+          : [],
+        keep_subdomains: !!src.keep_subdomains,
+        verify_emails: !!src.verify_emails,
+        verify_emails_smtp_probe: !!src.verify_emails_smtp_probe,
+        llm_rerank_candidates: !!src.llm_rerank_candidates,
+        locale: src.locale || '',
+        discovery_results: Number(src.discovery_results || 0),
+        contact_results: Number(src.contact_results || 0),
+        fallback_results: Number(src.fallback_results || 0),
+        max_reasons: Number(src.max_reasons || 0),
+        evidence_items: Number(src.evidence_items || 0),
+        evidence_chars: Number(src.evidence_chars || 0),
+        linkedin_connect_with_note: !!src.linkedin_connect_with_note,
+        max_candidates: Number(src.max_candidates || 0),
+        max_candidates_per_company: Number(src.max_candidates_per_company || 0),
+        dedupe_window_days: Number(src.dedupe_window_days || 0),
+        cite_evidence_source: !!src.cite_evidence_source,
+        min_contact_confidence: src.min_contact_confidence || null,
+        max_pending_approvals: Number(src.max_pending_approvals || 0),
+        schedule_jitter_minutes: Number(src.schedule_jitter_minutes || 0),
+        email_html_template: src.email_html_template || ''
       };
     },
 };
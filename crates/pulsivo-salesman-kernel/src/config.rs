@@ -3,7 +3,7 @@
 //! Supports config includes: the `include` field specifies additional TOML files
 //! to load and deep-merge before the root config (root overrides includes).
 
-use pulsivo_salesman_types::config::KernelConfig;
+use pulsivo_salesman_types::config::{ChannelsConfig, KernelConfig};
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use tracing::info;
@@ -20,74 +20,104 @@ pub fn load_config(path: Option<&Path>) -> KernelConfig {
         .map(|p| p.to_path_buf())
         .unwrap_or_else(default_config_path);
 
-    if config_path.exists() {
-        match std::fs::read_to_string(&config_path) {
-            Ok(contents) => match toml::from_str::<toml::Value>(&contents) {
-                Ok(mut root_value) => {
-                    // Process includes before deserializing
-                    let config_dir = config_path
-                        .parent()
-                        .unwrap_or_else(|| Path::new("."))
-                        .to_path_buf();
-                    let mut visited = HashSet::new();
-                    if let Ok(canonical) = std::fs::canonicalize(&config_path) {
-                        visited.insert(canonical);
-                    } else {
-                        visited.insert(config_path.clone());
-                    }
-
-                    if let Err(e) =
-                        resolve_config_includes(&mut root_value, &config_dir, &mut visited, 0)
-                    {
-                        tracing::warn!(
-                            error = %e,
-                            "Config include resolution failed, using root config only"
-                        );
-                    }
-
-                    // Remove the `include` field before deserializing to avoid confusion
-                    if let toml::Value::Table(ref mut tbl) = root_value {
-                        tbl.remove("include");
-                    }
-
-                    match root_value.try_into::<KernelConfig>() {
-                        Ok(config) => {
-                            info!(path = %config_path.display(), "Loaded configuration");
-                            return config;
-                        }
-                        Err(e) => {
-                            tracing::warn!(
-                                error = %e,
-                                path = %config_path.display(),
-                                "Failed to deserialize merged config, using defaults"
-                            );
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!(
-                        error = %e,
-                        path = %config_path.display(),
-                        "Failed to parse config, using defaults"
-                    );
-                }
-            },
-            Err(e) => {
-                tracing::warn!(
-                    error = %e,
-                    path = %config_path.display(),
-                    "Failed to read config file, using defaults"
-                );
-            }
-        }
-    } else {
+    if !config_path.exists() {
         info!(
             path = %config_path.display(),
             "Config file not found, using defaults"
         );
+        return KernelConfig::default();
+    }
+
+    match read_config_file(&config_path) {
+        Ok(config) => {
+            info!(path = %config_path.display(), "Loaded configuration");
+            config
+        }
+        Err(e) => {
+            tracing::warn!(
+                error = %e,
+                path = %config_path.display(),
+                "Failed to load config, using defaults"
+            );
+            KernelConfig::default()
+        }
+    }
+}
+
+/// Read, include-merge and deserialize the config file at `config_path`.
+///
+/// A deserialization failure inside `[channels]` is reported per field
+/// (e.g. `channels.email.smtp_port: ...`) rather than as one opaque error.
+pub fn read_config_file(config_path: &Path) -> Result<KernelConfig, String> {
+    let contents = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read config file: {e}"))?;
+    let mut root_value = toml::from_str::<toml::Value>(&contents)
+        .map_err(|e| format!("Failed to parse config: {e}"))?;
+
+    // Process includes before deserializing
+    let config_dir = config_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf();
+    let mut visited = HashSet::new();
+    if let Ok(canonical) = std::fs::canonicalize(config_path) {
+        visited.insert(canonical);
+    } else {
+        visited.insert(config_path.to_path_buf());
+    }
+
+    if let Err(e) = resolve_config_includes(&mut root_value, &config_dir, &mut visited, 0) {
+        tracing::warn!(
+            error = %e,
+            "Config include resolution failed, using root config only"
+        );
     }
 
-    KernelConfig::default()
+    // Remove the `include` field before deserializing to avoid confusion
+    if let toml::Value::Table(ref mut tbl) = root_value {
+        tbl.remove("include");
+    }
+
+    let channel_errors = channel_field_errors(&root_value);
+    root_value.try_into::<KernelConfig>().map_err(|e| {
+        if channel_errors.is_empty() {
+            format!("Failed to deserialize merged config: {e}")
+        } else {
+            format!("Invalid channels config: {}", channel_errors.join("; "))
+        }
+    })
+}
+
+/// Deserialize each `[channels.*]` field on its own so a type error names
+/// the exact key instead of failing the whole config without context.
+fn channel_field_errors(root: &toml::Value) -> Vec<String> {
+    let Some(toml::Value::Table(channels)) = root.get("channels") else {
+        return Vec::new();
+    };
+    let mut errors = Vec::new();
+    for (channel, value) in channels {
+        let toml::Value::Table(fields) = value else {
+            errors.push(format!("channels.{channel} must be a table"));
+            continue;
+        };
+        for (field, field_value) in fields {
+            let mut single = toml::map::Map::new();
+            single.insert(field.clone(), field_value.clone());
+            let mut probe = toml::map::Map::new();
+            probe.insert(channel.clone(), toml::Value::Table(single));
+            match toml::Value::Table(probe).try_into::<ChannelsConfig>() {
+                Ok(_) => {}
+                Err(_) if channel == "email" && field == "smtp_port" => {
+                    errors.push("channels.email.smtp_port must be 1-65535".to_string());
+                }
+                Err(e) => errors.push(format!(
+                    "channels.{channel}.{field}: {}",
+                    e.to_string().trim()
+                )),
+            }
+        }
+    }
+    errors
 }
 
 /// Resolve config includes by deep-merging included files into the root value.
@@ -431,4 +461,32 @@ mod tests {
         let config = load_config(Some(&root));
         assert_eq!(config.log_level, "trace");
     }
+
+    #[test]
+    fn test_read_config_file_names_invalid_channel_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let root_path = dir.path().join("config.toml");
+        std::fs::write(
+            &root_path,
+            r#"
+            [channels.email]
+            smtp_host = "smtp.example.com"
+            smtp_port = 70000
+            username = "sales@example.com"
+            tls_mode = "ssl"
+        "#,
+        )
+        .unwrap();
+
+        let err = read_config_file(&root_path).unwrap_err();
+        assert!(
+            err.contains("channels.email.smtp_port must be 1-65535"),
+            "{err}"
+        );
+        assert!(err.contains("channels.email.tls_mode"), "{err}");
+        assert!(!err.contains("smtp_host"), "{err}");
+
+        // The lenient loader still falls back to defaults.
+        assert!(load_config(Some(&root_path)).channels.email.is_none());
+    }
 }
@@ -1,14 +1,19 @@
 //! Sales-only kernel surface for the PulsivoSalesman daemon.
 
-use crate::config::load_config;
-use crate::config_reload::{build_reload_plan, ReloadPlan};
+use crate::config::{load_config, read_config_file};
+use crate::config_reload::{
+    build_reload_plan, validate_channels_config, validate_config_for_reload, ChannelValidation,
+    ReloadPlan,
+};
 use crate::error::{KernelError, KernelResult};
 use crate::registry::AgentRegistry;
 use crate::supervisor::Supervisor;
 
 use pulsivo_salesman_memory::MemorySubstrate;
+use pulsivo_salesman_runtime::drivers::pool::DriverPool;
 use pulsivo_salesman_runtime::model_catalog::ModelCatalog;
-use pulsivo_salesman_types::config::{KernelConfig, WebConfig};
+use pulsivo_salesman_runtime::response_cache::ResponseCache;
+use pulsivo_salesman_types::config::{ChannelsConfig, KernelConfig, WebConfig};
 
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, OnceLock, RwLock, Weak};
@@ -26,6 +31,10 @@ pub struct PulsivoSalesmanKernel {
     pub supervisor: Supervisor,
     /// Provider catalog used by the OAuth/status surface.
     pub model_catalog: RwLock<ModelCatalog>,
+    /// Cache for deterministic LLM responses (`None` when `[llm_cache]` is disabled).
+    pub llm_response_cache: Option<Arc<ResponseCache>>,
+    /// Warm driver pool for sales LLM calls (`None` when `[llm_driver_pool]` is disabled).
+    pub llm_driver_pool: Option<Arc<DriverPool>>,
     /// Weak self-handle retained for compatibility with the daemon bootstrap.
     self_handle: OnceLock<Weak<PulsivoSalesmanKernel>>,
 }
@@ -44,6 +53,9 @@ impl PulsivoSalesmanKernel {
         for warning in config.validate() {
             warn!("Config: {warning}");
         }
+        if let Err(e) = pulsivo_salesman_runtime::http_client::configure(&config.http) {
+            warn!("Config: {e}; outbound HTTP clients use the default settings");
+        }
 
         std::fs::create_dir_all(&config.home_dir)
             .map_err(|e| KernelError::BootFailed(format!("Failed to create home dir: {e}")))?;
@@ -60,6 +72,9 @@ impl PulsivoSalesmanKernel {
                 .map_err(|e| KernelError::BootFailed(format!("Memory init failed: {e}")))?,
         );
 
+        let llm_response_cache = ResponseCache::from_config(&config.llm_cache).map(Arc::new);
+        let llm_driver_pool = DriverPool::from_config(&config.llm_driver_pool).map(Arc::new);
+
         let mut model_catalog = ModelCatalog::new();
         model_catalog.detect_auth();
 
@@ -77,6 +92,8 @@ impl PulsivoSalesmanKernel {
             memory,
             supervisor: Supervisor::new(),
             model_catalog: RwLock::new(model_catalog),
+            llm_response_cache,
+            llm_driver_pool,
             self_handle: OnceLock::new(),
         })
     }
@@ -122,17 +139,28 @@ impl PulsivoSalesmanKernel {
     }
 
     /// Reload config.toml and apply hot-reloadable sales settings in place.
+    ///
+    /// A file that fails to deserialize or validate is rejected with the
+    /// offending fields listed, and the running config is left untouched.
     pub fn reload_config(&self) -> Result<ReloadPlan, String> {
         let current = self.config_snapshot();
         let config_path = current.home_dir.join("config.toml");
-        let mut next = load_config(Some(&config_path));
+        let mut next = if config_path.exists() {
+            read_config_file(&config_path)?
+        } else {
+            load_config(Some(&config_path))
+        };
         next.clamp_bounds();
+        validate_config_for_reload(&next).map_err(|errors| errors.join("; "))?;
 
         let plan = build_reload_plan(&current, &next);
         plan.log_summary();
 
         if !plan.restart_required {
             *self.config.write().unwrap_or_else(|e| e.into_inner()) = next;
+            if let Some(pool) = &self.llm_driver_pool {
+                pool.invalidate();
+            }
             self.model_catalog
                 .write()
                 .unwrap_or_else(|e| e.into_inner())
@@ -142,6 +170,27 @@ impl PulsivoSalesmanKernel {
         Ok(plan)
     }
 
+    /// Validate `channels` and, if every channel passes, swap it into the
+    /// running config under a single write lock.
+    ///
+    /// The swap is in memory only: config.toml is not rewritten, so the next
+    /// [`reload_config`](Self::reload_config) or restart reads the file again.
+    pub fn apply_channels_config(
+        &self,
+        channels: ChannelsConfig,
+    ) -> Result<Vec<ChannelValidation>, Vec<ChannelValidation>> {
+        let results = validate_channels_config(&channels);
+        if !results.iter().all(ChannelValidation::is_valid) {
+            return Err(results);
+        }
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .channels = channels;
+        info!("Applied channels config from admin API");
+        Ok(results)
+    }
+
     /// Compatibility no-op. The sales daemon no longer boots agent-side background systems.
     pub fn start_background_agents(self: &Arc<Self>) {}
 
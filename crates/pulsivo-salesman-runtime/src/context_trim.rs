@@ -0,0 +1,250 @@
+//! Context trimming for LLM requests.
+//!
+//! Long conversations eventually exceed a model's context window and fail at
+//! the provider with an opaque error. [`ContextTrimPolicy`] drops the oldest
+//! non-system messages — by message count and by an estimated token budget —
+//! before a request is sent, and logs a warning whenever it does.
+
+use crate::llm_driver::CompletionRequest;
+use crate::model_catalog::ModelCatalog;
+use pulsivo_salesman_types::config::ContextTrimConfig;
+use pulsivo_salesman_types::message::{ContentBlock, Message, MessageContent, Role};
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Rough per-message framing overhead (role markers, separators), in tokens.
+const MESSAGE_OVERHEAD_TOKENS: u64 = 4;
+
+/// Estimate tokens for `text` at roughly four characters per token.
+pub fn estimate_tokens(text_len: usize) -> u64 {
+    (text_len as u64).div_ceil(4)
+}
+
+/// Estimated input tokens for one message.
+pub fn estimate_message_tokens(message: &Message) -> u64 {
+    estimate_tokens(message.content.text_length()) + MESSAGE_OVERHEAD_TOKENS
+}
+
+/// Estimated input tokens for a request (system prompt plus messages).
+pub fn estimate_request_tokens(request: &CompletionRequest) -> u64 {
+    let system = request
+        .system
+        .as_deref()
+        .map(|system| estimate_tokens(system.len()))
+        .unwrap_or(0);
+    system
+        + request
+            .messages
+            .iter()
+            .map(estimate_message_tokens)
+            .sum::<u64>()
+}
+
+/// What a trim removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrimReport {
+    /// Messages dropped from the start of the conversation.
+    pub dropped_messages: usize,
+    /// Estimated input tokens before trimming.
+    pub estimated_tokens_before: u64,
+    /// Estimated input tokens after trimming.
+    pub estimated_tokens_after: u64,
+    /// Token budget the request was trimmed against, if any.
+    pub budget: Option<u64>,
+}
+
+/// Limits applied to a request's conversation before it is sent.
+#[derive(Debug, Clone, Default)]
+pub struct ContextTrimPolicy {
+    /// Keep at most this many non-system messages; 0 keeps all.
+    pub max_messages: usize,
+    /// Explicit input-token budget; 0 derives it from `context_windows`.
+    pub max_input_tokens: u64,
+    /// Context window (tokens) per model id, used when no explicit budget
+    /// is configured.
+    pub context_windows: HashMap<String, u64>,
+}
+
+impl ContextTrimPolicy {
+    /// Build a policy from config, looking up context windows for `models`
+    /// in the catalog.
+    pub fn from_config(
+        config: &ContextTrimConfig,
+        catalog: &ModelCatalog,
+        models: &[&str],
+    ) -> Self {
+        let context_windows = models
+            .iter()
+            .filter_map(|model| {
+                catalog
+                    .find_model(model)
+                    .filter(|entry| entry.context_window > 0)
+                    .map(|entry| (model.to_string(), entry.context_window))
+            })
+            .collect();
+        Self {
+            max_messages: config.max_messages,
+            max_input_tokens: config.max_input_tokens,
+            context_windows,
+        }
+    }
+
+    /// Input-token budget for `model`, leaving room for `max_tokens` of
+    /// output when derived from the context window.
+    pub fn budget_for(&self, model: &str, max_tokens: u32) -> Option<u64> {
+        if self.max_input_tokens > 0 {
+            return Some(self.max_input_tokens);
+        }
+        self.context_windows
+            .get(model)
+            .map(|window| window.saturating_sub(u64::from(max_tokens)))
+            .filter(|budget| *budget > 0)
+    }
+
+    /// Trim `request` in place. Returns `None` when nothing was dropped.
+    ///
+    /// System messages are always kept, as is the most recent non-system
+    /// message. The retained conversation starts at a user message that is
+    /// not a tool result, so tool calls are never split from their results.
+    pub fn apply(&self, request: &mut CompletionRequest) -> Option<TrimReport> {
+        let budget = self.budget_for(&request.model, request.max_tokens);
+        let estimated_tokens_before = estimate_request_tokens(request);
+        let conversation: Vec<usize> = request
+            .messages
+            .iter()
+            .enumerate()
+            .filter(|(_, message)| message.role != Role::System)
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut start = 0;
+        if self.max_messages > 0 && conversation.len() > self.max_messages {
+            start = conversation.len() - self.max_messages;
+        }
+        if let Some(budget) = budget {
+            let fixed: u64 = estimated_tokens_before
+                - conversation
+                    .iter()
+                    .map(|&index| estimate_message_tokens(&request.messages[index]))
+                    .sum::<u64>();
+            let mut kept: u64 = conversation[start..]
+                .iter()
+                .map(|&index| estimate_message_tokens(&request.messages[index]))
+                .sum();
+            while fixed + kept > budget && start + 1 < conversation.len() {
+                kept -= estimate_message_tokens(&request.messages[conversation[start]]);
+                start += 1;
+            }
+        }
+        if start > 0 {
+            while start + 1 < conversation.len()
+                && !starts_user_turn(&request.messages[conversation[start]])
+            {
+                start += 1;
+            }
+        }
+        if start == 0 {
+            return None;
+        }
+
+        let dropped: Vec<usize> = conversation[..start].to_vec();
+        let mut index = 0;
+        request.messages.retain(|_| {
+            let keep = dropped.binary_search(&index).is_err();
+            index += 1;
+            keep
+        });
+        let report = TrimReport {
+            dropped_messages: dropped.len(),
+            estimated_tokens_before,
+            estimated_tokens_after: estimate_request_tokens(request),
+            budget,
+        };
+        warn!(
+            model = %request.model,
+            dropped_messages = report.dropped_messages,
+            estimated_tokens_before = report.estimated_tokens_before,
+            estimated_tokens_after = report.estimated_tokens_after,
+            budget = ?report.budget,
+            "Trimmed conversation to fit the context policy"
+        );
+        Some(report)
+    }
+}
+
+/// Whether a conversation may start at `message`.
+fn starts_user_turn(message: &Message) -> bool {
+    message.role == Role::User
+        && !matches!(
+            &message.content,
+            MessageContent::Blocks(blocks)
+                if blocks.iter().any(|block| matches!(block, ContentBlock::ToolResult { .. }))
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(messages: Vec<Message>) -> CompletionRequest {
+        CompletionRequest {
+            model: "test-model".to_string(),
+            messages,
+            tools: Vec::new(),
+            max_tokens: 100,
+            temperature: 0.0,
+            system: None,
+            thinking: None,
+            reasoning_effort: None,
+        }
+    }
+
+    fn conversation(turns: usize) -> Vec<Message> {
+        let mut messages = vec![Message::system("You are a sales assistant.")];
+        for turn in 0..turns {
+            messages.push(Message::user(format!(
+                "question {turn} {}",
+                "x".repeat(400)
+            )));
+            messages.push(Message::assistant(format!(
+                "answer {turn} {}",
+                "y".repeat(400)
+            )));
+        }
+        messages.push(Message::user("latest question"));
+        messages
+    }
+
+    #[test]
+    fn test_max_messages_keeps_system_and_recent_turns() {
+        let policy = ContextTrimPolicy {
+            max_messages: 3,
+            ..ContextTrimPolicy::default()
+        };
+        let mut req = request(conversation(4));
+        let report = policy.apply(&mut req).expect("trimmed");
+        assert_eq!(report.dropped_messages, 6);
+        assert_eq!(req.messages.len(), 4);
+        assert_eq!(req.messages[0].role, Role::System);
+        assert_eq!(req.messages[1].role, Role::User);
+        assert_eq!(req.messages[3].content.text_content(), "latest question");
+    }
+
+    #[test]
+    fn test_token_budget_from_context_window() {
+        let policy = ContextTrimPolicy {
+            context_windows: HashMap::from([("test-model".to_string(), 500)]),
+            ..ContextTrimPolicy::default()
+        };
+        let mut req = request(conversation(4));
+        let report = policy.apply(&mut req).expect("trimmed");
+        assert_eq!(report.budget, Some(400));
+        assert!(report.estimated_tokens_after <= 400);
+        assert!(report.estimated_tokens_before > 400);
+        assert_eq!(req.messages[0].role, Role::System);
+        assert_eq!(req.messages[1].role, Role::User);
+
+        let mut short = request(conversation(0));
+        assert!(policy.apply(&mut short).is_none());
+    }
+}
@@ -3,7 +3,9 @@
 //! Full implementation of the Anthropic Messages API with tool use support,
 //! system prompt extraction, and retry on 429/529 errors.
 
-use crate::llm_driver::{CompletionRequest, CompletionResponse, LlmDriver, LlmError, StreamEvent};
+use crate::llm_driver::{
+    send_stream_event, CompletionRequest, CompletionResponse, LlmDriver, LlmError, StreamEvent,
+};
 use async_trait::async_trait;
 use futures::StreamExt;
 use pulsivo_salesman_types::message::{
@@ -27,7 +29,7 @@ impl AnthropicDriver {
         Self {
             api_key: Zeroizing::new(api_key),
             base_url,
-            client: reqwest::Client::new(),
+            client: crate::http_client::client(),
         }
     }
 }
@@ -396,12 +398,14 @@ impl LlmDriver for AnthropicDriver {
                                 "tool_use" => {
                                     let id = block["id"].as_str().unwrap_or("").to_string();
                                     let name = block["name"].as_str().unwrap_or("").to_string();
-                                    let _ = tx
-                                        .send(StreamEvent::ToolUseStart {
+                                    send_stream_event(
+                                        &tx,
+                                        StreamEvent::ToolUseStart {
                                             id: id.clone(),
                                             name: name.clone(),
-                                        })
-                                        .await;
+                                        },
+                                    )
+                                    .await;
                                     blocks.push(ContentBlockAccum::ToolUse {
                                         id,
                                         name,
@@ -424,11 +428,13 @@ impl LlmDriver for AnthropicDriver {
                                         {
                                             t.push_str(text);
                                         }
-                                        let _ = tx
-                                            .send(StreamEvent::TextDelta {
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::TextDelta {
                                                 text: text.to_string(),
-                                            })
-                                            .await;
+                                            },
+                                        )
+                                        .await;
                                     }
                                 }
                                 "input_json_delta" => {
@@ -440,11 +446,13 @@ impl LlmDriver for AnthropicDriver {
                                         {
                                             input_json.push_str(partial);
                                         }
-                                        let _ = tx
-                                            .send(StreamEvent::ToolInputDelta {
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::ToolInputDelta {
                                                 text: partial.to_string(),
-                                            })
-                                            .await;
+                                            },
+                                        )
+                                        .await;
                                     }
                                 }
                                 "thinking_delta" => {
@@ -454,29 +462,47 @@ impl LlmDriver for AnthropicDriver {
                                         {
                                             t.push_str(thinking);
                                         }
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::ThinkingDelta {
+                                                text: thinking.to_string(),
+                                            },
+                                        )
+                                        .await;
                                     }
                                 }
                                 _ => {}
                             }
                         }
-                        "content_block_stop" => {
-                            if let Some(ContentBlockAccum::ToolUse {
+                        "content_block_stop" => match blocks.last() {
+                            Some(ContentBlockAccum::ToolUse {
                                 id,
                                 name,
                                 input_json,
-                            }) = blocks.last()
-                            {
+                            }) => {
                                 let input: serde_json::Value =
                                     serde_json::from_str(input_json).unwrap_or_default();
-                                let _ = tx
-                                    .send(StreamEvent::ToolUseEnd {
+                                send_stream_event(
+                                    &tx,
+                                    StreamEvent::ToolUseEnd {
                                         id: id.clone(),
                                         name: name.clone(),
                                         input,
-                                    })
-                                    .await;
+                                    },
+                                )
+                                .await;
                             }
-                        }
+                            Some(ContentBlockAccum::Thinking(thinking)) => {
+                                send_stream_event(
+                                    &tx,
+                                    StreamEvent::ThinkingComplete {
+                                        text: thinking.clone(),
+                                    },
+                                )
+                                .await;
+                            }
+                            _ => {}
+                        },
                         "message_delta" => {
                             if let Some(sr) = json["delta"]["stop_reason"].as_str() {
                                 stop_reason = match sr {
@@ -524,9 +550,7 @@ impl LlmDriver for AnthropicDriver {
                 }
             }
 
-            let _ = tx
-                .send(StreamEvent::ContentComplete { stop_reason, usage })
-                .await;
+            send_stream_event(&tx, StreamEvent::ContentComplete { stop_reason, usage }).await;
 
             return Ok(CompletionResponse {
                 content,
@@ -3,7 +3,10 @@
 //! Uses OAuth access tokens against:
 //! `https://chatgpt.com/backend-api/codex/responses`
 
-use crate::llm_driver::{CompletionRequest, CompletionResponse, LlmDriver, LlmError, StreamEvent};
+use crate::llm_driver::{
+    send_stream_event, CallLog, CompletionRequest, CompletionResponse, DriverTimeouts, LlmDriver,
+    LlmError, StreamEvent,
+};
 use async_trait::async_trait;
 use base64::Engine;
 use futures::StreamExt;
@@ -14,16 +17,28 @@ use std::collections::{HashMap, HashSet};
 use tracing::debug;
 use zeroize::Zeroizing;
 
+/// Default `originator` header sent to the Codex backend.
+pub const DEFAULT_CODEX_ORIGINATOR: &str = "pi";
+/// Default `openai-beta` header sent to the Codex backend.
+pub const DEFAULT_CODEX_OPENAI_BETA: &str = "responses=experimental";
+/// Most non-SSE bytes kept from a stream to parse as a plain JSON error body.
+const MAX_NON_SSE_BODY_BYTES: usize = 64 * 1024;
+
 /// OpenAI Codex (OAuth) driver.
 pub struct CodexDriver {
     access_token: Zeroizing<String>,
     account_id: Option<String>,
     base_url: String,
+    originator: String,
+    openai_beta: String,
+    truncation: Option<String>,
     client: reqwest::Client,
+    stream_idle_timeout: std::time::Duration,
 }
 
 impl CodexDriver {
-    /// Create a new Codex driver.
+    /// Create a new Codex driver. HTTP timeouts apply once
+    /// [`Self::with_timeouts`] is called.
     pub fn new(access_token: String, base_url: String, account_id: Option<String>) -> Self {
         let account_id = account_id
             .map(|s| s.trim().to_string())
@@ -32,8 +47,60 @@ impl CodexDriver {
             access_token: Zeroizing::new(access_token),
             account_id,
             base_url,
-            client: reqwest::Client::new(),
+            originator: DEFAULT_CODEX_ORIGINATOR.to_string(),
+            openai_beta: DEFAULT_CODEX_OPENAI_BETA.to_string(),
+            truncation: None,
+            client: crate::http_client::client(),
+            stream_idle_timeout: DriverTimeouts::default().stream_idle,
+        }
+    }
+
+    /// Apply connect, request, and stream-inactivity timeouts. Fails if the
+    /// HTTP client cannot be built, rather than dropping the timeouts.
+    pub fn with_timeouts(mut self, timeouts: DriverTimeouts) -> Result<Self, LlmError> {
+        self.client = timeouts.http_client()?;
+        self.stream_idle_timeout = timeouts.stream_idle;
+        Ok(self)
+    }
+
+    /// Override the `originator` and `openai-beta` headers. `None` or blank
+    /// values keep the defaults.
+    pub fn with_request_headers(
+        mut self,
+        originator: Option<String>,
+        openai_beta: Option<String>,
+    ) -> Self {
+        if let Some(originator) = originator
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+        {
+            self.originator = originator;
+        }
+        if let Some(openai_beta) = openai_beta
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+        {
+            self.openai_beta = openai_beta;
         }
+        self
+    }
+
+    /// Set the Responses API `truncation` strategy (`auto` or `disabled`).
+    /// `None` omits the field so the backend default applies.
+    pub fn with_truncation(mut self, truncation: Option<String>) -> Self {
+        self.truncation = truncation
+            .map(|s| s.trim().to_ascii_lowercase())
+            .filter(|s| !s.is_empty());
+        self
+    }
+
+    /// `OPENAI_CODEX_ORIGINATOR` wins over the configured originator.
+    fn resolve_originator(&self) -> String {
+        std::env::var("OPENAI_CODEX_ORIGINATOR")
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| self.originator.clone())
     }
 
     fn endpoint_url(&self) -> String {
@@ -123,12 +190,22 @@ impl CodexDriver {
         Ok((access_token, account_id))
     }
 
+    /// `request.system` wins; otherwise a leading system (or `developer`)
+    /// message becomes the turn's instructions.
     fn instructions_for(request: &CompletionRequest) -> String {
+        let leading_system = request
+            .messages
+            .iter()
+            .take_while(|m| m.role == Role::System)
+            .map(|m| m.content.text_content())
+            .collect::<Vec<_>>()
+            .join("\n\n");
         request
             .system
-            .as_ref()
-            .map(|s| s.trim())
+            .as_deref()
+            .map(str::trim)
             .filter(|s| !s.is_empty())
+            .or_else(|| Some(leading_system.trim()).filter(|s| !s.is_empty()))
             .unwrap_or("You are a helpful assistant.")
             .to_string()
     }
@@ -352,7 +429,7 @@ impl CodexDriver {
 
     async fn maybe_send(tx: &Option<tokio::sync::mpsc::Sender<StreamEvent>>, event: StreamEvent) {
         if let Some(tx) = tx.as_ref() {
-            let _ = tx.send(event).await;
+            send_stream_event(tx, event).await;
         }
     }
 
@@ -373,10 +450,12 @@ impl CodexDriver {
     fn build_completion_from_response(
         response: Option<&Value>,
         fallback_text: String,
+        fallback_summaries: Vec<String>,
         fallback_tool_calls: Vec<ToolCall>,
         fallback_usage: TokenUsage,
     ) -> CompletionResponse {
         let mut text = String::new();
+        let mut summaries: Vec<String> = Vec::new();
         let mut content: Vec<ContentBlock> = Vec::new();
         let mut tool_calls: Vec<ToolCall> = Vec::new();
         let mut usage = fallback_usage;
@@ -386,7 +465,13 @@ impl CodexDriver {
             usage = Self::usage_from_response(resp);
 
             if let Some(status) = resp.get("status").and_then(Value::as_str) {
-                if status.eq_ignore_ascii_case("incomplete") {
+                let reason = resp
+                    .get("incomplete_details")
+                    .and_then(|d| d.get("reason"))
+                    .and_then(Value::as_str);
+                if status.eq_ignore_ascii_case("incomplete")
+                    && matches!(reason, None | Some("max_output_tokens"))
+                {
                     stop_reason = StopReason::MaxTokens;
                 }
             }
@@ -421,6 +506,17 @@ impl CodexDriver {
                                 }
                             }
                         }
+                        "reasoning" => {
+                            if let Some(parts) = item.get("summary").and_then(Value::as_array) {
+                                summaries.extend(
+                                    parts
+                                        .iter()
+                                        .filter_map(|part| part.get("text").and_then(Value::as_str))
+                                        .filter(|t| !t.is_empty())
+                                        .map(str::to_string),
+                                );
+                            }
+                        }
                         "function_call" => {
                             let call_id = item
                                 .get("call_id")
@@ -465,6 +561,17 @@ impl CodexDriver {
         if !text.is_empty() {
             content.insert(0, ContentBlock::Text { text });
         }
+        if summaries.is_empty() {
+            summaries = fallback_summaries;
+        }
+        if !summaries.is_empty() {
+            content.insert(
+                0,
+                ContentBlock::Thinking {
+                    thinking: summaries.join("\n\n"),
+                },
+            );
+        }
 
         if tool_calls.is_empty() && !fallback_tool_calls.is_empty() {
             for call in &fallback_tool_calls {
@@ -489,17 +596,10 @@ impl CodexDriver {
         }
     }
 
-    async fn run_completion(
-        &self,
-        request: CompletionRequest,
-        tx: Option<tokio::sync::mpsc::Sender<StreamEvent>>,
-    ) -> Result<CompletionResponse, LlmError> {
-        let (access_token, account_id) = self.resolve_auth_context()?;
-
-        let url = self.endpoint_url();
-        let input_items = Self::build_input_items(&request);
+    fn build_request_body(&self, request: &CompletionRequest) -> Value {
+        let input_items = Self::build_input_items(request);
         let tools = Self::build_tools(&request.tools);
-        let instructions = Self::instructions_for(&request);
+        let instructions = Self::instructions_for(request);
 
         let mut body = serde_json::json!({
             "model": request.model,
@@ -512,9 +612,39 @@ impl CodexDriver {
             body["tools"] = Value::Array(tools);
             body["tool_choice"] = serde_json::json!("auto");
         }
-        if let Some(effort) = Self::reasoning_effort_label(&request) {
-            body["reasoning"] = serde_json::json!({ "effort": effort });
+        if let Some(effort) = Self::reasoning_effort_label(request) {
+            body["reasoning"] = serde_json::json!({ "effort": effort, "summary": "auto" });
         }
+        if request.max_tokens > 0 {
+            body["max_output_tokens"] = serde_json::json!(request.max_tokens);
+        }
+        if let Some(truncation) = self.truncation.as_deref() {
+            body["truncation"] = serde_json::json!(truncation);
+        }
+        body
+    }
+
+    async fn run_completion(
+        &self,
+        request: CompletionRequest,
+        tx: Option<tokio::sync::mpsc::Sender<StreamEvent>>,
+    ) -> Result<CompletionResponse, LlmError> {
+        let mut call_log = CallLog::start("codex", &request);
+        let result = self.send_completion(&request, tx, &mut call_log).await;
+        call_log.finish(&result);
+        result
+    }
+
+    async fn send_completion(
+        &self,
+        request: &CompletionRequest,
+        tx: Option<tokio::sync::mpsc::Sender<StreamEvent>>,
+        call_log: &mut CallLog,
+    ) -> Result<CompletionResponse, LlmError> {
+        let (access_token, account_id) = self.resolve_auth_context()?;
+
+        let url = self.endpoint_url();
+        let body = self.build_request_body(request);
 
         debug!(url = %url, "Sending Codex responses request");
         let mut req = self
@@ -523,15 +653,12 @@ impl CodexDriver {
             .header("content-type", "application/json")
             .header("accept", "text/event-stream")
             .header("authorization", format!("Bearer {access_token}"))
-            .header("openai-beta", "responses=experimental")
-            .header("originator", "pi")
+            .header("openai-beta", &self.openai_beta)
+            .header("originator", self.resolve_originator())
             .json(&body);
         req = req.header("chatgpt-account-id", account_id);
 
-        let resp = req
-            .send()
-            .await
-            .map_err(|e| LlmError::Http(e.to_string()))?;
+        let resp = req.send().await.map_err(LlmError::from_http)?;
         let status = resp.status().as_u16();
         if !resp.status().is_success() {
             let body = resp.text().await.unwrap_or_default();
@@ -541,11 +668,79 @@ impl CodexDriver {
             });
         }
 
+        Self::consume_event_stream(
+            resp.bytes_stream(),
+            tx,
+            self.stream_idle_timeout,
+            call_log.first_output(),
+        )
+        .await
+    }
+
+    /// Map a streamed error code onto an HTTP-like status for `LlmError::Api`.
+    fn stream_error_status(code: &str) -> u16 {
+        match code {
+            "rate_limit_exceeded" | "insufficient_quota" | "usage_limit_reached" => 429,
+            "context_length_exceeded" | "invalid_request_error" | "invalid_prompt" => 400,
+            _ => 500,
+        }
+    }
+
+    /// Convert an `error` / `response.failed` payload into `LlmError::Api`.
+    /// The error object may be the payload itself, `payload.error`, or
+    /// `payload.response.error`.
+    fn stream_error(payload: &Value) -> LlmError {
+        let error = payload
+            .get("response")
+            .and_then(|r| r.get("error"))
+            .filter(|e| e.is_object())
+            .or_else(|| payload.get("error").filter(|e| e.is_object()))
+            .unwrap_or(payload);
+        let code = error
+            .get("code")
+            .and_then(Value::as_str)
+            .or_else(|| error.get("type").and_then(Value::as_str))
+            .filter(|c| *c != "error")
+            .unwrap_or("stream_error");
+        let message = error
+            .get("message")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+            .unwrap_or("Codex backend reported an error without a message");
+        LlmError::Api {
+            status: Self::stream_error_status(code),
+            message: format!("{code}: {message}"),
+        }
+    }
+
+    fn event_type_from_data(data: &str) -> Option<String> {
+        serde_json::from_str::<Value>(data.trim())
+            .ok()?
+            .get("type")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+    }
+
+    async fn consume_event_stream<S, B, E>(
+        byte_stream: S,
+        tx: Option<tokio::sync::mpsc::Sender<StreamEvent>>,
+        idle_timeout: std::time::Duration,
+        first_output: &mut Option<std::time::Instant>,
+    ) -> Result<CompletionResponse, LlmError>
+    where
+        S: futures::Stream<Item = Result<B, E>>,
+        B: AsRef<[u8]>,
+        E: std::fmt::Display,
+    {
+        let mut byte_stream = std::pin::pin!(byte_stream);
         let mut buffer = String::new();
         let mut current_event: Option<String> = None;
         let mut current_data = String::new();
 
         let mut text_accum = String::new();
+        let mut summary_accum = String::new();
+        let mut summaries: Vec<String> = Vec::new();
         let mut tool_meta: HashMap<String, (String, String)> = HashMap::new();
         let mut tool_args: HashMap<String, String> = HashMap::new();
         let mut started_item_ids: HashSet<String> = HashSet::new();
@@ -553,11 +748,22 @@ impl CodexDriver {
         let mut fallback_tool_calls: Vec<ToolCall> = Vec::new();
         let mut fallback_usage = TokenUsage::default();
         let mut completed_response: Option<Value> = None;
+        let mut non_sse_body = String::new();
+        let mut non_sse_overflow = false;
 
-        let mut byte_stream = resp.bytes_stream();
-        while let Some(chunk_result) = byte_stream.next().await {
+        loop {
+            let chunk_result = match tokio::time::timeout(idle_timeout, byte_stream.next()).await {
+                Ok(Some(chunk_result)) => chunk_result,
+                Ok(None) => break,
+                Err(_) => {
+                    return Err(LlmError::Timeout(format!(
+                        "Codex stream idle for {}s",
+                        idle_timeout.as_secs()
+                    )))
+                }
+            };
             let chunk = chunk_result.map_err(|e| LlmError::Http(e.to_string()))?;
-            buffer.push_str(&String::from_utf8_lossy(&chunk));
+            buffer.push_str(&String::from_utf8_lossy(chunk.as_ref()));
 
             while let Some(pos) = buffer.find('\n') {
                 let mut line = buffer[..pos].to_string();
@@ -567,7 +773,12 @@ impl CodexDriver {
                 buffer = buffer[pos + 1..].to_string();
 
                 if line.is_empty() {
-                    if let Some(event_name) = current_event.take() {
+                    // Some error payloads arrive as bare `data:` lines; fall back
+                    // to the JSON `type` when no `event:` line preceded them.
+                    if let Some(event_name) = current_event
+                        .take()
+                        .or_else(|| Self::event_type_from_data(&current_data))
+                    {
                         let data = current_data.trim().to_string();
                         current_data.clear();
                         if data.is_empty() || data == "[DONE]" {
@@ -594,6 +805,36 @@ impl CodexDriver {
                                     }
                                 }
                             }
+                            "response.reasoning_summary_text.delta" => {
+                                if let Some(delta) = json.get("delta").and_then(Value::as_str) {
+                                    if !delta.is_empty() {
+                                        summary_accum.push_str(delta);
+                                        Self::maybe_send(
+                                            &tx,
+                                            StreamEvent::ThinkingDelta {
+                                                text: delta.to_string(),
+                                            },
+                                        )
+                                        .await;
+                                    }
+                                }
+                            }
+                            "response.reasoning_summary_text.done" => {
+                                let summary = json
+                                    .get("text")
+                                    .and_then(Value::as_str)
+                                    .map(str::to_string)
+                                    .unwrap_or_else(|| summary_accum.clone());
+                                summary_accum.clear();
+                                if !summary.is_empty() {
+                                    summaries.push(summary.clone());
+                                    Self::maybe_send(
+                                        &tx,
+                                        StreamEvent::ThinkingComplete { text: summary },
+                                    )
+                                    .await;
+                                }
+                            }
                             "response.output_item.added" => {
                                 let item = json.get("item").and_then(Value::as_object);
                                 if let Some(item) = item {
@@ -741,8 +982,40 @@ impl CodexDriver {
                                     completed_response = Some(response.clone());
                                 }
                             }
+                            "error" | "response.error" | "response.failed" => {
+                                return Err(Self::stream_error(&json));
+                            }
+                            "response.incomplete" => {
+                                if let Some(response) = json.get("response") {
+                                    let reason = response
+                                        .get("incomplete_details")
+                                        .and_then(|d| d.get("reason"))
+                                        .and_then(Value::as_str)
+                                        .unwrap_or("unknown");
+                                    debug!(reason = %reason, "Codex response incomplete");
+                                    if reason != "max_output_tokens"
+                                        && text_accum.is_empty()
+                                        && tool_meta.is_empty()
+                                    {
+                                        return Err(LlmError::Api {
+                                            status: 500,
+                                            message: format!("Codex response incomplete: {reason}"),
+                                        });
+                                    }
+                                    fallback_usage = Self::usage_from_response(response);
+                                    completed_response = Some(response.clone());
+                                }
+                            }
                             _ => {}
                         }
+                        if first_output.is_none()
+                            && !(text_accum.is_empty()
+                                && summary_accum.is_empty()
+                                && summaries.is_empty()
+                                && tool_meta.is_empty())
+                        {
+                            *first_output = Some(std::time::Instant::now());
+                        }
                     }
                     continue;
                 }
@@ -759,13 +1032,50 @@ impl CodexDriver {
                         current_data.push('\n');
                     }
                     current_data.push_str(v.trim_start());
+                } else if !non_sse_overflow {
+                    if non_sse_body.len() + line.len() >= MAX_NON_SSE_BODY_BYTES {
+                        non_sse_overflow = true;
+                        non_sse_body.clear();
+                    } else {
+                        non_sse_body.push_str(&line);
+                        non_sse_body.push('\n');
+                    }
                 }
             }
         }
 
+        // A 200 response can still carry a plain JSON error body instead of SSE.
+        if completed_response.is_none()
+            && text_accum.is_empty()
+            && tool_meta.is_empty()
+            && !non_sse_overflow
+            && non_sse_body.len() + buffer.len() <= MAX_NON_SSE_BODY_BYTES
+        {
+            non_sse_body.push_str(&buffer);
+            if let Ok(json) = serde_json::from_str::<Value>(non_sse_body.trim()) {
+                if json.get("error").is_some()
+                    || json.get("type").and_then(Value::as_str) == Some("error")
+                {
+                    return Err(Self::stream_error(&json));
+                }
+            }
+        }
+
+        // Without `response.completed` (or `response.incomplete`) the
+        // connection dropped mid-turn: content may be cut off and usage is
+        // unknown, so don't report it as a clean end of turn.
+        if completed_response.is_none() {
+            return Err(LlmError::StreamTruncated(format!(
+                "Codex stream ended without response.completed after {} text chars and {} tool calls",
+                text_accum.len(),
+                tool_meta.len()
+            )));
+        }
+
         let mut response = Self::build_completion_from_response(
             completed_response.as_ref(),
             text_accum,
+            summaries,
             fallback_tool_calls,
             fallback_usage,
         );
@@ -794,6 +1104,10 @@ impl CodexDriver {
             response.stop_reason = StopReason::ToolUse;
         }
 
+        // `output_item.done` can repeat a call that the completed response (or
+        // the metadata reconstruction above) already carries.
+        response.dedupe_tool_calls();
+
         Self::maybe_send(
             &tx,
             StreamEvent::ContentComplete {
@@ -821,3 +1135,293 @@ impl LlmDriver for CodexDriver {
         self.run_completion(request, Some(tx)).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_headers_default_and_override() {
+        let driver = CodexDriver::new(
+            "token".to_string(),
+            "https://chatgpt.com/backend-api/codex".to_string(),
+            None,
+        );
+        assert_eq!(driver.originator, DEFAULT_CODEX_ORIGINATOR);
+        assert_eq!(driver.openai_beta, DEFAULT_CODEX_OPENAI_BETA);
+
+        let driver = driver.with_request_headers(
+            Some("codex_cli_rs".to_string()),
+            Some("responses=v2".to_string()),
+        );
+        assert_eq!(driver.originator, "codex_cli_rs");
+        assert_eq!(driver.openai_beta, "responses=v2");
+
+        let driver = driver.with_request_headers(Some("  ".to_string()), None);
+        assert_eq!(driver.originator, "codex_cli_rs");
+    }
+
+    #[test]
+    fn request_body_carries_output_budget_and_truncation() {
+        let driver = CodexDriver::new(
+            "token".to_string(),
+            "https://chatgpt.com/backend-api/codex".to_string(),
+            None,
+        )
+        .with_truncation(Some("Auto".to_string()));
+        let request = CompletionRequest {
+            model: "gpt-5-codex".to_string(),
+            messages: vec![pulsivo_salesman_types::message::Message::user("Hi")],
+            tools: Vec::new(),
+            max_tokens: 900,
+            temperature: 0.0,
+            system: None,
+            thinking: None,
+            reasoning_effort: None,
+        };
+        let body = driver.build_request_body(&request);
+        assert_eq!(body["max_output_tokens"], 900);
+        assert_eq!(body["truncation"], "auto");
+
+        let plain = CodexDriver::new("token".to_string(), String::new(), None);
+        let body = plain.build_request_body(&CompletionRequest {
+            max_tokens: 0,
+            ..request
+        });
+        assert!(body.get("max_output_tokens").is_none());
+        assert!(body.get("truncation").is_none());
+    }
+
+    #[test]
+    fn leading_system_message_becomes_instructions() {
+        use pulsivo_salesman_types::message::Message;
+        let mut request = CompletionRequest {
+            model: "gpt-5-codex".to_string(),
+            messages: vec![Message::system("Answer in Turkish."), Message::user("Hi")],
+            tools: Vec::new(),
+            max_tokens: 0,
+            temperature: 0.0,
+            system: None,
+            thinking: None,
+            reasoning_effort: None,
+        };
+        assert_eq!(
+            CodexDriver::instructions_for(&request),
+            "Answer in Turkish."
+        );
+
+        request.system = Some("Explicit system prompt".to_string());
+        assert_eq!(
+            CodexDriver::instructions_for(&request),
+            "Explicit system prompt"
+        );
+    }
+
+    #[tokio::test]
+    async fn incomplete_max_output_tokens_sets_max_tokens_stop_reason() {
+        let response = consume(&[
+            "event: response.output_text.delta\ndata: {\"delta\":\"Partial\"}\n\n",
+            "event: response.incomplete\ndata: {\"response\":{\"status\":\"incomplete\",\"incomplete_details\":{\"reason\":\"max_output_tokens\"},\"output\":[]}}\n\n",
+        ])
+        .await
+        .expect("incomplete response");
+        assert_eq!(response.stop_reason, StopReason::MaxTokens);
+        assert_eq!(response.text(), "Partial");
+    }
+
+    #[tokio::test]
+    async fn stream_without_completed_event_is_truncated() {
+        let err = consume(&[
+            "event: response.output_text.delta\ndata: {\"delta\":\"Partial\"}\n\n",
+            "event: response.output_text.delta\ndata: {\"delta\":\" ans",
+        ])
+        .await
+        .expect_err("truncated stream should error");
+        match err {
+            LlmError::StreamTruncated(message) => {
+                assert!(message.contains("response.completed"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn stalled_stream_times_out() {
+        let stalled = futures::stream::pending::<Result<Vec<u8>, std::convert::Infallible>>();
+        let err = CodexDriver::consume_event_stream(
+            stalled,
+            None,
+            std::time::Duration::from_millis(20),
+            &mut None,
+        )
+        .await
+        .expect_err("stalled stream should time out");
+        assert!(matches!(err, LlmError::Timeout(_)));
+    }
+
+    #[tokio::test]
+    async fn repeated_function_call_events_yield_one_tool_call() {
+        let added = "event: response.output_item.added\ndata: {\"item\":{\"type\":\"function_call\",\"id\":\"fc_1\",\"call_id\":\"call_1\",\"name\":\"lookup\"}}\n\n";
+        let args_done = "event: response.function_call_arguments.done\ndata: {\"item_id\":\"fc_1\",\"arguments\":\"{\\\"q\\\":1}\"}\n\n";
+        let item_done = "event: response.output_item.done\ndata: {\"item\":{\"type\":\"function_call\",\"id\":\"fc_1\",\"call_id\":\"call_1\",\"name\":\"lookup\",\"arguments\":\"{\\\"q\\\":1}\"}}\n\n";
+        let completed =
+            "event: response.completed\ndata: {\"response\":{\"status\":\"completed\",\"output\":[]}}\n\n";
+        let chunks = [added, args_done, item_done, item_done, completed];
+        let items = chunks
+            .iter()
+            .map(|chunk| Ok::<_, std::convert::Infallible>(chunk.as_bytes().to_vec()))
+            .collect::<Vec<_>>();
+        let (tx, mut rx) = tokio::sync::mpsc::channel(32);
+        let mut first_output = None;
+        let response = CodexDriver::consume_event_stream(
+            futures::stream::iter(items),
+            Some(tx),
+            DriverTimeouts::default().stream_idle,
+            &mut first_output,
+        )
+        .await
+        .expect("tool call response");
+        assert!(first_output.is_some());
+
+        assert_eq!(response.tool_calls.len(), 1);
+        assert_eq!(response.tool_calls[0].id, "call_1");
+        assert_eq!(response.tool_calls[0].input["q"], 1);
+        let tool_blocks = response
+            .content
+            .iter()
+            .filter(|block| matches!(block, ContentBlock::ToolUse { .. }))
+            .count();
+        assert_eq!(tool_blocks, 1);
+        assert_eq!(response.stop_reason, StopReason::ToolUse);
+
+        let mut ends = 0;
+        while let Ok(event) = rx.try_recv() {
+            if matches!(event, StreamEvent::ToolUseEnd { .. }) {
+                ends += 1;
+            }
+        }
+        assert_eq!(ends, 1);
+    }
+
+    #[tokio::test]
+    async fn reasoning_summary_streams_thinking_events() {
+        let chunks = [
+            "event: response.reasoning_summary_text.delta\ndata: {\"delta\":\"Check the \"}\n\n",
+            "event: response.reasoning_summary_text.delta\ndata: {\"delta\":\"domain.\"}\n\n",
+            "event: response.reasoning_summary_text.done\ndata: {\"text\":\"Check the domain.\"}\n\n",
+            "event: response.output_text.delta\ndata: {\"delta\":\"Looks valid.\"}\n\n",
+            "event: response.completed\ndata: {\"response\":{\"status\":\"completed\",\"output\":[{\"type\":\"reasoning\",\"summary\":[{\"type\":\"summary_text\",\"text\":\"Check the domain.\"}]},{\"type\":\"message\",\"content\":[{\"type\":\"output_text\",\"text\":\"Looks valid.\"}]}]}}\n\n",
+        ];
+        let items = chunks
+            .iter()
+            .map(|chunk| Ok::<_, std::convert::Infallible>(chunk.as_bytes().to_vec()))
+            .collect::<Vec<_>>();
+        let (tx, mut rx) = tokio::sync::mpsc::channel(32);
+        let response = CodexDriver::consume_event_stream(
+            futures::stream::iter(items),
+            Some(tx),
+            DriverTimeouts::default().stream_idle,
+            &mut None,
+        )
+        .await
+        .expect("reasoning response");
+
+        assert_eq!(response.text(), "Looks valid.");
+        assert!(matches!(
+            response.content.first(),
+            Some(ContentBlock::Thinking { thinking }) if thinking == "Check the domain."
+        ));
+
+        let mut kinds = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let StreamEvent::ThinkingComplete { text } = &event {
+                assert_eq!(text, "Check the domain.");
+            }
+            kinds.push(event.kind());
+        }
+        assert_eq!(
+            kinds,
+            [
+                "thinking_delta",
+                "thinking_delta",
+                "thinking_complete",
+                "text_delta",
+                "content_complete"
+            ]
+        );
+    }
+
+    async fn consume(chunks: &[&str]) -> Result<CompletionResponse, LlmError> {
+        let items = chunks
+            .iter()
+            .map(|chunk| Ok::<_, std::convert::Infallible>(chunk.as_bytes().to_vec()))
+            .collect::<Vec<_>>();
+        CodexDriver::consume_event_stream(
+            futures::stream::iter(items),
+            None,
+            DriverTimeouts::default().stream_idle,
+            &mut None,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn stream_error_event_becomes_api_error() {
+        let result = consume(&[
+            "event: response.output_text.delta\ndata: {\"delta\":\"\"}\n\n",
+            "event: error\ndata: {\"type\":\"error\",\"code\":\"rate_limit_exceeded\",\"message\":\"Slow down\"}\n\n",
+        ])
+        .await;
+        match result {
+            Err(LlmError::Api { status, message }) => {
+                assert_eq!(status, 429);
+                assert!(message.contains("Slow down"));
+            }
+            other => panic!("expected API error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_failed_and_bare_json_errors_are_reported() {
+        let failed = consume(&[
+            "data: {\"type\":\"response.failed\",\"response\":{\"status\":\"failed\",\"error\":{\"code\":\"server_error\",\"message\":\"Upstream failure\"}}}\n\n",
+        ])
+        .await;
+        match failed {
+            Err(LlmError::Api { status, message }) => {
+                assert_eq!(status, 500);
+                assert!(message.contains("Upstream failure"));
+            }
+            other => panic!("expected API error, got {other:?}"),
+        }
+
+        let bare = consume(&[
+            "{\"error\":{\"code\":\"invalid_request_error\",\"message\":\"Bad model\"}}",
+        ])
+        .await;
+        assert!(matches!(bare, Err(LlmError::Api { status: 400, .. })));
+
+        let filtered = consume(&[
+            "event: response.incomplete\ndata: {\"response\":{\"status\":\"incomplete\",\"incomplete_details\":{\"reason\":\"content_filter\"}}}\n\n",
+        ])
+        .await;
+        match filtered {
+            Err(LlmError::Api { message, .. }) => assert!(message.contains("content_filter")),
+            other => panic!("expected API error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn oversized_non_sse_body_is_not_buffered() {
+        let message = "x".repeat(MAX_NON_SSE_BODY_BYTES);
+        let head = "{\"error\":{\"code\":\"invalid_request_error\",\n";
+        let tail = format!("\"message\":\"{message}\"}}}}\n");
+        let err = consume(&[head, tail.as_str()])
+            .await
+            .expect_err("oversized body");
+        assert!(matches!(err, LlmError::StreamTruncated(_)), "{err:?}");
+
+        let small = consume(&[head, "\"message\":\"Bad model\"}}\n"]).await;
+        assert!(matches!(small, Err(LlmError::Api { status: 400, .. })));
+    }
+}
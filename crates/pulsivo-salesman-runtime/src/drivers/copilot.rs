@@ -76,7 +76,7 @@ impl Default for CopilotTokenCache {
 ///
 /// Response: {"token": "tid=...;exp=...;sku=...;proxy-ep=...", "expires_at": unix_timestamp}
 pub async fn exchange_copilot_token(github_token: &str) -> Result<CachedToken, String> {
-    let client = reqwest::Client::builder()
+    let client = crate::http_client::builder()
         .timeout(TOKEN_EXCHANGE_TIMEOUT)
         .build()
         .map_err(|e| format!("Failed to build HTTP client: {e}"))?;
@@ -87,7 +87,6 @@ pub async fn exchange_copilot_token(github_token: &str) -> Result<CachedToken, S
         .get(COPILOT_TOKEN_URL)
         .header("Authorization", format!("token {github_token}"))
         .header("Accept", "application/json")
-        .header("User-Agent", "PulsivoSalesman/1.0")
         .send()
         .await
         .map_err(|e| format!("Copilot token exchange failed: {e}"))?;
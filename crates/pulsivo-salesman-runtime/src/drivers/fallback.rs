@@ -2,11 +2,24 @@
 //!
 //! If the primary driver fails with a non-retryable error, the fallback driver
 //! moves to the next driver in the chain.
+//!
+//! [`ModelFallbackChain`] works one level up: it walks a list of provider/model
+//! pairs, building each driver on demand, and moves on when a model cannot
+//! serve the turn because of missing or rejected credentials or overload.
 
-use crate::llm_driver::{CompletionRequest, CompletionResponse, LlmDriver, LlmError, StreamEvent};
+use crate::context_trim::ContextTrimPolicy;
+use crate::llm_driver::{
+    stream_within_deadline, CompletionRequest, CompletionResponse, DriverConfig, LlmDriver,
+    LlmError, StreamEvent,
+};
+use crate::response_cache::ResponseCache;
+use crate::system_prompt::SystemPromptWrap;
 use async_trait::async_trait;
-use std::sync::Arc;
-use tracing::warn;
+use pulsivo_salesman_types::agent::FallbackModel;
+use pulsivo_salesman_types::message::{StopReason, TokenUsage};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
 
 /// A driver that wraps multiple LLM drivers and tries each in order.
 ///
@@ -85,6 +98,292 @@ impl LlmDriver for FallbackDriver {
     }
 }
 
+/// Builds a driver for one candidate in a [`ModelFallbackChain`].
+pub type DriverFactory =
+    Arc<dyn Fn(&DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmError> + Send + Sync>;
+
+/// A provider/model pair the chain may route a turn to.
+#[derive(Debug, Clone)]
+pub struct ModelCandidate {
+    /// Model identifier sent in the request.
+    pub model: String,
+    /// Driver configuration (provider, credentials, base URL).
+    pub config: DriverConfig,
+}
+
+impl ModelCandidate {
+    /// Create a candidate for `model` served by `config.provider`.
+    pub fn new(model: impl Into<String>, config: DriverConfig) -> Self {
+        Self {
+            model: model.into(),
+            config,
+        }
+    }
+
+    /// Build a candidate from a manifest/config fallback entry.
+    ///
+    /// The API key is read from `api_key_env` when set; otherwise the driver
+    /// falls back to the provider's default environment variable.
+    pub fn from_fallback_model(fallback: &FallbackModel) -> Self {
+        let api_key = fallback
+            .api_key_env
+            .as_deref()
+            .and_then(|env| std::env::var(env).ok())
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty());
+        Self::new(
+            fallback.model.clone(),
+            DriverConfig {
+                provider: fallback.provider.clone(),
+                api_key,
+                base_url: fallback.base_url.clone(),
+                codex_originator: None,
+                codex_openai_beta: None,
+                codex_truncation: None,
+                connect_timeout_secs: fallback.connect_timeout_secs,
+                request_timeout_secs: fallback.request_timeout_secs,
+                stream_idle_timeout_secs: fallback.stream_idle_timeout_secs,
+            },
+        )
+    }
+}
+
+/// The model that actually served a turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServedModel {
+    /// Provider name of the serving candidate.
+    pub provider: String,
+    /// Model identifier of the serving candidate.
+    pub model: String,
+    /// Position in the chain (0 = primary).
+    pub attempt: usize,
+    /// Tokens used by the turn. From [`ModelFallbackChain::served_models`]
+    /// this is the model's total over every turn the chain has served.
+    pub usage: TokenUsage,
+}
+
+impl ServedModel {
+    /// Whether a fallback (not the primary) served the turn.
+    pub fn is_fallback(&self) -> bool {
+        self.attempt > 0
+    }
+}
+
+/// Whether an error from one model should hand the turn to the next model.
+///
+/// Missing credentials, auth rejections (401/403) and overload are specific to
+/// one provider, so another provider may still succeed. Everything else,
+/// including rate limits, is returned to the caller unchanged.
+pub fn is_model_fallback_error(err: &LlmError) -> bool {
+    matches!(
+        err,
+        LlmError::MissingApiKey(_)
+            | LlmError::Overloaded { .. }
+            | LlmError::Api {
+                status: 401 | 403,
+                ..
+            }
+    )
+}
+
+/// Executes a turn against a primary model, falling back through the chain.
+///
+/// Each candidate's driver is rebuilt per attempt so refreshed credentials
+/// are picked up. The model that served the most recent turn is available
+/// from [`ModelFallbackChain::served_model`], and token usage per model from
+/// [`ModelFallbackChain::served_models`].
+pub struct ModelFallbackChain {
+    candidates: Vec<ModelCandidate>,
+    factory: DriverFactory,
+    /// Usage totals per served model, most recently used last.
+    served: Mutex<Vec<ServedModel>>,
+    context_trim: Option<ContextTrimPolicy>,
+    response_cache: Option<Arc<ResponseCache>>,
+    system_prompt: Option<SystemPromptWrap>,
+    turn_timeout: Option<Duration>,
+}
+
+impl ModelFallbackChain {
+    /// Create a chain from an ordered candidate list (primary first).
+    pub fn new(candidates: Vec<ModelCandidate>) -> Self {
+        Self {
+            candidates,
+            factory: Arc::new(super::create_driver),
+            served: Mutex::new(Vec::new()),
+            context_trim: None,
+            response_cache: None,
+            system_prompt: None,
+            turn_timeout: None,
+        }
+    }
+
+    /// Create a chain from a primary candidate and configured fallbacks.
+    pub fn with_fallbacks(primary: ModelCandidate, fallbacks: &[FallbackModel]) -> Self {
+        let mut candidates = vec![primary];
+        candidates.extend(fallbacks.iter().map(ModelCandidate::from_fallback_model));
+        Self::new(candidates)
+    }
+
+    /// Replace the driver factory (used by tests to inject stub drivers).
+    pub fn with_driver_factory(mut self, factory: DriverFactory) -> Self {
+        self.factory = factory;
+        self
+    }
+
+    /// Trim each candidate's request to `policy` before it is sent.
+    pub fn with_context_trim(mut self, policy: ContextTrimPolicy) -> Self {
+        self.context_trim = Some(policy);
+        self
+    }
+
+    /// Wrap every request's system prompt with `wrap` (skipped when empty).
+    pub fn with_system_prompt(mut self, wrap: SystemPromptWrap) -> Self {
+        self.system_prompt = (!wrap.is_empty()).then_some(wrap);
+        self
+    }
+
+    /// Bound each turn, fallbacks included, to `limit` of wall-clock time.
+    /// A turn that runs over returns its partial output with
+    /// [`StopReason::Timeout`] instead of waiting on the provider.
+    pub fn with_turn_timeout(mut self, limit: Duration) -> Self {
+        self.turn_timeout = Some(limit);
+        self
+    }
+
+    /// Serve identical deterministic requests from `cache`.
+    pub fn with_response_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// The model that served the most recent successful turn, if any, with
+    /// its usage totalled over the chain's turns.
+    pub fn served_model(&self) -> Option<ServedModel> {
+        self.served
+            .lock()
+            .ok()
+            .and_then(|served| served.last().cloned())
+    }
+
+    /// Token usage per model over every turn the chain has served, most
+    /// recently used model last. Models are priced differently, so costs
+    /// must be estimated per entry rather than from a combined total.
+    pub fn served_models(&self) -> Vec<ServedModel> {
+        self.served
+            .lock()
+            .map(|served| served.clone())
+            .unwrap_or_default()
+    }
+
+    /// Run a completion, returning the response and the model that served it.
+    pub async fn complete_with_model(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<(CompletionResponse, ServedModel), LlmError> {
+        let mut last_error = None;
+        let started = Instant::now();
+
+        for (attempt, candidate) in self.candidates.iter().enumerate() {
+            let mut req = request.clone();
+            req.model = candidate.model.clone();
+            if let Some(wrap) = &self.system_prompt {
+                wrap.apply(&mut req);
+            }
+            if let Some(policy) = &self.context_trim {
+                policy.apply(&mut req);
+            }
+            let cached = self
+                .response_cache
+                .as_ref()
+                .and_then(|cache| cache.get(&candidate.config, &req));
+            let result = match cached {
+                Some(response) => {
+                    debug!(model = %candidate.model, "Serving LLM response from cache");
+                    Ok(response)
+                }
+                None => match (self.factory)(&candidate.config) {
+                    Ok(driver) => {
+                        let response = match self.turn_timeout {
+                            Some(limit) => {
+                                let remaining = limit.saturating_sub(started.elapsed());
+                                stream_within_deadline(
+                                    driver.as_ref(),
+                                    req.clone(),
+                                    remaining,
+                                    None,
+                                )
+                                .await
+                            }
+                            None => driver.complete(req.clone()).await,
+                        };
+                        if let (Some(cache), Ok(response)) = (&self.response_cache, &response) {
+                            if response.stop_reason != StopReason::Timeout {
+                                cache.put(&candidate.config, &req, response);
+                            }
+                        }
+                        response
+                    }
+                    Err(e) => Err(e),
+                },
+            };
+            match result {
+                Ok(response) => {
+                    let served = ServedModel {
+                        provider: candidate.config.provider.clone(),
+                        model: candidate.model.clone(),
+                        attempt,
+                        usage: response.usage,
+                    };
+                    if served.is_fallback() {
+                        info!(
+                            provider = %served.provider,
+                            model = %served.model,
+                            attempt,
+                            "Turn served by fallback model"
+                        );
+                    }
+                    if let Ok(mut totals) = self.served.lock() {
+                        let mut total = served.clone();
+                        if let Some(pos) = totals.iter().position(|previous| {
+                            previous.provider == total.provider && previous.model == total.model
+                        }) {
+                            let previous = totals.remove(pos);
+                            total.usage.input_tokens += previous.usage.input_tokens;
+                            total.usage.output_tokens += previous.usage.output_tokens;
+                        }
+                        totals.push(total);
+                    }
+                    return Ok((response, served));
+                }
+                Err(e) if is_model_fallback_error(&e) => {
+                    warn!(
+                        provider = %candidate.config.provider,
+                        model = %candidate.model,
+                        error = %e,
+                        "Model unavailable, trying next in fallback chain"
+                    );
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| LlmError::Api {
+            status: 0,
+            message: "No models configured in fallback chain".to_string(),
+        }))
+    }
+}
+
+#[async_trait]
+impl LlmDriver for ModelFallbackChain {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        self.complete_with_model(request)
+            .await
+            .map(|(response, _)| response)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,4 +489,155 @@ mod tests {
         // Rate limit should NOT fall through to next driver
         assert!(matches!(result, Err(LlmError::RateLimited { .. })));
     }
+
+    fn stub_config(provider: &str) -> DriverConfig {
+        DriverConfig {
+            provider: provider.to_string(),
+            api_key: None,
+            base_url: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        }
+    }
+
+    fn stub_factory() -> DriverFactory {
+        struct AuthFailDriver;
+
+        #[async_trait]
+        impl LlmDriver for AuthFailDriver {
+            async fn complete(
+                &self,
+                _req: CompletionRequest,
+            ) -> Result<CompletionResponse, LlmError> {
+                Err(LlmError::Api {
+                    status: 401,
+                    message: "token expired".to_string(),
+                })
+            }
+        }
+
+        Arc::new(|config: &DriverConfig| match config.provider.as_str() {
+            "expired" => Ok(Arc::new(AuthFailDriver) as Arc<dyn LlmDriver>),
+            "unconfigured" => Err(LlmError::MissingApiKey("no key".to_string())),
+            "broken" => Ok(Arc::new(FailDriver) as Arc<dyn LlmDriver>),
+            _ => Ok(Arc::new(OkDriver) as Arc<dyn LlmDriver>),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_model_chain_skips_auth_failures_and_records_served_model() {
+        let chain = ModelFallbackChain::new(vec![
+            ModelCandidate::new("gpt-5.3-codex", stub_config("expired")),
+            ModelCandidate::new("claude-sonnet", stub_config("unconfigured")),
+            ModelCandidate::new("gemini-2.5-flash", stub_config("gemini")),
+        ])
+        .with_driver_factory(stub_factory());
+
+        let (response, served) = chain.complete_with_model(test_request()).await.unwrap();
+        assert_eq!(response.text(), "OK");
+        assert_eq!(served.provider, "gemini");
+        assert_eq!(served.model, "gemini-2.5-flash");
+        assert!(served.is_fallback());
+        assert_eq!(chain.served_model(), Some(served));
+    }
+
+    #[tokio::test]
+    async fn test_model_chain_returns_non_auth_errors() {
+        let chain = ModelFallbackChain::new(vec![
+            ModelCandidate::new("primary", stub_config("broken")),
+            ModelCandidate::new("gemini-2.5-flash", stub_config("gemini")),
+        ])
+        .with_driver_factory(stub_factory());
+
+        let result = chain.complete(test_request()).await;
+        assert!(matches!(result, Err(LlmError::Api { status: 500, .. })));
+        assert!(chain.served_model().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_model_chain_serves_identical_deterministic_request_from_cache() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let factory: DriverFactory = Arc::new(move |_config: &DriverConfig| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(Arc::new(OkDriver) as Arc<dyn LlmDriver>)
+        });
+        let chain =
+            ModelFallbackChain::new(vec![ModelCandidate::new("primary", stub_config("openai"))])
+                .with_driver_factory(factory)
+                .with_response_cache(Arc::new(ResponseCache::new(
+                    std::time::Duration::from_secs(60),
+                    16,
+                )));
+
+        let (first, _) = chain.complete_with_model(test_request()).await.unwrap();
+        let (second, served) = chain.complete_with_model(test_request()).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.text(), first.text());
+        assert_eq!(served.usage, TokenUsage::default());
+
+        let mut sampled = test_request();
+        sampled.temperature = 0.7;
+        chain.complete_with_model(sampled.clone()).await.unwrap();
+        chain.complete_with_model(sampled).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_model_chain_records_usage_per_served_model() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // The primary serves the first two turns, then its login expires.
+        let primary_builds = Arc::new(AtomicUsize::new(0));
+        let counter = primary_builds.clone();
+        let factory: DriverFactory = Arc::new(move |config: &DriverConfig| {
+            if config.provider == "openai" && counter.fetch_add(1, Ordering::SeqCst) >= 2 {
+                return Err(LlmError::MissingApiKey("login expired".to_string()));
+            }
+            Ok(Arc::new(OkDriver) as Arc<dyn LlmDriver>)
+        });
+        let chain = ModelFallbackChain::new(vec![
+            ModelCandidate::new("gpt-5.3-codex", stub_config("openai")),
+            ModelCandidate::new("gemini-2.5-flash", stub_config("gemini")),
+        ])
+        .with_driver_factory(factory);
+
+        for _ in 0..3 {
+            chain.complete_with_model(test_request()).await.unwrap();
+        }
+        let served = chain.served_models();
+        assert_eq!(served.len(), 2);
+        assert_eq!(served[0].model, "gpt-5.3-codex");
+        assert_eq!(served[0].usage.input_tokens, 20);
+        assert_eq!(served[0].usage.output_tokens, 10);
+        assert_eq!(served[1].model, "gemini-2.5-flash");
+        assert_eq!(served[1].usage.input_tokens, 10);
+        assert_eq!(
+            chain.served_model().map(|m| m.model).as_deref(),
+            Some("gemini-2.5-flash")
+        );
+    }
+
+    #[test]
+    fn test_from_fallback_model_carries_timeouts() {
+        let fallback = FallbackModel {
+            provider: "gemini".to_string(),
+            model: "gemini-2.5-flash".to_string(),
+            api_key_env: None,
+            base_url: None,
+            connect_timeout_secs: Some(5),
+            request_timeout_secs: Some(90),
+            stream_idle_timeout_secs: None,
+        };
+        let candidate = ModelCandidate::from_fallback_model(&fallback);
+        assert_eq!(candidate.config.connect_timeout_secs, Some(5));
+        assert_eq!(candidate.config.request_timeout_secs, Some(90));
+        assert_eq!(candidate.config.stream_idle_timeout_secs, None);
+    }
 }
@@ -8,7 +8,10 @@
 //! - Tool definitions via `functionDeclarations` inside `tools[]`
 //! - Response: `candidates[0].content.parts[]`
 
-use crate::llm_driver::{CompletionRequest, CompletionResponse, LlmDriver, LlmError, StreamEvent};
+use crate::llm_driver::{
+    close_thinking, send_stream_event, CallLog, CompletionRequest, CompletionResponse,
+    DriverTimeouts, LlmDriver, LlmError, StreamEvent,
+};
 use async_trait::async_trait;
 use futures::StreamExt;
 use pulsivo_salesman_types::message::{
@@ -16,6 +19,7 @@ use pulsivo_salesman_types::message::{
 };
 use pulsivo_salesman_types::tool::ToolCall;
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
 use tracing::{debug, warn};
 use zeroize::Zeroizing;
 
@@ -24,17 +28,40 @@ pub struct GeminiDriver {
     api_key: Zeroizing<String>,
     base_url: String,
     client: reqwest::Client,
+    stream_idle_timeout: std::time::Duration,
 }
 
 impl GeminiDriver {
-    /// Create a new Gemini driver.
+    /// Create a new Gemini driver. HTTP timeouts apply once
+    /// [`Self::with_timeouts`] is called.
     pub fn new(api_key: String, base_url: String) -> Self {
         Self {
             api_key: Zeroizing::new(api_key),
-            base_url,
-            client: reqwest::Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client: crate::http_client::client(),
+            stream_idle_timeout: DriverTimeouts::default().stream_idle,
+        }
+    }
+
+    /// `generateContent` (or streaming `streamGenerateContent`) URL for `model`.
+    fn model_url(&self, model: &str, stream: bool) -> String {
+        if stream {
+            format!(
+                "{}/v1beta/models/{model}:streamGenerateContent?alt=sse",
+                self.base_url
+            )
+        } else {
+            format!("{}/v1beta/models/{model}:generateContent", self.base_url)
         }
     }
+
+    /// Apply connect, request, and stream-inactivity timeouts. Fails if the
+    /// HTTP client cannot be built, rather than dropping the timeouts.
+    pub fn with_timeouts(mut self, timeouts: DriverTimeouts) -> Result<Self, LlmError> {
+        self.client = timeouts.http_client()?;
+        self.stream_idle_timeout = timeouts.stream_idle;
+        Ok(self)
+    }
 }
 
 // ── Request types ──────────────────────────────────────────────────────
@@ -64,6 +91,12 @@ struct GeminiContent {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 enum GeminiPart {
+    /// Thought summary (`thought: true`). Listed before `Text` so untagged
+    /// deserialization does not read it as answer text.
+    Thought {
+        text: String,
+        thought: bool,
+    },
     Text {
         text: String,
     },
@@ -301,7 +334,15 @@ fn convert_response(resp: GeminiResponse) -> Result<CompletionResponse, LlmError
     if let Some(gemini_content) = candidate.content {
         for part in gemini_content.parts {
             match part {
-                GeminiPart::Text { text } => {
+                GeminiPart::Thought {
+                    text,
+                    thought: true,
+                } => {
+                    if !text.is_empty() {
+                        content.push(ContentBlock::Thinking { thinking: text });
+                    }
+                }
+                GeminiPart::Text { text } | GeminiPart::Thought { text, .. } => {
                     if !text.is_empty() {
                         content.push(ContentBlock::Text { text });
                     }
@@ -353,11 +394,11 @@ fn convert_response(resp: GeminiResponse) -> Result<CompletionResponse, LlmError
     })
 }
 
-// ── LlmDriver implementation ──────────────────────────────────────────
-
-#[async_trait]
-impl LlmDriver for GeminiDriver {
-    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+impl GeminiDriver {
+    async fn send_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<CompletionResponse, LlmError> {
         let (contents, system_instruction) = convert_messages(&request.messages, &request.system);
         let tools = convert_tools(&request);
 
@@ -373,10 +414,7 @@ impl LlmDriver for GeminiDriver {
 
         let max_retries = 3;
         for attempt in 0..=max_retries {
-            let url = format!(
-                "{}/v1beta/models/{}:generateContent",
-                self.base_url, request.model
-            );
+            let url = self.model_url(&request.model, false);
             debug!(url = %url, attempt, "Sending Gemini API request");
 
             let resp = self
@@ -387,7 +425,7 @@ impl LlmDriver for GeminiDriver {
                 .json(&gemini_request)
                 .send()
                 .await
-                .map_err(|e| LlmError::Http(e.to_string()))?;
+                .map_err(LlmError::from_http)?;
 
             let status = resp.status().as_u16();
 
@@ -417,10 +455,7 @@ impl LlmDriver for GeminiDriver {
                 return Err(LlmError::Api { status, message });
             }
 
-            let body = resp
-                .text()
-                .await
-                .map_err(|e| LlmError::Http(e.to_string()))?;
+            let body = resp.text().await.map_err(LlmError::from_http)?;
             let gemini_response: GeminiResponse =
                 serde_json::from_str(&body).map_err(|e| LlmError::Parse(e.to_string()))?;
 
@@ -433,10 +468,11 @@ impl LlmDriver for GeminiDriver {
         })
     }
 
-    async fn stream(
+    async fn send_stream(
         &self,
         request: CompletionRequest,
         tx: tokio::sync::mpsc::Sender<StreamEvent>,
+        first_output: &mut Option<std::time::Instant>,
     ) -> Result<CompletionResponse, LlmError> {
         let (contents, system_instruction) = convert_messages(&request.messages, &request.system);
         let tools = convert_tools(&request);
@@ -453,10 +489,7 @@ impl LlmDriver for GeminiDriver {
 
         let max_retries = 3;
         for attempt in 0..=max_retries {
-            let url = format!(
-                "{}/v1beta/models/{}:streamGenerateContent?alt=sse",
-                self.base_url, request.model
-            );
+            let url = self.model_url(&request.model, true);
             debug!(url = %url, attempt, "Sending Gemini streaming request");
 
             let resp = self
@@ -467,7 +500,7 @@ impl LlmDriver for GeminiDriver {
                 .json(&gemini_request)
                 .send()
                 .await
-                .map_err(|e| LlmError::Http(e.to_string()))?;
+                .map_err(LlmError::from_http)?;
 
             let status = resp.status().as_u16();
 
@@ -503,14 +536,31 @@ impl LlmDriver for GeminiDriver {
             // Parse SSE stream
             let mut buffer = String::new();
             let mut text_content = String::new();
+            let mut thinking_content = String::new();
+            let mut thinking_closed = false;
             // Track function calls: (name, args_json)
             let mut fn_calls: Vec<(String, serde_json::Value)> = Vec::new();
             let mut finish_reason: Option<String> = None;
             let mut usage = TokenUsage::default();
 
             let mut byte_stream = resp.bytes_stream();
-            while let Some(chunk_result) = byte_stream.next().await {
-                let chunk = chunk_result.map_err(|e| LlmError::Http(e.to_string()))?;
+            loop {
+                let chunk_result = match tokio::time::timeout(
+                    self.stream_idle_timeout,
+                    byte_stream.next(),
+                )
+                .await
+                {
+                    Ok(Some(chunk_result)) => chunk_result,
+                    Ok(None) => break,
+                    Err(_) => {
+                        return Err(LlmError::Timeout(format!(
+                            "Gemini stream idle for {}s",
+                            self.stream_idle_timeout.as_secs()
+                        )))
+                    }
+                };
+                let chunk = chunk_result.map_err(LlmError::from_http)?;
                 buffer.push_str(&String::from_utf8_lossy(&chunk));
 
                 // Process complete SSE events (delimited by \n\n or \r\n\r\n)
@@ -547,34 +597,71 @@ impl LlmDriver for GeminiDriver {
                         if let Some(ref content) = candidate.content {
                             for part in &content.parts {
                                 match part {
-                                    GeminiPart::Text { text } => {
+                                    GeminiPart::Thought {
+                                        text,
+                                        thought: true,
+                                    } => {
+                                        if !text.is_empty() {
+                                            first_output.get_or_insert_with(Instant::now);
+                                            thinking_content.push_str(text);
+                                            send_stream_event(
+                                                &tx,
+                                                StreamEvent::ThinkingDelta { text: text.clone() },
+                                            )
+                                            .await;
+                                        }
+                                    }
+                                    GeminiPart::Text { text }
+                                    | GeminiPart::Thought { text, .. } => {
                                         if !text.is_empty() {
+                                            first_output.get_or_insert_with(Instant::now);
+                                            close_thinking(
+                                                &tx,
+                                                &thinking_content,
+                                                &mut thinking_closed,
+                                            )
+                                            .await;
                                             text_content.push_str(text);
-                                            let _ = tx
-                                                .send(StreamEvent::TextDelta { text: text.clone() })
-                                                .await;
+                                            send_stream_event(
+                                                &tx,
+                                                StreamEvent::TextDelta { text: text.clone() },
+                                            )
+                                            .await;
                                         }
                                     }
                                     GeminiPart::FunctionCall { function_call } => {
+                                        first_output.get_or_insert_with(Instant::now);
+                                        close_thinking(
+                                            &tx,
+                                            &thinking_content,
+                                            &mut thinking_closed,
+                                        )
+                                        .await;
                                         let id = format!("call_{}", uuid::Uuid::new_v4().simple());
-                                        let _ = tx
-                                            .send(StreamEvent::ToolUseStart {
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::ToolUseStart {
                                                 id: id.clone(),
                                                 name: function_call.name.clone(),
-                                            })
-                                            .await;
+                                            },
+                                        )
+                                        .await;
                                         let args_str = serde_json::to_string(&function_call.args)
                                             .unwrap_or_default();
-                                        let _ = tx
-                                            .send(StreamEvent::ToolInputDelta { text: args_str })
-                                            .await;
-                                        let _ = tx
-                                            .send(StreamEvent::ToolUseEnd {
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::ToolInputDelta { text: args_str },
+                                        )
+                                        .await;
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::ToolUseEnd {
                                                 id,
                                                 name: function_call.name.clone(),
                                                 input: function_call.args.clone(),
-                                            })
-                                            .await;
+                                            },
+                                        )
+                                        .await;
                                         fn_calls.push((
                                             function_call.name.clone(),
                                             function_call.args.clone(),
@@ -589,10 +676,17 @@ impl LlmDriver for GeminiDriver {
                 }
             }
 
+            close_thinking(&tx, &thinking_content, &mut thinking_closed).await;
+
             // Build final response
             let mut content = Vec::new();
             let mut tool_calls = Vec::new();
 
+            if !thinking_content.is_empty() {
+                content.push(ContentBlock::Thinking {
+                    thinking: thinking_content,
+                });
+            }
             if !text_content.is_empty() {
                 content.push(ContentBlock::Text { text: text_content });
             }
@@ -624,9 +718,7 @@ impl LlmDriver for GeminiDriver {
                 }
             };
 
-            let _ = tx
-                .send(StreamEvent::ContentComplete { stop_reason, usage })
-                .await;
+            send_stream_event(&tx, StreamEvent::ContentComplete { stop_reason, usage }).await;
 
             return Ok(CompletionResponse {
                 content,
@@ -643,6 +735,29 @@ impl LlmDriver for GeminiDriver {
     }
 }
 
+// ── LlmDriver implementation ──────────────────────────────────────────
+
+#[async_trait]
+impl LlmDriver for GeminiDriver {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, LlmError> {
+        let call_log = CallLog::start("gemini", &request);
+        let result = self.send_complete(request).await;
+        call_log.finish(&result);
+        result
+    }
+
+    async fn stream(
+        &self,
+        request: CompletionRequest,
+        tx: tokio::sync::mpsc::Sender<StreamEvent>,
+    ) -> Result<CompletionResponse, LlmError> {
+        let mut call_log = CallLog::start("gemini", &request);
+        let result = self.send_stream(request, tx, call_log.first_output()).await;
+        call_log.finish(&result);
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -658,6 +773,24 @@ mod tests {
         assert_eq!(driver.base_url, "https://generativelanguage.googleapis.com");
     }
 
+    #[test]
+    fn test_gemini_model_url_with_and_without_trailing_slash() {
+        for base_url in [
+            "https://generativelanguage.googleapis.com",
+            "https://generativelanguage.googleapis.com/",
+        ] {
+            let driver = GeminiDriver::new("test-key".to_string(), base_url.to_string());
+            assert_eq!(
+                driver.model_url("gemini-2.5-flash", false),
+                "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:generateContent"
+            );
+            assert_eq!(
+                driver.model_url("gemini-2.5-flash", true),
+                "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:streamGenerateContent?alt=sse"
+            );
+        }
+    }
+
     #[test]
     fn test_gemini_request_serialization() {
         let req = GeminiRequest {
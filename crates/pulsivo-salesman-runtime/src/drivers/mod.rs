@@ -10,8 +10,9 @@ pub mod copilot;
 pub mod fallback;
 pub mod gemini;
 pub mod openai;
+pub mod pool;
 
-use crate::llm_driver::{DriverConfig, LlmDriver, LlmError};
+use crate::llm_driver::{DriverConfig, DriverTimeouts, LlmDriver, LlmError};
 use pulsivo_salesman_types::model_catalog::{
     AI21_BASE_URL, ANTHROPIC_BASE_URL, CEREBRAS_BASE_URL, COHERE_BASE_URL, DEEPSEEK_BASE_URL,
     FIREWORKS_BASE_URL, GEMINI_BASE_URL, GROQ_BASE_URL, HUGGINGFACE_BASE_URL, LMSTUDIO_BASE_URL,
@@ -203,10 +204,7 @@ pub fn create_driver(config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmErr
             .ok_or_else(|| {
                 LlmError::MissingApiKey("Set ANTHROPIC_API_KEY environment variable".to_string())
             })?;
-        let base_url = config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| ANTHROPIC_BASE_URL.to_string());
+        let base_url = resolve_base_url(config, ANTHROPIC_BASE_URL)?;
         return Ok(Arc::new(anthropic::AnthropicDriver::new(api_key, base_url)));
     }
 
@@ -222,11 +220,11 @@ pub fn create_driver(config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmErr
                     "Set GEMINI_API_KEY or GOOGLE_API_KEY environment variable".to_string(),
                 )
             })?;
-        let base_url = config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| GEMINI_BASE_URL.to_string());
-        return Ok(Arc::new(gemini::GeminiDriver::new(api_key, base_url)));
+        let base_url = resolve_base_url(config, GEMINI_BASE_URL)?;
+        return Ok(Arc::new(
+            gemini::GeminiDriver::new(api_key, base_url)
+                .with_timeouts(DriverTimeouts::from_config(config))?,
+        ));
     }
 
     // GitHub Copilot — wraps OpenAI-compatible driver with automatic token exchange.
@@ -242,10 +240,7 @@ pub fn create_driver(config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmErr
                     "Set GITHUB_TOKEN environment variable for GitHub Copilot".to_string(),
                 )
             })?;
-        let base_url = config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| copilot::GITHUB_COPILOT_BASE_URL.to_string());
+        let base_url = resolve_base_url(config, copilot::GITHUB_COPILOT_BASE_URL)?;
         return Ok(Arc::new(copilot::CopilotDriver::new(
             github_token,
             base_url,
@@ -260,15 +255,16 @@ pub fn create_driver(config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmErr
             .or_else(|| std::env::var("OPENAI_CODEX_ACCESS_TOKEN").ok())
             .unwrap_or_default();
         let account_id = std::env::var("OPENAI_CODEX_ACCOUNT_ID").ok();
-        let base_url = config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| OPENAI_CODEX_BASE_URL.to_string());
-        return Ok(Arc::new(codex::CodexDriver::new(
-            access_token,
-            base_url,
-            account_id,
-        )));
+        let base_url = resolve_base_url(config, OPENAI_CODEX_BASE_URL)?;
+        return Ok(Arc::new(
+            codex::CodexDriver::new(access_token, base_url, account_id)
+                .with_request_headers(
+                    config.codex_originator.clone(),
+                    config.codex_openai_beta.clone(),
+                )
+                .with_truncation(config.codex_truncation.clone())
+                .with_timeouts(DriverTimeouts::from_config(config))?,
+        ));
     }
 
     // All other providers use OpenAI-compatible format
@@ -286,10 +282,7 @@ pub fn create_driver(config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmErr
             )));
         }
 
-        let base_url = config
-            .base_url
-            .clone()
-            .unwrap_or_else(|| defaults.base_url.to_string());
+        let base_url = resolve_base_url(config, defaults.base_url)?;
 
         return Ok(Arc::new(openai::OpenAIDriver::new(api_key, base_url)));
     }
@@ -299,22 +292,91 @@ pub fn create_driver(config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmErr
         let api_key = config.api_key.clone().unwrap_or_default();
         return Ok(Arc::new(openai::OpenAIDriver::new(
             api_key,
-            base_url.clone(),
+            normalize_base_url(provider, base_url)?,
         )));
     }
 
     Err(LlmError::Api {
         status: 0,
-        message: format!(
-            "Unknown provider '{}'. Supported: anthropic, gemini, openai, groq, openrouter, \
-             openai-codex, deepseek, together, mistral, fireworks, ollama, vllm, lmstudio, \
-             perplexity, cohere, ai21, cerebras, sambanova, huggingface, xai, replicate, \
-             github-copilot. Or set base_url for a custom OpenAI-compatible endpoint.",
-            provider
-        ),
+        message: unknown_provider_message(provider),
     })
 }
 
+/// The configured `base_url`, normalized, or `default` when none is set.
+fn resolve_base_url(config: &DriverConfig, default: &str) -> Result<String, LlmError> {
+    match config.base_url.as_deref() {
+        Some(raw) => normalize_base_url(&config.provider, raw),
+        None => Ok(default.to_string()),
+    }
+}
+
+/// The base URL a driver built from `config` would call: the normalized
+/// `base_url` override, else the provider default. `None` for an unknown
+/// provider without an override or a malformed override.
+pub fn effective_base_url(config: &DriverConfig) -> Option<String> {
+    match config.base_url.as_deref() {
+        Some(raw) => normalize_base_url(&config.provider, raw).ok(),
+        None if config.provider == "anthropic" => Some(ANTHROPIC_BASE_URL.to_string()),
+        None => provider_defaults(&config.provider).map(|d| d.base_url.to_string()),
+    }
+}
+
+/// Normalize a configured `base_url`: add a scheme when missing (`http://`
+/// for loopback hosts, `https://` otherwise) and strip trailing slashes, so
+/// drivers can append paths like `/v1beta/models/...` directly.
+pub fn normalize_base_url(provider: &str, raw: &str) -> Result<String, LlmError> {
+    let invalid = |reason: &str| LlmError::Api {
+        status: 0,
+        message: format!("Invalid base_url '{raw}' for provider '{provider}': {reason}"),
+    };
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Err(invalid("value is empty"));
+    }
+    let with_scheme = if trimmed.contains("://") {
+        trimmed.to_string()
+    } else if ["localhost", "127.0.0.1", "[::1]"]
+        .iter()
+        .any(|host| trimmed.starts_with(host))
+    {
+        format!("http://{trimmed}")
+    } else {
+        format!("https://{trimmed}")
+    };
+    let url = reqwest::Url::parse(&with_scheme).map_err(|e| invalid(&e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid("scheme must be http or https"));
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host"));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(invalid("query strings and fragments are not allowed"));
+    }
+    Ok(with_scheme.trim_end_matches('/').to_string())
+}
+
+/// Whether `provider` maps to a built-in driver (aliases included).
+pub fn is_known_provider(provider: &str) -> bool {
+    let provider = provider.trim();
+    matches!(provider, "google" | "copilot") || known_providers().contains(&provider)
+}
+
+/// Actionable error for an unrecognized provider, listing the supported ones.
+pub fn unknown_provider_message(provider: &str) -> String {
+    format!(
+        "Unknown provider '{}'. Supported: {}. \
+         Or set base_url for a custom OpenAI-compatible endpoint.",
+        provider,
+        known_providers().join(", ")
+    )
+}
+
+/// Whether a provider runs on the local machine (usable in offline mode).
+pub fn is_local_provider(provider: &str) -> bool {
+    matches!(provider, "ollama" | "vllm" | "lmstudio")
+}
+
 /// List all known provider names.
 pub fn known_providers() -> &'static [&'static str] {
     &[
@@ -373,6 +435,86 @@ mod tests {
         assert!(!d.key_required);
     }
 
+    #[test]
+    fn test_normalize_base_url() {
+        assert_eq!(
+            normalize_base_url("gemini", " https://proxy.example/gemini/ ").unwrap(),
+            "https://proxy.example/gemini"
+        );
+        assert_eq!(
+            normalize_base_url("openai", "api.example.com/v1").unwrap(),
+            "https://api.example.com/v1"
+        );
+        assert_eq!(
+            normalize_base_url("ollama", "localhost:11434/v1/").unwrap(),
+            "http://localhost:11434/v1"
+        );
+        for raw in ["", "ftp://files.example", "https://", "https://x.example/v1?key=1"] {
+            let err = normalize_base_url("gemini", raw).unwrap_err().to_string();
+            assert!(err.contains("Invalid base_url"), "{raw}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_create_driver_rejects_malformed_base_url() {
+        let config = DriverConfig {
+            provider: "gemini".to_string(),
+            api_key: Some("test-key".to_string()),
+            base_url: Some("http://".to_string()),
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        };
+        let err = create_driver(&config).err().expect("malformed base_url");
+        assert!(err.to_string().contains("provider 'gemini'"));
+    }
+
+    #[test]
+    fn test_effective_base_url_prefers_override() {
+        let mut config = DriverConfig {
+            provider: "gemini".to_string(),
+            api_key: None,
+            base_url: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        };
+        assert_eq!(
+            effective_base_url(&config).as_deref(),
+            Some(GEMINI_BASE_URL)
+        );
+        config.base_url = Some("localhost:8080/".to_string());
+        assert_eq!(
+            effective_base_url(&config).as_deref(),
+            Some("http://localhost:8080")
+        );
+        config.provider = "nonexistent".to_string();
+        config.base_url = None;
+        assert!(effective_base_url(&config).is_none());
+    }
+
+    #[test]
+    fn test_local_providers() {
+        assert!(is_local_provider("ollama"));
+        assert!(is_local_provider("lmstudio"));
+        assert!(!is_local_provider("openai-codex"));
+    }
+
+    #[test]
+    fn test_is_known_provider_accepts_aliases() {
+        assert!(is_known_provider("openai-codex"));
+        assert!(is_known_provider("google"));
+        assert!(is_known_provider("copilot"));
+        assert!(!is_known_provider("openai-codx"));
+        assert!(unknown_provider_message("openai-codx").contains("gemini, openai, openai-codex"));
+    }
+
     #[test]
     fn test_unknown_provider_returns_none() {
         assert!(provider_defaults("nonexistent").is_none());
@@ -384,6 +526,12 @@ mod tests {
             provider: "my-custom-llm".to_string(),
             api_key: Some("test".to_string()),
             base_url: Some("http://localhost:9999/v1".to_string()),
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
         };
         let driver = create_driver(&config);
         assert!(driver.is_ok());
@@ -395,6 +543,12 @@ mod tests {
             provider: "nonexistent".to_string(),
             api_key: None,
             base_url: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
         };
         let driver = create_driver(&config);
         assert!(driver.is_err());
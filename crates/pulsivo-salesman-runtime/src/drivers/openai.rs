@@ -2,7 +2,10 @@
 //!
 //! Works with OpenAI, Ollama, vLLM, and any other OpenAI-compatible endpoint.
 
-use crate::llm_driver::{CompletionRequest, CompletionResponse, LlmDriver, LlmError, StreamEvent};
+use crate::llm_driver::{
+    close_thinking, send_stream_event, CompletionRequest, CompletionResponse, LlmDriver, LlmError,
+    StreamEvent,
+};
 use async_trait::async_trait;
 use futures::StreamExt;
 use pulsivo_salesman_types::message::{ContentBlock, MessageContent, Role, StopReason, TokenUsage};
@@ -24,7 +27,7 @@ impl OpenAIDriver {
         Self {
             api_key: Zeroizing::new(api_key),
             base_url,
-            client: reqwest::Client::new(),
+            client: crate::http_client::client(),
         }
     }
 }
@@ -127,6 +130,9 @@ struct OaiChoice {
 #[derive(Debug, Deserialize)]
 struct OaiResponseMessage {
     content: Option<String>,
+    /// Reasoning text from compatible providers (DeepSeek, vLLM).
+    #[serde(default)]
+    reasoning_content: Option<String>,
     tool_calls: Option<Vec<OaiToolCall>>,
 }
 
@@ -154,15 +160,13 @@ impl LlmDriver for OpenAIDriver {
         // Convert messages
         for msg in &request.messages {
             match (&msg.role, &msg.content) {
-                (Role::System, MessageContent::Text(text)) => {
-                    if request.system.is_none() {
-                        oai_messages.push(OaiMessage {
-                            role: "system".to_string(),
-                            content: Some(OaiMessageContent::Text(text.clone())),
-                            tool_calls: None,
-                            tool_call_id: None,
-                        });
-                    }
+                (Role::System, MessageContent::Text(text)) if request.system.is_none() => {
+                    oai_messages.push(OaiMessage {
+                        role: "system".to_string(),
+                        content: Some(OaiMessageContent::Text(text.clone())),
+                        tool_calls: None,
+                        tool_call_id: None,
+                    });
                 }
                 (Role::User, MessageContent::Text(text)) => {
                     oai_messages.push(OaiMessage {
@@ -388,6 +392,11 @@ impl LlmDriver for OpenAIDriver {
             let mut content = Vec::new();
             let mut tool_calls = Vec::new();
 
+            if let Some(thinking) = choice.message.reasoning_content {
+                if !thinking.is_empty() {
+                    content.push(ContentBlock::Thinking { thinking });
+                }
+            }
             if let Some(text) = choice.message.content {
                 if !text.is_empty() {
                     content.push(ContentBlock::Text { text });
@@ -432,12 +441,14 @@ impl LlmDriver for OpenAIDriver {
                 })
                 .unwrap_or_default();
 
-            return Ok(CompletionResponse {
+            let mut response = CompletionResponse {
                 content,
                 stop_reason,
                 tool_calls,
                 usage,
-            });
+            };
+            response.dedupe_tool_calls();
+            return Ok(response);
         }
 
         Err(LlmError::Api {
@@ -465,15 +476,13 @@ impl LlmDriver for OpenAIDriver {
 
         for msg in &request.messages {
             match (&msg.role, &msg.content) {
-                (Role::System, MessageContent::Text(text)) => {
-                    if request.system.is_none() {
-                        oai_messages.push(OaiMessage {
-                            role: "system".to_string(),
-                            content: Some(OaiMessageContent::Text(text.clone())),
-                            tool_calls: None,
-                            tool_call_id: None,
-                        });
-                    }
+                (Role::System, MessageContent::Text(text)) if request.system.is_none() => {
+                    oai_messages.push(OaiMessage {
+                        role: "system".to_string(),
+                        content: Some(OaiMessageContent::Text(text.clone())),
+                        tool_calls: None,
+                        tool_call_id: None,
+                    });
                 }
                 (Role::User, MessageContent::Text(text)) => {
                     oai_messages.push(OaiMessage {
@@ -662,6 +671,8 @@ impl LlmDriver for OpenAIDriver {
             // Parse the SSE stream
             let mut buffer = String::new();
             let mut text_content = String::new();
+            let mut thinking_content = String::new();
+            let mut thinking_closed = false;
             // Track tool calls: index -> (id, name, arguments)
             let mut tool_accum: Vec<(String, String, String)> = Vec::new();
             let mut finish_reason: Option<String> = None;
@@ -713,20 +724,42 @@ impl LlmDriver for OpenAIDriver {
                     for choice in choices {
                         let delta = &choice["delta"];
 
+                        // Reasoning delta (`reasoning_content` on DeepSeek/vLLM,
+                        // `reasoning` on OpenRouter)
+                        if let Some(thinking) = delta["reasoning_content"]
+                            .as_str()
+                            .or_else(|| delta["reasoning"].as_str())
+                        {
+                            if !thinking.is_empty() {
+                                thinking_content.push_str(thinking);
+                                send_stream_event(
+                                    &tx,
+                                    StreamEvent::ThinkingDelta {
+                                        text: thinking.to_string(),
+                                    },
+                                )
+                                .await;
+                            }
+                        }
+
                         // Text content delta
                         if let Some(text) = delta["content"].as_str() {
                             if !text.is_empty() {
+                                close_thinking(&tx, &thinking_content, &mut thinking_closed).await;
                                 text_content.push_str(text);
-                                let _ = tx
-                                    .send(StreamEvent::TextDelta {
+                                send_stream_event(
+                                    &tx,
+                                    StreamEvent::TextDelta {
                                         text: text.to_string(),
-                                    })
-                                    .await;
+                                    },
+                                )
+                                .await;
                             }
                         }
 
                         // Tool call deltas
                         if let Some(calls) = delta["tool_calls"].as_array() {
+                            close_thinking(&tx, &thinking_content, &mut thinking_closed).await;
                             for call in calls {
                                 let idx = call["index"].as_u64().unwrap_or(0) as usize;
 
@@ -744,23 +777,27 @@ impl LlmDriver for OpenAIDriver {
                                     // Name (sent in first chunk)
                                     if let Some(name) = func["name"].as_str() {
                                         tool_accum[idx].1 = name.to_string();
-                                        let _ = tx
-                                            .send(StreamEvent::ToolUseStart {
+                                        send_stream_event(
+                                            &tx,
+                                            StreamEvent::ToolUseStart {
                                                 id: tool_accum[idx].0.clone(),
                                                 name: name.to_string(),
-                                            })
-                                            .await;
+                                            },
+                                        )
+                                        .await;
                                     }
 
                                     // Arguments delta
                                     if let Some(args) = func["arguments"].as_str() {
                                         tool_accum[idx].2.push_str(args);
                                         if !args.is_empty() {
-                                            let _ = tx
-                                                .send(StreamEvent::ToolInputDelta {
+                                            send_stream_event(
+                                                &tx,
+                                                StreamEvent::ToolInputDelta {
                                                     text: args.to_string(),
-                                                })
-                                                .await;
+                                                },
+                                            )
+                                            .await;
                                         }
                                     }
                                 }
@@ -775,15 +812,27 @@ impl LlmDriver for OpenAIDriver {
                 }
             }
 
+            close_thinking(&tx, &thinking_content, &mut thinking_closed).await;
+
             // Build the final response
             let mut content = Vec::new();
             let mut tool_calls = Vec::new();
 
+            if !thinking_content.is_empty() {
+                content.push(ContentBlock::Thinking {
+                    thinking: thinking_content,
+                });
+            }
             if !text_content.is_empty() {
                 content.push(ContentBlock::Text { text: text_content });
             }
 
+            let mut ended_ids = std::collections::HashSet::new();
             for (id, name, arguments) in &tool_accum {
+                // Some compatible providers resend a call under a new index.
+                if !id.is_empty() && !ended_ids.insert(id.clone()) {
+                    continue;
+                }
                 let input: serde_json::Value = serde_json::from_str(arguments).unwrap_or_default();
                 content.push(ContentBlock::ToolUse {
                     id: id.clone(),
@@ -796,13 +845,15 @@ impl LlmDriver for OpenAIDriver {
                     input,
                 });
 
-                let _ = tx
-                    .send(StreamEvent::ToolUseEnd {
+                send_stream_event(
+                    &tx,
+                    StreamEvent::ToolUseEnd {
                         id: id.clone(),
                         name: name.clone(),
                         input: serde_json::from_str(arguments).unwrap_or_default(),
-                    })
-                    .await;
+                    },
+                )
+                .await;
             }
 
             let stop_reason = match finish_reason.as_deref() {
@@ -818,9 +869,7 @@ impl LlmDriver for OpenAIDriver {
                 }
             };
 
-            let _ = tx
-                .send(StreamEvent::ContentComplete { stop_reason, usage })
-                .await;
+            send_stream_event(&tx, StreamEvent::ContentComplete { stop_reason, usage }).await;
 
             return Ok(CompletionResponse {
                 content,
@@ -0,0 +1,190 @@
+//! Warm pool of pre-built LLM drivers.
+//!
+//! Building a driver constructs its HTTP client, which is the slow part of
+//! the first call after startup or a login. The pool builds drivers ahead of
+//! time and hands them to [`ModelFallbackChain`](super::fallback::ModelFallbackChain)
+//! through its driver factory. Entries are keyed by a SHA-256 of the full
+//! [`DriverConfig`], so a rotated token or changed base URL never reuses a
+//! stale driver; [`DriverPool::invalidate`] drops everything after auth or
+//! config changes.
+
+use super::fallback::DriverFactory;
+use crate::llm_driver::{DriverConfig, LlmDriver, LlmError};
+use pulsivo_salesman_types::config::LlmDriverPoolConfig;
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+/// Bounded, least-recently-used pool of drivers keyed by configuration.
+pub struct DriverPool {
+    max_drivers: usize,
+    factory: DriverFactory,
+    /// Pooled drivers, least recently used first.
+    drivers: Mutex<Vec<(String, Arc<dyn LlmDriver>)>>,
+}
+
+impl DriverPool {
+    /// Create a pool holding at most `max_drivers` drivers.
+    pub fn new(max_drivers: usize) -> Self {
+        Self {
+            max_drivers: max_drivers.max(1),
+            factory: Arc::new(super::create_driver),
+            drivers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Build a pool from config. Returns `None` when pooling is disabled.
+    pub fn from_config(config: &LlmDriverPoolConfig) -> Option<Self> {
+        config.enabled.then(|| Self::new(config.max_drivers))
+    }
+
+    /// Replace the driver factory (used by tests to count constructions).
+    pub fn with_driver_factory(mut self, factory: DriverFactory) -> Self {
+        self.factory = factory;
+        self
+    }
+
+    fn key(config: &DriverConfig) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_vec(config).unwrap_or_default());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Return the pooled driver for `config`, building and pooling it on a miss.
+    pub fn get(&self, config: &DriverConfig) -> Result<Arc<dyn LlmDriver>, LlmError> {
+        let key = Self::key(config);
+        {
+            let mut drivers = self.drivers.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(pos) = drivers.iter().position(|(k, _)| *k == key) {
+                let entry = drivers.remove(pos);
+                let driver = entry.1.clone();
+                drivers.push(entry);
+                return Ok(driver);
+            }
+        }
+
+        let driver = (self.factory)(config)?;
+        let mut drivers = self.drivers.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(pos) = drivers.iter().position(|(k, _)| *k == key) {
+            // Another caller built the same driver while the lock was released.
+            let entry = drivers.remove(pos);
+            let driver = entry.1.clone();
+            drivers.push(entry);
+            return Ok(driver);
+        }
+        if drivers.len() >= self.max_drivers {
+            drivers.remove(0);
+        }
+        drivers.push((key, driver.clone()));
+        Ok(driver)
+    }
+
+    /// Build drivers for `configs` ahead of the first call. Failures are
+    /// logged and left for the call itself to report.
+    pub fn warm(&self, configs: &[DriverConfig]) {
+        for config in configs.iter().take(self.max_drivers) {
+            match self.get(config) {
+                Ok(_) => debug!(provider = %config.provider, "Warmed LLM driver"),
+                Err(e) => warn!(provider = %config.provider, "Failed to warm LLM driver: {e}"),
+            }
+        }
+    }
+
+    /// Drop every pooled driver (after auth or config changes).
+    pub fn invalidate(&self) {
+        self.drivers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// A [`DriverFactory`] that serves drivers from this pool.
+    pub fn factory(self: &Arc<Self>) -> DriverFactory {
+        let pool = self.clone();
+        Arc::new(move |config| pool.get(config))
+    }
+
+    /// Number of pooled drivers.
+    pub fn len(&self) -> usize {
+        self.drivers.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Whether the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn config(provider: &str, api_key: &str) -> DriverConfig {
+        DriverConfig {
+            provider: provider.to_string(),
+            api_key: Some(api_key.to_string()),
+            base_url: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        }
+    }
+
+    fn counting_pool(max_drivers: usize, built: Arc<AtomicUsize>) -> DriverPool {
+        DriverPool::new(max_drivers).with_driver_factory(Arc::new(move |config| {
+            built.fetch_add(1, Ordering::SeqCst);
+            super::super::create_driver(config)
+        }))
+    }
+
+    #[test]
+    fn test_warmed_driver_is_reused_until_invalidated() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let pool = counting_pool(4, built.clone());
+        pool.warm(&[config("openai", "sk-one")]);
+        assert_eq!(built.load(Ordering::SeqCst), 1);
+
+        pool.get(&config("openai", "sk-one")).unwrap();
+        assert_eq!(built.load(Ordering::SeqCst), 1);
+
+        // A rotated key is a different driver.
+        pool.get(&config("openai", "sk-two")).unwrap();
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+
+        pool.invalidate();
+        assert!(pool.is_empty());
+        pool.get(&config("openai", "sk-one")).unwrap();
+        assert_eq!(built.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_pool_evicts_least_recently_used() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let pool = counting_pool(2, built.clone());
+        pool.get(&config("openai", "sk-a")).unwrap();
+        pool.get(&config("openai", "sk-b")).unwrap();
+        pool.get(&config("openai", "sk-a")).unwrap();
+        pool.get(&config("openai", "sk-c")).unwrap();
+        assert_eq!(pool.len(), 2);
+        assert_eq!(built.load(Ordering::SeqCst), 3);
+
+        pool.get(&config("openai", "sk-a")).unwrap();
+        assert_eq!(built.load(Ordering::SeqCst), 3);
+        pool.get(&config("openai", "sk-b")).unwrap();
+        assert_eq!(built.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_disabled_config_builds_no_pool() {
+        assert!(DriverPool::from_config(&LlmDriverPoolConfig::default()).is_none());
+        let enabled = LlmDriverPoolConfig {
+            enabled: true,
+            ..LlmDriverPoolConfig::default()
+        };
+        assert!(DriverPool::from_config(&enabled).is_some());
+    }
+}
@@ -0,0 +1,101 @@
+//! Shared construction of outbound HTTP clients.
+//!
+//! Every client the daemon builds (LLM drivers, OAuth, web search/fetch,
+//! sales enrichment) starts from [`builder`], so the configured user agent
+//! and proxy apply everywhere. The kernel calls [`configure`] at boot; until
+//! then the defaults are used, which still honour the standard proxy
+//! environment variables.
+
+use pulsivo_salesman_types::config::HttpClientConfig;
+use std::sync::RwLock;
+
+/// User agent sent when none is configured.
+pub const DEFAULT_USER_AGENT: &str = concat!("PulsivoSalesman/", env!("CARGO_PKG_VERSION"));
+
+/// Default user agent for web search and page fetches.
+pub const WEB_USER_AGENT: &str = "Mozilla/5.0 (compatible; PulsivoSalesmanAgent/0.1)";
+
+static SETTINGS: RwLock<Option<HttpClientConfig>> = RwLock::new(None);
+
+/// Install the process-wide HTTP client settings.
+///
+/// Clients built earlier keep their old settings. Fails without changing
+/// anything if the proxy URL does not parse.
+pub fn configure(config: &HttpClientConfig) -> Result<(), String> {
+    let config = HttpClientConfig {
+        user_agent: config.user_agent.trim().to_string(),
+        proxy: config.proxy.trim().to_string(),
+        no_proxy: config.no_proxy.trim().to_string(),
+    };
+    proxy_for(&config)?;
+    *SETTINGS.write().unwrap_or_else(|e| e.into_inner()) = Some(config);
+    Ok(())
+}
+
+/// A client builder carrying the configured user agent and proxy.
+pub fn builder() -> reqwest::ClientBuilder {
+    builder_with_user_agent(DEFAULT_USER_AGENT)
+}
+
+/// Like [`builder`], but `fallback` is the user agent when none is
+/// configured. Used where a specific agent is needed by default, such as
+/// web search or fetching company websites that reject unknown clients.
+pub fn builder_with_user_agent(fallback: &str) -> reqwest::ClientBuilder {
+    let settings = SETTINGS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default();
+    let user_agent = if settings.user_agent.is_empty() {
+        fallback
+    } else {
+        settings.user_agent.as_str()
+    };
+    let builder = reqwest::Client::builder().user_agent(user_agent);
+    match proxy_for(&settings) {
+        Ok(Some(proxy)) => builder.proxy(proxy),
+        _ => builder,
+    }
+}
+
+/// A client with the shared settings and no timeouts of its own.
+pub fn client() -> reqwest::Client {
+    builder().build().unwrap_or_default()
+}
+
+/// The explicit proxy from config, if any. `None` leaves reqwest's
+/// environment-based proxy detection in place.
+fn proxy_for(config: &HttpClientConfig) -> Result<Option<reqwest::Proxy>, String> {
+    if config.proxy.is_empty() {
+        return Ok(None);
+    }
+    let proxy = reqwest::Proxy::all(config.proxy.as_str())
+        .map_err(|e| format!("invalid http.proxy: {e}"))?;
+    let no_proxy = if config.no_proxy.is_empty() {
+        reqwest::NoProxy::from_env()
+    } else {
+        reqwest::NoProxy::from_string(&config.no_proxy)
+    };
+    Ok(Some(proxy.no_proxy(no_proxy)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proxy_for_rejects_invalid_urls_and_skips_empty() {
+        assert!(proxy_for(&HttpClientConfig::default()).unwrap().is_none());
+        let config = HttpClientConfig {
+            proxy: "http://proxy.corp.example:3128".to_string(),
+            no_proxy: "localhost,10.0.0.0/8".to_string(),
+            ..HttpClientConfig::default()
+        };
+        assert!(proxy_for(&config).unwrap().is_some());
+        let config = HttpClientConfig {
+            proxy: "not a url".to_string(),
+            ..HttpClientConfig::default()
+        };
+        assert!(proxy_for(&config).is_err());
+    }
+}
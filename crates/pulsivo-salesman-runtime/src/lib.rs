@@ -1,8 +1,12 @@
 //! Sales runtime primitives: LLM drivers, provider catalog, and web tools.
 
+pub mod context_trim;
 pub mod drivers;
+pub mod http_client;
 pub mod llm_driver;
 pub mod model_catalog;
+pub mod response_cache;
+pub mod system_prompt;
 pub mod web_cache;
 pub mod web_content;
 pub mod web_fetch;
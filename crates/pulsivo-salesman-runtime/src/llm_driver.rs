@@ -6,6 +6,9 @@ use async_trait::async_trait;
 use pulsivo_salesman_types::message::{ContentBlock, Message, StopReason, TokenUsage};
 use pulsivo_salesman_types::tool::{ToolCall, ToolDefinition};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Error type for LLM driver operations.
@@ -40,6 +43,24 @@ pub enum LlmError {
         /// How long to wait before retrying.
         retry_after_ms: u64,
     },
+    /// Connect, request, or stream-inactivity timeout elapsed.
+    #[error("Timed out: {0}")]
+    Timeout(String),
+    /// The stream closed before the provider's terminal event, so the content
+    /// received so far may be cut short and usage is unknown.
+    #[error("Stream truncated: {0}")]
+    StreamTruncated(String),
+}
+
+impl LlmError {
+    /// Map a transport error, keeping timeouts distinct from other HTTP failures.
+    pub fn from_http(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            LlmError::Timeout(err.to_string())
+        } else {
+            LlmError::Http(err.to_string())
+        }
+    }
 }
 
 /// A request to an LLM for completion.
@@ -64,7 +85,7 @@ pub struct CompletionRequest {
 }
 
 /// A response from an LLM completion.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletionResponse {
     /// The content blocks in the response.
     pub content: Vec<ContentBlock>,
@@ -89,6 +110,19 @@ impl CompletionResponse {
             .collect::<Vec<_>>()
             .join("")
     }
+
+    /// Drop repeated tool calls and `ToolUse` blocks that share a call id,
+    /// keeping the first occurrence. Calls without an id are left untouched.
+    pub fn dedupe_tool_calls(&mut self) {
+        let mut seen = HashSet::new();
+        self.tool_calls
+            .retain(|call| call.id.is_empty() || seen.insert(call.id.clone()));
+        let mut seen = HashSet::new();
+        self.content.retain(|block| match block {
+            ContentBlock::ToolUse { id, .. } => id.is_empty() || seen.insert(id.clone()),
+            _ => true,
+        });
+    }
 }
 
 /// Events emitted during streaming LLM completion.
@@ -108,6 +142,8 @@ pub enum StreamEvent {
     },
     /// Incremental thinking/reasoning text.
     ThinkingDelta { text: String },
+    /// A thinking/reasoning block is finished; `text` is the whole block.
+    ThinkingComplete { text: String },
     /// The entire response is complete.
     ContentComplete {
         stop_reason: StopReason,
@@ -124,6 +160,205 @@ pub enum StreamEvent {
         result_preview: String,
         is_error: bool,
     },
+    /// The turn hit its wall-clock limit and the driver call was cancelled.
+    Timeout { elapsed_ms: u64 },
+}
+
+impl StreamEvent {
+    /// Stable event name used for logging and drop accounting.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            StreamEvent::TextDelta { .. } => "text_delta",
+            StreamEvent::ToolUseStart { .. } => "tool_use_start",
+            StreamEvent::ToolInputDelta { .. } => "tool_input_delta",
+            StreamEvent::ToolUseEnd { .. } => "tool_use_end",
+            StreamEvent::ThinkingDelta { .. } => "thinking_delta",
+            StreamEvent::ThinkingComplete { .. } => "thinking_complete",
+            StreamEvent::ContentComplete { .. } => "content_complete",
+            StreamEvent::PhaseChange { .. } => "phase_change",
+            StreamEvent::ToolExecutionResult { .. } => "tool_execution_result",
+            StreamEvent::Timeout { .. } => "timeout",
+        }
+    }
+}
+
+/// How long a driver waits for a slow stream consumer before dropping an event.
+pub const STREAM_SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Stream events that never reached their receiver, by [`StreamEvent::kind`].
+static DROPPED_STREAM_EVENTS: LazyLock<Mutex<BTreeMap<&'static str, u64>>> =
+    LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+/// Deliver a stream event, waiting up to [`STREAM_SEND_TIMEOUT`] for room.
+///
+/// Returns whether the event was delivered. Events the receiver never takes
+/// (channel closed, or full for the whole timeout) are counted per kind in
+/// [`dropped_stream_events`] so lossy consumers can be spotted.
+pub async fn send_stream_event(
+    tx: &tokio::sync::mpsc::Sender<StreamEvent>,
+    event: StreamEvent,
+) -> bool {
+    send_stream_event_within(tx, event, STREAM_SEND_TIMEOUT).await
+}
+
+/// [`send_stream_event`] with an explicit wait bound.
+pub async fn send_stream_event_within(
+    tx: &tokio::sync::mpsc::Sender<StreamEvent>,
+    event: StreamEvent,
+    timeout: Duration,
+) -> bool {
+    let kind = event.kind();
+    let reason = match tx.send_timeout(event, timeout).await {
+        Ok(()) => return true,
+        Err(tokio::sync::mpsc::error::SendTimeoutError::Closed(_)) => "closed",
+        Err(tokio::sync::mpsc::error::SendTimeoutError::Timeout(_)) => "timeout",
+    };
+    *DROPPED_STREAM_EVENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .entry(kind)
+        .or_insert(0) += 1;
+    if reason == "timeout" {
+        tracing::warn!(event = kind, "Stream consumer stalled; dropped event");
+    } else {
+        tracing::debug!(event = kind, "Stream receiver closed; dropped event");
+    }
+    false
+}
+
+/// Send [`StreamEvent::ThinkingComplete`] for `thinking` unless it is empty
+/// or was already closed.
+///
+/// For providers that interleave reasoning with the answer and never mark
+/// where it ends: drivers call this when the first answer text or tool call
+/// arrives, and again before [`StreamEvent::ContentComplete`].
+pub(crate) async fn close_thinking(
+    tx: &tokio::sync::mpsc::Sender<StreamEvent>,
+    thinking: &str,
+    closed: &mut bool,
+) {
+    if *closed || thinking.is_empty() {
+        return;
+    }
+    *closed = true;
+    send_stream_event(
+        tx,
+        StreamEvent::ThinkingComplete {
+            text: thinking.to_string(),
+        },
+    )
+    .await;
+}
+
+/// Size and latency of one provider call, logged at debug level when it
+/// finishes. Only lengths and timings are recorded, never message contents.
+pub(crate) struct CallLog {
+    provider: &'static str,
+    model: String,
+    prompt_chars: usize,
+    started: std::time::Instant,
+    first_output: Option<std::time::Instant>,
+}
+
+impl CallLog {
+    /// Start timing a call for `request`.
+    pub(crate) fn start(provider: &'static str, request: &CompletionRequest) -> Self {
+        let system = request.system.as_deref().map(str::len).unwrap_or(0);
+        let messages = request
+            .messages
+            .iter()
+            .map(|message| message.content.text_length())
+            .sum::<usize>();
+        let tools = request
+            .tools
+            .iter()
+            .map(|tool| {
+                tool.name.len() + tool.description.len() + tool.input_schema.to_string().len()
+            })
+            .sum::<usize>();
+        Self {
+            provider,
+            model: request.model.clone(),
+            prompt_chars: system + messages + tools,
+            started: std::time::Instant::now(),
+            first_output: None,
+        }
+    }
+
+    /// Slot for the moment the first text, reasoning or tool call arrived;
+    /// only the first write counts.
+    pub(crate) fn first_output(&mut self) -> &mut Option<std::time::Instant> {
+        &mut self.first_output
+    }
+
+    /// Log the call's sizes and timings.
+    pub(crate) fn finish(&self, result: &Result<CompletionResponse, LlmError>) {
+        let latency_ms = self.started.elapsed().as_millis() as u64;
+        let ttft_ms = self
+            .first_output
+            .map(|at| at.duration_since(self.started).as_millis() as u64);
+        let prompt_tokens_est = crate::context_trim::estimate_tokens(self.prompt_chars);
+        match result {
+            Ok(response) => {
+                let response_chars = response
+                    .content
+                    .iter()
+                    .map(|block| match block {
+                        ContentBlock::Text { text } => text.len(),
+                        ContentBlock::Thinking { thinking } => thinking.len(),
+                        ContentBlock::ToolUse { name, input, .. } => {
+                            name.len() + input.to_string().len()
+                        }
+                        _ => 0,
+                    })
+                    .sum::<usize>();
+                tracing::debug!(
+                    provider = self.provider,
+                    model = %self.model,
+                    prompt_chars = self.prompt_chars,
+                    prompt_tokens_est,
+                    response_chars,
+                    tool_calls = response.tool_calls.len(),
+                    input_tokens = response.usage.input_tokens,
+                    output_tokens = response.usage.output_tokens,
+                    stop_reason = ?response.stop_reason,
+                    ttft_ms,
+                    latency_ms,
+                    "LLM call finished"
+                );
+            }
+            Err(error) => {
+                let error_kind = match error {
+                    LlmError::Http(_) => "http",
+                    LlmError::Api { .. } => "api",
+                    LlmError::RateLimited { .. } => "rate_limited",
+                    LlmError::Parse(_) => "parse",
+                    LlmError::MissingApiKey(_) => "missing_api_key",
+                    LlmError::Overloaded { .. } => "overloaded",
+                    LlmError::Timeout(_) => "timeout",
+                    LlmError::StreamTruncated(_) => "stream_truncated",
+                };
+                tracing::debug!(
+                    provider = self.provider,
+                    model = %self.model,
+                    prompt_chars = self.prompt_chars,
+                    prompt_tokens_est,
+                    error_kind,
+                    ttft_ms,
+                    latency_ms,
+                    "LLM call failed"
+                );
+            }
+        }
+    }
+}
+
+/// Snapshot of dropped stream event counts by kind.
+pub fn dropped_stream_events() -> BTreeMap<&'static str, u64> {
+    DROPPED_STREAM_EVENTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
 }
 
 /// Trait for LLM drivers.
@@ -140,20 +375,111 @@ pub trait LlmDriver: Send + Sync {
         tx: tokio::sync::mpsc::Sender<StreamEvent>,
     ) -> Result<CompletionResponse, LlmError> {
         let response = self.complete(request).await?;
+        for block in &response.content {
+            if let ContentBlock::Thinking { thinking } = block {
+                send_stream_event(
+                    &tx,
+                    StreamEvent::ThinkingComplete {
+                        text: thinking.clone(),
+                    },
+                )
+                .await;
+            }
+        }
         let text = response.text();
         if !text.is_empty() {
-            let _ = tx.send(StreamEvent::TextDelta { text }).await;
+            send_stream_event(&tx, StreamEvent::TextDelta { text }).await;
         }
-        let _ = tx
-            .send(StreamEvent::ContentComplete {
+        send_stream_event(
+            &tx,
+            StreamEvent::ContentComplete {
                 stop_reason: response.stop_reason,
                 usage: response.usage,
-            })
-            .await;
+            },
+        )
+        .await;
         Ok(response)
     }
 }
 
+/// Stream one turn from `driver`, cancelling it once `deadline` elapses.
+///
+/// Events are forwarded to `tx` when given. On timeout the in-flight call is
+/// dropped, a [`StreamEvent::Timeout`] is sent, and the text and completed
+/// tool calls received so far come back with [`StopReason::Timeout`].
+pub async fn stream_within_deadline(
+    driver: &dyn LlmDriver,
+    request: CompletionRequest,
+    deadline: Duration,
+    tx: Option<&tokio::sync::mpsc::Sender<StreamEvent>>,
+) -> Result<CompletionResponse, LlmError> {
+    let started = std::time::Instant::now();
+    let (inner_tx, mut inner_rx) = tokio::sync::mpsc::channel(64);
+    let mut text = String::new();
+    let mut tool_calls = Vec::new();
+    let turn = driver.stream(request, inner_tx);
+    tokio::pin!(turn);
+    let expired = tokio::time::sleep(deadline);
+    tokio::pin!(expired);
+
+    loop {
+        tokio::select! {
+            result = &mut turn => {
+                while let Ok(event) = inner_rx.try_recv() {
+                    if let Some(tx) = tx {
+                        send_stream_event(tx, event).await;
+                    }
+                }
+                return result;
+            }
+            Some(event) = inner_rx.recv() => {
+                match &event {
+                    StreamEvent::TextDelta { text: delta } => text.push_str(delta),
+                    StreamEvent::ToolUseEnd { id, name, input } => tool_calls.push(ToolCall {
+                        id: id.clone(),
+                        name: name.clone(),
+                        input: input.clone(),
+                    }),
+                    _ => {}
+                }
+                if let Some(tx) = tx {
+                    let remaining = deadline.saturating_sub(started.elapsed());
+                    send_stream_event_within(tx, event, remaining).await;
+                }
+            }
+            _ = &mut expired => break,
+        }
+    }
+
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    tracing::warn!(
+        elapsed_ms,
+        partial_chars = text.len(),
+        "LLM turn exceeded its wall-clock limit; returning partial result"
+    );
+    if let Some(tx) = tx {
+        send_stream_event_within(tx, StreamEvent::Timeout { elapsed_ms }, STREAM_SEND_TIMEOUT)
+            .await;
+    }
+    let mut content = Vec::new();
+    if !text.is_empty() {
+        content.push(ContentBlock::Text { text });
+    }
+    content.extend(tool_calls.iter().map(|call| ContentBlock::ToolUse {
+        id: call.id.clone(),
+        name: call.name.clone(),
+        input: call.input.clone(),
+    }));
+    let mut response = CompletionResponse {
+        content,
+        stop_reason: StopReason::Timeout,
+        tool_calls,
+        usage: TokenUsage::default(),
+    };
+    response.dedupe_tool_calls();
+    Ok(response)
+}
+
 /// Configuration for creating an LLM driver.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct DriverConfig {
@@ -163,6 +489,75 @@ pub struct DriverConfig {
     pub api_key: Option<String>,
     /// Base URL override.
     pub base_url: Option<String>,
+    /// `originator` header for the OpenAI Codex backend (defaults to `pi`).
+    #[serde(default)]
+    pub codex_originator: Option<String>,
+    /// `openai-beta` header for the OpenAI Codex backend
+    /// (defaults to `responses=experimental`).
+    #[serde(default)]
+    pub codex_openai_beta: Option<String>,
+    /// Responses API `truncation` strategy for the OpenAI Codex backend
+    /// (`auto` or `disabled`); omitted when unset.
+    #[serde(default)]
+    pub codex_truncation: Option<String>,
+    /// TCP/TLS connect timeout in seconds (default 10).
+    #[serde(default)]
+    pub connect_timeout_secs: Option<u64>,
+    /// Overall per-request timeout in seconds, including the response body
+    /// (default 600).
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
+    /// Maximum gap between streamed chunks in seconds (default 120).
+    #[serde(default)]
+    pub stream_idle_timeout_secs: Option<u64>,
+}
+
+/// Resolved HTTP timeouts for a provider driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriverTimeouts {
+    /// TCP/TLS connect timeout.
+    pub connect: Duration,
+    /// Overall request timeout, including the response body.
+    pub request: Duration,
+    /// Maximum gap between streamed chunks.
+    pub stream_idle: Duration,
+}
+
+impl Default for DriverTimeouts {
+    fn default() -> Self {
+        Self {
+            connect: Duration::from_secs(10),
+            request: Duration::from_secs(600),
+            stream_idle: Duration::from_secs(120),
+        }
+    }
+}
+
+impl DriverTimeouts {
+    /// Resolve timeouts from a driver config; unset or zero values keep the defaults.
+    pub fn from_config(config: &DriverConfig) -> Self {
+        let defaults = Self::default();
+        let secs = |value: Option<u64>, fallback: Duration| {
+            value
+                .filter(|secs| *secs > 0)
+                .map(Duration::from_secs)
+                .unwrap_or(fallback)
+        };
+        Self {
+            connect: secs(config.connect_timeout_secs, defaults.connect),
+            request: secs(config.request_timeout_secs, defaults.request),
+            stream_idle: secs(config.stream_idle_timeout_secs, defaults.stream_idle),
+        }
+    }
+
+    /// Build an HTTP client enforcing the connect and request timeouts.
+    pub fn http_client(&self) -> Result<reqwest::Client, LlmError> {
+        crate::http_client::builder()
+            .connect_timeout(self.connect)
+            .timeout(self.request)
+            .build()
+            .map_err(|e| LlmError::Http(format!("Failed to build HTTP client: {e}")))
+    }
 }
 
 /// SECURITY: Custom Debug impl redacts the API key.
@@ -172,6 +567,12 @@ impl std::fmt::Debug for DriverConfig {
             .field("provider", &self.provider)
             .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
             .field("base_url", &self.base_url)
+            .field("codex_originator", &self.codex_originator)
+            .field("codex_openai_beta", &self.codex_openai_beta)
+            .field("codex_truncation", &self.codex_truncation)
+            .field("connect_timeout_secs", &self.connect_timeout_secs)
+            .field("request_timeout_secs", &self.request_timeout_secs)
+            .field("stream_idle_timeout_secs", &self.stream_idle_timeout_secs)
             .finish()
     }
 }
@@ -198,6 +599,32 @@ mod tests {
         assert_eq!(response.text(), "Hello world!");
     }
 
+    #[test]
+    fn test_call_log_counts_prompt_chars_and_waits_for_first_output() {
+        let request = CompletionRequest {
+            model: "gpt-5".to_string(),
+            messages: vec![pulsivo_salesman_types::message::Message::user("hello")],
+            tools: vec![],
+            max_tokens: 64,
+            temperature: 0.0,
+            system: Some("be brief".to_string()),
+            thinking: None,
+            reasoning_effort: None,
+        };
+        let mut call_log = CallLog::start("codex", &request);
+        assert_eq!(call_log.prompt_chars, "be brief".len() + "hello".len());
+        assert!(call_log.first_output.is_none());
+
+        call_log
+            .first_output()
+            .get_or_insert_with(std::time::Instant::now);
+        let marked = call_log.first_output;
+        call_log
+            .first_output()
+            .get_or_insert_with(std::time::Instant::now);
+        assert_eq!(call_log.first_output, marked);
+    }
+
     #[test]
     fn test_stream_event_clone() {
         let event = StreamEvent::TextDelta {
@@ -291,4 +718,142 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_driver_timeouts_from_config() {
+        let config = DriverConfig {
+            provider: "gemini".to_string(),
+            api_key: None,
+            base_url: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: Some(3),
+            request_timeout_secs: Some(0),
+            stream_idle_timeout_secs: Some(45),
+        };
+        let timeouts = DriverTimeouts::from_config(&config);
+        assert_eq!(timeouts.connect, Duration::from_secs(3));
+        assert_eq!(timeouts.request, DriverTimeouts::default().request);
+        assert_eq!(timeouts.stream_idle, Duration::from_secs(45));
+        assert!(timeouts.http_client().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_stream_event_counts_drops_on_full_channel() {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        assert!(
+            send_stream_event(
+                &tx,
+                StreamEvent::TextDelta {
+                    text: "first".to_string(),
+                },
+            )
+            .await
+        );
+
+        let before = dropped_stream_events()
+            .get("content_complete")
+            .copied()
+            .unwrap_or(0);
+        let delivered = send_stream_event_within(
+            &tx,
+            StreamEvent::ContentComplete {
+                stop_reason: StopReason::EndTurn,
+                usage: TokenUsage::default(),
+            },
+            Duration::from_millis(20),
+        )
+        .await;
+        assert!(!delivered);
+        let after = dropped_stream_events()
+            .get("content_complete")
+            .copied()
+            .unwrap_or(0);
+        assert!(after > before);
+    }
+
+    #[tokio::test]
+    async fn test_send_stream_event_reports_closed_receiver() {
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        drop(rx);
+        let delivered = send_stream_event(
+            &tx,
+            StreamEvent::ThinkingDelta {
+                text: "lost".to_string(),
+            },
+        )
+        .await;
+        assert!(!delivered);
+        assert!(dropped_stream_events().get("thinking_delta").copied() >= Some(1));
+    }
+
+    #[tokio::test]
+    async fn stream_within_deadline_returns_partial_output_on_timeout() {
+        struct StallingDriver;
+
+        #[async_trait]
+        impl LlmDriver for StallingDriver {
+            async fn complete(
+                &self,
+                _request: CompletionRequest,
+            ) -> Result<CompletionResponse, LlmError> {
+                unreachable!("stream is overridden")
+            }
+
+            async fn stream(
+                &self,
+                _request: CompletionRequest,
+                tx: tokio::sync::mpsc::Sender<StreamEvent>,
+            ) -> Result<CompletionResponse, LlmError> {
+                send_stream_event(
+                    &tx,
+                    StreamEvent::TextDelta {
+                        text: "Partial ".to_string(),
+                    },
+                )
+                .await;
+                send_stream_event(
+                    &tx,
+                    StreamEvent::ToolUseEnd {
+                        id: "call_1".to_string(),
+                        name: "lookup".to_string(),
+                        input: serde_json::json!({"q": 1}),
+                    },
+                )
+                .await;
+                futures::future::pending().await
+            }
+        }
+
+        let request = CompletionRequest {
+            model: "test".to_string(),
+            messages: vec![],
+            tools: vec![],
+            max_tokens: 100,
+            temperature: 0.0,
+            system: None,
+            thinking: None,
+            reasoning_effort: None,
+        };
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let response = stream_within_deadline(
+            &StallingDriver,
+            request,
+            Duration::from_millis(50),
+            Some(&tx),
+        )
+        .await
+        .expect("partial response");
+
+        assert_eq!(response.stop_reason, StopReason::Timeout);
+        assert_eq!(response.text(), "Partial ");
+        assert_eq!(response.tool_calls.len(), 1);
+
+        let mut kinds = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            kinds.push(event.kind());
+        }
+        assert_eq!(kinds, vec!["text_delta", "tool_use_end", "timeout"]);
+    }
 }
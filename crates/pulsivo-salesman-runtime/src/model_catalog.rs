@@ -3,6 +3,8 @@
 //! Provides a comprehensive catalog of 130+ builtin models across 28 providers,
 //! with alias resolution, auth status detection, and pricing lookups.
 
+use pulsivo_salesman_types::config::ModelPricingConfig;
+use pulsivo_salesman_types::message::TokenUsage;
 use pulsivo_salesman_types::model_catalog::{
     AuthStatus, ModelCatalogEntry, ModelTier, ProviderInfo, AI21_BASE_URL, ANTHROPIC_BASE_URL,
     BEDROCK_BASE_URL, CEREBRAS_BASE_URL, COHERE_BASE_URL, DEEPSEEK_BASE_URL, FIREWORKS_BASE_URL,
@@ -123,6 +125,27 @@ impl ModelCatalog {
             .map(|m| (m.input_cost_per_m, m.output_cost_per_m))
     }
 
+    /// Estimate the USD cost of `usage` on a model.
+    ///
+    /// `overrides` (from `model_pricing` config) win over catalog prices.
+    /// Returns `None` for models with no known price.
+    pub fn estimate_cost(
+        &self,
+        model_id: &str,
+        usage: &TokenUsage,
+        overrides: &[ModelPricingConfig],
+    ) -> Option<f64> {
+        let (input_per_m, output_per_m) = overrides
+            .iter()
+            .find(|entry| entry.model.eq_ignore_ascii_case(model_id.trim()))
+            .map(|entry| (entry.input_cost_per_m, entry.output_cost_per_m))
+            .or_else(|| self.pricing(model_id))?;
+        Some(
+            (usage.input_tokens as f64 * input_per_m + usage.output_tokens as f64 * output_per_m)
+                / 1_000_000.0,
+        )
+    }
+
     /// List all alias mappings.
     pub fn list_aliases(&self) -> &HashMap<String, String> {
         &self.aliases
@@ -2336,6 +2359,29 @@ mod tests {
         assert_eq!(catalog.list_providers().len(), 28);
     }
 
+    #[test]
+    fn test_estimate_cost_prefers_overrides() {
+        let catalog = ModelCatalog::new();
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 100_000,
+        };
+        assert_eq!(
+            catalog.estimate_cost("claude-sonnet-4-20250514", &usage, &[]),
+            Some(4.5)
+        );
+        let overrides = vec![ModelPricingConfig {
+            model: "Claude-Sonnet-4-20250514".to_string(),
+            input_cost_per_m: 1.0,
+            output_cost_per_m: 10.0,
+        }];
+        assert_eq!(
+            catalog.estimate_cost("claude-sonnet-4-20250514", &usage, &overrides),
+            Some(2.0)
+        );
+        assert_eq!(catalog.estimate_cost("no-such-model", &usage, &[]), None);
+    }
+
     #[test]
     fn test_find_model_by_id() {
         let catalog = ModelCatalog::new();
@@ -0,0 +1,198 @@
+//! Content-addressed cache for deterministic LLM responses.
+//!
+//! Requests sent with `temperature == 0` (planning, profile autofill, JSON
+//! repair) are keyed by a SHA-256 of the provider, base URL, model, messages,
+//! tools and sampling parameters. Identical requests within the TTL are answered from memory
+//! instead of re-hitting the provider. Storage is a bounded [`WebCache`].
+
+use crate::drivers::effective_base_url;
+use crate::llm_driver::{CompletionRequest, CompletionResponse, DriverConfig};
+use crate::web_cache::WebCache;
+use pulsivo_salesman_types::config::LlmCacheConfig;
+use pulsivo_salesman_types::message::TokenUsage;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// Bounded TTL cache of serialized completion responses.
+pub struct ResponseCache {
+    entries: WebCache,
+}
+
+impl ResponseCache {
+    /// Create a cache holding at most `max_entries` responses for `ttl`.
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            entries: WebCache::new(ttl).with_max_entries(max_entries),
+        }
+    }
+
+    /// Build a cache from config. Returns `None` when caching is disabled.
+    pub fn from_config(config: &LlmCacheConfig) -> Option<Self> {
+        if !config.enabled || config.ttl_secs == 0 {
+            return None;
+        }
+        Some(Self::new(
+            Duration::from_secs(config.ttl_secs),
+            config.max_entries,
+        ))
+    }
+
+    /// Cache key for `request` sent through `config`, or `None` if the
+    /// request is not deterministic.
+    pub fn key(config: &DriverConfig, request: &CompletionRequest) -> Option<String> {
+        if request.temperature != 0.0 {
+            return None;
+        }
+        let material = serde_json::json!({
+            "provider": config.provider,
+            "base_url": effective_base_url(config),
+            "model": request.model,
+            "messages": request.messages,
+            "tools": request.tools,
+            "max_tokens": request.max_tokens,
+            "system": request.system,
+            "thinking": request.thinking,
+            "reasoning_effort": request.reasoning_effort,
+        });
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_vec(&material).ok()?);
+        Some(hex::encode(hasher.finalize()))
+    }
+
+    /// Look up a cached response. Hits report zero token usage, since the
+    /// provider was not called.
+    pub fn get(
+        &self,
+        config: &DriverConfig,
+        request: &CompletionRequest,
+    ) -> Option<CompletionResponse> {
+        let raw = self.entries.get(&Self::key(config, request)?)?;
+        let mut response: CompletionResponse = serde_json::from_str(&raw).ok()?;
+        response.usage = TokenUsage::default();
+        Some(response)
+    }
+
+    /// Store `response` for `request` if the request is deterministic.
+    pub fn put(
+        &self,
+        config: &DriverConfig,
+        request: &CompletionRequest,
+        response: &CompletionResponse,
+    ) {
+        let Some(key) = Self::key(config, request) else {
+            return;
+        };
+        if let Ok(raw) = serde_json::to_string(response) {
+            self.entries.put(key, raw);
+        }
+    }
+
+    /// Number of cached responses (including possibly expired).
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsivo_salesman_types::message::{ContentBlock, Message, StopReason};
+
+    fn request(temperature: f32) -> CompletionRequest {
+        CompletionRequest {
+            model: "test-model".to_string(),
+            messages: vec![Message::user("Plan queries for Acme")],
+            tools: Vec::new(),
+            max_tokens: 100,
+            temperature,
+            system: None,
+            thinking: None,
+            reasoning_effort: None,
+        }
+    }
+
+    fn config(provider: &str, base_url: Option<&str>) -> DriverConfig {
+        DriverConfig {
+            provider: provider.to_string(),
+            api_key: None,
+            base_url: base_url.map(str::to_string),
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        }
+    }
+
+    fn response() -> CompletionResponse {
+        CompletionResponse {
+            content: vec![ContentBlock::Text {
+                text: "{\"queries\":[]}".to_string(),
+            }],
+            stop_reason: StopReason::EndTurn,
+            tool_calls: Vec::new(),
+            usage: TokenUsage {
+                input_tokens: 10,
+                output_tokens: 5,
+            },
+        }
+    }
+
+    #[test]
+    fn test_deterministic_request_round_trips() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 8);
+        let openai = config("openai", None);
+        cache.put(&openai, &request(0.0), &response());
+        let hit = cache.get(&openai, &request(0.0)).expect("cached");
+        assert_eq!(hit.text(), "{\"queries\":[]}");
+        assert_eq!(hit.usage.input_tokens, 0);
+
+        let mut other = request(0.0);
+        other.model = "other-model".to_string();
+        assert!(cache.get(&openai, &other).is_none());
+    }
+
+    #[test]
+    fn test_key_separates_providers_and_endpoints() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 8);
+        cache.put(&config("openai", None), &request(0.0), &response());
+        assert!(cache.get(&config("groq", None), &request(0.0)).is_none());
+        assert!(cache
+            .get(
+                &config("openai", Some("http://localhost:8000/v1")),
+                &request(0.0)
+            )
+            .is_none());
+        assert!(cache
+            .get(
+                &config("openai", Some("https://api.openai.com/v1/")),
+                &request(0.0)
+            )
+            .is_some());
+    }
+
+    #[test]
+    fn test_sampled_requests_are_not_cached() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 8);
+        let openai = config("openai", None);
+        cache.put(&openai, &request(0.7), &response());
+        assert!(cache.is_empty());
+        assert!(ResponseCache::key(&openai, &request(0.7)).is_none());
+    }
+
+    #[test]
+    fn test_disabled_config_builds_no_cache() {
+        assert!(ResponseCache::from_config(&LlmCacheConfig::default()).is_none());
+        let enabled = LlmCacheConfig {
+            enabled: true,
+            ..LlmCacheConfig::default()
+        };
+        assert!(ResponseCache::from_config(&enabled).is_some());
+    }
+}
@@ -0,0 +1,135 @@
+//! Server-level system prompt wrapping.
+//!
+//! Regulated deployments need a fixed disclaimer on every turn. Rather than
+//! relying on each caller's prompt, [`SystemPromptWrap`] folds the configured
+//! `prepend_system` / `append_system` text around whatever system prompt the
+//! request already carries, before any provider sees it.
+
+use crate::llm_driver::CompletionRequest;
+use pulsivo_salesman_types::config::SystemPromptConfig;
+use pulsivo_salesman_types::message::Role;
+
+/// Text placed before and after a request's own system prompt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SystemPromptWrap {
+    pub prepend: String,
+    pub append: String,
+}
+
+impl SystemPromptWrap {
+    /// Build from config, trimming surrounding whitespace.
+    pub fn from_config(config: &SystemPromptConfig) -> Self {
+        Self {
+            prepend: config.prepend_system.trim().to_string(),
+            append: config.append_system.trim().to_string(),
+        }
+    }
+
+    /// True when neither side is configured; [`apply`](Self::apply) is then a no-op.
+    pub fn is_empty(&self) -> bool {
+        self.prepend.is_empty() && self.append.is_empty()
+    }
+
+    /// Wrap the request's system prompt in place.
+    ///
+    /// When `request.system` is unset, leading system messages are folded into
+    /// it first so every driver sees the same single, wrapped prompt.
+    pub fn apply(&self, request: &mut CompletionRequest) {
+        if self.is_empty() {
+            return;
+        }
+        let own = match request
+            .system
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            Some(system) => system.to_string(),
+            None => {
+                let leading = request
+                    .messages
+                    .iter()
+                    .take_while(|m| m.role == Role::System)
+                    .count();
+                request
+                    .messages
+                    .drain(..leading)
+                    .map(|m| m.content.text_content())
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            }
+        };
+        let wrapped = [self.prepend.as_str(), own.trim(), self.append.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        request.system = Some(wrapped);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulsivo_salesman_types::message::Message;
+
+    fn request(system: Option<&str>, messages: Vec<Message>) -> CompletionRequest {
+        CompletionRequest {
+            model: "test-model".to_string(),
+            messages,
+            tools: Vec::new(),
+            max_tokens: 100,
+            temperature: 0.0,
+            system: system.map(str::to_string),
+            thinking: None,
+            reasoning_effort: None,
+        }
+    }
+
+    fn wrap() -> SystemPromptWrap {
+        SystemPromptWrap::from_config(&SystemPromptConfig {
+            prepend_system: " Not financial advice. ".to_string(),
+            append_system: "Disclose that you are automated.".to_string(),
+        })
+    }
+
+    #[test]
+    fn wraps_explicit_system_prompt() {
+        let mut req = request(
+            Some("You are a sales assistant."),
+            vec![Message::user("hi")],
+        );
+        wrap().apply(&mut req);
+        assert_eq!(
+            req.system.as_deref(),
+            Some("Not financial advice.\n\nYou are a sales assistant.\n\nDisclose that you are automated.")
+        );
+        assert_eq!(req.messages.len(), 1);
+    }
+
+    #[test]
+    fn folds_leading_system_messages_when_system_is_unset() {
+        let mut req = request(
+            None,
+            vec![Message::system("Be brief."), Message::user("hi")],
+        );
+        wrap().apply(&mut req);
+        assert_eq!(
+            req.system.as_deref(),
+            Some("Not financial advice.\n\nBe brief.\n\nDisclose that you are automated.")
+        );
+        assert_eq!(req.messages.len(), 1);
+        assert_eq!(req.messages[0].role, Role::User);
+    }
+
+    #[test]
+    fn unset_config_is_a_no_op() {
+        let mut req = request(
+            None,
+            vec![Message::system("Be brief."), Message::user("hi")],
+        );
+        SystemPromptWrap::from_config(&SystemPromptConfig::default()).apply(&mut req);
+        assert!(req.system.is_none());
+        assert_eq!(req.messages.len(), 2);
+    }
+}
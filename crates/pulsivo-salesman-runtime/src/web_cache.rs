@@ -2,7 +2,8 @@
 //!
 //! Thread-safe via `DashMap`. Lazy eviction on `get()` — expired entries
 //! are only cleaned up when accessed. A `Duration::ZERO` TTL disables
-//! caching entirely (zero-cost passthrough).
+//! caching entirely (zero-cost passthrough). An optional entry bound evicts
+//! the oldest entry when the cache is full.
 
 use dashmap::DashMap;
 use std::time::{Duration, Instant};
@@ -17,6 +18,7 @@ struct CacheEntry {
 pub struct WebCache {
     entries: DashMap<String, CacheEntry>,
     ttl: Duration,
+    max_entries: usize,
 }
 
 impl WebCache {
@@ -25,9 +27,16 @@ impl WebCache {
         Self {
             entries: DashMap::new(),
             ttl,
+            max_entries: 0,
         }
     }
 
+    /// Bound the cache to `max_entries` (0 = unbounded).
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
     /// Get a cached value by key. Returns `None` if missing or expired.
     /// Expired entries are lazily evicted on access.
     pub fn get(&self, key: &str) -> Option<String> {
@@ -49,6 +58,22 @@ impl WebCache {
         if self.ttl.is_zero() {
             return;
         }
+        if self.max_entries > 0
+            && self.entries.len() >= self.max_entries
+            && !self.entries.contains_key(&key)
+        {
+            self.evict_expired();
+            if self.entries.len() >= self.max_entries {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|entry| entry.inserted_at)
+                    .map(|entry| entry.key().clone());
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
         self.entries.insert(
             key,
             CacheEntry {
@@ -126,6 +151,18 @@ mod tests {
         assert_eq!(cache.get("key1"), Some("new".to_string()));
     }
 
+    #[test]
+    fn test_max_entries_evicts_oldest() {
+        let cache = WebCache::new(Duration::from_secs(60)).with_max_entries(2);
+        cache.put("a".to_string(), "1".to_string());
+        std::thread::sleep(Duration::from_millis(2));
+        cache.put("b".to_string(), "2".to_string());
+        cache.put("c".to_string(), "3".to_string());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("c"), Some("3".to_string()));
+    }
+
     #[test]
     fn test_len() {
         let cache = WebCache::new(Duration::from_secs(60));
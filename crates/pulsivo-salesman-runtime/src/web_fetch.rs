@@ -4,6 +4,7 @@
 //! Pipeline: SSRF check → cache lookup → HTTP GET → detect HTML →
 //! html_to_markdown() → truncate → wrap_external_content() → cache → return
 
+use crate::http_client::{self, WEB_USER_AGENT};
 use crate::web_cache::WebCache;
 use crate::web_content::{html_to_markdown, wrap_external_content};
 use pulsivo_salesman_types::config::WebFetchConfig;
@@ -21,7 +22,7 @@ pub struct WebFetchEngine {
 impl WebFetchEngine {
     /// Create a new fetch engine from config with a shared cache.
     pub fn new(config: WebFetchConfig, cache: Arc<WebCache>) -> Self {
-        let client = reqwest::Client::builder()
+        let client = http_client::builder_with_user_agent(WEB_USER_AGENT)
             .timeout(std::time::Duration::from_secs(config.timeout_secs))
             .build()
             .unwrap_or_default();
@@ -48,10 +49,6 @@ impl WebFetchEngine {
         let resp = self
             .client
             .get(url)
-            .header(
-                "User-Agent",
-                "Mozilla/5.0 (compatible; PulsivoSalesmanAgent/0.1)",
-            )
             .send()
             .await
             .map_err(|e| format!("HTTP request failed: {e}"))?;
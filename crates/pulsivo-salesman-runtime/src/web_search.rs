@@ -9,6 +9,7 @@
 //! All API keys use `Zeroizing<String>` via `resolve_api_key()` to auto-wipe
 //! secrets from memory on drop.
 
+use crate::http_client::{self, WEB_USER_AGENT};
 use crate::web_cache::WebCache;
 use crate::web_content::wrap_external_content;
 use pulsivo_salesman_types::config::{SearchProvider, WebConfig};
@@ -34,7 +35,7 @@ pub struct WebToolsContext {
 impl WebSearchEngine {
     /// Create a new search engine from config with a shared cache.
     pub fn new(config: WebConfig, cache: Arc<WebCache>) -> Self {
-        let client = reqwest::Client::builder()
+        let client = http_client::builder_with_user_agent(WEB_USER_AGENT)
             .timeout(std::time::Duration::from_secs(15))
             .build()
             .unwrap_or_default();
@@ -292,10 +293,6 @@ impl WebSearchEngine {
             .client
             .get("https://html.duckduckgo.com/html/")
             .query(&[("q", query)])
-            .header(
-                "User-Agent",
-                "Mozilla/5.0 (compatible; PulsivoSalesmanAgent/0.1)",
-            )
             .send()
             .await
         {
@@ -356,10 +353,6 @@ impl WebSearchEngine {
                 ("mkt", "en-US"),
                 ("cc", "us"),
             ])
-            .header(
-                "User-Agent",
-                "Mozilla/5.0 (compatible; PulsivoSalesmanAgent/0.1)",
-            )
             .send()
             .await
             .map_err(|e| format!("Bing RSS request failed: {e}"))?;
@@ -416,6 +416,18 @@ pub struct FallbackModel {
     pub api_key_env: Option<String>,
     #[serde(default)]
     pub base_url: Option<String>,
+    /// TCP/TLS connect timeout in seconds; unset or `0` keeps the driver
+    /// default (10).
+    #[serde(default)]
+    pub connect_timeout_secs: Option<u64>,
+    /// Overall per-request timeout in seconds, including the response body;
+    /// unset or `0` keeps the driver default (600).
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
+    /// Maximum gap between streamed chunks in seconds; unset or `0` keeps the
+    /// driver default (120).
+    #[serde(default)]
+    pub stream_idle_timeout_secs: Option<u64>,
 }
 
 /// Tool configuration within an agent manifest.
@@ -513,6 +525,30 @@ impl Default for AgentManifest {
     }
 }
 
+impl AgentManifest {
+    /// Serialize to TOML and confirm the output parses back to the same manifest.
+    ///
+    /// Catches field combinations that serialize but do not round-trip, so a
+    /// broken manifest is rejected before it is written to disk.
+    pub fn to_toml_checked(&self) -> Result<String, crate::error::PulsivoSalesmanError> {
+        let invalid = |msg: String| crate::error::PulsivoSalesmanError::InvalidInput(msg);
+        let toml_str = toml::to_string_pretty(self)
+            .map_err(|e| invalid(format!("Manifest is not TOML-serializable: {e}")))?;
+        let parsed: AgentManifest = toml::from_str(&toml_str)
+            .map_err(|e| invalid(format!("Manifest TOML does not parse back: {e}")))?;
+        let original = serde_json::to_value(self)
+            .map_err(|e| invalid(format!("Manifest is not serializable: {e}")))?;
+        let round_tripped = serde_json::to_value(&parsed)
+            .map_err(|e| invalid(format!("Manifest is not serializable: {e}")))?;
+        if original != round_tripped {
+            return Err(invalid(
+                "Manifest TOML does not round-trip to the same manifest".to_string(),
+            ));
+        }
+        Ok(toml_str)
+    }
+}
+
 /// Capability declarations in a manifest (human-readable TOML format).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -768,6 +804,60 @@ mod tests {
         assert_eq!(deserialized.tags, vec!["test".to_string()]);
     }
 
+    #[test]
+    fn test_manifest_toml_round_trip_scheduled() {
+        let manifest = AgentManifest {
+            name: "daily-digest".to_string(),
+            schedule: ScheduleMode::Periodic {
+                cron: "0 9 * * *".to_string(),
+            },
+            ..Default::default()
+        };
+        let toml_str = manifest.to_toml_checked().unwrap();
+        let back: AgentManifest = toml::from_str(&toml_str).unwrap();
+        assert!(
+            matches!(back.schedule, ScheduleMode::Periodic { ref cron } if cron == "0 9 * * *")
+        );
+    }
+
+    #[test]
+    fn test_manifest_toml_round_trip_browser_capabilities() {
+        let manifest = AgentManifest {
+            capabilities: ManifestCapabilities {
+                network: vec!["*".to_string()],
+                tools: vec![
+                    "browser_navigate".to_string(),
+                    "browser_screenshot".to_string(),
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let toml_str = manifest.to_toml_checked().unwrap();
+        let back: AgentManifest = toml::from_str(&toml_str).unwrap();
+        assert_eq!(back.capabilities.tools.len(), 2);
+        assert_eq!(back.capabilities.network, vec!["*".to_string()]);
+    }
+
+    #[test]
+    fn test_manifest_toml_round_trip_empty_collections() {
+        let manifest = AgentManifest::default();
+        let toml_str = manifest.to_toml_checked().unwrap();
+        let back: AgentManifest = toml::from_str(&toml_str).unwrap();
+        assert!(back.tags.is_empty());
+        assert!(back.tools.is_empty());
+        assert!(back.capabilities.tools.is_empty());
+    }
+
+    #[test]
+    fn test_manifest_toml_rejects_unrepresentable_metadata() {
+        let mut manifest = AgentManifest::default();
+        manifest
+            .metadata
+            .insert("missing".to_string(), serde_json::Value::Null);
+        assert!(manifest.to_toml_checked().is_err());
+    }
+
     // ----- ToolProfile tests -----
 
     #[test]
@@ -950,6 +1040,9 @@ mod tests {
             model: "llama-3.3-70b".to_string(),
             api_key_env: Some("GROQ_API_KEY".to_string()),
             base_url: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
         };
         let json = serde_json::to_string(&fb).unwrap();
         let back: FallbackModel = serde_json::from_str(&json).unwrap();
@@ -967,6 +1060,9 @@ mod tests {
                 model: "llama-3.3-70b".to_string(),
                 api_key_env: None,
                 base_url: None,
+                connect_timeout_secs: None,
+                request_timeout_secs: None,
+                stream_idle_timeout_secs: None,
             }],
             ..Default::default()
         };
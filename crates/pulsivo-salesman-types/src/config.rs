@@ -1,7 +1,8 @@
 //! Minimal configuration types for the sales-only PulsivoSalesman daemon.
 
-use crate::agent::ReasoningEffort;
+use crate::agent::{FallbackModel, ReasoningEffort};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// Kernel operating mode.
@@ -14,6 +15,17 @@ pub enum KernelMode {
     Dev,
 }
 
+/// Log output format, chosen once at startup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// Human-readable, ANSI-coloured lines.
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log shippers.
+    Json,
+}
+
 /// Web search provider selection.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -194,6 +206,34 @@ pub struct DefaultModelConfig {
     pub base_url: Option<String>,
     #[serde(default)]
     pub reasoning_effort: Option<ReasoningEffort>,
+    /// TCP/TLS connect timeout in seconds; unset or `0` keeps the driver
+    /// default (10).
+    #[serde(default)]
+    pub connect_timeout_secs: Option<u64>,
+    /// Overall per-request timeout in seconds, including the response body;
+    /// unset or `0` keeps the driver default (600).
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
+    /// Maximum gap between streamed chunks in seconds; unset or `0` keeps the
+    /// driver default (120).
+    #[serde(default)]
+    pub stream_idle_timeout_secs: Option<u64>,
+    /// `originator` header for the OpenAI Codex backend; unset keeps the
+    /// driver default (`pi`). `OPENAI_CODEX_ORIGINATOR` still wins.
+    #[serde(default)]
+    pub codex_originator: Option<String>,
+    /// `openai-beta` header for the OpenAI Codex backend; unset keeps the
+    /// driver default (`responses=experimental`).
+    #[serde(default)]
+    pub codex_openai_beta: Option<String>,
+    /// Responses API `truncation` strategy for the OpenAI Codex backend
+    /// (`auto` or `disabled`); unset omits the field.
+    #[serde(default)]
+    pub codex_truncation: Option<String>,
+    /// Models tried in order when the primary is unconfigured, rejects its
+    /// credentials or is overloaded.
+    #[serde(default)]
+    pub fallback_models: Vec<FallbackModel>,
 }
 
 impl Default for DefaultModelConfig {
@@ -204,6 +244,13 @@ impl Default for DefaultModelConfig {
             api_key_env: "OPENAI_CODEX_ACCESS_TOKEN".to_string(),
             base_url: None,
             reasoning_effort: Some(ReasoningEffort::High),
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+            codex_originator: None,
+            codex_openai_beta: None,
+            codex_truncation: None,
+            fallback_models: Vec::new(),
         }
     }
 }
@@ -242,6 +289,33 @@ impl Default for MemoryConfig {
     }
 }
 
+/// How the SMTP connection is secured.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpTlsMode {
+    /// Connect in plaintext and upgrade with STARTTLS (usually port 587).
+    #[default]
+    Starttls,
+    /// TLS from the first byte (usually port 465).
+    ImplicitTls,
+    /// No encryption. Only for local relays and test servers.
+    Plaintext,
+}
+
+impl SmtpTlsMode {
+    /// Reject combinations that cannot work, such as implicit TLS against a
+    /// port that only speaks plaintext or STARTTLS.
+    pub fn validate_port(self, port: u16) -> Result<(), String> {
+        if self == Self::ImplicitTls && matches!(port, 25 | 587 | 2525) {
+            return Err(format!(
+                "SMTP tls_mode 'implicit_tls' needs a TLS port such as 465, but smtp_port is {port}; \
+                 use tls_mode 'starttls' for port {port}"
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Outbound email channel configuration used by the sales engine.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -250,6 +324,30 @@ pub struct EmailConfig {
     pub smtp_port: u16,
     pub username: String,
     pub password_env: String,
+    /// `starttls` (default), `implicit_tls` or `plaintext`.
+    pub tls_mode: SmtpTlsMode,
+    /// Display name for the From header. Empty uses the sales profile's
+    /// `sender_name`.
+    pub from_name: String,
+    /// Reply-To address, e.g. a shared inbox. Empty sends replies to From.
+    pub reply_to: String,
+    /// Mailbox added as a `mailto:` entry to `List-Unsubscribe`, next to the
+    /// one-click suppression URL.
+    pub unsubscribe_mailto: String,
+    /// Extra headers added to every outbound email. Names must be printable
+    /// ASCII and may not override standard headers; values must be
+    /// single-line printable ASCII.
+    pub custom_headers: BTreeMap<String, String>,
+    /// Upper bound on the combined decoded size of one email's attachments.
+    /// The approval edit route accepts bodies this much larger (base64) than
+    /// `api_limits.max_body_bytes`, so `content_base64` attachments can reach
+    /// it; the route limit is fixed at startup. `path` attachments are read
+    /// from `<home>/attachments`, which the API does not write to: operators
+    /// place files there themselves.
+    pub max_attachment_bytes: u64,
+    /// Extra attempts after a transient SMTP failure (4xx reply, timeout or
+    /// dropped connection), with doubling backoff from one second.
+    pub send_retries: u32,
 }
 
 impl Default for EmailConfig {
@@ -259,7 +357,43 @@ impl Default for EmailConfig {
             smtp_port: 587,
             username: String::new(),
             password_env: "EMAIL_PASSWORD".to_string(),
+            tls_mode: SmtpTlsMode::default(),
+            from_name: String::new(),
+            reply_to: String::new(),
+            unsubscribe_mailto: String::new(),
+            custom_headers: BTreeMap::new(),
+            max_attachment_bytes: 10 * 1024 * 1024,
+            send_retries: 2,
+        }
+    }
+}
+
+impl EmailConfig {
+    /// Field-level problems that would make every send fail, each prefixed
+    /// with the offending `email.<field>` path. The secret env var itself is
+    /// not checked here since it depends on the running process.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.smtp_host.trim().is_empty() {
+            errors.push("email.smtp_host is required".to_string());
+        } else if self.smtp_host.trim().contains(char::is_whitespace) {
+            errors.push("email.smtp_host must be a host name without spaces".to_string());
         }
+        if self.smtp_port == 0 {
+            errors.push("email.smtp_port must be 1-65535".to_string());
+        } else if let Err(e) = self.tls_mode.validate_port(self.smtp_port) {
+            errors.push(format!("email.tls_mode: {e}"));
+        }
+        if self.username.trim().is_empty() {
+            errors.push("email.username is required".to_string());
+        }
+        if self.password_env.trim().is_empty() {
+            errors.push("email.password_env is required".to_string());
+        }
+        if self.from_name.chars().any(char::is_control) {
+            errors.push("email.from_name contains control characters".to_string());
+        }
+        errors
     }
 }
 
@@ -268,6 +402,288 @@ impl Default for EmailConfig {
 #[serde(default)]
 pub struct ChannelsConfig {
     pub email: Option<EmailConfig>,
+    /// Outbound sandbox for staging: deliveries are recorded as `sandboxed`
+    /// and real prospects are never contacted.
+    pub sandbox: OutboundSandboxConfig,
+}
+
+/// Outbound sandbox settings. `PULSIVO_SALESMAN_SALES_SANDBOX=1` also enables
+/// sandbox mode, and `PULSIVO_SALESMAN_SALES_SANDBOX_REDIRECT` overrides
+/// `redirect_to`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OutboundSandboxConfig {
+    pub enabled: bool,
+    /// Test address that receives sandboxed emails. Empty skips the SMTP
+    /// send entirely.
+    pub redirect_to: String,
+}
+
+/// A named API bearer token restricted to a set of scopes.
+///
+/// Scopes gate route groups: `sales` covers `/api/sales/*`, `admin` covers the
+/// remaining protected routes, and `*` grants everything.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiTokenConfig {
+    /// Principal name reported in logs and request extensions.
+    pub name: String,
+    /// Bearer token value.
+    pub token: String,
+    /// Scopes granted to this token.
+    pub scopes: Vec<String>,
+}
+
+impl std::fmt::Debug for ApiTokenConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiTokenConfig")
+            .field("name", &self.name)
+            .field("token", &"<redacted>")
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
+
+/// Cross-origin access for browser clients hosted apart from the daemon.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CorsConfig {
+    /// Allowed origins; empty keeps the built-in localhost origins, `*` allows any.
+    pub allowed_origins: Vec<String>,
+    /// Allowed methods; empty allows any.
+    pub allowed_methods: Vec<String>,
+    /// Allowed request headers; empty allows any.
+    pub allowed_headers: Vec<String>,
+    /// Send `Access-Control-Allow-Credentials: true` (ignored with a `*` origin).
+    pub allow_credentials: bool,
+}
+
+/// Limits applied by the HTTP API to protect providers and daemon memory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiLimitsConfig {
+    /// Maximum number of LLM-backed runs (autofill, discovery jobs) executing at once.
+    pub max_concurrent_runs: usize,
+    /// Maximum number of runs waiting for a free slot before new ones are rejected with 503.
+    pub max_queued_runs: usize,
+    /// `Retry-After` value (seconds) sent when the run queue is full.
+    pub queue_retry_after_secs: u64,
+    /// Default maximum request body size in bytes (1 MiB). Oversized bodies get a JSON 413.
+    pub max_body_bytes: usize,
+}
+
+impl Default for ApiLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_runs: 4,
+            max_queued_runs: 16,
+            queue_retry_after_secs: 15,
+            max_body_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Token price override for one model, in USD per million tokens.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelPricingConfig {
+    /// Model id (case-insensitive), e.g. `gpt-5-codex`.
+    pub model: String,
+    /// USD per million input tokens.
+    pub input_cost_per_m: f64,
+    /// USD per million output tokens.
+    pub output_cost_per_m: f64,
+}
+
+/// Prometheus metrics endpoint settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Serve `/metrics` and `/api/metrics` to loopback callers without a token
+    /// even when API keys are configured. Remote callers always need the `admin` scope.
+    pub loopback_public: bool,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            loopback_public: true,
+        }
+    }
+}
+
+/// Conversation trimming applied to LLM requests before they are sent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContextTrimConfig {
+    /// Keep at most this many non-system messages (0 = no count limit).
+    pub max_messages: usize,
+    /// Estimated input-token budget (0 = model context window minus `max_tokens`).
+    pub max_input_tokens: u64,
+}
+
+/// Knobs for the sales LLM calls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SalesLlmConfig {
+    /// Follow-up calls asking the model to repair unparseable profile
+    /// autofill output before giving up (0 disables repair, max 5).
+    pub autofill_repair_attempts: u32,
+    /// Wall-clock limit per LLM turn, fallbacks included (0 = unbounded).
+    pub turn_timeout_secs: u64,
+    /// Sampling for the lead discovery query planner.
+    pub query_plan: SalesLlmStepConfig,
+    /// Sampling for LLM company candidate generation.
+    pub company_candidates: SalesLlmStepConfig,
+    /// Sampling for onboarding profile autofill.
+    pub profile_autofill: SalesLlmStepConfig,
+}
+
+impl Default for SalesLlmConfig {
+    fn default() -> Self {
+        Self {
+            autofill_repair_attempts: 1,
+            turn_timeout_secs: 300,
+            query_plan: SalesLlmStepConfig::default(),
+            company_candidates: SalesLlmStepConfig::default(),
+            profile_autofill: SalesLlmStepConfig::default(),
+        }
+    }
+}
+
+/// Per-step overrides for a sales LLM call. Unset fields keep the step's
+/// built-in value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SalesLlmStepConfig {
+    /// Sampling temperature, clamped to `0.0..=2.0`.
+    pub temperature: Option<f32>,
+    /// Output token budget, capped at the model's output limit.
+    pub max_tokens: Option<u32>,
+}
+
+impl SalesLlmStepConfig {
+    fn clamp_bounds(&mut self) {
+        self.temperature = self
+            .temperature
+            .filter(|t| t.is_finite())
+            .map(|t| t.clamp(0.0, 2.0));
+        self.max_tokens = self.max_tokens.filter(|&n| n > 0);
+    }
+}
+
+/// Settings applied to every outbound HTTP client (providers, OAuth, web
+/// search/fetch and sales enrichment).
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpClientConfig {
+    /// `User-Agent` sent on all outbound requests. Empty uses
+    /// `PulsivoSalesman/<version>` for provider and OAuth traffic and the
+    /// built-in crawler/browser agents for web search and website fetches.
+    pub user_agent: String,
+    /// Proxy URL for all outbound traffic, e.g. `http://proxy.corp:3128`.
+    /// Empty falls back to the `HTTPS_PROXY` / `HTTP_PROXY` / `ALL_PROXY`
+    /// environment variables.
+    pub proxy: String,
+    /// Comma-separated hosts, domains or CIDRs that bypass `proxy`. Empty
+    /// falls back to `NO_PROXY`.
+    pub no_proxy: String,
+}
+
+/// SECURITY: Custom Debug impl redacts the proxy URL, which may carry
+/// credentials.
+impl std::fmt::Debug for HttpClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpClientConfig")
+            .field("user_agent", &self.user_agent)
+            .field(
+                "proxy",
+                &if self.proxy.is_empty() {
+                    ""
+                } else {
+                    "<redacted>"
+                },
+            )
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+/// Fixed text wrapped around every LLM request's system prompt.
+///
+/// Used for compliance disclaimers that must reach the model on every turn,
+/// regardless of provider or of the caller's own system prompt.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SystemPromptConfig {
+    /// Inserted before the caller's system prompt (empty = none).
+    pub prepend_system: String,
+    /// Inserted after the caller's system prompt (empty = none).
+    pub append_system: String,
+}
+
+/// Opt-in cache for deterministic (`temperature == 0`) LLM responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LlmCacheConfig {
+    /// Serve identical deterministic requests from memory.
+    pub enabled: bool,
+    /// Seconds a cached response stays valid.
+    pub ttl_secs: u64,
+    /// Maximum cached responses; the oldest entry is evicted when full.
+    pub max_entries: usize,
+}
+
+impl Default for LlmCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_secs: 3600,
+            max_entries: 256,
+        }
+    }
+}
+
+/// Opt-in pool of pre-built LLM drivers, warmed at startup and after auth
+/// changes so the first sales call skips client construction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LlmDriverPoolConfig {
+    /// Reuse warmed drivers instead of building one per call.
+    pub enabled: bool,
+    /// Maximum pooled drivers; the least recently used one is dropped when full.
+    pub max_drivers: usize,
+}
+
+impl Default for LlmDriverPoolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_drivers: 4,
+        }
+    }
+}
+
+/// OAuth login settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OAuthConfig {
+    /// Redirect URIs the OAuth start endpoint may use. Empty allows only the
+    /// built-in loopback default (`http://localhost:1455/auth/callback`) and
+    /// `OPENAI_OAUTH_REDIRECT_URI` when set.
+    pub redirect_uri_allowlist: Vec<String>,
+    /// Pick up `~/.codex/auth.json` automatically when no stored Codex auth
+    /// exists. The explicit import endpoint works either way.
+    pub import_codex_cli_auth: bool,
+}
+
+impl Default for OAuthConfig {
+    fn default() -> Self {
+        Self {
+            redirect_uri_allowlist: Vec::new(),
+            import_codex_cli_auth: true,
+        }
+    }
 }
 
 /// Top-level kernel configuration for the sales daemon.
@@ -280,13 +696,51 @@ pub struct KernelConfig {
     pub data_dir: PathBuf,
     /// Log level (trace, debug, info, warn, error).
     pub log_level: String,
+    /// Log output format (`pretty` or `json`). Changing it requires a restart.
+    pub log_format: LogFormat,
     /// API listen address.
     #[serde(alias = "listen_addr")]
     pub api_listen: String,
     /// API authentication key.
     pub api_key: String,
+    /// Additional scoped bearer tokens for team access.
+    #[serde(default)]
+    pub api_tokens: Vec<ApiTokenConfig>,
+    /// CORS settings for the HTTP API.
+    #[serde(default)]
+    pub cors: CorsConfig,
+    /// API concurrency and request limits.
+    #[serde(default)]
+    pub api_limits: ApiLimitsConfig,
+    /// Metrics endpoint settings.
+    #[serde(default)]
+    pub metrics: MetricsConfig,
     /// Default model configuration.
     pub default_model: DefaultModelConfig,
+    /// Per-model token prices used for cost estimates; entries override the
+    /// built-in model catalog prices.
+    #[serde(default)]
+    pub model_pricing: Vec<ModelPricingConfig>,
+    /// Conversation trimming policy for LLM requests.
+    #[serde(default)]
+    pub context_trim: ContextTrimConfig,
+    /// Response cache for deterministic LLM requests.
+    #[serde(default)]
+    pub llm_cache: LlmCacheConfig,
+    /// Pre-built driver pool for sales LLM calls.
+    #[serde(default)]
+    pub llm_driver_pool: LlmDriverPoolConfig,
+    /// Compliance text wrapped around every system prompt.
+    #[serde(default)]
+    pub system_prompt: SystemPromptConfig,
+    #[serde(default)]
+    pub sales_llm: SalesLlmConfig,
+    /// User agent and proxy for outbound HTTP clients.
+    #[serde(default)]
+    pub http: HttpClientConfig,
+    /// OAuth login settings.
+    #[serde(default)]
+    pub oauth: OAuthConfig,
     /// Memory substrate configuration.
     pub memory: MemoryConfig,
     /// Web search/fetch configuration.
@@ -304,6 +758,11 @@ pub struct KernelConfig {
     /// Informational language tag.
     #[serde(default = "default_language")]
     pub language: String,
+    /// Air-gapped mode: web discovery runs, outbound sends, channel probes and
+    /// OAuth/provider calls fail fast, and sales LLM calls only use local
+    /// providers (ollama, vllm, lmstudio).
+    #[serde(default)]
+    pub offline: bool,
     /// Config include files loaded before the root file.
     #[serde(default)]
     pub include: Vec<String>,
@@ -320,15 +779,29 @@ impl Default for KernelConfig {
             home_dir: home_dir.clone(),
             data_dir: home_dir.join("data"),
             log_level: "info".to_string(),
+            log_format: LogFormat::default(),
             api_listen: "127.0.0.1:50051".to_string(),
             api_key: String::new(),
+            api_tokens: Vec::new(),
+            cors: CorsConfig::default(),
+            api_limits: ApiLimitsConfig::default(),
+            metrics: MetricsConfig::default(),
             default_model: DefaultModelConfig::default(),
+            model_pricing: Vec::new(),
+            context_trim: ContextTrimConfig::default(),
+            llm_cache: LlmCacheConfig::default(),
+            llm_driver_pool: LlmDriverPoolConfig::default(),
+            system_prompt: SystemPromptConfig::default(),
+            sales_llm: SalesLlmConfig::default(),
+            http: HttpClientConfig::default(),
+            oauth: OAuthConfig::default(),
             memory: MemoryConfig::default(),
             web: WebConfig::default(),
             channels: ChannelsConfig::default(),
             reload: ReloadConfig::default(),
             mode: KernelMode::default(),
             language: default_language(),
+            offline: false,
             include: Vec::new(),
         }
     }
@@ -340,23 +813,43 @@ impl KernelConfig {
         let mut warnings = Vec::new();
 
         if let Some(ref email) = self.channels.email {
-            if email.smtp_host.trim().is_empty() {
-                warnings.push("Email channel configured but smtp_host is empty".to_string());
+            warnings.extend(email.validate());
+            if !email.password_env.trim().is_empty()
+                && std::env::var(email.password_env.trim())
+                    .unwrap_or_default()
+                    .is_empty()
+            {
+                warnings.push(format!(
+                    "email.password_env: env var {} is not set",
+                    email.password_env.trim()
+                ));
             }
-            if email.username.trim().is_empty() {
-                warnings.push("Email channel configured but username is empty".to_string());
+        }
+
+        for token in &self.api_tokens {
+            if token.token.trim().is_empty() {
+                warnings.push(format!("API token '{}' has an empty token", token.name));
             }
-            if std::env::var(&email.password_env)
-                .unwrap_or_default()
-                .is_empty()
-            {
+            if token.scopes.is_empty() {
                 warnings.push(format!(
-                    "Email channel configured but {} is not set",
-                    email.password_env
+                    "API token '{}' has no scopes and will be rejected",
+                    token.name
                 ));
             }
         }
 
+        if self.offline
+            && !self.default_model.fallback_models.iter().any(|fallback| {
+                matches!(fallback.provider.as_str(), "ollama" | "vllm" | "lmstudio")
+            })
+        {
+            warnings.push(
+                "Offline mode is enabled but default_model.fallback_models has no local provider \
+                 (ollama, vllm, lmstudio); sales LLM steps will fall back to heuristics"
+                    .to_string(),
+            );
+        }
+
         match self.web.search_provider {
             SearchProvider::Brave => {
                 if std::env::var(&self.web.brave.api_key_env)
@@ -414,6 +907,33 @@ impl KernelConfig {
         } else if self.web.fetch.timeout_secs > 120 {
             self.web.fetch.timeout_secs = 120;
         }
+
+        if self.api_limits.max_concurrent_runs == 0 {
+            self.api_limits.max_concurrent_runs = 1;
+        }
+
+        if self.api_limits.max_body_bytes == 0 {
+            self.api_limits.max_body_bytes = ApiLimitsConfig::default().max_body_bytes;
+        }
+
+        if self.llm_cache.max_entries == 0 {
+            self.llm_cache.max_entries = LlmCacheConfig::default().max_entries;
+        } else if self.llm_cache.max_entries > 10_000 {
+            self.llm_cache.max_entries = 10_000;
+        }
+
+        if self.llm_driver_pool.max_drivers == 0 {
+            self.llm_driver_pool.max_drivers = LlmDriverPoolConfig::default().max_drivers;
+        } else if self.llm_driver_pool.max_drivers > 64 {
+            self.llm_driver_pool.max_drivers = 64;
+        }
+
+        if self.sales_llm.autofill_repair_attempts > 5 {
+            self.sales_llm.autofill_repair_attempts = 5;
+        }
+        self.sales_llm.query_plan.clamp_bounds();
+        self.sales_llm.company_candidates.clamp_bounds();
+        self.sales_llm.profile_autofill.clamp_bounds();
     }
 }
 
@@ -423,6 +943,7 @@ impl std::fmt::Debug for KernelConfig {
             .field("home_dir", &self.home_dir)
             .field("data_dir", &self.data_dir)
             .field("log_level", &self.log_level)
+            .field("log_format", &self.log_format)
             .field("api_listen", &self.api_listen)
             .field(
                 "api_key",
@@ -432,13 +953,20 @@ impl std::fmt::Debug for KernelConfig {
                     "<redacted>"
                 },
             )
+            .field("api_tokens", &self.api_tokens)
+            .field("cors", &self.cors)
+            .field("api_limits", &self.api_limits)
+            .field("metrics", &self.metrics)
             .field("default_model", &self.default_model)
+            .field("model_pricing", &self.model_pricing)
             .field("memory", &self.memory)
             .field("web", &self.web)
+            .field("http", &self.http)
             .field("channels", &self.channels)
             .field("reload", &self.reload)
             .field("mode", &self.mode)
             .field("language", &self.language)
+            .field("offline", &self.offline)
             .field("include", &format!("{} file(s)", self.include.len()))
             .finish()
     }
@@ -468,6 +996,32 @@ mod tests {
         assert!(toml_str.contains("default_model"));
     }
 
+    #[test]
+    fn test_default_model_codex_options_from_toml() {
+        let config: KernelConfig = toml::from_str(
+            r#"
+            [default_model]
+            codex_originator = "codex_cli_rs"
+            codex_openai_beta = "responses=v2"
+            codex_truncation = "auto"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.default_model.codex_originator.as_deref(),
+            Some("codex_cli_rs")
+        );
+        assert_eq!(
+            config.default_model.codex_openai_beta.as_deref(),
+            Some("responses=v2")
+        );
+        assert_eq!(
+            config.default_model.codex_truncation.as_deref(),
+            Some("auto")
+        );
+        assert_eq!(config.default_model.model, "gpt-5.3-codex");
+    }
+
     #[test]
     fn test_validate_no_channels() {
         let config = KernelConfig::default();
@@ -483,17 +1037,75 @@ mod tests {
             smtp_port: 587,
             username: "ops@example.com".to_string(),
             password_env: "PULSIVO_SALESMAN_TEST_NONEXISTENT_EMAIL".to_string(),
+            tls_mode: SmtpTlsMode::Starttls,
+            ..EmailConfig::default()
         });
         let warnings = config.validate();
         assert_eq!(warnings.len(), 1);
         assert!(warnings[0].contains("PULSIVO_SALESMAN_TEST_NONEXISTENT_EMAIL"));
     }
 
+    #[test]
+    fn test_validate_api_token_without_scopes() {
+        let mut config = KernelConfig::default();
+        config.api_tokens.push(ApiTokenConfig {
+            name: "ops".to_string(),
+            token: "secret".to_string(),
+            scopes: Vec::new(),
+        });
+        let warnings = config.validate();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'ops' has no scopes"));
+    }
+
+    #[test]
+    fn test_validate_offline_needs_local_fallback() {
+        let mut config = KernelConfig {
+            offline: true,
+            ..KernelConfig::default()
+        };
+        let warnings = config.validate();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Offline mode"));
+
+        config.default_model.fallback_models.push(FallbackModel {
+            provider: "ollama".to_string(),
+            model: "llama3.2".to_string(),
+            api_key_env: None,
+            base_url: None,
+            connect_timeout_secs: None,
+            request_timeout_secs: None,
+            stream_idle_timeout_secs: None,
+        });
+        assert!(config.validate().is_empty());
+    }
+
     #[test]
     fn test_email_config_defaults() {
         let config = EmailConfig::default();
         assert_eq!(config.smtp_port, 587);
         assert_eq!(config.password_env, "EMAIL_PASSWORD");
+        assert_eq!(config.tls_mode, SmtpTlsMode::Starttls);
+    }
+
+    #[test]
+    fn test_smtp_tls_mode_serde_and_port_check() {
+        let config: EmailConfig = toml::from_str(
+            r#"
+            smtp_host = "mail.example.com"
+            smtp_port = 465
+            username = "ops@example.com"
+            tls_mode = "implicit_tls"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.tls_mode, SmtpTlsMode::ImplicitTls);
+        assert!(config.tls_mode.validate_port(465).is_ok());
+
+        let err = SmtpTlsMode::ImplicitTls.validate_port(587).unwrap_err();
+        assert!(err.contains("implicit_tls"));
+        assert!(SmtpTlsMode::Starttls.validate_port(587).is_ok());
+        assert!(SmtpTlsMode::Plaintext.validate_port(25).is_ok());
     }
 
     #[test]
@@ -521,6 +1133,34 @@ mod tests {
         assert_eq!(config.web.fetch.timeout_secs, 30);
     }
 
+    #[test]
+    fn test_clamp_bounds_zero_concurrent_runs() {
+        let mut config = KernelConfig::default();
+        config.api_limits.max_concurrent_runs = 0;
+        config.clamp_bounds();
+        assert_eq!(config.api_limits.max_concurrent_runs, 1);
+    }
+
+    #[test]
+    fn test_clamp_bounds_zero_body_limit() {
+        let mut config = KernelConfig::default();
+        config.api_limits.max_body_bytes = 0;
+        config.clamp_bounds();
+        assert_eq!(config.api_limits.max_body_bytes, 1024 * 1024);
+    }
+
+    #[test]
+    fn test_clamp_bounds_sales_llm_steps() {
+        let mut config = KernelConfig::default();
+        config.sales_llm.query_plan.temperature = Some(3.5);
+        config.sales_llm.company_candidates.temperature = Some(f32::NAN);
+        config.sales_llm.profile_autofill.max_tokens = Some(0);
+        config.clamp_bounds();
+        assert_eq!(config.sales_llm.query_plan.temperature, Some(2.0));
+        assert_eq!(config.sales_llm.company_candidates.temperature, None);
+        assert_eq!(config.sales_llm.profile_autofill.max_tokens, None);
+    }
+
     #[test]
     fn test_clamp_bounds_defaults_unchanged() {
         let mut config = KernelConfig::default();
@@ -15,7 +15,8 @@ pub struct Message {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
-    /// System prompt.
+    /// System prompt. Also accepts OpenAI's newer `developer` role.
+    #[serde(alias = "developer")]
     System,
     /// Human user.
     User,
@@ -113,6 +114,48 @@ pub fn validate_image(media_type: &str, data: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// A base64 image attached to a chat request (e.g. a pasted screenshot).
+///
+/// `data` may be raw base64 or a `data:<media_type>;base64,...` URL; with a
+/// data URL, `media_type` may be left empty and is taken from the URL.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImageAttachment {
+    /// MIME type (e.g. "image/png").
+    #[serde(default)]
+    pub media_type: String,
+    /// Base64 image data or data URL.
+    pub data: String,
+}
+
+impl ImageAttachment {
+    /// Validate the attachment and convert it to a [`ContentBlock::Image`].
+    pub fn into_content_block(self) -> Result<ContentBlock, String> {
+        let (media_type, data) = match self.data.strip_prefix("data:") {
+            Some(rest) => {
+                let (header, payload) = rest
+                    .split_once(',')
+                    .ok_or_else(|| "Malformed image data URL".to_string())?;
+                let url_type = header
+                    .strip_suffix(";base64")
+                    .ok_or_else(|| "Image data URL must be base64-encoded".to_string())?;
+                let media_type = if self.media_type.is_empty() {
+                    url_type.to_string()
+                } else {
+                    self.media_type
+                };
+                (media_type, payload.to_string())
+            }
+            None => (self.media_type, self.data),
+        };
+        let media_type = media_type.trim().to_ascii_lowercase();
+        if data.trim().is_empty() {
+            return Err("Image attachment has no data".to_string());
+        }
+        validate_image(&media_type, &data)?;
+        Ok(ContentBlock::Image { media_type, data })
+    }
+}
+
 impl MessageContent {
     /// Create simple text content.
     pub fn text(content: impl Into<String>) -> Self {
@@ -170,6 +213,30 @@ impl Message {
         }
     }
 
+    /// Create a user message with image attachments.
+    ///
+    /// Falls back to plain text content when there are no attachments.
+    pub fn user_with_images(
+        content: impl Into<String>,
+        attachments: Vec<ImageAttachment>,
+    ) -> Result<Self, String> {
+        let text = content.into();
+        if attachments.is_empty() {
+            return Ok(Self::user(text));
+        }
+        let mut blocks = Vec::with_capacity(attachments.len() + 1);
+        if !text.is_empty() {
+            blocks.push(ContentBlock::Text { text });
+        }
+        for attachment in attachments {
+            blocks.push(attachment.into_content_block()?);
+        }
+        Ok(Self {
+            role: Role::User,
+            content: MessageContent::Blocks(blocks),
+        })
+    }
+
     /// Create an assistant message.
     pub fn assistant(content: impl Into<String>) -> Self {
         Self {
@@ -191,10 +258,13 @@ pub enum StopReason {
     MaxTokens,
     /// The model hit a stop sequence.
     StopSequence,
+    /// The turn ran past its wall-clock limit; content is whatever arrived
+    /// before the call was cancelled.
+    Timeout,
 }
 
 /// Token usage information from an LLM call.
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TokenUsage {
     /// Tokens used for the input/prompt.
     pub input_tokens: u64,
@@ -239,6 +309,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_developer_role_deserializes_as_system() {
+        let msg: Message =
+            serde_json::from_str(r#"{"role":"developer","content":"Be brief."}"#).unwrap();
+        assert_eq!(msg.role, Role::System);
+    }
+
+    #[test]
+    fn test_user_with_images_accepts_data_url() {
+        let msg = Message::user_with_images(
+            "What is on this screen?",
+            vec![ImageAttachment {
+                media_type: String::new(),
+                data: "data:image/png;base64,iVBORw0KGgo=".to_string(),
+            }],
+        )
+        .unwrap();
+        match msg.content {
+            MessageContent::Blocks(blocks) => {
+                assert_eq!(blocks.len(), 2);
+                match &blocks[1] {
+                    ContentBlock::Image { media_type, data } => {
+                        assert_eq!(media_type, "image/png");
+                        assert_eq!(data, "iVBORw0KGgo=");
+                    }
+                    other => panic!("Expected image block, got {other:?}"),
+                }
+            }
+            _ => panic!("Expected blocks content"),
+        }
+    }
+
+    #[test]
+    fn test_user_with_images_rejects_unsupported_type() {
+        let err = Message::user_with_images(
+            "hi",
+            vec![ImageAttachment {
+                media_type: "image/svg+xml".to_string(),
+                data: "PHN2Zz4=".to_string(),
+            }],
+        )
+        .unwrap_err();
+        assert!(err.contains("Unsupported image type 'image/svg+xml'"));
+    }
+
     #[test]
     fn test_token_usage() {
         let usage = TokenUsage {